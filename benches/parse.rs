@@ -0,0 +1,39 @@
+//! Criterion benchmarks for the end-to-end parse path.
+//!
+//! Run with `cargo bench`. Inputs are shared with the CLI `--bench` mode so
+//! numbers from both are comparable.
+
+use astorion::{Context, Options, parse_with};
+use chrono::NaiveDateTime;
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+#[path = "../src/bench_inputs.rs"]
+mod bench_inputs;
+
+fn reference_context() -> Context {
+    let reference_time = NaiveDateTime::parse_from_str("2013-02-12T04:30:00", "%Y-%m-%dT%H:%M:%S").unwrap();
+    Context { reference_time: Some(reference_time), ..Default::default() }
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let ctx = reference_context();
+    let opts = Options::default();
+
+    // Force lazy regex compilation outside of the measured loop.
+    for (_, input) in bench_inputs::CASES {
+        parse_with(input, &ctx, &opts);
+    }
+
+    // The long inputs take hundreds of milliseconds per parse; keep the sample
+    // count at criterion's minimum so the suite finishes in reasonable time.
+    let mut group = c.benchmark_group("parse");
+    group.sample_size(10);
+    for (name, input) in bench_inputs::CASES {
+        group.bench_function(*name, |b| b.iter(|| parse_with(black_box(input), &ctx, &opts)));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
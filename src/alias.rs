@@ -0,0 +1,181 @@
+//! Domain vocabulary for times.
+//!
+//! Teams have words for times the rules cannot know: "standup" is 9:30am,
+//! "sprint end" is whatever date the tracker says. An [`Alias`] in
+//! [`Options::aliases`] makes such a phrase a time during parsing, so it
+//! combines with the words around it ("standup tomorrow", "the day after
+//! sprint end") like any other. Entities built on an alias carry
+//! `alias:<phrase>` in [`Entity::hints`](crate::Entity::hints).
+
+use crate::api::parser_for;
+use crate::preprocess::whole_words;
+use crate::time_expr::TimeExpr;
+use crate::{Context, Dimension, Node, Options, Precision, Range, Token, TokenKind};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// Name of the rule reported for alias matches.
+pub(crate) const ALIAS_RULE: &str = "alias";
+
+/// A phrase standing for a time, set through
+/// [`Options::with_alias`].
+#[derive(Clone)]
+pub struct Alias {
+    phrase: String,
+    target: Target,
+}
+
+#[derive(Clone)]
+enum Target {
+    Text(String),
+    Date(Arc<dyn Fn(NaiveDateTime) -> Option<NaiveDate> + Send + Sync>),
+}
+
+impl Alias {
+    /// `phrase` means what the rules read in `text`:
+    /// `Alias::text("standup", "9:30am")`.
+    pub fn text(phrase: impl Into<String>, text: impl Into<String>) -> Self {
+        Self { phrase: phrase.into().to_ascii_lowercase(), target: Target::Text(text.into()) }
+    }
+
+    /// `phrase` means the date `provider` gives for the reference time; it
+    /// is left unmatched when the provider has none, or there is no
+    /// reference time.
+    pub fn date(
+        phrase: impl Into<String>,
+        provider: impl Fn(NaiveDateTime) -> Option<NaiveDate> + Send + Sync + 'static,
+    ) -> Self {
+        Self { phrase: phrase.into().to_ascii_lowercase(), target: Target::Date(Arc::new(provider)) }
+    }
+
+    /// The phrase, lowercased.
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Text activating the rules that combine with what this alias stands
+    /// for, e.g. those for dates and times of day with digits.
+    fn stand_in(&self) -> &str {
+        match &self.target {
+            Target::Text(text) => text,
+            Target::Date(_) => "2000-01-01",
+        }
+    }
+
+    /// The time this alias stands for, and its precision.
+    fn expr(&self, context: &Context, options: &Options) -> Option<(TimeExpr, Precision)> {
+        match &self.target {
+            Target::Text(text) => {
+                let options = Options { aliases: Vec::new(), ..options.clone() };
+                let span = text.len() - text.trim_start().len()..text.trim_end().len();
+                parser_for(text, &options).run(context, &options).into_iter().find_map(|rt| {
+                    let covers = rt.node.range.start <= span.start && rt.node.range.end >= span.end;
+                    match rt.node.token.kind {
+                        TokenKind::TimeExpr(expr) if covers => Some((expr, rt.node.token.precision)),
+                        _ => None,
+                    }
+                })
+            }
+            Target::Date(provider) => {
+                let date = provider(context.reference_time?)?;
+                let (year, month, day) = (date.year(), date.month(), date.day());
+                Some((TimeExpr::Absolute { year, month, day, hour: None, minute: None }, Precision::Exact))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Alias {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let target = match &self.target {
+            Target::Text(text) => text.as_str(),
+            Target::Date(_) => "<provider>",
+        };
+        f.debug_struct("Alias").field("phrase", &self.phrase).field("target", &target).finish()
+    }
+}
+
+/// `input` followed by stand-ins for the aliases of `options` in it, to
+/// scan for the rules to activate.
+pub(crate) fn trigger_text<'a>(input: &'a str, options: &Options) -> Cow<'a, str> {
+    let lower = input.to_ascii_lowercase();
+    let mut text = Cow::Borrowed(input);
+    for alias in options.aliases.iter().filter(|alias| whole_words(&lower, &alias.phrase).next().is_some()) {
+        let text = text.to_mut();
+        text.push('\n');
+        text.push_str(alias.stand_in());
+    }
+    text
+}
+
+/// Time nodes for the aliases of `options` found in `input`, to seed the
+/// parse with.
+pub(crate) fn alias_nodes(input: &str, context: &Context, options: &Options) -> Vec<Node> {
+    if options.aliases.is_empty() {
+        return Vec::new();
+    }
+    let lower = input.to_ascii_lowercase();
+    let mut nodes = Vec::new();
+    for alias in &options.aliases {
+        let mut found = whole_words(&lower, &alias.phrase).peekable();
+        if found.peek().is_none() {
+            continue;
+        }
+        let Some((expr, precision)) = alias.expr(context, options) else { continue };
+        nodes.extend(found.map(|range| Node {
+            range: Range { start: range.start, end: range.end },
+            token: Token { dim: Dimension::Time, kind: TokenKind::TimeExpr(expr.clone()), precision },
+            rule_name: ALIAS_RULE,
+            evidence: Vec::new(),
+            children: Arc::from([]),
+        }));
+    }
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_with;
+    use chrono::Duration;
+
+    #[test]
+    fn aliases_are_times_that_combine_with_their_context() {
+        let reference = NaiveDate::from_ymd_opt(2013, 2, 12).and_then(|d| d.and_hms_opt(4, 30, 0));
+        let context = Context { reference_time: reference, ..Default::default() };
+        let mut options = Options::default().with_alias(Alias::text("standup", "9:30am"));
+        options.add_alias(Alias::date("sprint end", |reference| Some(reference.date() + Duration::days(10))));
+        let summary = |text: &str, options: &Options| -> Vec<(String, String, Vec<String>)> {
+            let result = parse_with(text, &context, options);
+            let mut found: Vec<_> =
+                result.results.into_iter().filter(|e| e.name == "time").map(|e| (e.body, e.value, e.hints)).collect();
+            // Rules combining a time of day and a date from either side agree.
+            found.dedup();
+            found
+        };
+        let hints = |phrase: &str| vec![format!("alias:{phrase}")];
+
+        assert_eq!(summary("see you at standup", &Options::default()), []);
+        assert_eq!(
+            summary("see you at Standup", &options),
+            [("at Standup".into(), "2013-02-12 09:30:00".into(), hints("standup"))]
+        );
+        assert_eq!(
+            summary("standup tomorrow", &options),
+            [("standup tomorrow".into(), "2013-02-13 09:30:00".into(), hints("standup"))]
+        );
+        assert_eq!(
+            summary("demo at sprint end", &options),
+            [("sprint end".into(), "2013-02-22 00:00:00".into(), hints("sprint end"))]
+        );
+        assert_eq!(
+            summary("the day after sprint end", &options),
+            [("the day after sprint end".into(), "2013-02-23 00:00:00".into(), hints("sprint end"))]
+        );
+
+        // Only whole words count.
+        assert_eq!(summary("standups are short", &options), []);
+    }
+}
@@ -1,11 +1,129 @@
+use crate::alias::{ALIAS_RULE, trigger_text};
 use crate::engine;
-use crate::engine::RegexProfileSummary;
-use crate::{Dimension, ResolvedToken, Rule};
-use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
+use crate::engine::{CompiledRules, InternalError, RegexProfileSummary, TriggerInfo};
+use crate::locale::apply_fallback;
+use crate::{Alias, BusinessCalendar, Daylight, FallbackLocale, Preprocessor};
+use crate::{CanonicalFormat, Dimension, Identifier, Numeral, ResolvedToken, Rule, Time, TimeExpr, TimeValue, Value};
+use chrono::{Month, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
 use once_cell::sync::Lazy;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
 use std::time::Duration;
 
 static DEFAULT_RULES: Lazy<Vec<Rule>> = Lazy::new(crate::rules::time::rules::get);
+static TRANSPORT_RULES: Lazy<Vec<Rule>> = Lazy::new(|| {
+    let mut rules = crate::rules::time::rules::get();
+    rules.extend(crate::rules::time::rules::transport());
+    rules
+});
+
+/// The rule set selected by `options`.
+fn rules_for(options: &Options) -> &'static [Rule] {
+    if let Some(set) = options.rule_set {
+        return set.rules;
+    }
+    if options.transport_shorthand { &TRANSPORT_RULES } else { &DEFAULT_RULES }
+}
+
+/// A rule set to parse with in place of the built-in one, set through
+/// [`Options::with_rule_set`]. With the `rule-files` feature,
+/// `RuleSet::from_toml` builds one from the default rules plus a
+/// declarative rule file.
+#[derive(Clone, Copy)]
+pub struct RuleSet {
+    pub(crate) rules: &'static [Rule],
+}
+
+impl RuleSet {
+    /// Number of rules in the set.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+impl std::fmt::Debug for RuleSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RuleSet").field("rules", &self.rules.len()).finish()
+    }
+}
+
+/// A parser over the rule set selected by `options`, minus disabled groups.
+pub(crate) fn parser_for<'a>(text: &'a str, options: &Options) -> engine::Parser<'a> {
+    let mut parser = match trigger_text(text, options) {
+        Cow::Borrowed(_) => engine::Parser::new(text, rules_for(options)),
+        Cow::Owned(scanned) => {
+            engine::Parser::new_scanned(text, CompiledRules::new(rules_for(options)), &TriggerInfo::scan(&scanned))
+        }
+    };
+    parser.disable_groups(options.disabled_groups);
+    parser
+}
+
+/// Names of the rules a parse of `text` activates.
+pub(crate) fn active_rules(text: &str, options: &Options) -> Vec<&'static str> {
+    parser_for(options.prepare(text).text(), options).active_rule_names()
+}
+
+/// Parse `window` of `document` as [`parse_with`] parses a whole text, but
+/// with the rules a parse of the whole document would activate. Spans are
+/// relative to the window.
+pub(crate) fn parse_window(document: &str, window: Range<usize>, context: &Context, options: &Options) -> ParseResult {
+    let admitted = options.admit(document);
+    let (text, blanked) = if admitted.is_empty() { ("", "") } else { (&document[window.clone()], &admitted[window]) };
+    let prepared = options.rewrite(Cow::Borrowed(blanked));
+    let scanned = options.rewrite(admitted.clone());
+    let mut parser = engine::Parser::new_scanned(
+        prepared.text(),
+        CompiledRules::new(rules_for(options)),
+        &TriggerInfo::scan(&trigger_text(scanned.text(), options)),
+    );
+    parser.disable_groups(options.disabled_groups);
+    let run = parser.run_with_metrics(context, options);
+
+    let mut result = ParseResult {
+        text: text.to_string(),
+        results: run.tokens.iter().map(|rt| resolved_to_entity(text, rt, &prepared)).collect(),
+        elapsed: run.metrics.total,
+    };
+    apply_fallback(&mut result, context, options);
+    result
+}
+
+/// The input as the rules see it, with the rewrites that made it.
+struct Prepared<'a> {
+    admitted: Cow<'a, str>,
+    /// One per preprocessor that changed the text, in order.
+    layers: Vec<engine::Corrections>,
+    typos: Option<engine::Corrections>,
+}
+
+impl Prepared<'_> {
+    /// The text the rules run on.
+    fn text(&self) -> &str {
+        match (&self.typos, self.layers.last()) {
+            (Some(typos), _) => &typos.text,
+            (None, Some(layer)) => &layer.text,
+            (None, None) => &self.admitted,
+        }
+    }
+
+    /// `span` of [`text`](Self::text) mapped back to `input`, and whether
+    /// it covers a spelling correction.
+    fn to_input(&self, input: &str, span: Range<usize>) -> (Range<usize>, bool) {
+        let span = self.typos.as_ref().map_or(span.clone(), |c| c.to_original(span));
+        let corrected = self.typos.as_ref().is_some_and(|c| c.touches(&span));
+        let span = self.layers.iter().rev().fold(span, |span, layer| layer.to_original(span));
+        (engine::snap(input, span), corrected)
+    }
+}
 
 /// Parsing context.
 ///
@@ -13,17 +131,79 @@ static DEFAULT_RULES: Lazy<Vec<Rule>> = Lazy::new(crate::rules::time::rules::get
 #[derive(Debug, Clone)]
 pub struct Context {
     /// Reference datetime used to resolve relative expressions.
-    pub reference_time: NaiveDateTime,
+    ///
+    /// `None` when there is no meaningful "now": times are still detected,
+    /// but come back as [`Value::Symbolic`] (as with [`Options::resolve`]
+    /// disabled) for [`Entity::resolve_with`] to resolve once a reference is
+    /// known, and [`normalize`] returns `None`.
+    pub reference_time: Option<NaiveDateTime>,
+    /// Timezone `reference_time` is wall-clock time in, if known; UTC is
+    /// assumed otherwise. [`Options::local_timezone`] takes precedence.
+    ///
+    /// When set, hour/minute/second shifts ("in 24 hours") are applied in
+    /// absolute time across DST transitions, while day and larger shifts keep
+    /// the wall-clock time. Times stated in another zone are converted into it.
+    pub timezone: Option<Tz>,
+    /// Business days and hours used by "in 3 business days", "EOD Friday", ...
+    pub calendar: BusinessCalendar,
+    /// Hemisphere whose seasons "summer", "early winter", ... refer to.
+    pub hemisphere: Hemisphere,
+    /// Location and calculator for "sunrise", "at dusk", ...
+    pub daylight: Daylight,
+}
+
+impl Context {
+    /// Interpret the reference time as wall-clock time in `timezone`.
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Use `calendar` to count business days.
+    pub fn with_calendar(mut self, calendar: BusinessCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Resolve seasons for `hemisphere`.
+    pub fn with_hemisphere(mut self, hemisphere: Hemisphere) -> Self {
+        self.hemisphere = hemisphere;
+        self
+    }
+
+    /// Work out sunrise and sunset with `daylight`.
+    pub fn with_daylight(mut self, daylight: Daylight) -> Self {
+        self.daylight = daylight;
+        self
+    }
+
+    /// The zone resolution runs in: the override in `options`, else
+    /// [`Context::timezone`]. `None` is UTC.
+    pub(crate) fn local_timezone(&self, options: &Options) -> Option<Tz> {
+        options.local_timezone.or(self.timezone)
+    }
+}
+
+/// The current time as wall-clock time in UTC, the zone a context without
+/// [`Context::timezone`] resolves in.
+fn utc_now() -> NaiveDateTime {
+    Utc::now().naive_utc()
 }
 
 impl Default for Context {
+    /// The current time in UTC, with no timezone (so UTC) to go with it.
     fn default() -> Self {
-        if cfg!(test) {
-            let date = NaiveDate::from_ymd_opt(2013, 2, 12).unwrap();
-            let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
-            Self { reference_time: NaiveDateTime::new(date, time) }
+        let reference_time = if cfg!(test) {
+            NaiveDateTime::new(NaiveDate::from_ymd_opt(2013, 2, 12).unwrap(), NaiveTime::from_hms_opt(0, 0, 0).unwrap())
         } else {
-            Self { reference_time: Local::now().naive_local() }
+            utc_now()
+        };
+        Self {
+            reference_time: Some(reference_time),
+            timezone: None,
+            calendar: BusinessCalendar::default(),
+            hemisphere: Hemisphere::default(),
+            daylight: Daylight::default(),
         }
     }
 }
@@ -31,10 +211,139 @@ impl Default for Context {
 /// Options that affect parsing/resolution behavior.
 ///
 /// This now includes optional regex profiling controls.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct Options {
     /// Regex profiling configuration (disabled by default).
     pub regex_profiling: RegexProfilingOptions,
+    /// How overlapping candidates of the same dimension are reduced.
+    pub overlap_policy: OverlapPolicy,
+    /// Drop numerals fully covered by an entity of another dimension, such as
+    /// the `5` in "at 5pm" (disabled by default).
+    pub suppress_covered_numerals: bool,
+    /// Resolve dates without a year ("June 14") to a [`PartialDate`] instead
+    /// of guessing the next occurrence (disabled by default).
+    pub partial_dates: bool,
+    /// First month of the fiscal year used by "FY2025" and "Q2 FY25"; `None`
+    /// means fiscal years are calendar years. A fiscal year starting mid-year
+    /// is named after the year it ends in (October start: FY2025 begins
+    /// October 2024).
+    pub fiscal_year_start: Option<Month>,
+    /// Resolve time expressions against the reference (enabled by default).
+    /// When disabled, times are returned as [`Value::Symbolic`], to be stored
+    /// and resolved later against another reference.
+    pub resolve: bool,
+    /// Widen instants to an interval covering their grain, so "March 3"
+    /// resolves to the whole day and "3pm" to 15:00–16:00 (disabled by
+    /// default; see [`Time::grain`]).
+    pub round_to_grain: bool,
+    /// Century window for two-digit years in numeric dates ("3/5/99"):
+    /// years below the pivot are read as 20xx, the rest as 19xx. Defaults
+    /// to 50, so "49" is 2049 and "50" is 1950.
+    pub two_digit_year_pivot: u32,
+    /// Which June "next June" names (and, mirrored, "last June").
+    pub next_month: NextMonthPolicy,
+    /// Which month a bare day of the month ("the 3rd") falls in.
+    pub day_of_month: DayOfMonthPolicy,
+    /// Also recognize airline/rail itinerary shorthand: "14MAR", "06JUN25
+    /// 0830", "dep 0735 arr 0950" (disabled by default, as these forms
+    /// collide with codes and reference numbers).
+    pub transport_shorthand: bool,
+    /// Merge a day and a time of day separated by up to this many
+    /// unrecognized words ("tomorrow maybe at 5") into one entity; 0 (the
+    /// default) disables merging.
+    pub merge_gap: usize,
+    /// Rule families switched off for this parse (none by default).
+    pub disabled_groups: RuleGroups,
+    /// Rules to parse with instead of the built-in set; takes precedence
+    /// over `transport_shorthand`.
+    pub rule_set: Option<RuleSet>,
+    /// Longest input, in bytes, that is parsed at all (64 KiB by default;
+    /// `None` for no limit). Longer inputs yield no entities from
+    /// [`parse_with`] and a [`ParseError::InputTooLong`] from
+    /// [`try_parse_with`]. Parse long documents in chunks split at line or
+    /// sentence boundaries, adding each chunk's byte offset to its spans.
+    pub max_input_len: Option<usize>,
+    /// Correct near-miss spellings of weekdays, months and relative day
+    /// words ("wednseday", "febuary", "tommorow") before matching (disabled
+    /// by default). Entities spanning a correction have
+    /// [`Entity::corrected`] set.
+    pub typo_tolerance: bool,
+    /// Mark times in conditional or hypothetical sentences ("if we met
+    /// yesterday", "had it been Monday") as latent, with the cue recorded as
+    /// `hypothetical:<cue>` in their evidence (disabled by default).
+    pub latent_hypotheticals: bool,
+    /// Words and symbols that introduce an identifier rather than a
+    /// quantity or a time ("room 237", "flight 1234", "order #5678"): the
+    /// number after them is a latent numeral and is not read as a time.
+    /// Defaults to [`DEFAULT_IDENTIFIER_MARKERS`]; empty turns this off.
+    pub identifier_markers: Vec<String>,
+    /// Codes claimed as identifier entities ("ABC-123", "#5678",
+    /// "1Z999AA10123456784"); times and numerals are not read from any part
+    /// of them. Defaults to [`IdentifierPattern::defaults`]; empty turns
+    /// this off.
+    pub identifier_patterns: Vec<IdentifierPattern>,
+    /// End saturation as soon as a time covering the whole input is found,
+    /// for latency-sensitive callers parsing single-intent inputs ("remind
+    /// me tomorrow at 5" in a form field) who only want that one reading
+    /// (disabled by default). Other entities found by then are still
+    /// returned, but ones only later passes would build are not.
+    pub stop_at_full_span_time: bool,
+    /// How much of the input entities must cover to be returned at all
+    /// ([`Coverage::Any`] by default), for command parsing that wants a
+    /// date or time only when it is (nearly) the whole input.
+    pub coverage: Coverage,
+    /// Read "may", "march", "sat", "sun", "wed" and "mon" as a month or a
+    /// weekday only when capitalized or with date context in the same
+    /// entity ("may 5", "next sat"), for prose where the lowercase words
+    /// are usually verbs and nouns (disabled by default).
+    pub capitalized_names: bool,
+    /// Local timezone to resolve in, overriding [`Context::timezone`], for
+    /// tests and replays that pretend to run somewhere else (`None` by
+    /// default). Times stated in another zone are converted into it, and
+    /// hour shifts follow its DST transitions.
+    pub local_timezone: Option<Tz>,
+    /// Rules for a second language, tried on sentences the primary rules
+    /// found nothing but numerals and latent matches in (none by default).
+    pub fallback_locale: Option<FallbackLocale>,
+    /// Rewrites applied to the input, in order, before the rules see it
+    /// (none by default). Entity spans still point into the input.
+    pub preprocessors: Vec<Arc<dyn Preprocessor>>,
+    /// Phrases standing for times in the app's domain, like "standup"
+    /// (none by default).
+    pub aliases: Vec<Alias>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            regex_profiling: RegexProfilingOptions::default(),
+            overlap_policy: OverlapPolicy::default(),
+            suppress_covered_numerals: false,
+            partial_dates: false,
+            fiscal_year_start: None,
+            resolve: true,
+            round_to_grain: false,
+            two_digit_year_pivot: 50,
+            next_month: NextMonthPolicy::default(),
+            day_of_month: DayOfMonthPolicy::default(),
+            transport_shorthand: false,
+            merge_gap: 0,
+            disabled_groups: RuleGroups::empty(),
+            rule_set: None,
+            max_input_len: Some(DEFAULT_MAX_INPUT_LEN),
+            typo_tolerance: false,
+            latent_hypotheticals: false,
+            identifier_markers: DEFAULT_IDENTIFIER_MARKERS.iter().map(|m| m.to_string()).collect(),
+            identifier_patterns: IdentifierPattern::defaults(),
+            stop_at_full_span_time: false,
+            coverage: Coverage::default(),
+            capitalized_names: false,
+            local_timezone: None,
+            fallback_locale: None,
+            preprocessors: Vec::new(),
+            aliases: Vec::new(),
+        }
+    }
 }
 
 impl Options {
@@ -59,6 +368,419 @@ impl Options {
     pub fn set_regex_profile_limit(&mut self, max_rules: usize) {
         self.regex_profiling.max_rules = max_rules.max(1);
     }
+
+    /// Choose how overlapping entities of the same dimension are reduced.
+    pub fn with_overlap_policy(mut self, policy: OverlapPolicy) -> Self {
+        self.overlap_policy = policy;
+        self
+    }
+
+    /// Mutably choose how overlapping entities of the same dimension are reduced.
+    pub fn set_overlap_policy(&mut self, policy: OverlapPolicy) {
+        self.overlap_policy = policy;
+    }
+
+    /// Drop numerals that sit entirely inside another entity (e.g. a time).
+    pub fn enable_covered_numeral_suppression(mut self) -> Self {
+        self.suppress_covered_numerals = true;
+        self
+    }
+
+    /// Mutably enable suppression of numerals covered by another entity.
+    pub fn enable_covered_numeral_suppression_mut(&mut self) {
+        self.suppress_covered_numerals = true;
+    }
+
+    /// Keep the year unspecified for dates stated without one.
+    pub fn enable_partial_dates(mut self) -> Self {
+        self.partial_dates = true;
+        self
+    }
+
+    /// Mutably keep the year unspecified for dates stated without one.
+    pub fn enable_partial_dates_mut(&mut self) {
+        self.partial_dates = true;
+    }
+
+    /// Start fiscal years in `month`.
+    pub fn with_fiscal_year_start(mut self, month: Month) -> Self {
+        self.fiscal_year_start = Some(month);
+        self
+    }
+
+    /// Mutably start fiscal years in `month`.
+    pub fn set_fiscal_year_start(&mut self, month: Month) {
+        self.fiscal_year_start = Some(month);
+    }
+
+    /// Resolve instants to an interval spanning their grain.
+    pub fn enable_round_to_grain(mut self) -> Self {
+        self.round_to_grain = true;
+        self
+    }
+
+    /// Mutably resolve instants to an interval spanning their grain.
+    pub fn enable_round_to_grain_mut(&mut self) {
+        self.round_to_grain = true;
+    }
+
+    /// Read two-digit years below `pivot` as 20xx and the rest as 19xx.
+    pub fn with_two_digit_year_pivot(mut self, pivot: u32) -> Self {
+        self.two_digit_year_pivot = pivot;
+        self
+    }
+
+    /// Mutably set the century pivot for two-digit years.
+    pub fn set_two_digit_year_pivot(&mut self, pivot: u32) {
+        self.two_digit_year_pivot = pivot;
+    }
+
+    /// Choose which occurrence "next <month>" and "last <month>" name.
+    pub fn with_next_month_policy(mut self, policy: NextMonthPolicy) -> Self {
+        self.next_month = policy;
+        self
+    }
+
+    /// Mutably choose which occurrence "next <month>" and "last <month>" name.
+    pub fn set_next_month_policy(&mut self, policy: NextMonthPolicy) {
+        self.next_month = policy;
+    }
+
+    /// Choose which month a bare day of the month ("the 3rd") falls in.
+    pub fn with_day_of_month_policy(mut self, policy: DayOfMonthPolicy) -> Self {
+        self.day_of_month = policy;
+        self
+    }
+
+    /// Mutably choose which month a bare day of the month ("the 3rd") falls in.
+    pub fn set_day_of_month_policy(&mut self, policy: DayOfMonthPolicy) {
+        self.day_of_month = policy;
+    }
+
+    /// Recognize airline/rail itinerary shorthand.
+    pub fn enable_transport_shorthand(mut self) -> Self {
+        self.transport_shorthand = true;
+        self
+    }
+
+    /// Mutably recognize airline/rail itinerary shorthand.
+    pub fn enable_transport_shorthand_mut(&mut self) {
+        self.transport_shorthand = true;
+    }
+
+    /// Merge a day and a time of day separated by up to `words` unrecognized words.
+    pub fn with_merge_gap(mut self, words: usize) -> Self {
+        self.merge_gap = words;
+        self
+    }
+
+    /// Mutably set how many unrecognized words a day/time merge may skip.
+    pub fn set_merge_gap(&mut self, words: usize) {
+        self.merge_gap = words;
+    }
+
+    /// Switch off every rule in `groups`, e.g. `RuleGroups::LATENT` to stop
+    /// bare numbers being read as times.
+    pub fn disable_groups(mut self, groups: RuleGroups) -> Self {
+        self.disabled_groups |= groups;
+        self
+    }
+
+    /// Mutably switch off every rule in `groups`.
+    pub fn disable_groups_mut(&mut self, groups: RuleGroups) {
+        self.disabled_groups |= groups;
+    }
+
+    /// Parse with `rules` instead of the built-in rule set.
+    pub fn with_rule_set(mut self, rules: RuleSet) -> Self {
+        self.rule_set = Some(rules);
+        self
+    }
+
+    /// Mutably parse with `rules` instead of the built-in rule set.
+    pub fn set_rule_set(&mut self, rules: RuleSet) {
+        self.rule_set = Some(rules);
+    }
+
+    /// Refuse inputs longer than `len` bytes; `None` lifts the limit.
+    pub fn with_max_input_len(mut self, len: Option<usize>) -> Self {
+        self.max_input_len = len;
+        self
+    }
+
+    /// Mutably refuse inputs longer than `len` bytes; `None` lifts the limit.
+    pub fn set_max_input_len(&mut self, len: Option<usize>) {
+        self.max_input_len = len;
+    }
+
+    /// Correct near-miss spellings of common date words before matching.
+    pub fn enable_typo_tolerance(mut self) -> Self {
+        self.typo_tolerance = true;
+        self
+    }
+
+    /// Mutably correct near-miss spellings of common date words before matching.
+    pub fn enable_typo_tolerance_mut(&mut self) {
+        self.typo_tolerance = true;
+    }
+
+    /// Mark times in conditional or hypothetical sentences as latent.
+    pub fn enable_latent_hypotheticals(mut self) -> Self {
+        self.latent_hypotheticals = true;
+        self
+    }
+
+    /// Mutably mark times in conditional or hypothetical sentences as latent.
+    pub fn enable_latent_hypotheticals_mut(&mut self) {
+        self.latent_hypotheticals = true;
+    }
+
+    /// Treat the number after any of `markers` as an identifier, replacing
+    /// the default markers; an empty list turns this off.
+    pub fn with_identifier_markers<S: Into<String>>(mut self, markers: impl IntoIterator<Item = S>) -> Self {
+        self.set_identifier_markers(markers);
+        self
+    }
+
+    /// Mutably set the words and symbols that introduce an identifier.
+    pub fn set_identifier_markers<S: Into<String>>(&mut self, markers: impl IntoIterator<Item = S>) {
+        self.identifier_markers = markers.into_iter().map(Into::into).collect();
+    }
+
+    /// Also claim codes matched by `pattern` as identifiers, before the
+    /// patterns already set.
+    pub fn with_identifier_pattern(mut self, pattern: IdentifierPattern) -> Self {
+        self.add_identifier_pattern(pattern);
+        self
+    }
+
+    /// Mutably claim codes matched by `pattern` as identifiers.
+    pub fn add_identifier_pattern(&mut self, pattern: IdentifierPattern) {
+        self.identifier_patterns.insert(0, pattern);
+    }
+
+    /// Claim only codes matched by `patterns` as identifiers; an empty list
+    /// turns identifiers off.
+    pub fn with_identifier_patterns(mut self, patterns: Vec<IdentifierPattern>) -> Self {
+        self.identifier_patterns = patterns;
+        self
+    }
+
+    /// Stop parsing at the first time that covers the whole input.
+    pub fn enable_stop_at_full_span_time(mut self) -> Self {
+        self.stop_at_full_span_time = true;
+        self
+    }
+
+    /// Mutably stop parsing at the first time that covers the whole input.
+    pub fn enable_stop_at_full_span_time_mut(&mut self) {
+        self.stop_at_full_span_time = true;
+    }
+
+    /// Return entities only when they cover as much of the input as `coverage` asks.
+    pub fn with_coverage(mut self, coverage: Coverage) -> Self {
+        self.coverage = coverage;
+        self
+    }
+
+    /// Mutably set how much of the input entities must cover.
+    pub fn set_coverage(&mut self, coverage: Coverage) {
+        self.coverage = coverage;
+    }
+
+    /// Require capitalization or date context for month and weekday names
+    /// that are also common words.
+    pub fn enable_capitalized_names(mut self) -> Self {
+        self.capitalized_names = true;
+        self
+    }
+
+    /// Mutably require capitalization for ambiguous month and weekday names.
+    pub fn enable_capitalized_names_mut(&mut self) {
+        self.capitalized_names = true;
+    }
+
+    /// Resolve as if running in `timezone`, whatever the context says.
+    pub fn with_local_timezone(mut self, timezone: Tz) -> Self {
+        self.local_timezone = Some(timezone);
+        self
+    }
+
+    /// Mutably resolve as if running in `timezone`.
+    pub fn set_local_timezone(&mut self, timezone: Option<Tz>) {
+        self.local_timezone = timezone;
+    }
+
+    /// Try `fallback` on sentences the primary rules find nothing in.
+    pub fn with_fallback_locale(mut self, fallback: FallbackLocale) -> Self {
+        self.fallback_locale = Some(fallback);
+        self
+    }
+
+    /// Mutably set the rules tried on sentences the primary rules find
+    /// nothing in.
+    pub fn set_fallback_locale(&mut self, fallback: Option<FallbackLocale>) {
+        self.fallback_locale = fallback;
+    }
+
+    /// Rewrite the input with `preprocessor` before parsing, after any
+    /// preprocessors added before.
+    pub fn with_preprocessor(mut self, preprocessor: impl Preprocessor + 'static) -> Self {
+        self.add_preprocessor(preprocessor);
+        self
+    }
+
+    /// Mutably rewrite the input with `preprocessor`, after the others.
+    pub fn add_preprocessor(&mut self, preprocessor: impl Preprocessor + 'static) {
+        self.preprocessors.push(Arc::new(preprocessor));
+    }
+
+    /// Read the phrase of `alias` as the time it stands for.
+    pub fn with_alias(mut self, alias: Alias) -> Self {
+        self.add_alias(alias);
+        self
+    }
+
+    /// Mutably read the phrase of `alias` as the time it stands for.
+    pub fn add_alias(&mut self, alias: Alias) {
+        self.aliases.push(alias);
+    }
+
+    /// Check `text` against the limits in these options before parsing.
+    pub fn check_input(&self, text: &str) -> Result<(), ParseError> {
+        match self.max_input_len {
+            Some(max) if text.len() > max => Err(ParseError::InputTooLong { len: text.len(), max }),
+            _ => Ok(()),
+        }
+    }
+
+    /// The text the parser sees for `text`: empty if it may not be parsed,
+    /// else `text` with emoji blanked out (see `engine::blank_emoji`).
+    fn admit<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.check_input(text).is_ok() { engine::blank_emoji(text) } else { Cow::Borrowed("") }
+    }
+
+    /// What the rules see of `text`: [`admit`](Self::admit)ted, then
+    /// [`rewrite`](Self::rewrite)n.
+    fn prepare<'a>(&self, text: &'a str) -> Prepared<'a> {
+        self.rewrite(self.admit(text))
+    }
+
+    /// `admitted` run through the preprocessors, then spelling corrections
+    /// when typo tolerance is on.
+    fn rewrite<'a>(&self, admitted: Cow<'a, str>) -> Prepared<'a> {
+        let mut prepared = Prepared { admitted, layers: Vec::new(), typos: None };
+        for preprocessor in &self.preprocessors {
+            let edits = preprocessor.edits(prepared.text()).into_iter().map(|e| (e.range, e.text)).collect();
+            prepared.layers.extend(engine::Corrections::apply(prepared.text(), edits));
+        }
+        if self.typo_tolerance {
+            prepared.typos = engine::correct_typos(prepared.text());
+        }
+        prepared
+    }
+
+    /// Return times as symbolic expressions instead of resolving them.
+    pub fn disable_resolution(mut self) -> Self {
+        self.resolve = false;
+        self
+    }
+
+    /// Mutably return times as symbolic expressions instead of resolving them.
+    pub fn disable_resolution_mut(&mut self) {
+        self.resolve = false;
+    }
+}
+
+/// Hemisphere used to resolve seasons, selected via [`Context::hemisphere`].
+///
+/// Southern-hemisphere seasons are offset by half a year: "summer" runs from
+/// December to March.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Hemisphere {
+    #[default]
+    Northern,
+    Southern,
+}
+
+bitflags::bitflags! {
+    /// Named rule families that can be switched off as a whole through
+    /// [`Options::disabled_groups`]. Rules outside every group always run.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct RuleGroups: u32 {
+        /// Named holidays: "Christmas", "Thanksgiving 2025", "Ramadan".
+        const HOLIDAYS  = 1 << 0;
+        /// Times stated in a timezone: "9am PST", "3pm ET / 12pm PT".
+        const TIMEZONES = 1 << 1;
+        /// Ranges: "from 3 to 5", "March 3-5", "until Friday".
+        const INTERVALS = 1 << 2;
+        /// Bare numbers read as times or years: "at 5", "1930", "1990-2000".
+        const LATENT    = 1 << 3;
+    }
+}
+
+/// Overlap resolution between candidates of the same dimension, selected via
+/// [`Options::overlap_policy`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    /// Drop spans strictly contained in a longer span (the default).
+    #[default]
+    KeepLongest,
+    /// Keep every candidate, including nested ones such as the numeral
+    /// inside a date.
+    KeepAll,
+    /// Among overlapping spans, keep the one produced by the highest-priority
+    /// rule. Between equal priorities it works like `KeepLongest`: a span
+    /// strictly inside another is dropped, while partly overlapping spans are
+    /// both kept.
+    KeepHighestPriority,
+}
+
+/// How much of the input the entities of a parse must cover for any to be
+/// returned, selected via [`Options::coverage`]. Coverage counts the letters
+/// and digits of the input inside some entity, so whitespace and punctuation
+/// between entities do not count against it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Coverage {
+    /// Return entities whatever they cover (the default).
+    #[default]
+    Any,
+    /// Return entities only when together they cover the whole input, as
+    /// when the input is a single date or time ("tomorrow at 5").
+    FullSpan,
+    /// Return entities only when together they cover at least this fraction
+    /// (0.0 to 1.0) of the input, so "remind me tomorrow at 5" passes at 0.5
+    /// where free text mentioning a date does not.
+    MinRatio(f32),
+}
+
+/// Meaning of "next <month>", selected via [`Options::next_month`]. "last
+/// <month>" is read the same way, backwards.
+///
+/// Speakers disagree on "next June" said in February: the coming June, or
+/// the June of next year (as opposed to "this June").
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NextMonthPolicy {
+    /// The first occurrence after the current month (the default): "next
+    /// June" is this year's June until June has come.
+    #[default]
+    Upcoming,
+    /// That month of the following calendar year: "next June" is always
+    /// next year's.
+    FollowingYear,
+}
+
+/// Meaning of a bare day of the month ("on the 3rd", "the 15th"), selected
+/// via [`Options::day_of_month`]. Months without that day ("the 31st" in
+/// February) are skipped, and a day naming today moves a month away.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DayOfMonthPolicy {
+    /// The next such day (the default): "the 3rd" said on the 12th is next
+    /// month's.
+    #[default]
+    Upcoming,
+    /// The most recent such day: "the 3rd" said on the 12th is this month's.
+    Past,
 }
 
 /// Regex profiling configuration toggled via [`Options`].
@@ -85,16 +807,190 @@ pub struct Entity {
     pub name: String,
     /// Slice of the original input that matched.
     pub body: String,
-    /// Resolved value (currently formatted as a string).
+    /// Resolved value in its canonical string form.
     pub value: String,
+    /// Resolved value in structured form.
+    pub resolved: Value,
     /// Start byte index of the match.
     pub start: usize,
     /// End byte index of the match (exclusive).
     pub end: usize,
-    /// Whether this is a "latent" (low-confidence) match.
+    /// Whether this is a "latent" (low-confidence) match: one produced by a
+    /// [`RuleGroups::LATENT`] rule, like a bare "1530" or "the 3rd".
     pub latent: bool,
     /// Name of the rule that produced this entity.
     pub rule: String,
+    /// Semantic hints from the rules involved, e.g. `"dob"` or `"anniversary"`,
+    /// for downstream slot filling.
+    pub hints: Vec<String>,
+    /// Other readings of the same text that lost disambiguation, e.g. 20:17
+    /// for "2017" resolved as the year.
+    pub alternatives: Vec<Value>,
+    /// Whether a misspelled word in the match was corrected to parse it
+    /// (see [`Options::typo_tolerance`]).
+    pub corrected: bool,
+    /// Whether the match is under a negation ("not on Friday", "any day
+    /// except Tuesday", "never after 6pm"), so it names a time to avoid
+    /// rather than one to schedule. This is a heuristic over the few words
+    /// before the match.
+    pub excluded: bool,
+    /// Locale of the [`FallbackLocale`] whose rules found this entity;
+    /// `None` for the primary rules.
+    pub locale: Option<String>,
+}
+
+impl Entity {
+    /// Content hash of the dimension, resolved value and matched text, for
+    /// deduplicating the same entity seen in overlapping windows of a
+    /// stream. Offsets are not part of it, so a match keeps its id wherever
+    /// it falls in the window. The hash (64-bit FNV-1a) is stable across
+    /// runs and builds.
+    pub fn id(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+        let mut hash = OFFSET_BASIS;
+        for part in [&self.name, &self.value, &self.body] {
+            // 0xff never occurs in UTF-8, so it separates the parts unambiguously.
+            for byte in part.bytes().chain(std::iter::once(0xff)) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(PRIME);
+            }
+        }
+        hash
+    }
+
+    /// The resolved value's canonical surface form, for display as a
+    /// normalized chip ("2025-06-20T15:00:00", "every Monday 09:00").
+    pub fn canonical(&self, format: CanonicalFormat) -> String {
+        self.resolved.canonical(format)
+    }
+
+    /// Resolve this entity's symbolic expression (see [`Options::resolve`])
+    /// against `context`, so a time parsed earlier resolves against a new
+    /// reference. Returns `None` for entities that were already resolved or
+    /// have no value for this reference.
+    pub fn resolve_with(&self, context: &Context, options: &Options) -> Option<Value> {
+        self.resolved.as_symbolic()?.resolve_with(context, options)
+    }
+}
+
+/// Default for [`Options::identifier_markers`].
+pub const DEFAULT_IDENTIFIER_MARKERS: &[&str] = &[
+    "#",
+    "room",
+    "rm",
+    "suite",
+    "apt",
+    "unit",
+    "flight",
+    "train",
+    "bus",
+    "route",
+    "gate",
+    "platform",
+    "seat",
+    "order",
+    "invoice",
+    "receipt",
+    "ticket",
+    "ref",
+    "reference",
+    "account",
+    "acct",
+    "id",
+    "ext",
+    "extension",
+    "case",
+    "issue",
+];
+
+/// A named regex for codes claimed as identifiers, set in
+/// [`Options::identifier_patterns`].
+#[derive(Debug, Clone)]
+pub struct IdentifierPattern {
+    /// Name reported in [`Identifier::kind`].
+    pub kind: String,
+    pub regex: regex::Regex,
+}
+
+static DEFAULT_IDENTIFIER_PATTERNS: Lazy<Vec<IdentifierPattern>> = Lazy::new(|| {
+    [
+        ("uuid", r"\b[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}\b"),
+        ("tracking", r"\b1Z[0-9A-Z]{16}\b"),
+        ("ticket", r"\b[A-Z][A-Z0-9]{1,9}-[0-9]+\b"),
+        ("reference", r"#[0-9A-Za-z]*[0-9][0-9A-Za-z]*(?:-[0-9A-Za-z]+)*"),
+    ]
+    .into_iter()
+    .map(|(kind, pattern)| IdentifierPattern::new(kind, pattern).expect("valid identifier pattern"))
+    .collect()
+});
+
+impl IdentifierPattern {
+    /// Claim codes matching `pattern` as identifiers of `kind`.
+    pub fn new(kind: impl Into<String>, pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self { kind: kind.into(), regex: regex::Regex::new(pattern)? })
+    }
+
+    /// The built-in patterns: UUIDs, UPS tracking numbers ("1Z999AA10123456784"),
+    /// ticket keys ("ABC-123") and `#` references ("#5678", "#A-77").
+    pub fn defaults() -> Vec<Self> {
+        DEFAULT_IDENTIFIER_PATTERNS.clone()
+    }
+}
+
+/// Default for [`Options::max_input_len`].
+pub const DEFAULT_MAX_INPUT_LEN: usize = 64 * 1024;
+
+/// An input [`try_parse_with`] refused to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input is longer than [`Options::max_input_len`].
+    InputTooLong { len: usize, max: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InputTooLong { len, max } => write!(
+                f,
+                "input is {len} bytes, over the limit of {max}; parse it in chunks split at line or sentence boundaries, or raise Options::max_input_len"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// What kind of data-quality issue a [`ParseWarning`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WarningKind {
+    /// An interval's end read on its own falls before its start, so it was
+    /// moved after the start ("Friday 11pm to 1am").
+    IntervalEndCorrected,
+    /// A two-digit year was expanded with [`Options::two_digit_year_pivot`].
+    TwoDigitYear,
+    /// A timezone abbreviation names several zones; the most common reading
+    /// was used.
+    AmbiguousTimezone,
+}
+
+/// A suspicious but non-fatal reading of a returned entity, reported in
+/// [`ParseDetails::warnings`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWarning {
+    pub kind: WarningKind,
+    /// Span of the entity the warning is about.
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}: {}", self.start, self.end, self.message)
+    }
 }
 
 /// Result from [`parse`] and [`parse_with`].
@@ -108,14 +1004,57 @@ pub struct ParseResult {
     pub elapsed: Duration,
 }
 
-/// A compact per-pass saturation trace.
-#[derive(Debug, Clone)]
-pub struct SaturationPass {
-    pub pass: usize,
-    pub duration: Duration,
-    pub produced: usize,
-    pub samples: Vec<NodeSummary>,
-}
+impl ParseResult {
+    /// Time entities, in result order.
+    pub fn times(&self) -> impl Iterator<Item = TimeEntity<'_>> {
+        self.results.iter().filter_map(|entity| Some(TimeEntity { entity, time: entity.resolved.as_time()? }))
+    }
+
+    /// Numeral entities, in result order.
+    pub fn numerals(&self) -> impl Iterator<Item = NumeralEntity<'_>> {
+        self.results.iter().filter_map(|entity| Some(NumeralEntity { entity, numeral: entity.resolved.as_numeral()? }))
+    }
+
+    /// Identifier entities, in result order.
+    pub fn identifiers(&self) -> impl Iterator<Item = IdentifierEntity<'_>> {
+        self.results
+            .iter()
+            .filter_map(|entity| Some(IdentifierEntity { entity, identifier: entity.resolved.as_identifier()? }))
+    }
+}
+
+/// Typed view of a time [`Entity`], returned by [`ParseResult::times`].
+#[derive(Debug, Clone, Copy)]
+pub struct TimeEntity<'a> {
+    /// The underlying entity (span, body, rule, ...).
+    pub entity: &'a Entity,
+    pub time: &'a Time,
+}
+
+/// Typed view of a numeral [`Entity`], returned by [`ParseResult::numerals`].
+#[derive(Debug, Clone, Copy)]
+pub struct NumeralEntity<'a> {
+    /// The underlying entity (span, body, rule, ...).
+    pub entity: &'a Entity,
+    pub numeral: &'a Numeral,
+}
+
+/// Typed view of an identifier [`Entity`], returned by [`ParseResult::identifiers`].
+#[derive(Debug, Clone, Copy)]
+pub struct IdentifierEntity<'a> {
+    /// The underlying entity (span, body, rule, ...).
+    pub entity: &'a Entity,
+    pub identifier: &'a Identifier,
+}
+
+/// A compact per-pass saturation trace.
+#[derive(Debug, Clone)]
+pub struct SaturationPass {
+    pub pass: usize,
+    pub duration: Duration,
+    pub produced: usize,
+    pub samples: Vec<NodeSummary>,
+}
 
 /// A compact node summary used in verbose traces.
 #[derive(Debug, Clone)]
@@ -141,10 +1080,221 @@ pub struct ParseDetails {
     pub resolve: Duration,
     /// Names of rules that were active for this input.
     pub active_rules: Vec<String>,
+    /// Names of rules that produced at least one node.
+    pub fired_rules: Vec<String>,
     /// All resolved candidates before classifier filtering.
     pub all_candidates: Vec<Entity>,
     /// Optional regex profiling summary (only present when enabled in [`Options`]).
     pub regex_profile: Option<RegexProfileSummary>,
+    /// How each returned entity was derived; parallel to the verbose `results`.
+    pub explanations: Vec<Explanation>,
+    /// Panics caught in rule code; the failing rules contributed nothing.
+    pub errors: Vec<InternalError>,
+    /// Suspicious readings of the returned entities, for logging data-quality
+    /// issues without a full trace.
+    pub warnings: Vec<ParseWarning>,
+    /// Nodes that several rules derived alike, a sign one of them is
+    /// redundant.
+    pub identical_nodes: Vec<IdenticalNodes>,
+}
+
+/// A node several rules produced with the same span, dimension, precision
+/// and value. The span refers to the text as parsed, like
+/// [`Explanation::derivation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdenticalNodes {
+    pub start: usize,
+    pub end: usize,
+    /// The rules, in the order they first produced the node.
+    pub rules: Vec<String>,
+}
+
+/// Rule usage accumulated over many parses, for spotting dead and shadowed
+/// rules (see `astorion corpus coverage`).
+#[derive(Debug, Clone, Default)]
+pub struct RuleCoverage {
+    /// Every rule name in the rule set, in registration order.
+    pub rules: Vec<String>,
+    /// Priority of each rule; for rules sharing a name, the first one's.
+    pub priorities: HashMap<String, u16>,
+    /// Number of recorded parses in which each rule produced a node.
+    pub fired: HashMap<String, usize>,
+    /// Number of recorded parses in which each rule contributed to a
+    /// returned entity.
+    pub kept: HashMap<String, usize>,
+    /// Number of recorded parses in which each pair of rules, ordered by
+    /// name, produced an identical node (see [`ParseDetails::identical_nodes`]).
+    pub identical: HashMap<(String, String), usize>,
+    /// Number of recorded parses.
+    pub parses: usize,
+}
+
+/// A rule that produced nothing another rule did not produce alike, from
+/// [`RuleCoverage::shadowed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowedRule {
+    pub rule: String,
+    pub priority: u16,
+    /// The rule producing the same nodes.
+    pub by: String,
+    pub by_priority: u16,
+    /// Number of recorded parses in which `rule` fired, each of them with an
+    /// identical node from `by`.
+    pub parses: usize,
+}
+
+impl RuleCoverage {
+    /// Empty coverage over the rule set `options` selects, minus disabled groups.
+    pub fn new(options: &Options) -> Self {
+        let mut rules: Vec<String> = Vec::new();
+        let mut priorities = HashMap::new();
+        for rule in rules_for(options).iter().filter(|rule| !rule.groups.intersects(options.disabled_groups)) {
+            if !rules.iter().any(|name| name == rule.name) {
+                rules.push(rule.name.to_string());
+                priorities.insert(rule.name.to_string(), rule.priority);
+            }
+        }
+        Self { rules, priorities, ..Self::default() }
+    }
+
+    /// Add the rules exercised by one verbose parse.
+    pub fn record(&mut self, result: &ParseResultVerbose) {
+        self.parses += 1;
+        for name in &result.details.fired_rules {
+            *self.fired.entry(name.clone()).or_default() += 1;
+        }
+        let mut kept: Vec<&String> = result.details.explanations.iter().flat_map(|e| &e.rules).collect();
+        kept.sort();
+        kept.dedup();
+        for name in kept.into_iter().filter(|name| self.rules.contains(name)) {
+            *self.kept.entry(name.clone()).or_default() += 1;
+        }
+        let mut pairs: Vec<(&String, &String)> = Vec::new();
+        for identical in &result.details.identical_nodes {
+            for (i, a) in identical.rules.iter().enumerate() {
+                pairs.extend(identical.rules[i + 1..].iter().map(|b| if a < b { (a, b) } else { (b, a) }));
+            }
+        }
+        pairs.sort();
+        pairs.dedup();
+        for (a, b) in pairs.into_iter().filter(|(a, b)| self.rules.contains(a) && self.rules.contains(b)) {
+            *self.identical.entry((a.clone(), b.clone())).or_default() += 1;
+        }
+    }
+
+    /// Rules that never produced a node.
+    pub fn never_fired(&self) -> Vec<&str> {
+        self.rules.iter().filter(|name| !self.fired.contains_key(*name)).map(String::as_str).collect()
+    }
+
+    /// Rules that fired but never made it into a returned entity.
+    pub fn always_filtered(&self) -> Vec<&str> {
+        self.rules
+            .iter()
+            .filter(|name| self.fired.contains_key(*name) && !self.kept.contains_key(*name))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Rules that produced an identical node from another rule in every
+    /// parse they fired in, likely duplicates of it. Of two rules shadowing
+    /// each other, the one of lower priority is reported, or else the one
+    /// registered later.
+    pub fn shadowed(&self) -> Vec<ShadowedRule> {
+        let fired = |name: &str| self.fired.get(name).copied().unwrap_or(0);
+        let priority = |name: &str| self.priorities.get(name).copied().unwrap_or(0);
+        let position = |name: &str| self.rules.iter().position(|rule| rule == name).unwrap_or(usize::MAX);
+        // Lower priority, then later registration, sorts first.
+        let rank = |name: &str| (priority(name), std::cmp::Reverse(position(name)));
+        let mut shadowed = Vec::new();
+        for ((a, b), &parses) in &self.identical {
+            for (rule, by) in [(a, b), (b, a)] {
+                let mutual = fired(by) == parses;
+                if fired(rule) == parses && (!mutual || rank(rule) < rank(by)) {
+                    shadowed.push(ShadowedRule {
+                        rule: rule.clone(),
+                        priority: priority(rule),
+                        by: by.clone(),
+                        by_priority: priority(by),
+                        parses,
+                    });
+                }
+            }
+        }
+        shadowed.sort_by(|x, y| y.parses.cmp(&x.parses).then_with(|| (&x.rule, &x.by).cmp(&(&y.rule, &y.by))));
+        shadowed
+    }
+}
+
+/// Why an entity resolved the way it did: the winning rule chain plus the
+/// full derivation tree.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    /// The winning rule followed by every rule that contributed to it
+    /// (the node's evidence), outermost first.
+    pub rules: Vec<String>,
+    /// Derivation tree rooted at the winning node. Its spans and bodies
+    /// refer to the text as parsed, after any [`Options::typo_tolerance`]
+    /// corrections.
+    pub derivation: Derivation,
+}
+
+/// One step of a derivation tree.
+#[derive(Debug, Clone)]
+pub struct Derivation {
+    /// Rule that produced this step (`"<regex>"` for raw pattern matches).
+    pub rule: String,
+    pub start: usize,
+    pub end: usize,
+    /// Slice of the original input covered by this step.
+    pub body: String,
+    /// Debug rendering of the token produced at this step, e.g. the
+    /// `TimeExpr` before normalization, or the capture groups as written
+    /// for raw pattern matches.
+    pub token: String,
+    /// Steps matched by the rule's pattern, in order.
+    pub children: Vec<Derivation>,
+}
+
+/// Engine counters for a single parse, suitable for exporting to a metrics
+/// backend (Prometheus, StatsD, ...).
+///
+/// Enable the `serde` feature to serialize this struct.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metrics {
+    /// Total elapsed time (saturation + resolution).
+    pub total: Duration,
+    /// Time spent in saturation, across all passes.
+    pub saturation: Duration,
+    /// Time spent resolving and filtering candidates.
+    pub resolve: Duration,
+    /// Number of rules that survived bucket/phrase gating for this input.
+    pub active_rules: usize,
+    /// Number of saturation passes, including the initial regex pass.
+    pub pass_count: usize,
+    /// Total nodes added to the stash across all passes.
+    pub nodes_produced: usize,
+    /// Number of entities returned to the caller.
+    pub entities: usize,
+    /// Per-pass breakdown; index 0 is the initial regex pass.
+    pub passes: Vec<PassStats>,
+}
+
+/// Counters for a single saturation pass (see [`Metrics::passes`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PassStats {
+    /// Elapsed time for the pass.
+    pub duration: Duration,
+    /// New nodes added to the stash during the pass.
+    pub produced: usize,
+    /// Rules attempted during the pass.
+    pub rules_considered: usize,
+    /// Rules whose first pattern matched at least once.
+    pub rules_seeded: usize,
+    /// Total matches of first-position regex patterns.
+    pub regex_first_pattern_hits: usize,
 }
 
 /// Result from [`parse_verbose`] and [`parse_verbose_with`].
@@ -154,6 +1304,8 @@ pub struct ParseResultVerbose {
     pub results: Vec<Entity>,
     pub elapsed: Duration,
     pub details: ParseDetails,
+    /// Aggregate engine counters for this parse.
+    pub metrics: Metrics,
 }
 
 /// Parse `text` using the default ruleset and a default [`Context`].
@@ -172,14 +1324,133 @@ pub fn parse(text: &str) -> ParseResult {
 /// Parse `text` using the default ruleset and the provided `context`/`options`.
 ///
 /// Use this when you want deterministic parsing by supplying a reference time.
+/// Inputs over [`Options::max_input_len`] yield no entities; use
+/// [`try_parse_with`] to tell them apart from inputs without any.
 pub fn parse_with(text: &str, context: &Context, options: &Options) -> ParseResult {
-    let parser = engine::Parser::new(text, &DEFAULT_RULES);
+    let prepared = options.prepare(text);
+    let parser = parser_for(prepared.text(), options);
     let run = parser.run_with_metrics(context, options);
 
-    ParseResult {
+    let mut result = ParseResult {
         text: text.to_string(),
-        results: run.tokens.iter().map(|rt| resolved_to_entity(text, rt)).collect(),
+        results: run.tokens.iter().map(|rt| resolved_to_entity(text, rt, &prepared)).collect(),
         elapsed: run.metrics.total,
+    };
+    apply_fallback(&mut result, context, options);
+    result
+}
+
+/// Like [`parse_with`], but report inputs over the limits in `options`
+/// instead of returning no entities for them.
+pub fn try_parse_with(text: &str, context: &Context, options: &Options) -> Result<ParseResult, ParseError> {
+    options.check_input(text)?;
+    Ok(parse_with(text, context, options))
+}
+
+/// Resolve a symbolic time expression against `context` with default [`Options`].
+///
+/// This is the resolution step of [`parse_with`] on its own, for expressions
+/// built programmatically. Returns `None` when the expression has no value
+/// for this reference (e.g. a holiday the context's provider does not know),
+/// and for [`TimeExpr::List`], which resolves to several values.
+///
+/// # Example
+/// ```
+/// use astorion::{Constraint, Context, TimeExpr, TimeValue, normalize};
+/// use chrono::{NaiveDate, Weekday};
+///
+/// // Tuesday 2013-02-12
+/// let reference = NaiveDate::from_ymd_opt(2013, 2, 12).unwrap().and_hms_opt(4, 30, 0).unwrap();
+/// let context = Context { reference_time: Some(reference), ..Default::default() };
+/// let friday = TimeExpr::Intersect { expr: Box::new(TimeExpr::Reference), constraint: Constraint::DayOfWeek(Weekday::Fri) };
+///
+/// let expected = NaiveDate::from_ymd_opt(2013, 2, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// assert_eq!(normalize(&friday, &context), Some(TimeValue::Instant(expected)));
+/// ```
+pub fn normalize(expr: &TimeExpr, context: &Context) -> Option<TimeValue> {
+    normalize_with(expr, context, &Options::default())
+}
+
+/// Resolve a symbolic time expression against `context` with the provided
+/// `options` (fiscal-year start, ...). See [`normalize`].
+pub fn normalize_with(expr: &TimeExpr, context: &Context, options: &Options) -> Option<TimeValue> {
+    crate::rules::time::normalize::normalize(expr, context.reference_time?, context, options)
+}
+
+/// What [`warm_up`] compiled, and how long it took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WarmUpReport {
+    /// Rules across every built-in rule set.
+    pub rules: usize,
+    /// Distinct regexes used by rule patterns.
+    pub regexes: usize,
+    /// Time spent; close to zero when the engine was already warm.
+    pub elapsed: Duration,
+}
+
+/// Build the built-in rule sets and compile their regexes now, instead of
+/// on the first parse.
+///
+/// Rule sets and regexes are compiled lazily, which puts a latency spike on
+/// whichever call parses first. Services can call this at startup; it is
+/// cheap to call again.
+pub fn warm_up() -> WarmUpReport {
+    let start = std::time::Instant::now();
+    // The transport set is a superset of the default one.
+    Lazy::force(&DEFAULT_RULES);
+    let rules = Lazy::force(&TRANSPORT_RULES);
+    Lazy::force(&crate::rules::time::predicates::MONTH_REGEX);
+    Lazy::force(&crate::rules::time::predicates::MONTH_PATTERN_REGEX);
+    let mut regexes: Vec<*const regex::Regex> = rules
+        .iter()
+        .flat_map(|rule| &rule.pattern)
+        .filter_map(|pattern| match pattern {
+            crate::Pattern::Regex(re) => Some(*re as *const regex::Regex),
+            crate::Pattern::Predicate(_) | crate::Pattern::Words(_) => None,
+        })
+        .collect();
+    regexes.sort_unstable();
+    regexes.dedup();
+    // Regexes inside productions compile on first use; one parse through
+    // the common shapes reaches most of them.
+    let options = Options::default().enable_transport_shorthand();
+    let _ = parse_with(
+        "tomorrow at 5:30pm EST, 2025-06-14T09:30:00Z or the 3rd of May, dep 0735 arr 0950",
+        &Context::default(),
+        &options,
+    );
+    WarmUpReport { rules: rules.len(), regexes: regexes.len(), elapsed: start.elapsed() }
+}
+
+/// Parse `input` under several option sets and panic if any result breaks
+/// an output invariant: spans are non-empty, in bounds and on UTF-8
+/// boundaries, and each body is the text its span covers.
+///
+/// This is the entry point for fuzzing (see `fuzz/`): parsing must never
+/// panic, whatever the input. Only built with the `fuzzing` feature.
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub fn fuzz_parse(input: &str) {
+    let reference = NaiveDate::from_ymd_opt(2013, 2, 12).and_then(|d| d.and_hms_opt(4, 30, 0)).unwrap();
+    let context = Context { reference_time: Some(reference), ..Default::default() };
+    let option_sets = [
+        Options::default(),
+        Options::default().with_overlap_policy(OverlapPolicy::KeepAll).enable_partial_dates(),
+        Options::default().enable_round_to_grain().enable_transport_shorthand().with_merge_gap(2),
+        Options::default().disable_resolution(),
+    ];
+    for options in &option_sets {
+        for entity in parse_with(input, &context, options).results {
+            assert!(entity.start < entity.end, "empty span {}..{} in {input:?}", entity.start, entity.end);
+            assert!(entity.end <= input.len(), "span {}..{} past the end of {input:?}", entity.start, entity.end);
+            assert!(
+                input.is_char_boundary(entity.start) && input.is_char_boundary(entity.end),
+                "span {}..{} splits a character in {input:?}",
+                entity.start,
+                entity.end
+            );
+            assert_eq!(entity.body, input[entity.start..entity.end], "body/span mismatch in {input:?}");
+        }
     }
 }
 
@@ -193,13 +1464,16 @@ pub fn parse_verbose(text: &str) -> ParseResultVerbose {
 /// This is useful for profiling and rule debugging. The default [`parse_with`]
 /// path does not allocate these extra traces.
 pub fn parse_verbose_with(text: &str, context: &Context, options: &Options) -> ParseResultVerbose {
-    let parser = engine::Parser::new(text, &DEFAULT_RULES);
+    let prepared = options.prepare(text);
+    let mut parser = parser_for(prepared.text(), options);
+    parser.record_nodes();
     let active_rules = parser.active_rule_names().into_iter().map(|s| s.to_string()).collect();
 
     let run = parser.run_with_metrics(context, options);
 
-    let results: Vec<Entity> = run.tokens.iter().map(|rt| resolved_to_entity(text, rt)).collect();
-    let all_candidates: Vec<Entity> = run.all_tokens.iter().map(|rt| resolved_to_entity(text, rt)).collect();
+    let entity = |rt: &ResolvedToken| resolved_to_entity(text, rt, &prepared);
+    let results: Vec<Entity> = run.tokens.iter().map(entity).collect();
+    let all_candidates: Vec<Entity> = run.all_tokens.iter().map(entity).collect();
 
     let mut saturation: Vec<SaturationPass> = Vec::new();
 
@@ -220,32 +1494,205 @@ pub fn parse_verbose_with(text: &str, context: &Context, options: &Options) -> P
         });
     }
 
+    let metrics = metrics_from_run(&run.metrics, results.len());
+    let passes = std::iter::once(initial).chain(&run.metrics.saturation.iterations);
+
     let details = ParseDetails {
         total: run.metrics.total,
         saturation_total: run.metrics.saturation.total,
         saturation,
         resolve: run.metrics.resolve,
         active_rules,
+        fired_rules: run.fired_rules.iter().map(|s| s.to_string()).collect(),
         all_candidates,
         regex_profile: run.metrics.regex_profile.clone(),
+        explanations: run.tokens.iter().map(|rt| explain(prepared.text(), &rt.node)).collect(),
+        errors: run.errors,
+        warnings: run.tokens.iter().zip(&results).flat_map(|(rt, e)| warnings_of(context, options, rt, e)).collect(),
+        identical_nodes: engine::identical_nodes(passes.flat_map(|pass| &pass.nodes))
+            .into_iter()
+            .map(|(span, rules)| IdenticalNodes {
+                start: span.start,
+                end: span.end,
+                rules: rules.into_iter().map(str::to_string).collect(),
+            })
+            .collect(),
     };
 
-    ParseResultVerbose { text: text.to_string(), results, elapsed: run.metrics.total, details }
+    ParseResultVerbose { text: text.to_string(), results, elapsed: run.metrics.total, details, metrics }
+}
+
+fn metrics_from_run(run: &engine::RunMetrics, entities: usize) -> Metrics {
+    let passes: Vec<PassStats> = std::iter::once(&run.saturation.initial_regex)
+        .chain(run.saturation.iterations.iter())
+        .map(|pass| PassStats {
+            duration: pass.duration,
+            produced: pass.produced,
+            rules_considered: pass.rules_considered,
+            rules_seeded: pass.rules_seeded,
+            regex_first_pattern_hits: pass.regex_first_pattern_hits,
+        })
+        .collect();
+
+    Metrics {
+        total: run.total,
+        saturation: run.saturation.total,
+        resolve: run.resolve,
+        active_rules: run.active_rules,
+        pass_count: passes.len(),
+        nodes_produced: passes.iter().map(|p| p.produced).sum(),
+        entities,
+        passes,
+    }
 }
 
-fn resolved_to_entity(input: &str, rt: &ResolvedToken) -> Entity {
-    let start = rt.node.range.start;
-    let end = rt.node.range.end;
-    let body = input.get(start..end).unwrap_or("").to_string();
+/// The entity for `rt`; spans found in the rewritten text are mapped back
+/// to `input`.
+fn resolved_to_entity(input: &str, rt: &ResolvedToken, prepared: &Prepared) -> Entity {
+    let (span, corrected) = prepared.to_input(input, rt.node.range.start..rt.node.range.end);
+    let (start, end) = (span.start, span.end);
+    let body = input[span].to_string();
 
     Entity {
         name: dimension_name(rt.node.token.dim).to_string(),
         body,
         value: rt.value.clone(),
+        resolved: rt.resolved.clone(),
         start,
         end,
         latent: rt.latent,
         rule: rt.node.rule_name.to_string(),
+        hints: hints_of(prepared.text(), &rt.node),
+        alternatives: rt.alternatives.clone(),
+        corrected,
+        excluded: is_negated(input, start),
+        locale: None,
+    }
+}
+
+/// Words that put what follows them under negation.
+const NEGATIONS: &[&str] = &["not", "never", "no", "nor", "except", "excluding", "without"];
+
+/// Whether a match starting at byte `start` of `input` is negated: one of
+/// [`NEGATIONS`] or an "-n't" contraction is among the three words before it
+/// in the same clause. "but" ends the scope ("not Monday but Friday"), and
+/// "no later than" is a deadline, not a negation.
+fn is_negated(input: &str, start: usize) -> bool {
+    let clause = engine::slice(input, ..start).rsplit(['.', ',', ';', ':', '!', '?', '\n']).next().unwrap_or("");
+    let words: Vec<String> = clause
+        .split_whitespace()
+        .rev()
+        .take(3)
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'').to_lowercase().replace('’', "'"))
+        .collect();
+
+    for (idx, word) in words.iter().enumerate() {
+        if word == "but" {
+            return false;
+        }
+        // `words` runs backwards, so the word after this one comes first.
+        let next = idx.checked_sub(1).map(|i| words[i].as_str());
+        if matches!(word.as_str(), "no" | "not") && matches!(next, Some("later" | "earlier" | "sooner")) {
+            continue;
+        }
+        if NEGATIONS.contains(&word.as_str()) || word.ends_with("n't") {
+            return true;
+        }
+    }
+    false
+}
+
+/// The `hint:` evidence of `node`, then `alias:<phrase>` for the aliases
+/// in its derivation over `input`.
+fn hints_of(input: &str, node: &crate::Node) -> Vec<String> {
+    fn aliases(input: &str, node: &crate::Node, hints: &mut Vec<String>) {
+        if node.rule_name == ALIAS_RULE {
+            hints
+                .push(format!("alias:{}", engine::slice(input, node.range.start..node.range.end).to_ascii_lowercase()));
+        }
+        for child in node.children.iter() {
+            aliases(input, child, hints);
+        }
+    }
+
+    let mut hints: Vec<String> =
+        node.evidence.iter().filter_map(|e| e.strip_prefix("hint:")).map(str::to_string).collect();
+    aliases(input, node, &mut hints);
+    let mut seen = std::collections::HashSet::new();
+    hints.retain(|hint| seen.insert(hint.clone()));
+    hints
+}
+
+fn explain(input: &str, node: &crate::Node) -> Explanation {
+    let rules = std::iter::once(node.rule_name).chain(node.evidence.iter().copied()).map(str::to_string).collect();
+    Explanation { rules, derivation: derivation_of(input, node) }
+}
+
+/// Data-quality warnings for `entity`, a returned time entity.
+fn warnings_of(context: &Context, options: &Options, rt: &ResolvedToken, entity: &Entity) -> Vec<ParseWarning> {
+    let crate::TokenKind::TimeExpr(expr) = &rt.node.token.kind else {
+        return Vec::new();
+    };
+    let warning = |kind, message| ParseWarning { kind, start: entity.start, end: entity.end, message };
+    let mut warnings = Vec::new();
+
+    let first = |value: TimeValue| match value {
+        TimeValue::Instant(dt) | TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => dt,
+        TimeValue::Interval { start, .. } => start,
+    };
+    let corrected = expr.find_map(&|e| match e {
+        TimeExpr::IntervalBetween { start, end } => {
+            let start = normalize_with(start, context, options).map(first)?;
+            let end = normalize_with(end, context, options).map(first)?;
+            (end < start).then_some(())
+        }
+        _ => None,
+    });
+    if corrected.is_some() {
+        warnings.push(warning(
+            WarningKind::IntervalEndCorrected,
+            "interval end before start corrected to follow it".to_string(),
+        ));
+    }
+
+    if let Some(year) = expr.find_map(&|e| match e {
+        TimeExpr::ShortYearDate { year, .. } => Some(*year),
+        _ => None,
+    }) {
+        let century = if year < options.two_digit_year_pivot { 2000 } else { 1900 };
+        warnings
+            .push(warning(WarningKind::TwoDigitYear, format!("two-digit year {year:02} read as {}", century + year)));
+    }
+
+    if let Some((zone, reading)) = expr.find_map(&|e| match e {
+        TimeExpr::InTimezone { zone, .. } => {
+            Some((zone, crate::rules::time::helpers::timezone::ambiguous_abbreviation_reading(zone)?))
+        }
+        _ => None,
+    }) {
+        warnings.push(warning(
+            WarningKind::AmbiguousTimezone,
+            format!("timezone abbreviation {} is ambiguous; read as {reading}", zone.to_ascii_uppercase()),
+        ));
+    }
+
+    warnings
+}
+
+fn derivation_of(input: &str, node: &crate::Node) -> Derivation {
+    let token = match &node.token.kind {
+        crate::TokenKind::TimeExpr(expr) => format!("{:?}", expr),
+        crate::TokenKind::Numeral(n) => format!("Numeral({})", n.value),
+        crate::TokenKind::RegexMatch(groups) => format!("{:?}", groups.raw()),
+    };
+
+    Derivation {
+        rule: node.rule_name.to_string(),
+        start: node.range.start,
+        end: node.range.end,
+        body: engine::slice(input, node.range.start..node.range.end).to_string(),
+        token,
+        children: node.children.iter().map(|child| derivation_of(input, child)).collect(),
     }
 }
 
@@ -254,9 +1701,19 @@ fn dimension_name(dim: Dimension) -> &'static str {
         Dimension::Time => "time",
         Dimension::RegexMatch => "regex",
         Dimension::Numeral => "numeral",
+        Dimension::Identifier => "identifier",
     }
 }
 
+/// Position of the dimension named `name` in result order (see
+/// `Parser::resolve_filtered`), for merging results parsed separately.
+pub(crate) fn dimension_rank(name: &str) -> usize {
+    [Dimension::Time, Dimension::RegexMatch, Dimension::Numeral, Dimension::Identifier]
+        .into_iter()
+        .position(|dim| dimension_name(dim) == name)
+        .unwrap_or(usize::MAX)
+}
+
 fn node_to_summary(node: &crate::Node) -> NodeSummary {
     NodeSummary {
         start: node.range.start,
@@ -270,7 +1727,7 @@ fn format_token_preview(kind: &crate::TokenKind) -> String {
     let s = match kind {
         crate::TokenKind::TimeExpr(expr) => format!("{:?}", expr),
         crate::TokenKind::Numeral(n) => format!("({})", n.value),
-        crate::TokenKind::RegexMatch(groups) => groups.first().cloned().unwrap_or_default(),
+        crate::TokenKind::RegexMatch(groups) => groups.raw().first().cloned().unwrap_or_default(),
     };
     s.chars().take(80).collect()
 }
@@ -278,18 +1735,40 @@ fn format_token_preview(kind: &crate::TokenKind) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Constraint, Coordinates, Holiday, PartOfDay, PartialDate, Precision, TimeValue};
     use chrono::{NaiveDate, NaiveTime};
 
     fn reference_context() -> Context {
         let date = NaiveDate::from_ymd_opt(2013, 2, 12).unwrap();
         let time = NaiveTime::from_hms_opt(4, 30, 0).unwrap();
-        Context { reference_time: NaiveDateTime::new(date, time) }
+        Context { reference_time: Some(NaiveDateTime::new(date, time)), ..Default::default() }
+    }
+
+    /// Parse `input` at the reference time with default options.
+    fn parse_at(input: &str) -> ParseResult {
+        parse_at_with(input, &Options::default())
+    }
+
+    /// Parse `input` at the reference time.
+    fn parse_at_with(input: &str, options: &Options) -> ParseResult {
+        parse_with(input, &reference_context(), options)
+    }
+
+    #[test]
+    fn default_context_reads_the_clock_in_its_own_zone() {
+        // No timezone means UTC, so "now" must be UTC wall-clock time as well:
+        // local time would shift every stated zone by the machine's offset.
+        assert_eq!(Context::default().timezone, None);
+        assert!((utc_now() - Utc::now().naive_utc()).num_seconds().abs() <= 1);
+
+        let ctx = Context { reference_time: Some(utc_now()), ..Context::default() };
+        let value = |input: &str| parse_with(input, &ctx, &Options::default()).results[0].value.clone();
+        assert_eq!(value("tomorrow at 9am UTC"), value("tomorrow at 9am"));
     }
 
     #[test]
     fn parse_with_returns_entities() {
-        let ctx = reference_context();
-        let res = parse_with("today", &ctx, &Options::default());
+        let res = parse_at("today");
 
         assert_eq!(res.text, "today");
         assert!(res.elapsed >= Duration::ZERO);
@@ -313,6 +1792,1304 @@ mod tests {
         assert!(res.details.regex_profile.is_none());
     }
 
+    #[test]
+    fn parse_verbose_exposes_public_metrics() {
+        let ctx = reference_context();
+        let res = parse_verbose_with("tomorrow at 5pm", &ctx, &Options::default());
+        let metrics = &res.metrics;
+
+        assert_eq!(metrics.total, res.details.total);
+        assert_eq!(metrics.pass_count, res.details.saturation.len());
+        assert_eq!(metrics.passes.len(), metrics.pass_count);
+        assert_eq!(metrics.nodes_produced, metrics.passes.iter().map(|p| p.produced).sum::<usize>());
+        assert_eq!(metrics.entities, res.results.len());
+        assert!(metrics.active_rules > 0);
+        assert!(metrics.passes[0].rules_considered > 0);
+    }
+
+    #[test]
+    fn typed_accessors_split_results_by_dimension() {
+        let res = parse_at("tomorrow at 5pm");
+
+        let times: Vec<_> = res.times().collect();
+        assert_eq!(times.len(), 1);
+        let expected = NaiveDate::from_ymd_opt(2013, 2, 13).unwrap().and_hms_opt(17, 0, 0).unwrap();
+        assert_eq!(times[0].time.value, TimeValue::Instant(expected));
+        assert_eq!(times[0].time.precision, Precision::Exact);
+        assert_eq!(times[0].entity.name, "time");
+
+        let numerals: Vec<_> = res.numerals().collect();
+        assert_eq!(numerals.len(), 1);
+        assert_eq!(numerals[0].numeral.value, 5.0);
+        assert_eq!(numerals[0].entity.value, "5");
+    }
+
+    #[test]
+    fn overlap_policy_controls_nested_spans() {
+        let ctx = reference_context();
+        let input = "tomorrow at 5pm";
+        let spans = |policy| {
+            let opts = Options::default().with_overlap_policy(policy);
+            parse_at_with(input, &opts).times().map(|t| (t.entity.start, t.entity.end)).collect::<Vec<_>>()
+        };
+
+        let longest = spans(OverlapPolicy::KeepLongest);
+        assert_eq!(longest, vec![(0, 15)]);
+
+        let all = spans(OverlapPolicy::KeepAll);
+        assert!(all.contains(&(0, 15)));
+        assert!(all.contains(&(0, 8)));
+        assert!(all.contains(&(12, 15)));
+
+        // Built-in rules share a priority, so the widest span wins as above.
+        assert_eq!(spans(OverlapPolicy::KeepHighestPriority), longest);
+
+        // A shorter span from a higher-priority rule beats the overlapping
+        // "tomorrow at 5pm"; the disjoint "tomorrow" stays.
+        let mut rules = crate::rules::time::rules::get();
+        rules.push(rule! {
+            name: "preferred at 5",
+            pattern: [re!(r"(?i)at 5")],
+            priority: 10,
+            prod: |_tokens: &[crate::Token]| -> Option<TimeExpr> { Some(TimeExpr::Reference) }
+        });
+        let opts = Options::default().with_overlap_policy(OverlapPolicy::KeepHighestPriority);
+        let run = engine::Parser::new(input, &rules).run_with_metrics(&ctx, &opts);
+        let kept: Vec<_> = run
+            .tokens
+            .iter()
+            .filter(|rt| rt.node.token.dim == Dimension::Time)
+            .map(|rt| (rt.node.rule_name, rt.node.range.start, rt.node.range.end))
+            .collect();
+        assert_eq!(kept, [("tomorrow", 0, 8), ("preferred at 5", 9, 13)]);
+
+        // Partly overlapping spans of equal priority are both kept, whatever
+        // their lengths; the "tomorrow" inside one of them is not.
+        rules.push(rule! {
+            name: "preferred tomorrow at",
+            pattern: [re!(r"(?i)tomorrow at")],
+            priority: 10,
+            prod: |_tokens: &[crate::Token]| -> Option<TimeExpr> { Some(TimeExpr::Reference) }
+        });
+        let run = engine::Parser::new(input, &rules).run_with_metrics(&ctx, &opts);
+        let kept: Vec<_> = run
+            .tokens
+            .iter()
+            .filter(|rt| rt.node.token.dim == Dimension::Time)
+            .map(|rt| (rt.node.rule_name, rt.node.range.start, rt.node.range.end))
+            .collect();
+        assert_eq!(kept, [("preferred tomorrow at", 0, 11), ("preferred at 5", 9, 13)]);
+    }
+
+    #[test]
+    fn covered_numerals_can_be_suppressed() {
+        let input = "at 5pm for 42 guests";
+
+        let default = parse_at(input);
+        assert!(default.numerals().any(|n| n.entity.body == "5"));
+
+        let opts = Options::default().enable_covered_numeral_suppression();
+        let res = parse_at_with(input, &opts);
+        let numerals: Vec<_> = res.numerals().map(|n| n.entity.body.as_str()).collect();
+        assert_eq!(numerals, vec!["42"]);
+        assert_eq!(res.times().count(), default.times().count());
+    }
+
+    #[test]
+    fn hedged_phrases_resolve_as_approximate() {
+        let time_precision = |input: &str| {
+            let res = parse_at(input);
+            let time = res.times().max_by_key(|t| t.entity.end - t.entity.start).expect(input).time.clone();
+            (time.precision, res.times().any(|t| t.entity.body == input))
+        };
+
+        assert_eq!(time_precision("at 3pm"), (Precision::Exact, true));
+        assert_eq!(time_precision("at about 3pm"), (Precision::Approximate, true));
+        assert_eq!(time_precision("around 3pm"), (Precision::Approximate, true));
+        assert_eq!(time_precision("3pm sharp"), (Precision::Exact, true));
+        assert_eq!(time_precision("150ish"), (Precision::Approximate, true));
+        assert_eq!(time_precision("tomorrow at 150ish"), (Precision::Approximate, true));
+        assert_eq!(time_precision("in about half an hour"), (Precision::Approximate, true));
+        assert_eq!(time_precision("in half an hour"), (Precision::Exact, true));
+
+        let res = parse_at("about 20 guests");
+        let numeral = res.numerals().find(|n| n.entity.body == "about 20").expect("hedged numeral");
+        assert_eq!(numeral.numeral.value, 20.0);
+        assert_eq!(numeral.numeral.precision, Precision::Approximate);
+        assert_eq!(numeral.entity.resolved.precision(), Precision::Approximate);
+    }
+
+    #[test]
+    fn timezone_is_kept_on_resolved_times() {
+        let at = |h, m| NaiveDate::from_ymd_opt(2013, 2, 12).unwrap().and_hms_opt(h, m, 0).unwrap();
+
+        let res = parse_at("9am PST");
+        let time = res.times().next().expect("time entity").time;
+        assert_eq!(time.value, TimeValue::Instant(at(17, 0)));
+        let tz = time.timezone.as_ref().expect("timezone");
+        assert_eq!(tz.name, "PST");
+        assert_eq!(tz.utc_offset_seconds, -8 * 3600);
+        assert_eq!(tz.local, TimeValue::Instant(at(9, 0)));
+
+        // India's half-hour offset is kept, not rounded to +05:00.
+        let res = parse_at("3pm IST");
+        let time = res.times().next().expect("time entity").time;
+        assert_eq!(time.value, TimeValue::Instant(at(9, 30)));
+        assert_eq!(time.timezone.as_ref().expect("timezone").utc_offset_seconds, 5 * 3600 + 30 * 60);
+
+        let res = parse_at("15:00 GMT - 18:00 GMT");
+        let tz = res.times().next().expect("time entity").time.timezone.clone().expect("timezone");
+        assert_eq!(tz.name, "GMT");
+        assert_eq!(tz.local, TimeValue::Interval { start: at(15, 0), end: at(18, 1) });
+
+        let res = parse_at("2013-02-12T09:30+02:00");
+        let tz = res.times().next().expect("time entity").time.timezone.clone().expect("timezone");
+        assert_eq!(tz.name, "+02:00");
+        assert_eq!(tz.utc_offset_seconds, 2 * 3600);
+        assert_eq!(tz.local, TimeValue::Instant(at(9, 30)));
+
+        let res = parse_at("9am PST or 10am PST");
+        let zones = res.times().map(|time| time.time.timezone.clone().expect("timezone").name).collect::<Vec<_>>();
+        assert_eq!(zones, ["PST", "PST"]);
+
+        let res = parse_at("9am");
+        assert_eq!(res.times().next().expect("time entity").time.timezone, None);
+    }
+
+    #[test]
+    fn find_map_visits_list_items_and_secondary_readings() {
+        let nine = || TimeExpr::Intersect {
+            expr: Box::new(TimeExpr::Reference),
+            constraint: Constraint::TimeOfDay(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+        };
+        let pst = || TimeExpr::InTimezone { expr: Box::new(nine()), zone: "PST".to_string() };
+        let zone = |expr: &TimeExpr| {
+            expr.find_map(&|e| match e {
+                TimeExpr::InTimezone { zone, .. } => Some(zone.clone()),
+                _ => None,
+            })
+        };
+
+        assert_eq!(zone(&TimeExpr::List(vec![nine(), pst()])), Some("PST".to_string()));
+        let dual = TimeExpr::DualTimezone { primary: Box::new(nine()), secondary: Box::new(pst()) };
+        assert_eq!(zone(&dual), Some("PST".to_string()));
+        assert_eq!(zone(&TimeExpr::List(vec![nine()])), None);
+    }
+
+    #[test]
+    fn partial_dates_leave_the_year_unspecified() {
+        let opts = Options::default().enable_partial_dates();
+
+        for input in ["June 14", "14th of June", "6/14"] {
+            let res = parse_at_with(input, &opts);
+            let entity = &res.results[0];
+            assert_eq!(entity.resolved.as_partial_date(), Some(&PartialDate { month: 6, day: 14 }), "{input}");
+            assert_eq!(entity.value, "--06-14");
+            assert_eq!(res.times().count(), 0);
+        }
+
+        // Dates with a year, or a time attached, still resolve fully.
+        let res = parse_at_with("June 14 1987", &opts);
+        assert_eq!(res.results[0].value, "1987-06-14 00:00:00");
+        let res = parse_at("June 14");
+        assert_eq!(res.results[0].value, "2013-06-14 00:00:00");
+    }
+
+    #[test]
+    fn birthday_and_anniversary_entities_carry_hints() {
+        let top = |input: &str| parse_at(input).results.remove(0);
+
+        let dob = top("DOB 06/14/1987");
+        assert_eq!(dob.value, "1987-06-14 00:00:00");
+        assert_eq!(dob.hints, vec!["dob".to_string()]);
+
+        let anniversary = top("my anniversary is the 3rd of May");
+        assert_eq!(anniversary.value, "2012-05-03 00:00:00");
+        assert_eq!(anniversary.hints, vec!["anniversary".to_string()]);
+
+        assert!(top("June 14th").hints.is_empty());
+    }
+
+    #[test]
+    fn business_days_follow_the_context_calendar() {
+        use crate::BusinessCalendar;
+        use chrono::{Datelike, Weekday};
+
+        let value = |input: &str, ctx: &Context| parse_with(input, ctx, &Options::default()).results[0].value.clone();
+
+        // Reference is Tuesday 2013-02-12; Monday 2013-02-18 is a holiday.
+        let calendar =
+            BusinessCalendar::default().with_holidays(|date: NaiveDate| date.month() == 2 && date.day() == 18);
+        let ctx = reference_context().with_calendar(calendar);
+        assert_eq!(value("in 3 business days", &ctx), "2013-02-15 00:00:00");
+        assert_eq!(value("in 4 business days", &ctx), "2013-02-19 00:00:00");
+
+        let sunday_to_thursday = [Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu];
+        let ctx = reference_context().with_calendar(BusinessCalendar::default().with_workweek(sunday_to_thursday));
+        assert_eq!(value("in 3 business days", &ctx), "2013-02-17 00:00:00");
+
+        let ctx = reference_context().with_calendar(BusinessCalendar::default().with_workweek([]));
+        assert!(parse_with("next business day", &ctx, &Options::default()).results.is_empty());
+    }
+
+    #[test]
+    fn business_hours_follow_the_context_calendar() {
+        use crate::BusinessCalendar;
+
+        let hours = BusinessCalendar::default().with_business_hours(
+            NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+        );
+        let ctx = reference_context().with_calendar(hours);
+        let value = |input: &str| parse_with(input, &ctx, &Options::default()).results[0].value.clone();
+
+        assert_eq!(value("EOD Friday"), "2013-02-15 18:00:00");
+        assert_eq!(value("start of business tomorrow"), "2013-02-13 08:30:00");
+    }
+
+    #[test]
+    fn fiscal_years_follow_the_configured_start_month() {
+        let options = Options::default().with_fiscal_year_start(chrono::Month::October);
+        let value = |input: &str| parse_at_with(input, &options).results[0].value.clone();
+
+        assert_eq!(value("FY2025"), "2024-10-01 00:00:00/2025-10-01 00:00:00");
+        assert_eq!(value("Q2 FY25"), "2025-01-01 00:00:00/2025-04-01 00:00:00");
+        assert_eq!(value("fiscal year 2024"), "2023-10-01 00:00:00/2024-10-01 00:00:00");
+    }
+
+    #[test]
+    fn southern_hemisphere_swaps_the_seasons() {
+        let ctx = reference_context().with_hemisphere(crate::Hemisphere::Southern);
+        let value = |input: &str| parse_with(input, &ctx, &Options::default()).results[0].value.clone();
+
+        assert_eq!(value("summer"), "2012-12-21 00:00:00/2013-03-21 00:00:00");
+        assert_eq!(value("winter"), "2013-06-21 00:00:00/2013-09-24 00:00:00");
+        assert_eq!(value("early summer"), "2012-12-21 00:00:00/2013-01-20 00:00:00");
+    }
+
+    #[test]
+    fn lunar_holidays_resolve_through_the_holiday_provider() {
+        use crate::{BusinessCalendar, HolidayProvider};
+
+        struct Diwali;
+        impl HolidayProvider for Diwali {
+            fn is_holiday(&self, _date: NaiveDate) -> bool {
+                false
+            }
+            fn resolve(&self, name: &str, year: i32) -> Option<NaiveDate> {
+                match (name, year) {
+                    ("diwali", 2013) => NaiveDate::from_ymd_opt(2013, 11, 3),
+                    ("diwali", 2014) => NaiveDate::from_ymd_opt(2014, 10, 23),
+                    _ => None,
+                }
+            }
+        }
+
+        let ctx = reference_context().with_calendar(BusinessCalendar::default().with_holidays(Diwali));
+        let value = |input: &str| parse_with(input, &ctx, &Options::default()).results[0].value.clone();
+        assert_eq!(value("Diwali"), "2013-11-03 00:00:00");
+        assert_eq!(value("deepavali 2014"), "2014-10-23 00:00:00");
+
+        // Without a provider there is nothing to resolve against.
+        assert!(parse_at("Diwali").times().next().is_none());
+    }
+
+    #[cfg(feature = "calendars")]
+    #[test]
+    fn islamic_calendar_resolves_ramadan_and_eid() {
+        use crate::{BusinessCalendar, IslamicCalendar};
+
+        let ctx = reference_context().with_calendar(BusinessCalendar::default().with_holidays(IslamicCalendar));
+        let value = |input: &str| parse_with(input, &ctx, &Options::default()).results[0].value.clone();
+        assert_eq!(value("Ramadan"), "2013-07-09 00:00:00");
+        assert_eq!(value("eid al-fitr 2025"), "2025-03-31 00:00:00");
+    }
+
+    #[test]
+    fn enumerated_days_resolve_to_one_entity_with_several_times() {
+        let at = |m, d| TimeValue::Instant(NaiveDate::from_ymd_opt(2013, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+        let options = Options::default().enable_covered_numeral_suppression();
+        for input in ["on the 3rd, 7th and 12th of May", "May 3, 7 and 12"] {
+            let res = parse_at_with(input, &options);
+            assert_eq!(res.results.len(), 1, "{input}");
+            let entity = &res.results[0];
+            assert_eq!((entity.start, entity.end), (0, input.len()));
+            let values: Vec<_> =
+                entity.resolved.as_times().expect("time list").iter().map(|t| t.value.clone()).collect();
+            assert_eq!(values, vec![at(5, 3), at(5, 7), at(5, 12)]);
+            assert_eq!(entity.value, "2013-05-03 00:00:00, 2013-05-07 00:00:00, 2013-05-12 00:00:00");
+        }
+
+        let res = parse_at("March 3rd and 5th");
+        assert_eq!(res.results[0].resolved.as_times().map(<[_]>::len), Some(2));
+    }
+
+    #[test]
+    fn interval_durations() {
+        use crate::Grain;
+        use chrono::Duration;
+
+        let value = |input: &str| parse_at(input).results[0].resolved.as_time().unwrap().value.clone();
+
+        let week = value("next week");
+        assert_eq!(week.duration(), Some(Duration::days(7)));
+        assert_eq!(week.duration_seconds(), Some(7 * 86_400));
+        assert_eq!(week.duration_in(Grain::Day), Some(7));
+        assert_eq!(week.duration_in(Grain::Week), Some(1));
+        assert_eq!(week.duration_in(Grain::Month), Some(0));
+
+        let year = value("2014");
+        assert_eq!(year.duration_in(Grain::Month), Some(12));
+        assert_eq!(year.duration_in(Grain::Quarter), Some(4));
+        assert_eq!(year.duration_in(Grain::Year), Some(1));
+
+        assert_eq!(value("tomorrow at 3pm").duration(), None);
+        assert_eq!(value("after 3pm").duration(), None);
+        assert_eq!(value("after 3pm").duration_in(Grain::Hour), None);
+    }
+
+    #[test]
+    fn disabled_resolution_keeps_the_symbolic_expression() {
+        let ctx = reference_context();
+        let options = Options::default().disable_resolution();
+
+        for input in ["next Friday", "around 3pm", "from 10pm to 2am"] {
+            let resolved = &parse_at(input).results[0];
+            let symbolic = &parse_at_with(input, &options).results[0];
+            let Value::Symbolic(unresolved) = &symbolic.resolved else { panic!("{input}: {:?}", symbolic.resolved) };
+
+            assert_eq!((symbolic.start, symbolic.end), (resolved.start, resolved.end), "{input}");
+            assert_eq!(symbolic.value, format!("{:?}", unresolved.expr));
+            assert_eq!(unresolved.precision, resolved.resolved.precision(), "{input}");
+            assert_eq!(
+                crate::normalize(&unresolved.expr, &ctx).as_ref(),
+                resolved.resolved.as_time().map(|t| &t.value)
+            );
+        }
+    }
+
+    #[test]
+    fn symbolic_entities_resolve_against_a_new_reference() {
+        let options = Options::default().disable_resolution();
+        let entity = parse_at_with("tomorrow at 3pm", &options).results[0].clone();
+
+        let next_day = NaiveDate::from_ymd_opt(2013, 2, 13).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let ctx = Context { reference_time: Some(next_day), ..Default::default() };
+        let resolved = entity.resolve_with(&ctx, &options).expect("resolves");
+        assert_eq!(resolved.to_string(), "2013-02-14 15:00:00");
+
+        let dates = parse_at_with("May 3 and 7", &options).results[0].clone();
+        let later = Context { reference_time: Some(next_day + chrono::Duration::days(365)), ..Default::default() };
+        assert_eq!(
+            dates.resolve_with(&later, &options).unwrap().to_string(),
+            "2014-05-03 00:00:00, 2014-05-07 00:00:00"
+        );
+
+        let resolved = &parse_at("tomorrow").results[0];
+        assert_eq!(resolved.resolve_with(&ctx, &options), None);
+    }
+
+    #[test]
+    fn times_carry_their_grain_and_can_round_to_it() {
+        use crate::Grain;
+
+        let time = |input: &str, options: &Options| {
+            parse_at_with(input, options).results[0].resolved.as_time().expect(input).clone()
+        };
+
+        let defaults = Options::default();
+        for (input, grain) in [
+            ("March 3", Grain::Day),
+            ("tomorrow", Grain::Day),
+            ("next Friday", Grain::Day),
+            ("at midnight", Grain::Hour),
+            ("3pm", Grain::Hour),
+            ("tomorrow at 3:30pm", Grain::Minute),
+            ("in 2 hours", Grain::Second),
+            ("now", Grain::Second),
+        ] {
+            assert_eq!(time(input, &defaults).grain, grain, "{input}");
+        }
+        assert_eq!(Value::Time(time("March 3", &defaults)).to_string(), "2013-03-03 00:00:00");
+
+        let rounded = Options::default().enable_round_to_grain();
+        let value = |input: &str| Value::Time(time(input, &rounded)).to_string();
+        assert_eq!(value("March 3"), "2013-03-03 00:00:00/2013-03-04 00:00:00");
+        assert_eq!(value("3pm"), "2013-02-12 15:00:00/2013-02-12 16:00:00");
+        assert_eq!(value("in 2 hours"), "2013-02-12 06:30:00");
+        assert_eq!(value("next week"), "2013-02-18 00:00:00/2013-02-25 00:00:00");
+    }
+
+    #[test]
+    fn two_digit_years_follow_the_century_pivot() {
+        let value = |input: &str, options: &Options| parse_at_with(input, options).results[0].value.clone();
+
+        assert_eq!(value("3/5/49", &Options::default()), "2049-03-05 00:00:00");
+        assert_eq!(value("3/5/50", &Options::default()), "1950-03-05 00:00:00");
+
+        let options = Options::default().with_two_digit_year_pivot(30);
+        assert_eq!(value("3/5/49", &options), "1949-03-05 00:00:00");
+        assert_eq!(value("31.12.29", &options), "2029-12-31 00:00:00");
+    }
+
+    #[test]
+    fn years_and_clock_times_are_disambiguated_from_context() {
+        let year = |y| {
+            Value::Time(Time {
+                value: TimeValue::Interval {
+                    start: NaiveDate::from_ymd_opt(y, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+                    end: NaiveDate::from_ymd_opt(y + 1, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+                },
+                precision: Precision::Exact,
+                grain: crate::Grain::Year,
+                timezone: None,
+                holiday: None,
+                part_of_day: None,
+            })
+        };
+        let first_time = |input: &str| {
+            let res = parse_at(input);
+            res.results.into_iter().find(|e| e.name == "time").expect(input)
+        };
+
+        for input in ["2017", "born in 2017", "March 2017"] {
+            let entity = first_time(input);
+            assert!(entity.value.starts_with("2017-"), "{input}: {}", entity.value);
+        }
+
+        let entity = first_time("2017");
+        assert_eq!(entity.resolved, year(2017));
+        assert_eq!(entity.alternatives.len(), 1);
+        assert_eq!(entity.alternatives[0].to_string(), "2013-02-12 20:17:00");
+
+        // The "hours" suffix settles it as a clock time.
+        let entity = first_time("1930 hours");
+        assert_eq!(entity.value, "2013-02-12 19:30:00");
+        assert!(entity.alternatives.is_empty());
+
+        assert_eq!(first_time("see you at 2017").value, "2013-02-12 20:17:00");
+    }
+
+    #[test]
+    fn next_month_follows_the_configured_policy() {
+        let value = |input: &str, options: &Options| parse_at_with(input, options).results[0].value.clone();
+
+        let upcoming = Options::default();
+        assert_eq!(value("this June", &upcoming), "2013-06-01 00:00:00");
+        assert_eq!(value("next June", &upcoming), "2013-06-01 00:00:00");
+        assert_eq!(value("next February", &upcoming), "2014-02-01 00:00:00");
+        assert_eq!(value("last June", &upcoming), "2012-06-01 00:00:00");
+
+        let following = Options::default().with_next_month_policy(NextMonthPolicy::FollowingYear);
+        assert_eq!(value("this June", &following), "2013-06-01 00:00:00");
+        assert_eq!(value("next June", &following), "2014-06-01 00:00:00");
+        assert_eq!(value("last January", &following), "2012-01-01 00:00:00");
+    }
+
+    #[test]
+    fn nth_weekday_recurrences_resolve_to_rrules() {
+        let recurrence = |input: &str| {
+            let res = parse_at(input);
+            res.results[0].resolved.as_recurrence().expect(input).clone()
+        };
+
+        for input in ["on the first Monday of every month", "every first Monday of the month"] {
+            let monthly = recurrence(input);
+            assert_eq!(monthly.rrule(), "FREQ=MONTHLY;BYDAY=MO;BYSETPOS=1", "{input}");
+            assert_eq!(Value::Time(monthly.next).to_string(), "2013-03-04 00:00:00", "{input}");
+        }
+        // The reference day itself is the second Tuesday of February.
+        let today = recurrence("the second Tuesday of each month");
+        assert_eq!(Value::Time(today.next).to_string(), "2013-02-12 00:00:00");
+
+        let quarterly = recurrence("every last Friday of the quarter");
+        assert_eq!(quarterly.rrule(), "FREQ=MONTHLY;INTERVAL=3;BYDAY=FR;BYSETPOS=-1");
+        assert_eq!(
+            Value::Recurrence(quarterly).to_string(),
+            "2013-03-29 00:00:00 RRULE:FREQ=MONTHLY;INTERVAL=3;BYDAY=FR;BYSETPOS=-1"
+        );
+    }
+
+    #[test]
+    fn dual_timezone_times_flag_disagreeing_readings() {
+        let first = |input: &str| parse_at(input).results[0].clone();
+
+        let consistent = first("9am ET / 6am PT");
+        assert_eq!(consistent.end - consistent.start, "9am ET / 6am PT".len());
+        assert!(consistent.hints.is_empty());
+        assert!(consistent.alternatives.is_empty());
+
+        let mismatch = first("9am ET / 7am PT");
+        assert_eq!(mismatch.value, "2013-02-12 14:00:00");
+        assert_eq!(mismatch.hints, vec!["timezone-mismatch".to_string()]);
+        assert_eq!(mismatch.alternatives.len(), 1);
+        assert_eq!(mismatch.alternatives[0].to_string(), "2013-02-12 15:00:00");
+    }
+
+    #[test]
+    fn interval_endpoints_convert_from_their_own_zones() {
+        let first = |input: &str| parse_at(input).results[0].clone();
+
+        // 9am ET is 6am PT: an hour, each end read in its own zone.
+        let hour = first("9am ET to 7am PT");
+        assert_eq!(hour.end - hour.start, "9am ET to 7am PT".len());
+        assert_eq!(hour.value, first("9am ET to 10am ET").value);
+        assert!(hour.hints.is_empty());
+        // Overnight across zones is a range like any other.
+        assert!(first("10pm ET to 2am PT").hints.is_empty());
+
+        // The same instant twice, and an end before the start.
+        for input in ["9am ET to 6am PT", "11am ET to 7am PT", "11pm PT to 1am ET"] {
+            let odd = first(input);
+            assert_eq!(odd.end - odd.start, input.len(), "{input}");
+            assert_eq!(odd.hints, vec!["timezone-mismatch".to_string()], "{input}");
+        }
+    }
+
+    #[test]
+    fn transport_shorthand_is_opt_in() {
+        let transport = Options::default().enable_transport_shorthand();
+        let value = |input: &str, options: &Options| {
+            parse_at_with(input, options).results.first().map(|e| (e.value.clone(), e.end))
+        };
+
+        assert_eq!(value("06JUN25", &transport), Some(("2025-06-06 00:00:00".to_string(), 7)));
+        assert_eq!(value("06JUN25 0830", &transport), Some(("2025-06-06 08:30:00".to_string(), 12)));
+        assert_eq!(
+            value("dep 0735 arr 0950", &transport),
+            Some(("2013-02-12 07:35:00/2013-02-12 09:51:00".to_string(), 17))
+        );
+        // An arrival earlier than the departure lands on the next day.
+        assert_eq!(
+            value("dep 2330 arr 0150", &transport),
+            Some(("2013-02-12 23:30:00/2013-02-13 01:51:00".to_string(), 17))
+        );
+
+        let default = Options::default();
+        assert_ne!(value("dep 0735 arr 0950", &default).map(|(_, end)| end), Some(17));
+        assert_ne!(value("06JUN25 0830", &default).map(|(_, end)| end), Some(12));
+    }
+
+    #[test]
+    fn merge_gap_joins_a_day_and_a_time_across_unknown_words() {
+        let ctx = reference_context();
+        let input = "tomorrow maybe at 5";
+
+        let spans_all = |res: &ParseResult| res.results.iter().any(|e| (e.start, e.end) == (0, res.text.len()));
+        assert!(!spans_all(&parse_at(input)));
+
+        let res = parse_at_with(input, &Options::default().with_merge_gap(2));
+        let times: Vec<&Entity> = res.results.iter().filter(|e| e.name == "time").collect();
+        assert_eq!(times.len(), 1);
+        assert_eq!(times[0].value, "2013-02-13 05:00:00");
+        assert_eq!((times[0].start, times[0].end), (0, input.len()));
+
+        let verbose = parse_verbose_with(input, &ctx, &Options::default().with_merge_gap(2));
+        let (_, explanation) =
+            verbose.results.iter().zip(&verbose.details.explanations).find(|(e, _)| e.name == "time").unwrap();
+        assert!(explanation.rules.iter().any(|r| r == "merge:gap"));
+
+        // Gaps wider than the tolerance, or holding digits, stay apart.
+        let options = Options::default().with_merge_gap(2);
+        assert!(!spans_all(&parse_at_with("tomorrow or maybe later at 5", &options)));
+        assert!(!spans_all(&parse_at_with("tomorrow room 12 at 5", &options)));
+    }
+
+    #[test]
+    fn rule_coverage_separates_dead_and_filtered_rules() {
+        let ctx = reference_context();
+        let options = Options::default();
+        let mut coverage = RuleCoverage::new(&options);
+        for input in ["tomorrow at 5pm", "next Friday"] {
+            coverage.record(&parse_verbose_with(input, &ctx, &options));
+        }
+
+        assert_eq!(coverage.parses, 2);
+        assert_eq!(coverage.fired.get("tomorrow"), Some(&1));
+        assert_eq!(coverage.kept.get("tomorrow"), Some(&1));
+        assert!(coverage.never_fired().contains(&"FY<year>"));
+        assert!(!coverage.never_fired().contains(&"tomorrow"));
+        for name in coverage.always_filtered() {
+            assert!(coverage.fired.contains_key(name) && !coverage.kept.contains_key(name), "{name}");
+        }
+        assert!(coverage.never_fired().len() + coverage.fired.len() >= coverage.rules.len());
+    }
+
+    #[test]
+    fn rule_coverage_reports_rules_producing_identical_nodes() {
+        static RULES: Lazy<Vec<Rule>> = Lazy::new(|| {
+            fn tomorrow() -> Option<TimeExpr> {
+                Some(crate::rules::time::helpers::shift::shift_by_grain(TimeExpr::Reference, 1, crate::Grain::Day))
+            }
+            vec![
+                rule! {
+                    name: "tomorrow",
+                    pattern: [re!(r"(?i)\btomorrow\b")],
+                    prod: |_tokens: &[crate::Token]| -> Option<TimeExpr> { tomorrow() }
+                },
+                rule! {
+                    name: "tomorrow (copy)",
+                    pattern: [re!(r"(?i)\btomorrow\b")],
+                    prod: |_tokens: &[crate::Token]| -> Option<TimeExpr> { tomorrow() }
+                },
+                rule! {
+                    name: "tmrw",
+                    pattern: [re!(r"(?i)\b(?:tomorrow|tmrw)\b")],
+                    priority: 5,
+                    prod: |_tokens: &[crate::Token]| -> Option<TimeExpr> { tomorrow() }
+                },
+            ]
+        });
+        let ctx = reference_context();
+        let options = Options { rule_set: Some(RuleSet { rules: &RULES }), ..Options::default() };
+
+        let result = parse_verbose_with("tomorrow", &ctx, &options);
+        // "tmrw" runs first, being of higher priority.
+        let rules = ["tmrw", "tomorrow", "tomorrow (copy)"].map(String::from).to_vec();
+        assert_eq!(result.details.identical_nodes, [IdenticalNodes { start: 0, end: 8, rules }]);
+
+        let mut coverage = RuleCoverage::new(&options);
+        for input in ["tomorrow", "tmrw", "see you tomorrow"] {
+            coverage.record(&parse_verbose_with(input, &ctx, &options));
+        }
+        let shadowed = |rule: &str, by: &str| ShadowedRule {
+            rule: rule.to_string(),
+            priority: 0,
+            by: by.to_string(),
+            by_priority: if by == "tmrw" { 5 } else { 0 },
+            parses: 2,
+        };
+        // The copy shadows the original and the other way around; the one
+        // registered later is reported. "tmrw" matches more, so it is not.
+        assert_eq!(
+            coverage.shadowed(),
+            [
+                shadowed("tomorrow", "tmrw"),
+                shadowed("tomorrow (copy)", "tmrw"),
+                shadowed("tomorrow (copy)", "tomorrow")
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fuzzing")]
+    fn fuzz_parse_survives_adversarial_inputs() {
+        for input in [
+            "",
+            "in P999999999D",
+            "in 5000000 years",
+            "next 99999999 weeks",
+            "in 99999999 months",
+            "in 2000000000 weekends",
+            "İstanbul tomorrow at 5 🎉",
+            "１２：３０ ﬃ ǅ Ⅻ",
+            "[14/Jun/2025:09:30:01 +0200] dep 2359 arr 0000",
+        ] {
+            fuzz_parse(input);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "fuzzing")]
+    fn fuzz_parse_survives_multibyte_characters_anywhere() {
+        for base in ["tomorrow at 5pm", "March, 3 in the evening"] {
+            for insert in ["🎉", "\u{301}", "👩\u{200d}💻"] {
+                for (idx, _) in base.char_indices() {
+                    fuzz_parse(&format!("{}{insert}{}", &base[..idx], &base[idx..]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_forms_follow_the_grain() {
+        let canonical = |input: &str, format: CanonicalFormat| parse_at(input).results[0].canonical(format);
+
+        assert_eq!(canonical("tomorrow", CanonicalFormat::Iso), "2013-02-13");
+        assert_eq!(canonical("tomorrow", CanonicalFormat::Readable), "Wed 13 Feb 2013");
+        assert_eq!(canonical("2014", CanonicalFormat::Iso), "2014");
+        assert_eq!(canonical("3pm ET", CanonicalFormat::Iso), "2013-02-12T15:00:00-05:00[America/New_York]");
+        assert_eq!(canonical("friday at 3pm PST", CanonicalFormat::Readable), "Fri 15 Feb 2013 15:00 PST");
+        assert_eq!(canonical("from 3 to 5pm", CanonicalFormat::Readable), "Tue 12 Feb 2013 15:00–18:00");
+        assert_eq!(canonical("after friday", CanonicalFormat::Iso), "2013-02-15/..");
+        assert_eq!(
+            canonical("every first monday of the month", CanonicalFormat::Readable),
+            "every month on the first Monday"
+        );
+    }
+
+    #[test]
+    fn entity_id_ignores_offsets() {
+        let first = parse_at("call me tomorrow at 5pm");
+        let second = parse_at("ok, tomorrow at 5pm then");
+        let id_of = |result: &ParseResult| result.results.iter().find(|e| e.body == "tomorrow at 5pm").unwrap().id();
+
+        assert_eq!(id_of(&first), id_of(&second));
+        let mut other = first.results[0].clone();
+        other.value.push('x');
+        assert_ne!(other.id(), first.results[0].id());
+    }
+
+    #[test]
+    fn holidays_are_labelled() {
+        let holiday = |input: &str| {
+            let result = parse_at(input);
+            let time = result.times().find(|t| t.entity.body == input).unwrap();
+            time.time.holiday
+        };
+
+        assert_eq!(holiday("thanksgiving"), Some(Holiday::Thanksgiving));
+        assert_eq!(holiday("christmas at 6pm"), Some(Holiday::Christmas));
+        assert_eq!(holiday("black friday 2014"), Some(Holiday::BlackFriday));
+        assert_eq!(holiday("3 days before christmas"), None);
+        assert_eq!(holiday("tomorrow"), None);
+        assert_eq!(Holiday::NewYearsEve.name(), "New Year's Eve");
+    }
+
+    #[test]
+    fn parts_of_day_are_labelled() {
+        let part_of_day = |input: &str| {
+            let result = parse_at(input);
+            let time = result.times().find(|t| t.entity.body == input).unwrap();
+            time.time.part_of_day
+        };
+
+        assert_eq!(part_of_day("tomorrow evening"), Some(PartOfDay::Evening));
+        assert_eq!(part_of_day("monday afternoon"), Some(PartOfDay::Afternoon));
+        assert_eq!(part_of_day("tonight"), Some(PartOfDay::Night));
+        assert_eq!(part_of_day("tomorrow evening at 7"), Some(PartOfDay::Evening));
+        assert_eq!(part_of_day("tomorrow"), None);
+        assert_eq!(part_of_day("friday overnight"), Some(PartOfDay::Overnight));
+    }
+
+    #[test]
+    fn intervals_are_half_open() {
+        let interval = |input: &str| {
+            let result = parse_at(input);
+            let time = result.times().find(|t| t.entity.body == input).unwrap().time.clone();
+            let TimeValue::Interval { end, .. } = time.value else { panic!("{input}: not an interval") };
+            (end.to_string(), time.value.inclusive_end(time.grain).unwrap().to_string())
+        };
+
+        assert_eq!(interval("from 3 to 5pm"), ("2013-02-12 18:00:00".into(), "2013-02-12 17:00:00".into()));
+        assert_eq!(interval("July 13-15"), ("2013-07-16 00:00:00".into(), "2013-07-15 00:00:00".into()));
+        assert_eq!(interval("from march to may"), ("2013-06-01 00:00:00".into(), "2013-05-01 00:00:00".into()));
+        assert_eq!(interval("from monday to friday"), ("2013-02-16 00:00:00".into(), "2013-02-15 00:00:00".into()));
+    }
+
+    #[test]
+    fn instants_widen_to_their_grain() {
+        let widened = |input: &str| {
+            let result = parse_at(input);
+            result.results.iter().find(|e| e.body == input).unwrap().resolved.widen().to_string()
+        };
+
+        assert_eq!(widened("tomorrow"), "2013-02-13 00:00:00/2013-02-14 00:00:00");
+        assert_eq!(widened("3pm"), "2013-02-12 15:00:00/2013-02-12 16:00:00");
+        assert_eq!(widened("march"), "2013-03-01 00:00:00/2013-04-01 00:00:00");
+        assert_eq!(widened("next 3 days"), "2013-02-13 00:00:00/2013-02-16 00:00:00");
+        assert_eq!(widened("in 2 hours"), "2013-02-12 06:30:00");
+    }
+
+    #[test]
+    fn times_stay_symbolic_without_a_reference() {
+        let ctx = Context { reference_time: None, ..reference_context() };
+        let result = parse_with("tomorrow at 5pm and 3 apples", &ctx, &Options::default());
+
+        let time = result.results.iter().find(|e| e.body == "tomorrow at 5pm").unwrap();
+        assert!(time.resolved.as_symbolic().is_some());
+        assert!(result.numerals().any(|n| n.numeral.value == 3.0));
+
+        let resolved = time.resolve_with(&reference_context(), &Options::default()).unwrap();
+        assert_eq!(resolved.to_string(), "2013-02-13 17:00:00");
+    }
+
+    #[test]
+    fn long_inputs_are_refused() {
+        let ctx = reference_context();
+        let options = Options::default().with_max_input_len(Some(16));
+
+        assert_eq!(try_parse_with("tomorrow at 5pm", &ctx, &options).unwrap().times().count(), 1);
+        let long = "tomorrow at 5pm, or else friday";
+        let err = try_parse_with(long, &ctx, &options).unwrap_err();
+        assert_eq!(err, ParseError::InputTooLong { len: 31, max: 16 });
+        assert!(err.to_string().contains("chunks"));
+        assert!(parse_with(long, &ctx, &options).results.is_empty());
+        assert!(!parse_with(long, &ctx, &options.with_max_input_len(None)).results.is_empty());
+    }
+
+    #[test]
+    fn suspicious_readings_are_warned_about() {
+        let ctx = reference_context();
+        // One warning per returned entity; several readings may share one.
+        let kinds = |input: &str| -> Vec<WarningKind> {
+            let res = parse_verbose_with(input, &ctx, &Options::default());
+            let mut kinds: Vec<WarningKind> = res.details.warnings.iter().map(|w| w.kind).collect();
+            kinds.dedup();
+            kinds
+        };
+
+        assert_eq!(kinds("friday 11pm to 1am"), vec![WarningKind::IntervalEndCorrected]);
+        assert_eq!(kinds("10/31/74"), vec![WarningKind::TwoDigitYear]);
+        assert_eq!(kinds("9am CST"), vec![WarningKind::AmbiguousTimezone]);
+        assert!(kinds("tomorrow from 9am to 5pm PST").is_empty());
+
+        let res = parse_verbose_with("due 10/31/74", &ctx, &Options::default());
+        let (entity, warning) = (&res.results[0], &res.details.warnings[0]);
+        assert_eq!((warning.start, warning.end), (entity.start, entity.end));
+        assert_eq!(warning.message, "two-digit year 74 read as 1974");
+    }
+
+    #[test]
+    fn typos_are_corrected_when_enabled() {
+        let input = "lunch on wednseday or Febuary 3";
+        assert!(parse_at(input).times().all(|t| t.entity.body != "wednseday"));
+
+        let res = parse_at_with(input, &Options::default().enable_typo_tolerance());
+        let times: Vec<(&str, &str, bool)> =
+            res.results.iter().filter(|e| e.name == "time").map(|e| (&*e.body, &*e.value, e.corrected)).collect();
+        assert_eq!(times, vec![("wednseday", "2013-02-13 00:00:00", true), ("Febuary 3", "2014-02-03 00:00:00", true)]);
+
+        let tomorrow = parse_at_with("see you tommorow at 5pm", &Options::default().enable_typo_tolerance());
+        let time = tomorrow.times().next().unwrap();
+        assert_eq!((time.entity.body.as_str(), time.entity.value.as_str()), ("tommorow at 5pm", "2013-02-13 17:00:00"));
+    }
+
+    #[test]
+    fn bare_days_of_the_month_are_latent_and_follow_the_policy() {
+        let time = |input: &str, options: &Options| {
+            let res = parse_at_with(input, options);
+            res.results.iter().find(|e| e.name == "time").map(|e| (e.value.clone(), e.latent))
+        };
+        let upcoming = Options::default();
+        let past = Options::default().with_day_of_month_policy(DayOfMonthPolicy::Past);
+
+        assert_eq!(time("I'll do it on the 3rd", &upcoming), Some(("2013-03-03 00:00:00".into(), true)));
+        assert_eq!(time("I'll do it on the 3rd", &past), Some(("2013-02-03 00:00:00".into(), true)));
+        assert_eq!(time("the 15th", &upcoming), Some(("2013-02-15 00:00:00".into(), true)));
+        assert_eq!(time("the 15th", &past), Some(("2013-01-15 00:00:00".into(), true)));
+        assert_eq!(time("the twenty-third", &upcoming), Some(("2013-02-23 00:00:00".into(), true)));
+        // Months without the day are skipped.
+        assert_eq!(time("on the 31st", &upcoming), Some(("2013-03-31 00:00:00".into(), true)));
+        assert_eq!(time("on the 30th", &past), Some(("2013-01-30 00:00:00".into(), true)));
+
+        // With a month or weekday the day is no longer latent.
+        assert_eq!(time("friday the 15th", &upcoming), Some(("2013-02-15 00:00:00".into(), false)));
+        let strict = Options::default().disable_groups(RuleGroups::LATENT);
+        assert_eq!(time("the 3rd", &strict), None);
+        assert_eq!(time("march 3rd", &strict), Some(("2013-03-03 00:00:00".into(), false)));
+    }
+
+    #[test]
+    fn negated_times_are_marked_excluded() {
+        let excluded = |input: &str| {
+            let res = parse_at(input);
+            res.results.iter().filter(|e| e.name == "time").map(|e| (e.body.clone(), e.excluded)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(excluded("not on Friday"), [("Friday".to_string(), true)]);
+        assert_eq!(excluded("any day except Tuesday"), [("Tuesday".to_string(), true)]);
+        assert_eq!(excluded("I can't make it Monday"), [("Monday".to_string(), true)]);
+        assert_eq!(excluded("not Monday but Friday"), [("Monday".to_string(), true), ("Friday".to_string(), false)]);
+        assert_eq!(excluded("no later than Friday"), [("no later than Friday".to_string(), false)]);
+        assert_eq!(
+            excluded("Not Monday. Tuesday works"),
+            [("Monday".to_string(), true), ("Tuesday".to_string(), false)]
+        );
+        assert!(excluded("never after 6pm").iter().all(|(_, excluded)| *excluded));
+        assert_eq!(excluded("see you on Friday"), [("Friday".to_string(), false)]);
+    }
+
+    #[test]
+    fn hypothetical_times_are_latent_when_enabled() {
+        let ctx = reference_context();
+        let options = Options::default().enable_latent_hypotheticals();
+        let latent = |input: &str, options: &Options| {
+            let res = parse_at_with(input, options);
+            res.results.iter().filter(|e| e.name == "time").map(|e| (e.body.clone(), e.latent)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(latent("if we met yesterday", &Options::default()), [("yesterday".to_string(), false)]);
+        assert_eq!(latent("if we met yesterday", &options), [("yesterday".to_string(), true)]);
+        assert_eq!(latent("Had it been Monday", &options), [("Monday".to_string(), true)]);
+        assert_eq!(latent("tuesday would have worked", &options), [("tuesday".to_string(), true)]);
+        // Only the sentence holding the cue.
+        assert_eq!(latent("If it rains we stay in. See you tomorrow", &options), [("tomorrow".to_string(), false)]);
+        assert_eq!(latent("we met yesterday", &options), [("yesterday".to_string(), false)]);
+
+        let verbose = parse_verbose_with("unless it's friday", &ctx, &options);
+        let (_, explanation) =
+            verbose.results.iter().zip(&verbose.details.explanations).find(|(e, _)| e.name == "time").unwrap();
+        assert!(explanation.rules.iter().any(|r| r == "hypothetical:unless"));
+    }
+
+    #[test]
+    fn deadlines_run_from_now_and_carry_a_hint() {
+        let deadline = |input: &str| {
+            let res = parse_at(input);
+            let time = res.results.into_iter().find(|e| e.name == "time" && e.body == input).unwrap();
+            (time.value, time.hints)
+        };
+        let hint = vec!["deadline".to_string()];
+
+        assert_eq!(deadline("no later than Friday"), ("2013-02-12 04:30:00/2013-02-15 00:00:00".into(), hint.clone()));
+        assert_eq!(deadline("not later than 5pm"), ("2013-02-12 04:30:00/2013-02-12 17:00:00".into(), hint.clone()));
+        assert_eq!(deadline("Friday at the latest"), ("2013-02-12 04:30:00/2013-02-15 00:00:00".into(), hint.clone()));
+        assert_eq!(deadline("within 3 days"), ("2013-02-12 04:30:00/2013-02-15 00:00:00".into(), hint.clone()));
+        assert_eq!(deadline("by Friday"), ("2013-02-12 04:30:00/2013-02-15 00:00:00".into(), hint.clone()));
+
+        let res = parse_at("by Tuesday EOD at the latest");
+        let readings: Vec<&str> = res.results.iter().filter(|e| e.name == "time").map(|e| e.value.as_str()).collect();
+        assert!(readings.contains(&"2013-02-12 04:30:00/2013-02-19 17:00:00"));
+        assert!(readings.iter().all(|value| value.starts_with("2013-02-12 04:30:00/") && !value.ends_with("04:30:00")));
+    }
+
+    #[test]
+    fn urgent_words_resolve_to_the_reference_with_a_hint() {
+        let first = |input: &str| {
+            let res = parse_at(input);
+            let time = res.results.into_iter().find(|e| e.name == "time").unwrap();
+            (time.body, time.value, time.hints)
+        };
+        let asap = vec!["asap".to_string()];
+
+        assert_eq!(first("call me ASAP"), ("ASAP".into(), "2013-02-12 04:30:00".into(), asap.clone()));
+        assert_eq!(first("fix it immediately"), ("immediately".into(), "2013-02-12 04:30:00".into(), asap));
+        assert_eq!(first("right now"), ("right now".into(), "2013-02-12 04:30:00".into(), vec![]));
+        assert_eq!(first("from now on"), ("from now on".into(), "2013-02-12 04:30:00+".into(), vec![]));
+    }
+
+    #[test]
+    fn solar_events_follow_the_context_daylight() {
+        let value = |input: &str, ctx: &Context| {
+            let res = parse_with(input, ctx, &Options::default());
+            res.results.into_iter().find(|e| e.name == "time" && e.body == input).unwrap().value
+        };
+
+        let ctx = reference_context();
+        assert_eq!(value("sunrise", &ctx), "2013-02-12 06:00:00");
+        assert_eq!(value("tomorrow at dawn", &ctx), "2013-02-13 05:30:00/2013-02-13 06:00:00");
+        assert_eq!(value("dusk Friday", &ctx), "2013-02-15 18:00:00/2013-02-15 18:30:00");
+
+        // Sunrise later and sunset earlier the further north; today's sunrise
+        // at 03:00 is already over at the 04:30 reference.
+        let daylight = Daylight::default().with_location(59.3, 18.1).with_calculator(|_, at: Coordinates| {
+            let shift = (at.latitude / 15.0) as u32;
+            Some((NaiveTime::from_hms_opt(shift, 0, 0)?, NaiveTime::from_hms_opt(20 - shift, 0, 0)?))
+        });
+        let ctx = reference_context().with_daylight(daylight);
+        assert_eq!(value("sunrise", &ctx), "2013-02-13 03:00:00");
+        assert_eq!(value("sunset", &ctx), "2013-02-12 17:00:00");
+        assert_eq!(value("Friday at sunrise", &ctx), "2013-02-15 03:00:00");
+    }
+
+    #[test]
+    fn bare_clock_times_lean_on_the_time_before_them() {
+        let ctx = reference_context();
+        let value = |input: &str, body: &str| {
+            let res = parse_at(input);
+            res.results.into_iter().find(|e| e.name == "time" && e.body == body).unwrap().value
+        };
+
+        assert_eq!(value("the meeting is 9-10, call at 1", "at 1"), "2013-02-12 13:00:00");
+        assert_eq!(value("meeting at 10pm, call at 11", "at 11"), "2013-02-12 23:00:00");
+        assert_eq!(value("at 9am, then at 11", "at 11"), "2013-02-12 11:00:00");
+        // Not across sentences, nor over an explicit part of the day.
+        assert_eq!(value("at 10pm. call at 11", "at 11"), "2013-02-12 11:00:00");
+        assert_eq!(value("at 9pm then at 8 in the morning", "at 8 in the morning"), "2013-02-12 08:00:00");
+
+        // Past 11:00, a bare "at 11" alone is tomorrow morning's; after a
+        // time this evening it is read on the same evening instead.
+        let noon = NaiveDate::from_ymd_opt(2013, 2, 12).unwrap().and_hms_opt(12, 30, 0);
+        let noon = Context { reference_time: noon, ..reference_context() };
+        let value_at_noon = |input: &str| {
+            let res = parse_with(input, &noon, &Options::default());
+            res.results.into_iter().find(|e| e.name == "time" && e.body == "at 11").unwrap().value
+        };
+        assert_eq!(value_at_noon("call at 11"), "2013-02-13 11:00:00");
+        assert_eq!(value_at_noon("at 9pm, then at 11"), "2013-02-12 23:00:00");
+        assert_eq!(value_at_noon("today at 8pm, then at 11"), "2013-02-12 23:00:00");
+
+        let verbose = parse_verbose_with("from 9 to 10, then at 2", &ctx, &Options::default());
+        let (entity, explanation) =
+            verbose.results.iter().zip(&verbose.details.explanations).find(|(e, _)| e.body == "at 2").unwrap();
+        assert_eq!(entity.value, "2013-02-12 14:00:00");
+        assert!(explanation.rules.iter().any(|r| r == "meridiem:inferred"));
+        assert_eq!(entity.alternatives.len(), 1);
+    }
+
+    #[test]
+    fn numbers_after_identifier_markers_are_latent() {
+        let found = |input: &str, options: &Options| {
+            parse_at_with(input, options)
+                .results
+                .into_iter()
+                .map(|e| format!("{}:{}{}", e.body, e.value, if e.latent { " (latent)" } else { "" }))
+                .collect::<Vec<_>>()
+        };
+        let options = Options::default();
+
+        assert_eq!(found("room 237", &options), ["237:237 (latent)"]);
+        assert_eq!(found("order no. 5678", &options), ["5678:5678 (latent)"]);
+        assert_eq!(found("flight 1234 at 5pm", &options)[0], "at 5pm:2013-02-12 17:00:00");
+        assert_eq!(found("meet in room 12 tomorrow", &options)[0], "tomorrow:2013-02-13 00:00:00");
+
+        let custom = Options::default().with_identifier_markers(["Booth"]);
+        assert_eq!(found("booth 12", &custom), ["12:12 (latent)"]);
+        assert!(found("room 237", &custom).iter().any(|e| e == "237:237"));
+        let off = Options::default().with_identifier_markers(Vec::<String>::new());
+        assert!(found("room 237", &off).iter().any(|e| e == "237:237"));
+    }
+
+    #[test]
+    fn identifiers_claim_their_spans() {
+        let res = parse_at("order #5678 and ABC-123 ship tomorrow");
+        let ids: Vec<_> = res.identifiers().map(|id| (id.identifier.kind.as_str(), id.entity.body.as_str())).collect();
+        assert_eq!(ids, [("reference", "#5678"), ("ticket", "ABC-123")]);
+        assert_eq!(res.times().map(|t| t.entity.body.as_str()).collect::<Vec<_>>(), ["tomorrow"]);
+        assert_eq!(res.numerals().count(), 0);
+
+        let tracking = parse_at("1Z999AA10123456784 at 5pm");
+        assert_eq!(tracking.identifiers().next().unwrap().identifier.kind, "tracking");
+        assert_eq!(tracking.times().next().unwrap().entity.value, "2013-02-12 17:00:00");
+
+        // Offsets and ISO week dates are not codes.
+        assert_eq!(parse_at("3pm GMT-5").identifiers().count(), 0);
+        assert_eq!(parse_at("2025-W23-2").identifiers().count(), 0);
+
+        let custom =
+            Options::default().with_identifier_pattern(IdentifierPattern::new("booking", r"\bBK[0-9]{4}\b").unwrap());
+        let res = parse_at_with("booking BK2024 for May", &custom);
+        assert_eq!(res.identifiers().next().unwrap().identifier.code, "BK2024");
+        assert!(res.times().all(|t| t.entity.body == "May"));
+
+        let off = Options::default().with_identifier_patterns(Vec::new());
+        assert_eq!(parse_at_with("order #5678", &off).identifiers().count(), 0);
+    }
+
+    #[test]
+    fn saturation_can_stop_at_a_full_span_time() {
+        let ctx = reference_context();
+        let early = Options::default().enable_stop_at_full_span_time();
+        let first_time = |input: &str, options: &Options| {
+            parse_at_with(input, options).times().map(|t| (t.entity.body.clone(), t.entity.value.clone())).next()
+        };
+        for input in ["tomorrow at 5pm", "next Monday 9am.", "  March 3, 2025  "] {
+            assert_eq!(first_time(input, &early), first_time(input, &Options::default()), "{input:?}");
+        }
+
+        let rules = crate::rules::time::rules::get();
+        let passes = |options: &Options| {
+            engine::Parser::new("tomorrow at 5pm", &rules)
+                .run_with_metrics(&ctx, options)
+                .metrics
+                .saturation
+                .iterations
+                .len()
+        };
+        assert!(passes(&early) < passes(&Options::default()));
+
+        // Rules run highest priority first, so the pass stops before the
+        // built-in "tomorrow" rule gets to fire.
+        let mut rules = crate::rules::time::rules::get();
+        rules.push(rule! {
+            name: "preferred tomorrow",
+            pattern: [re!(r"(?i)tomorrow")],
+            priority: 10,
+            prod: |_tokens: &[crate::Token]| -> Option<TimeExpr> { Some(TimeExpr::Reference) }
+        });
+        let run = engine::Parser::new("tomorrow", &rules).run_with_metrics(&ctx, &early);
+        assert_eq!(run.fired_rules, ["preferred tomorrow"]);
+    }
+
+    #[test]
+    fn coverage_requires_entities_to_span_the_input() {
+        let bodies = |input: &str, coverage: Coverage| -> Vec<String> {
+            let options = Options::default().with_coverage(coverage);
+            parse_at_with(input, &options).times().map(|t| t.entity.body.clone()).collect()
+        };
+
+        assert_eq!(bodies("tomorrow at 5pm", Coverage::FullSpan), ["tomorrow at 5pm"]);
+        assert_eq!(bodies("  March 3, 2025.", Coverage::FullSpan), ["March 3, 2025"]);
+        assert!(bodies("remind me tomorrow at 5pm", Coverage::FullSpan).is_empty());
+        assert_eq!(bodies("remind me tomorrow at 5pm", Coverage::MinRatio(0.5)), ["tomorrow at 5pm"]);
+        let free_text = "we talked about the project for a while and agreed to meet tomorrow";
+        assert!(bodies(free_text, Coverage::MinRatio(0.5)).is_empty());
+        assert_eq!(bodies(free_text, Coverage::Any), ["tomorrow"]);
+    }
+
+    #[test]
+    fn capitalized_names_skip_lowercase_words() {
+        let bodies = |input: &str, options: &Options| -> Vec<String> {
+            parse_at_with(input, options).times().map(|t| t.entity.body.clone()).collect()
+        };
+        let strict = Options::default().enable_capitalized_names();
+
+        assert_eq!(bodies("plans in may", &Options::default()), ["may"]);
+        assert!(bodies("plans in may", &strict).is_empty());
+        assert!(bodies("on sat", &strict).is_empty());
+        assert_eq!(bodies("plans in May", &strict), ["May"]);
+        assert_eq!(bodies("may 5", &strict), ["may 5"]);
+        assert_eq!(bodies("next sat", &strict), ["next sat"]);
+        assert_eq!(bodies("Friday or monday", &strict), ["Friday", "monday"]);
+    }
+
+    #[test]
+    fn day_of_month_lists_and_their_recurrences() {
+        use crate::CanonicalFormat;
+
+        let first = |input: &str| parse_at(input).results[0].clone();
+        let day = |y, m, d| TimeValue::Instant(NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+        // Each day resolves to its next occurrence; the 1st has passed this
+        // month, so it is listed after the 15th.
+        let paid = first("paid on the 1st and 15th");
+        assert_eq!(paid.body, "on the 1st and 15th");
+        assert!(!paid.latent);
+        assert_eq!(paid.value, "2013-02-15 00:00:00, 2013-03-01 00:00:00");
+        let days = paid.resolved.as_times().expect("times").iter().map(|time| time.value.clone()).collect::<Vec<_>>();
+        assert_eq!(days, [day(2013, 2, 15), day(2013, 3, 1)]);
+        assert_eq!(first("May 12, 3 and 7").value, "2013-05-03 00:00:00, 2013-05-07 00:00:00, 2013-05-12 00:00:00");
+        assert!(first("the 1st and 2nd place").latent);
+
+        for input in ["on the 1st and 15th of every month", "every 1st and 15th", "the 1st and the 15th of each month"]
+        {
+            let payroll = first(input);
+            assert_eq!(payroll.end - payroll.start, input.len(), "{input}");
+            let recurrence = payroll.resolved.as_recurrence().expect(input);
+            assert_eq!(recurrence.rrule(), "FREQ=MONTHLY;BYMONTHDAY=1,15", "{input}");
+            assert_eq!(payroll.value, "2013-02-15 00:00:00 RRULE:FREQ=MONTHLY;BYMONTHDAY=1,15", "{input}");
+        }
+        // The reference day is the 12th: it is the next occurrence itself.
+        let today = first("every 12th and 28th");
+        assert_eq!(today.value, "2013-02-12 00:00:00 RRULE:FREQ=MONTHLY;BYMONTHDAY=12,28");
+        assert_eq!(today.resolved.canonical(CanonicalFormat::Readable), "every month on the 12th and 28th");
+        // Past both days this month, it moves to the next.
+        assert_eq!(first("every 1st, 10th and 11th of the month").value.split(' ').next(), Some("2013-03-01"));
+    }
+
+    #[test]
+    fn disabled_rule_groups_do_not_fire() {
+        let times = |input: &str, options: &Options| parse_at_with(input, options).times().count();
+
+        assert_eq!(times("Christmas", &Options::default()), 1);
+        assert_eq!(times("Christmas", &Options::default().disable_groups(RuleGroups::HOLIDAYS)), 0);
+        assert!(times("5", &Options::default()) > 0);
+        assert_eq!(times("5", &Options::default().disable_groups(RuleGroups::LATENT)), 0);
+
+        let no_intervals = Options::default().disable_groups(RuleGroups::INTERVALS);
+        let result = parse_at_with("from 3 to 5pm", &no_intervals);
+        assert!(result.times().all(|t| !matches!(t.time.value, TimeValue::Interval { .. })));
+    }
+
+    #[test]
+    fn warm_up_reports_compiled_rules() {
+        let first = warm_up();
+        assert!(first.rules > 100);
+        assert!(first.regexes > 100);
+        let again = warm_up();
+        assert_eq!((again.rules, again.regexes), (first.rules, first.regexes));
+    }
+
+    #[test]
+    fn repeated_parses_return_identical_results() {
+        // Guards the `parallel` feature's merge order as much as the sequential path.
+        let text = "call me tomorrow at 5pm or next friday around noon, not on march 3rd 2014 from 9 to 11";
+        let results = || format!("{:?}", parse_at(text).results);
+        let first = results();
+        for _ in 0..8 {
+            assert_eq!(results(), first);
+        }
+    }
+
+    #[test]
+    fn panicking_rule_is_reported_not_propagated() {
+        let mut rules = crate::rules::time::rules::get();
+        rules.push(rule! {
+            name: "broken rule",
+            pattern: [re!(r"(?i)tomorrow")],
+            prod: |tokens: &[crate::Token]| -> Option<TimeExpr> {
+                assert!(tokens.len() > 1, "bug in production");
+                None
+            }
+        });
+        let run = engine::Parser::new("tomorrow", &rules).run_with_metrics(&reference_context(), &Options::default());
+
+        assert!(run.tokens.iter().any(|rt| rt.node.rule_name == "tomorrow"));
+        assert_eq!(
+            run.errors,
+            vec![InternalError {
+                rule: "broken rule".to_string(),
+                stage: engine::ErrorStage::Production,
+                message: "bug in production".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn context_timezone_makes_hour_shifts_dst_aware() {
+        let reference = NaiveDate::from_ymd_opt(2013, 3, 9).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let ctx = Context { reference_time: Some(reference), ..Default::default() }
+            .with_timezone(chrono_tz::America::New_York);
+        let value = |input: &str| parse_with(input, &ctx, &Options::default()).results[0].value.clone();
+
+        assert_eq!(value("in 24 hours"), "2013-03-10 13:00:00");
+        assert_eq!(value("in 1 day"), "2013-03-10 12:00:00");
+        assert_eq!(value("tomorrow at noon"), "2013-03-10 12:00:00");
+    }
+
+    #[test]
+    fn iana_timezones_use_the_offset_at_the_resolved_date() {
+        let summer = Context {
+            reference_time: NaiveDate::from_ymd_opt(2013, 7, 2).unwrap().and_hms_opt(4, 30, 0),
+            ..Default::default()
+        };
+        let at = |h, m| NaiveDate::from_ymd_opt(2013, 7, 2).unwrap().and_hms_opt(h, m, 0).unwrap();
+
+        let res = parse_with("9am america/new_york", &summer, &Options::default());
+        let time = res.times().next().expect("time entity").time;
+        assert_eq!(time.value, TimeValue::Instant(at(13, 0)));
+        let tz = time.timezone.as_ref().expect("timezone");
+        assert_eq!(tz.name, "America/New_York");
+        assert_eq!(tz.utc_offset_seconds, -4 * 3600);
+        assert_eq!(tz.local, TimeValue::Instant(at(9, 0)));
+
+        // A fixed abbreviation keeps its offset regardless of the date.
+        let res = parse_with("9am EST", &summer, &Options::default());
+        assert_eq!(res.times().next().expect("time entity").time.value, TimeValue::Instant(at(14, 0)));
+    }
+
+    #[test]
+    fn local_timezone_override_takes_precedence() {
+        let at = |h, m| NaiveDate::from_ymd_opt(2013, 2, 12).unwrap().and_hms_opt(h, m, 0).unwrap();
+        let value = |ctx: &Context, options: &Options| {
+            parse_with("9am PST", ctx, options).times().next().expect("time entity").time.value.clone()
+        };
+
+        let ctx = reference_context();
+        assert_eq!(value(&ctx, &Options::default()), TimeValue::Instant(at(17, 0)));
+        let stockholm = Options::default().with_local_timezone(Tz::Europe__Stockholm);
+        assert_eq!(value(&ctx, &stockholm), TimeValue::Instant(at(18, 0)));
+        let new_york = reference_context().with_timezone(Tz::America__New_York);
+        assert_eq!(value(&new_york, &Options::default()), TimeValue::Instant(at(12, 0)));
+        assert_eq!(value(&new_york, &stockholm), TimeValue::Instant(at(18, 0)));
+    }
+
+    #[test]
+    fn parse_verbose_explains_each_result() {
+        let ctx = reference_context();
+        let res = parse_verbose_with("tomorrow at 5pm", &ctx, &Options::default());
+
+        assert_eq!(res.details.explanations.len(), res.results.len());
+        for (entity, explanation) in res.results.iter().zip(&res.details.explanations) {
+            assert_eq!(explanation.rules.first(), Some(&entity.rule));
+            assert_eq!(explanation.derivation.rule, entity.rule);
+            assert_eq!((explanation.derivation.start, explanation.derivation.end), (entity.start, entity.end));
+            assert!(!explanation.derivation.children.is_empty());
+        }
+    }
+
     #[test]
     fn regex_profiling_summary_present_when_enabled() {
         let ctx = reference_context();
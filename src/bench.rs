@@ -0,0 +1,42 @@
+use crate::bench_inputs;
+use astorion::{Context, Options, parse_with};
+use std::time::{Duration, Instant};
+
+/// Timing summary for one benchmark input.
+pub struct BenchSample {
+    pub name: &'static str,
+    pub input_len: usize,
+    pub entities: usize,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+/// Parse every representative input `iterations` times and collect timings.
+///
+/// Each case is parsed once before timing so lazily compiled regexes don't
+/// skew the first sample.
+pub fn run(ctx: &Context, opts: &Options, iterations: usize) -> Vec<BenchSample> {
+    let iterations = iterations.max(1);
+
+    bench_inputs::CASES
+        .iter()
+        .map(|(name, input)| {
+            let entities = parse_with(input, ctx, opts).results.len();
+
+            let mut total = Duration::ZERO;
+            let mut min = Duration::MAX;
+            let mut max = Duration::ZERO;
+            for _ in 0..iterations {
+                let start = Instant::now();
+                parse_with(input, ctx, opts);
+                let elapsed = start.elapsed();
+                total += elapsed;
+                min = min.min(elapsed);
+                max = max.max(elapsed);
+            }
+
+            BenchSample { name, input_len: input.len(), entities, mean: total.div_f64(iterations as f64), min, max }
+        })
+        .collect()
+}
@@ -0,0 +1,28 @@
+//! Representative inputs shared by the criterion suite (`benches/parse.rs`)
+//! and the CLI `--bench` mode.
+//!
+//! Each case stresses a different part of the engine: the short case is
+//! dominated by rule activation, the long document by regex scanning, the
+//! numeral case by predicate composition, and the digit soup by latent
+//! time/numeral rules that fire on every digit run.
+
+/// `(name, input)` pairs used for benchmarking.
+pub const CASES: &[(&str, &str)] = &[
+    ("short_time", "tomorrow at 5pm"),
+    (
+        "long_document",
+        "Hi team, quick update before the weekend. The vendor call moved from Tuesday at 10am to \
+         Thursday at 3:30pm, and the design review is now the first Monday of next month. Please \
+         send feedback by end of day Friday. I'll be out from March 3 to March 7 and back on the \
+         10th; if anything urgent comes up in the next 2 weeks, ping me before noon. Last year we \
+         shipped on December 15th, so let's aim for mid-December again, ideally the week after \
+         Thanksgiving.",
+    ),
+    (
+        "numeral_heavy",
+        "three hundred twenty-one thousand four hundred and twelve, 45, two dozen, 1.5, \
+         seven hundred twenty-one thousand twelve and thirty-one million two hundred fifty-six \
+         thousand seven hundred twenty-one",
+    ),
+    ("adversarial_digits", "12 34 56 78 90 1234 5678 9012 3456 78 9 10 11 12 13 14 15 16 17 18 19 20 2017 1999 0830"),
+];
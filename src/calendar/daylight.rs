@@ -0,0 +1,73 @@
+use chrono::{NaiveDate, NaiveTime};
+use std::fmt;
+use std::sync::Arc;
+
+/// A place on Earth, in degrees (north and east positive).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Source of local sunrise and sunset times, for "sunrise", "at dusk", ...
+///
+/// Implemented for closures, so any `Fn(NaiveDate, Coordinates) -> Option<(NaiveTime, NaiveTime)>`
+/// (for instance one wrapping a solar position crate) is a valid calculator.
+pub trait SolarCalculator: Send + Sync {
+    /// Sunrise and sunset on `date` at `location`, as wall-clock times in the
+    /// context's timezone. `None` when the sun does not rise or set that day.
+    fn sun_times(&self, date: NaiveDate, location: Coordinates) -> Option<(NaiveTime, NaiveTime)>;
+}
+
+impl<F> SolarCalculator for F
+where
+    F: Fn(NaiveDate, Coordinates) -> Option<(NaiveTime, NaiveTime)> + Send + Sync,
+{
+    fn sun_times(&self, date: NaiveDate, location: Coordinates) -> Option<(NaiveTime, NaiveTime)> {
+        self(date, location)
+    }
+}
+
+/// Where and how "dawn", "sunrise", "sunset" and "dusk" are worked out.
+///
+/// Without both a location and a calculator the sun rises at 06:00 and sets
+/// at 18:00 every day. Dawn and dusk are the half hours of twilight before
+/// sunrise and after sunset.
+#[derive(Clone, Default)]
+pub struct Daylight {
+    /// Where the sun is observed from.
+    pub location: Option<Coordinates>,
+    /// Calculator giving sunrise and sunset at `location`.
+    pub calculator: Option<Arc<dyn SolarCalculator>>,
+}
+
+impl Daylight {
+    /// Observe the sun from `latitude`/`longitude` (degrees).
+    pub fn with_location(mut self, latitude: f64, longitude: f64) -> Self {
+        self.location = Some(Coordinates { latitude, longitude });
+        self
+    }
+
+    /// Work out sunrise and sunset with `calculator`.
+    pub fn with_calculator(mut self, calculator: impl SolarCalculator + 'static) -> Self {
+        self.calculator = Some(Arc::new(calculator));
+        self
+    }
+
+    /// Sunrise and sunset on `date`.
+    pub(crate) fn sun_times(&self, date: NaiveDate) -> Option<(NaiveTime, NaiveTime)> {
+        match (&self.calculator, self.location) {
+            (Some(calculator), Some(location)) => calculator.sun_times(date, location),
+            _ => Some((NaiveTime::from_hms_opt(6, 0, 0)?, NaiveTime::from_hms_opt(18, 0, 0)?)),
+        }
+    }
+}
+
+impl fmt::Debug for Daylight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Daylight")
+            .field("location", &self.location)
+            .field("calculator", &self.calculator.as_ref().map(|_| "<calculator>"))
+            .finish()
+    }
+}
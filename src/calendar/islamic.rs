@@ -0,0 +1,68 @@
+use super::HolidayProvider;
+use chrono::{Datelike, NaiveDate};
+
+/// Julian day number of 1 Muharram 1 AH (16 July 622, Julian calendar).
+const EPOCH_JDN: i64 = 1_948_440;
+
+/// Julian day number of 0001-01-01 (proleptic Gregorian) minus one, so that
+/// `jdn - CE_OFFSET` is chrono's day count from the common era.
+const CE_OFFSET: i64 = 1_721_425;
+
+/// The tabular (arithmetic) Islamic calendar, resolving Ramadan, the two
+/// Eids and the Islamic new year to Gregorian dates.
+///
+/// The tabular calendar follows a fixed 30-year leap cycle, so dates can
+/// differ by a day or two from those set by moon sighting. It marks no
+/// non-working days; pair it with another provider for business days.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IslamicCalendar;
+
+impl IslamicCalendar {
+    /// Gregorian date of day `day` of month `month` (1 = Muharram) of Islamic `year`.
+    pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+            return None;
+        }
+        let (year, month, day) = (year as i64, month as i64, day as i64);
+        let jdn = day + (59 * (month - 1) + 1) / 2 + (year - 1) * 354 + (3 + 11 * year).div_euclid(30) + EPOCH_JDN - 1;
+        NaiveDate::from_num_days_from_ce_opt(i32::try_from(jdn - CE_OFFSET).ok()?)
+    }
+}
+
+impl HolidayProvider for IslamicCalendar {
+    fn is_holiday(&self, _date: NaiveDate) -> bool {
+        false
+    }
+
+    fn resolve(&self, name: &str, year: i32) -> Option<NaiveDate> {
+        let (month, day) = match name {
+            "islamic new year" => (1, 1),
+            "ramadan" => (9, 1),
+            "eid al-fitr" => (10, 1),
+            "eid al-adha" => (12, 10),
+            _ => return None,
+        };
+        // An Islamic year is ~11 days shorter, so the Islamic years around
+        // `year` cover every occurrence in it; keep the first.
+        let approx = (year - 622) * 33 / 32;
+        (approx - 1..=approx + 2)
+            .filter_map(|y| IslamicCalendar::to_gregorian(y, month, day))
+            .filter(|date| date.year() == year)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_holidays_to_gregorian_dates() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(IslamicCalendar::to_gregorian(1, 1, 1), Some(date(622, 7, 19)));
+        assert_eq!(IslamicCalendar.resolve("ramadan", 2025), Some(date(2025, 3, 1)));
+        assert_eq!(IslamicCalendar.resolve("eid al-fitr", 2013), Some(date(2013, 8, 8)));
+        assert_eq!(IslamicCalendar.resolve("diwali", 2013), None);
+    }
+}
@@ -0,0 +1,144 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use std::fmt;
+use std::sync::Arc;
+
+mod daylight;
+#[cfg(feature = "calendars")]
+mod islamic;
+
+pub use daylight::{Coordinates, Daylight, SolarCalculator};
+
+#[cfg(feature = "calendars")]
+pub use islamic::IslamicCalendar;
+
+/// Source of holidays: the non-working days skipped when counting business
+/// days, and the dates of holidays from other calendars ("Ramadan", "Diwali").
+///
+/// Implemented for closures, so `|date: NaiveDate| date.month() == 12 && date.day() == 25`
+/// is a valid provider, and for pairs, which combine two providers.
+pub trait HolidayProvider: Send + Sync {
+    /// Whether `date` is a holiday (not a business day).
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+
+    /// Gregorian date of the holiday `name` in Gregorian `year`, if known.
+    ///
+    /// `name` is the lowercase canonical name produced by the rules:
+    /// `"ramadan"`, `"eid al-fitr"`, `"eid al-adha"`, `"islamic new year"`,
+    /// `"diwali"`, `"hanukkah"`, `"chinese new year"`, `"rosh hashanah"`,
+    /// `"yom kippur"` or `"passover"`. When a holiday occurs twice in a year,
+    /// return the first occurrence.
+    fn resolve(&self, name: &str, year: i32) -> Option<NaiveDate> {
+        let _ = (name, year);
+        None
+    }
+}
+
+impl<A: HolidayProvider, B: HolidayProvider> HolidayProvider for (A, B) {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.0.is_holiday(date) || self.1.is_holiday(date)
+    }
+
+    fn resolve(&self, name: &str, year: i32) -> Option<NaiveDate> {
+        self.0.resolve(name, year).or_else(|| self.1.resolve(name, year))
+    }
+}
+
+impl<F> HolidayProvider for F
+where
+    F: Fn(NaiveDate) -> bool + Send + Sync,
+{
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self(date)
+    }
+}
+
+/// Working days and hours used to resolve "in 3 business days", "next working
+/// day", "EOD Friday", ...
+///
+/// Defaults to a Monday–Friday workweek without holidays, open 09:00–17:00.
+#[derive(Clone)]
+pub struct BusinessCalendar {
+    /// Days of the week that are business days.
+    pub workweek: Vec<Weekday>,
+    /// Holidays skipped on top of the non-working weekdays.
+    pub holidays: Option<Arc<dyn HolidayProvider>>,
+    /// Time of day meant by "start of business".
+    pub start_of_business: NaiveTime,
+    /// Time of day meant by "EOD", "COB", "end of business".
+    pub end_of_business: NaiveTime,
+}
+
+impl BusinessCalendar {
+    /// Use `workweek` as the business days of the week.
+    pub fn with_workweek(mut self, workweek: impl IntoIterator<Item = Weekday>) -> Self {
+        self.workweek = workweek.into_iter().collect();
+        self
+    }
+
+    /// Skip the days reported by `provider`.
+    pub fn with_holidays(mut self, provider: impl HolidayProvider + 'static) -> Self {
+        self.holidays = Some(Arc::new(provider));
+        self
+    }
+
+    /// Use `start`/`end` as the opening and closing time of a business day.
+    pub fn with_business_hours(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.start_of_business = start;
+        self.end_of_business = end;
+        self
+    }
+
+    /// Gregorian date of the holiday `name` in `year`, from the holiday provider.
+    pub(crate) fn resolve_holiday(&self, name: &str, year: i32) -> Option<NaiveDate> {
+        self.holidays.as_ref()?.resolve(name, year)
+    }
+
+    /// Whether `date` is a business day.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        self.workweek.contains(&date.weekday()) && !self.holidays.as_ref().is_some_and(|h| h.is_holiday(date))
+    }
+
+    /// Move `dt` by `amount` business days, keeping the time of day.
+    ///
+    /// Returns `None` when the calendar has no business days.
+    pub(crate) fn shift_business_days(&self, dt: NaiveDateTime, amount: i32) -> Option<NaiveDateTime> {
+        if self.workweek.is_empty() {
+            return None;
+        }
+        let step = Duration::days(if amount < 0 { -1 } else { 1 });
+        let mut current = dt;
+        let mut remaining = amount.unsigned_abs();
+        // Holidays could in principle cover every workday; give up after ten years.
+        let mut budget = 3660 + 7 * remaining;
+        while remaining > 0 {
+            current += step;
+            if self.is_business_day(current.date()) {
+                remaining -= 1;
+            }
+            budget = budget.checked_sub(1)?;
+        }
+        Some(current)
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self {
+            workweek: vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+            holidays: None,
+            start_of_business: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end_of_business: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        }
+    }
+}
+
+impl fmt::Debug for BusinessCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BusinessCalendar")
+            .field("workweek", &self.workweek)
+            .field("holidays", &self.holidays.as_ref().map(|_| "<provider>"))
+            .field("start_of_business", &self.start_of_business)
+            .field("end_of_business", &self.end_of_business)
+            .finish()
+    }
+}
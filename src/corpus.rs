@@ -0,0 +1,376 @@
+//! `astorion corpus run <file>`: golden-file snapshot testing.
+//! `astorion corpus coverage <file>`: which rules the corpus exercises, and
+//! which duplicate others.
+//!
+//! A corpus is a plain text file with one input per line. Blank lines and
+//! lines starting with `#` are ignored, and `@reference <ts>` changes the
+//! reference time for the inputs that follow it. Snapshots live next to the
+//! corpus as `<file>.snap`.
+
+use astorion::{Context, Options, RuleCoverage, parse_verbose_with, parse_with};
+use chrono::NaiveDateTime;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Exit code when at least one case differs from its snapshot.
+pub const EXIT_MISMATCH: i32 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Command {
+    Run,
+    Coverage,
+}
+
+impl Command {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Command::Run => "corpus run",
+            Command::Coverage => "corpus coverage",
+        }
+    }
+}
+
+pub(crate) struct CorpusArgs {
+    pub(crate) command: Command,
+    pub(crate) file: PathBuf,
+    pub(crate) snapshot: Option<PathBuf>,
+    pub(crate) update: bool,
+    pub(crate) reference_time: NaiveDateTime,
+}
+
+/// One corpus input with the reference time in effect for it.
+struct Input {
+    line: usize,
+    text: String,
+    reference_time: NaiveDateTime,
+}
+
+/// One corpus input and its rendered snapshot entry.
+struct Case {
+    line: usize,
+    input: String,
+    rendered: String,
+}
+
+/// The differences between a corpus run and its snapshot.
+struct Comparison {
+    report: String,
+    failures: usize,
+    stale: usize,
+}
+
+/// Run `corpus run` or `corpus coverage` and return the process exit code.
+/// A corpus command and the corpus file it runs on.
+pub(crate) type Subcommand = (Command, String);
+
+/// Split a leading `corpus run <file>` or `corpus coverage <file>` off the
+/// command line. Anything else, including input that merely starts with
+/// "corpus", is returned untouched for the option parser.
+pub(crate) fn split_subcommand(args: &[String]) -> Result<(Option<Subcommand>, &[String]), String> {
+    let command = match args {
+        [first, command, ..] if first == "corpus" && command == "run" => Command::Run,
+        [first, command, ..] if first == "corpus" && command == "coverage" => Command::Coverage,
+        _ => return Ok((None, args)),
+    };
+    match args.get(2) {
+        Some(file) if !file.starts_with('-') => Ok((Some((command, file.clone())), &args[3..])),
+        _ => Err(format!("error: {} expects a file", command.name())),
+    }
+}
+
+pub fn run(args: &CorpusArgs, opts: &Options) -> i32 {
+    let result = match args.command {
+        Command::Run => snapshot(args, opts),
+        Command::Coverage => coverage(args, opts),
+    };
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("{err}");
+            1
+        }
+    }
+}
+
+fn read_corpus(args: &CorpusArgs) -> Result<Vec<Input>, String> {
+    let corpus = fs::read_to_string(&args.file)
+        .map_err(|err| format!("error: failed to read corpus '{}': {err}", args.file.display()))?;
+    corpus_inputs(&corpus, args.reference_time)
+}
+
+fn snapshot(args: &CorpusArgs, opts: &Options) -> Result<i32, String> {
+    let cases = parse_corpus(read_corpus(args)?, opts);
+    let rendered = render_snapshot(&cases);
+
+    let snapshot_path = args.snapshot.clone().unwrap_or_else(|| default_snapshot_path(&args.file));
+    if args.update {
+        fs::write(&snapshot_path, &rendered)
+            .map_err(|err| format!("error: failed to write snapshot '{}': {err}", snapshot_path.display()))?;
+        println!("updated {} ({} cases)", snapshot_path.display(), cases.len());
+        return Ok(0);
+    }
+
+    let expected = fs::read_to_string(&snapshot_path).map_err(|err| {
+        format!("error: failed to read snapshot '{}': {err} (run with --update to create it)", snapshot_path.display())
+    })?;
+    let comparison = compare(&cases, &expected);
+    print!("{}", comparison.report);
+    if comparison.failures > 0 || comparison.stale > 0 {
+        println!("run `astorion corpus run {} --update` to accept the new output", args.file.display());
+        return Ok(EXIT_MISMATCH);
+    }
+    Ok(0)
+}
+
+fn compare(cases: &[Case], snapshot: &str) -> Comparison {
+    let expected = split_snapshot(snapshot);
+    let mut report = String::new();
+
+    let mut failures = 0;
+    for case in cases {
+        match expected.iter().find(|(input, body)| same_case(input, body, case)) {
+            Some((_, body)) if *body == case.rendered => {}
+            Some((_, body)) => {
+                failures += 1;
+                report.push_str(&format!("FAIL line {}: {}\n", case.line, case.input));
+                report.push_str(&format!("  expected:\n{}\n", indent(body)));
+                report.push_str(&format!("  actual:\n{}\n", indent(&case.rendered)));
+            }
+            None => {
+                failures += 1;
+                report.push_str(&format!("NEW  line {}: {} (not in snapshot)\n", case.line, case.input));
+            }
+        }
+    }
+    let mut stale = 0;
+    for (input, body) in &expected {
+        if !cases.iter().any(|case| same_case(input, body, case)) {
+            stale += 1;
+            report.push_str(&format!("GONE {input} (in snapshot, not in corpus)\n"));
+        }
+    }
+
+    report.push_str(&format!(
+        "{} cases, {} passed, {} failed, {} stale\n",
+        cases.len(),
+        cases.len() - failures,
+        failures,
+        stale
+    ));
+    Comparison { report, failures, stale }
+}
+
+/// Cases are keyed by input and reference time, so the same input may appear
+/// under several `@reference` directives.
+fn same_case(input: &str, body: &str, case: &Case) -> bool {
+    input == case.input && body.lines().next() == case.rendered.lines().next()
+}
+
+/// Report rules that never fired and rules that fired but never reached a
+/// returned entity.
+fn coverage(args: &CorpusArgs, opts: &Options) -> Result<i32, String> {
+    let inputs = read_corpus(args)?;
+    let mut coverage = RuleCoverage::new(opts);
+    for input in &inputs {
+        let ctx = Context { reference_time: Some(input.reference_time), ..Default::default() };
+        coverage.record(&parse_verbose_with(&input.text, &ctx, opts));
+    }
+
+    let never_fired = coverage.never_fired();
+    let always_filtered = coverage.always_filtered();
+    println!("never fired ({}):", never_fired.len());
+    for name in &never_fired {
+        println!("  {name}");
+    }
+    println!("fired but always filtered out ({}):", always_filtered.len());
+    for name in &always_filtered {
+        println!("  {name} (fired in {} inputs)", coverage.fired[*name]);
+    }
+    let shadowed = coverage.shadowed();
+    println!("likely shadowed ({}):", shadowed.len());
+    for rule in &shadowed {
+        println!(
+            "  {} (priority {}) by {} (priority {}), identical in all {} inputs it fired in",
+            rule.rule, rule.priority, rule.by, rule.by_priority, rule.parses
+        );
+    }
+    let kept = coverage.rules.iter().filter(|name| coverage.kept.contains_key(*name)).count();
+    println!(
+        "{} inputs, {} rules: {} fired, {} contributed to results, {} never fired",
+        coverage.parses,
+        coverage.rules.len(),
+        coverage.rules.len() - never_fired.len(),
+        kept,
+        never_fired.len()
+    );
+    Ok(0)
+}
+
+fn corpus_inputs(corpus: &str, mut reference_time: NaiveDateTime) -> Result<Vec<Input>, String> {
+    let mut inputs = Vec::new();
+
+    for (idx, raw) in corpus.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(value) = line.strip_prefix("@reference") {
+            reference_time = crate::parse_reference(value.trim()).map_err(|err| format!("line {}: {err}", idx + 1))?;
+            continue;
+        }
+        inputs.push(Input { line: idx + 1, text: line.to_string(), reference_time });
+    }
+
+    Ok(inputs)
+}
+
+fn parse_corpus(inputs: Vec<Input>, opts: &Options) -> Vec<Case> {
+    inputs
+        .into_iter()
+        .map(|input| {
+            let ctx = Context { reference_time: Some(input.reference_time), ..Default::default() };
+            let res = parse_with(&input.text, &ctx, opts);
+            let mut rendered = format!("@ {}\n", input.reference_time.format("%Y-%m-%dT%H:%M:%S"));
+            for ent in &res.results {
+                rendered.push_str(&format!("{} {}..{} {:?} = {}\n", ent.name, ent.start, ent.end, ent.body, ent.value));
+            }
+            Case { line: input.line, input: input.text, rendered }
+        })
+        .collect()
+}
+
+/// Snapshot layout: `> <input>` followed by the rendered lines, one blank line
+/// between cases.
+fn render_snapshot(cases: &[Case]) -> String {
+    cases.iter().map(|case| format!("> {}\n{}", case.input, case.rendered)).collect::<Vec<_>>().join("\n")
+}
+
+fn split_snapshot(snapshot: &str) -> Vec<(String, String)> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for line in snapshot.lines() {
+        if let Some(input) = line.strip_prefix("> ") {
+            entries.push((input.to_string(), String::new()));
+        } else if let Some((_, body)) = entries.last_mut() {
+            if !line.is_empty() {
+                body.push_str(line);
+                body.push('\n');
+            }
+        }
+    }
+    entries
+}
+
+fn default_snapshot_path(file: &Path) -> PathBuf {
+    let mut name = file.as_os_str().to_owned();
+    name.push(".snap");
+    PathBuf::from(name)
+}
+
+fn indent(text: &str) -> String {
+    text.lines().map(|line| format!("    {line}")).collect::<Vec<_>>().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference() -> NaiveDateTime {
+        crate::parse_reference(crate::DEFAULT_REFERENCE).unwrap()
+    }
+
+    fn cases(corpus: &str) -> Vec<Case> {
+        parse_corpus(corpus_inputs(corpus, reference()).unwrap(), &Options::default())
+    }
+
+    /// A corpus file in a fresh scratch directory.
+    fn corpus_file(name: &str, corpus: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("astorion-corpus-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("times.txt");
+        fs::write(&file, corpus).unwrap();
+        file
+    }
+
+    fn args(file: &Path, update: bool) -> CorpusArgs {
+        CorpusArgs {
+            command: Command::Run,
+            file: file.to_path_buf(),
+            snapshot: None,
+            update,
+            reference_time: reference(),
+        }
+    }
+
+    #[test]
+    fn update_writes_a_snapshot_the_next_run_matches() {
+        let file = corpus_file("update", "# dates\ntomorrow\n\n@reference 2014-01-01T00:00:00\ntomorrow\n");
+        let opts = Options::default();
+
+        assert_eq!(snapshot(&args(&file, true), &opts), Ok(0));
+        let written = fs::read_to_string(default_snapshot_path(&file)).unwrap();
+        assert_eq!(
+            written,
+            "> tomorrow\n@ 2013-02-12T04:30:00\ntime 0..8 \"tomorrow\" = 2013-02-13 00:00:00\n\n\
+             > tomorrow\n@ 2014-01-01T00:00:00\ntime 0..8 \"tomorrow\" = 2014-01-02 00:00:00\n"
+        );
+        assert_eq!(snapshot(&args(&file, false), &opts), Ok(0));
+
+        fs::write(&file, "tomorrow\nyesterday\n").unwrap();
+        assert_eq!(snapshot(&args(&file, false), &opts), Ok(EXIT_MISMATCH));
+        fs::remove_dir_all(file.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn subcommand_must_lead_the_command_line() {
+        let argv = |line: &str| line.split(' ').map(String::from).collect::<Vec<_>>();
+
+        let args = argv("corpus run dates.txt --update");
+        let (command, rest) = split_subcommand(&args).unwrap();
+        assert!(command == Some((Command::Run, "dates.txt".to_string())));
+        assert_eq!(rest, ["--update"]);
+
+        let args = argv("corpus coverage dates.txt");
+        let (command, rest) = split_subcommand(&args).unwrap();
+        assert!(command == Some((Command::Coverage, "dates.txt".to_string())));
+        assert!(rest.is_empty());
+
+        for line in ["corpus of texts", "--reference 2013-01-01T00:00:00 corpus run dates.txt", "-- corpus run late"] {
+            let args = argv(line);
+            let (command, rest) = split_subcommand(&args).unwrap();
+            assert!(command.is_none(), "{line}");
+            assert_eq!(rest, args.as_slice());
+        }
+
+        let err = split_subcommand(&argv("corpus run --update")).err().unwrap();
+        assert_eq!(err, "error: corpus run expects a file");
+    }
+
+    #[test]
+    fn mismatch_report_lists_failed_new_and_gone_cases() {
+        let snapshot = "> tomorrow\n@ 2013-02-12T04:30:00\ntime 0..8 \"tomorrow\" = 2013-02-14 00:00:00\n\n\
+                        > next week\n@ 2013-02-12T04:30:00\ntime 0..9 \"next week\" = 2013-02-18 00:00:00\n";
+        let comparison = compare(&cases("tomorrow\nat 5pm\n"), snapshot);
+
+        assert_eq!((comparison.failures, comparison.stale), (2, 1));
+        assert_eq!(
+            comparison.report,
+            "FAIL line 1: tomorrow\n  expected:\n    @ 2013-02-12T04:30:00\n    \
+             time 0..8 \"tomorrow\" = 2013-02-14 00:00:00\n  actual:\n    @ 2013-02-12T04:30:00\n    \
+             time 0..8 \"tomorrow\" = 2013-02-13 00:00:00\n\
+             NEW  line 2: at 5pm (not in snapshot)\n\
+             GONE next week (in snapshot, not in corpus)\n\
+             2 cases, 0 passed, 2 failed, 1 stale\n"
+        );
+    }
+
+    #[test]
+    fn reference_directive_applies_to_following_lines() {
+        let inputs = corpus_inputs("today\n@reference 2020-05-01T12:00:00\n# note\ntoday\n", reference()).unwrap();
+        let seen: Vec<_> = inputs.iter().map(|i| (i.line, i.reference_time.to_string())).collect();
+        assert_eq!(seen, [(1, "2013-02-12 04:30:00".to_string()), (4, "2020-05-01 12:00:00".to_string())]);
+
+        let err = corpus_inputs("@reference tomorrow\n", reference()).err().unwrap();
+        assert!(err.starts_with("line 1: error: invalid --reference 'tomorrow'"), "{err}");
+    }
+}
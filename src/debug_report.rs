@@ -1,4 +1,7 @@
-use astorion::{NodeSummary, ParseDetails};
+use crate::bench::BenchSample;
+use crate::sweep::SweepRow;
+use astorion::{Derivation, Entity, NodeSummary, ParseDetails, ParseResultVerbose};
+use std::time::Duration;
 
 mod ansi {
     pub const RESET: &str = "\x1b[0m";
@@ -34,8 +37,9 @@ mod ansi {
     }
 }
 
-pub fn print_run(input: &str, details: &ParseDetails, color: bool) {
+pub fn print_run(input: &str, res: &ParseResultVerbose, color: bool, explain: bool) {
     let palette = ansi::Palette::new(color);
+    let details = &res.details;
     println!("\n{}", palette.bold(palette.paint(format!("⚙  Parsing: \"{}\"", input), ansi::CYAN)));
 
     // Saturation summary
@@ -61,6 +65,11 @@ pub fn print_run(input: &str, details: &ParseDetails, color: bool) {
         print_results(details, &palette);
     }
 
+    if explain {
+        println!("\n{}", palette.paint("━━━ Explain ━━━", ansi::GRAY));
+        print_explain(res, &palette);
+    }
+
     // Timing
     println!("\n{}", palette.paint("━━━ Timing ━━━", ansi::GRAY));
     println!(
@@ -72,6 +81,67 @@ pub fn print_run(input: &str, details: &ParseDetails, color: bool) {
     println!();
 }
 
+/// Compact one-line-per-entity output used by the REPL.
+pub fn print_entities(entities: &[&Entity], elapsed: Duration, color: bool) {
+    let palette = ansi::Palette::new(color);
+    if entities.is_empty() {
+        println!("  {}", palette.dim("No entities"));
+    }
+    for ent in entities {
+        println!(
+            "  {} {} {} {} {}",
+            palette.bold(palette.paint(&ent.value, ansi::GREEN)),
+            palette.paint(format!("\"{}\"", ent.body), ansi::YELLOW),
+            palette.dim(format!("{}..{}", ent.start, ent.end)),
+            palette.paint(&ent.name, ansi::BLUE),
+            palette.paint(&ent.rule, ansi::CYAN),
+        );
+    }
+    println!("  {}", palette.dim(format!("{:?}", elapsed)));
+}
+
+pub fn print_sweep(input: &str, rows: &[SweepRow], color: bool) {
+    let palette = ansi::Palette::new(color);
+    println!("\n{}", palette.bold(palette.paint(format!("⚙  Sweeping: \"{}\"", input), ansi::CYAN)));
+    println!("\n{}", palette.paint("━━━ Resolutions ━━━", ansi::GRAY));
+
+    for row in rows {
+        let marker = if row.discontinuity { palette.paint("▲", ansi::YELLOW) } else { " ".to_string() };
+        let values = if row.values.is_empty() { palette.dim("-") } else { row.values.join("  ") };
+        let values = if row.discontinuity { palette.bold(palette.paint(values, ansi::YELLOW)) } else { values };
+        println!(
+            "  {} {} {} {}",
+            marker,
+            palette.paint(row.reference.format("%Y-%m-%d %H:%M").to_string(), ansi::BLUE),
+            palette.dim("│"),
+            values
+        );
+    }
+
+    let jumps = rows.iter().filter(|row| row.discontinuity).count();
+    println!("\n  {}", palette.dim(format!("{} references, {} discontinuities (▲)", rows.len(), jumps)));
+    println!();
+}
+
+pub fn print_bench(samples: &[BenchSample], iterations: usize, color: bool) {
+    let palette = ansi::Palette::new(color);
+    println!("\n{}", palette.bold(palette.paint(format!("⚙  Benchmark ({iterations} iterations)"), ansi::CYAN)));
+    println!("\n{}", palette.paint("━━━ Inputs ━━━", ansi::GRAY));
+
+    for sample in samples {
+        println!(
+            "  {} {}  {} {}  {} {}",
+            palette.paint(format!("{:<20}", sample.name), ansi::BLUE),
+            palette.bold(palette.paint(format!("{:>12?}", sample.mean), ansi::GREEN)),
+            palette.dim("min/max:"),
+            palette.paint(format!("{:?}/{:?}", sample.min, sample.max), ansi::YELLOW),
+            palette.dim("│"),
+            palette.dim(format!("{} bytes, {} entities", sample.input_len, sample.entities)),
+        );
+    }
+    println!();
+}
+
 fn print_saturation(details: &ParseDetails, palette: &ansi::Palette) {
     for pass in &details.saturation {
         let label = if pass.pass == 0 { "Pass 0 (regex):".to_string() } else { format!("Pass {}:", pass.pass) };
@@ -114,6 +184,39 @@ fn print_results(details: &ParseDetails, palette: &ansi::Palette) {
     }
 }
 
+fn print_explain(res: &ParseResultVerbose, palette: &ansi::Palette) {
+    if res.results.is_empty() {
+        println!("{}", palette.dim("  No entities returned"));
+        return;
+    }
+
+    for (idx, (ent, explanation)) in res.results.iter().zip(&res.details.explanations).enumerate() {
+        println!(
+            "  {} {} {} {}",
+            palette.paint(format!("[{}]", idx), ansi::GRAY),
+            palette.bold(palette.paint(&ent.value, ansi::GREEN)),
+            palette.dim("│"),
+            palette.paint(format!("\"{}\"", ent.body), ansi::YELLOW),
+        );
+        println!("      {} {}", palette.dim("chain:"), palette.paint(explanation.rules.join(" ← "), ansi::CYAN));
+        print_derivation(&explanation.derivation, 3, palette);
+    }
+}
+
+fn print_derivation(step: &Derivation, depth: usize, palette: &ansi::Palette) {
+    println!(
+        "{}{} {} {}",
+        "  ".repeat(depth),
+        palette.paint(format!("{}..{}", step.start, step.end), ansi::YELLOW),
+        palette.paint(&step.rule, ansi::BLUE),
+        palette.dim(format!("\"{}\"", step.body)),
+    );
+    println!("{}  {}", "  ".repeat(depth), palette.dim(&step.token));
+    for child in &step.children {
+        print_derivation(child, depth + 1, palette);
+    }
+}
+
 fn print_regex_profile(details: &ParseDetails, palette: &ansi::Palette) {
     let Some(profile) = &details.regex_profile else {
         return;
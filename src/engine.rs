@@ -45,10 +45,22 @@
 //!   phrases for rule activation.
 //! - `parser.rs`: performs matching + saturation over a `Stash`, producing
 //!   candidate nodes and resolving them to output tokens.
-//! - `dedup.rs`: defines stable dedup keys to keep saturation finite.
+//! - `lexer.rs`: splits the input into word lexemes for rules matching word
+//!   sequences (`Pattern::Words`), on demand.
+//! - `dedup.rs`: defines stable dedup keys to keep saturation finite, shared
+//!   by the parser's seen-set and `Stash::union`, and finds rules deriving
+//!   identical nodes.
 //! - `resolve.rs`: turns nodes into user-facing values (`ResolvedToken`s), with
 //!   dimension-specific logic.
+//! - `emoji.rs`: blanks emoji out of the input so they separate words like
+//!   whitespace.
+//! - `span.rs`: slices the input by node ranges without panicking on
+//!   character boundaries.
+//! - `guard.rs`: catches panics in rule code and reports them as
+//!   `InternalError`s instead of unwinding into the caller.
 //! - `metrics.rs`: optional timing/debug data for runs and passes.
+//! - `validate.rs`: static checks over rule definitions, run over the
+//!   built-in rules by tests and over rule files as they load.
 //!
 //! ## Public surface
 //!
@@ -63,8 +75,8 @@
 //! - New rules are added under `src/rules/**` and ultimately passed into
 //!   `Parser::new(..)` / `CompiledRules::new(..)`.
 //! - If a new rule needs a new coarse trigger, add a new `BucketMask` bit and
-//!   teach `TriggerInfo::scan` + `CompiledRules::new` + `Parser::new_compiled` to
-//!   wire it through.
+//!   teach `TriggerInfo::scan` to detect it; indexing and activation follow
+//!   the bits on their own.
 //! - If a new semantic dimension is added, extend `resolve.rs` so that
 //!   `resolve_node` can produce a stable canonical value for that dimension.
 //!
@@ -76,20 +88,44 @@
 mod compiled_rules;
 #[path = "engine/dedup.rs"]
 mod dedup;
+#[path = "engine/emoji.rs"]
+mod emoji;
+#[path = "engine/guard.rs"]
+mod guard;
+#[path = "engine/lexer.rs"]
+mod lexer;
 #[path = "engine/metrics.rs"]
 mod metrics;
 #[path = "engine/parser.rs"]
 mod parser;
+#[path = "engine/phrases.rs"]
+mod phrases;
 #[path = "engine/resolve.rs"]
 mod resolve;
+#[path = "engine/span.rs"]
+mod span;
 #[path = "engine/trigger.rs"]
 mod trigger;
+#[path = "engine/typos.rs"]
+mod typos;
+#[path = "engine/validate.rs"]
+mod validate;
 
 #[allow(unused_imports)]
 pub use compiled_rules::{BucketMask, CompiledRules, DimensionSet, RuleIndex, RuleMeta};
+pub(crate) use dedup::{NodeKey, identical_nodes};
+pub(crate) use emoji::blank_emoji;
+pub use guard::{ErrorStage, InternalError};
 #[allow(unused_imports)]
 pub use metrics::{PassMetrics, RegexProfileSummary, RegexRuleProfile, RunMetrics, RunResult, SaturationMetrics};
 #[allow(unused_imports)]
 pub use parser::Parser;
+pub(crate) use phrases::derive_phrases;
+pub(crate) use resolve::resolve_time_expr;
+pub(crate) use span::{slice, snap};
 #[allow(unused_imports)]
 pub use trigger::TriggerInfo;
+pub(crate) use typos::{Corrections, correct_typos};
+#[cfg(feature = "rule-files")]
+pub(crate) use validate::check_rule;
+pub(crate) use validate::rule_patterns;
@@ -20,11 +20,11 @@
 //! ## Extension points
 //!
 //! - Adding a new bucket:
-//!   1. Add a `BucketMask` bit.
-//!   2. Add a `BUCKET_*` constant and bump `BUCKET_COUNT`.
-//!   3. Teach `CompiledRules::new` to index that bucket.
-//!   4. Teach `TriggerInfo::scan` (in `trigger.rs`) to detect it.
-//!   5. Teach `Parser::new_compiled` (in `parser.rs`) to activate rules from it.
+//!   1. Add a `BucketMask` bit (the next free one; bits index `by_bucket`).
+//!   2. Teach `TriggerInfo::scan` (in `trigger.rs`) to detect it.
+//!
+//!   `CompiledRules::new` and `Parser::new_compiled` loop over the bits, so
+//!   rules declaring the bucket are indexed and activated without changes.
 //!
 //! - Adding new per-rule metadata:
 //!   extend `RuleMeta` and populate it from the `Rule` in `CompiledRules::new`.
@@ -33,8 +33,9 @@
 //!
 //! - `RuleId` is an index into `CompiledRules::rules` and `CompiledRules::metas`.
 //!   Those vectors must stay aligned.
-//! - `RuleIndex::by_bucket` uses fixed indices (`BUCKET_*`) to avoid `HashMap`
-//!   overhead in the hot path.
+//! - `RuleIndex::by_bucket` is indexed by bucket bit position
+//!   ([`BucketMask::index`]) to avoid `HashMap` overhead in the hot path, so
+//!   `BucketMask` bits must stay contiguous from 0.
 
 use crate::{Dimension, Rule};
 
@@ -53,6 +54,18 @@ bitflags::bitflags! {
         const WEEKDAYISH   = 1 << 3;
         const MONTHISH     = 1 << 4;
         const ORDINALISH   = 1 << 5;
+        const TRANSPORT    = 1 << 6;
+        const CURRENCY     = 1 << 7;
+        const UNITS        = 1 << 8;
+        const PHONEISH     = 1 << 9;
+        const EMAIL_URL    = 1 << 10;
+    }
+}
+
+impl BucketMask {
+    /// Position of a single-bucket mask in `RuleIndex::by_bucket`.
+    pub fn index(self) -> usize {
+        self.bits().trailing_zeros() as usize
     }
 }
 
@@ -66,6 +79,7 @@ bitflags::bitflags! {
         const TIME    = 1 << 0;
         const NUMERAL = 1 << 1;
         const REGEX   = 1 << 2;
+        const IDENTIFIER = 1 << 3;
     }
 }
 
@@ -74,6 +88,7 @@ bitflags::bitflags! {
 pub struct RuleMeta {
     pub required_phrases: &'static [&'static str],
     pub optional_phrases: &'static [&'static str],
+    pub derived_phrases: &'static [&'static str],
     pub buckets: BucketMask,
     pub _deps: &'static [Dimension],
     pub _priority: u16,
@@ -85,13 +100,7 @@ pub struct RuleIndex {
     pub by_bucket: [Vec<RuleId>; BUCKET_COUNT],
 }
 
-pub const BUCKET_COUNT: usize = 6;
-pub const BUCKET_HAS_DIGITS: usize = 0;
-pub const BUCKET_HAS_COLON: usize = 1;
-pub const BUCKET_HAS_AMPM: usize = 2;
-pub const BUCKET_WEEKDAYISH: usize = 3;
-pub const BUCKET_MONTHISH: usize = 4;
-pub const BUCKET_ORDINALISH: usize = 5;
+pub const BUCKET_COUNT: usize = BucketMask::all().bits().count_ones() as usize;
 
 /// Pre-compiled rule set with metadata and indexes.
 #[derive(Debug)]
@@ -117,6 +126,7 @@ impl<'a> CompiledRules<'a> {
             .map(|r| RuleMeta {
                 required_phrases: r.required_phrases,
                 optional_phrases: r.optional_phrases,
+                derived_phrases: r.derived_phrases,
                 buckets: BucketMask::from_bits_truncate(r.buckets),
                 _deps: r.deps,
                 _priority: r.priority,
@@ -131,24 +141,8 @@ impl<'a> CompiledRules<'a> {
                 // No bucket requirements -> always on (phrase filtering will happen later)
                 index.always_on.push(id);
             } else {
-                // Index by buckets using fixed array
-                if meta.buckets.contains(BucketMask::HAS_DIGITS) {
-                    index.by_bucket[BUCKET_HAS_DIGITS].push(id);
-                }
-                if meta.buckets.contains(BucketMask::HAS_COLON) {
-                    index.by_bucket[BUCKET_HAS_COLON].push(id);
-                }
-                if meta.buckets.contains(BucketMask::HAS_AMPM) {
-                    index.by_bucket[BUCKET_HAS_AMPM].push(id);
-                }
-                if meta.buckets.contains(BucketMask::WEEKDAYISH) {
-                    index.by_bucket[BUCKET_WEEKDAYISH].push(id);
-                }
-                if meta.buckets.contains(BucketMask::MONTHISH) {
-                    index.by_bucket[BUCKET_MONTHISH].push(id);
-                }
-                if meta.buckets.contains(BucketMask::ORDINALISH) {
-                    index.by_bucket[BUCKET_ORDINALISH].push(id);
+                for bucket in meta.buckets.iter() {
+                    index.by_bucket[bucket.index()].push(id);
                 }
             }
         }
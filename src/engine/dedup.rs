@@ -8,7 +8,9 @@
 //! - Produce non-deterministic output (depending on iteration order)
 //!
 //! This module defines `NodeKey`, a compact, hashable representation of a node
-//! that is used by the parser to avoid re-adding equivalent nodes.
+//! that is used by the parser to avoid re-adding equivalent nodes, and by
+//! `Stash::union` to merge stashes. Both go through [`NodeKey::from_node`], so
+//! there is a single definition of when two nodes are the same.
 //!
 //! ## What counts as “the same node”
 //!
@@ -17,11 +19,20 @@
 //! - Span (`start`, `end`)
 //! - Dimension (`dim`)
 //! - Producing rule name (`rule_name`)
-//! - A dimension-specific `kind_key`
+//! - Precision (`precision`), so a hedged reading is kept next to an exact one
+//! - A token-kind-specific `kind_key` covering everything that tells two
+//!   tokens of that kind apart: the numeral's value, grain and whether it can
+//!   be multiplied; the time expression; all capture groups of a regex match,
+//!   as written
 //!
 //! This is deliberately conservative: including `rule_name` avoids collapsing
 //! distinct derivations that share the same span/value, which is useful for
-//! debugging and can matter for evidence.
+//! debugging and can matter for evidence. Evidence itself is left out: it is
+//! derived from the route, and two routes to the same value by the same rule
+//! are the same node for saturation.
+//!
+//! [`identical_nodes`] drops the rule name from the key to find rules that
+//! derive the same nodes, which usually means one of them is redundant.
 //!
 //! ## Tradeoffs
 //!
@@ -29,7 +40,9 @@
 //!   not allocation-free, but keeps behavior stable until a more structured,
 //!   hashable representation is introduced.
 
-use crate::{Dimension, Node};
+use crate::{Dimension, Node, Precision};
+use std::collections::HashMap;
+use std::ops::Range;
 
 /// Lightweight key for deduplicating nodes in the stash.
 ///
@@ -41,14 +54,15 @@ pub(crate) struct NodeKey {
     pub(crate) end: usize,
     pub(crate) dim: Dimension,
     pub(crate) rule_name: &'static str,
+    pub(crate) precision: Precision,
     pub(crate) kind_key: NodeKindKey,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum NodeKindKey {
-    Numeral(u64),       // Store bits of f64 value for hashing
-    TimeExpr(String),   // Use debug format for uniqueness (falls back to allocation for correctness)
-    RegexMatch(String), // Keep group 0 for regex matches
+    Numeral { value: u64, grain: Option<u32>, multipliable: bool }, // Store bits of f64 value for hashing
+    TimeExpr(String), // Use debug format for uniqueness (falls back to allocation for correctness)
+    RegexMatch(Vec<String>), // Every matched group, with the casing of the input
 }
 
 impl NodeKey {
@@ -56,17 +70,14 @@ impl NodeKey {
         let kind_key = match &node.token.kind {
             crate::TokenKind::Numeral(d) => {
                 // Use bits of f64 for hashing to handle floats
-                NodeKindKey::Numeral(d.value.to_bits())
+                NodeKindKey::Numeral { value: d.value.to_bits(), grain: d.grain, multipliable: d.multipliable }
             }
             crate::TokenKind::TimeExpr(expr) => {
                 // Use debug format for stable key - still better than old approach
                 // which formatted the entire node context with many allocations
                 NodeKindKey::TimeExpr(format!("{:?}", expr))
             }
-            crate::TokenKind::RegexMatch(groups) => {
-                // Keep the first capture group for identification
-                NodeKindKey::RegexMatch(groups.first().map(|s| s.as_str()).unwrap_or("").to_string())
-            }
+            crate::TokenKind::RegexMatch(groups) => NodeKindKey::RegexMatch(groups.raw().to_vec()),
         };
 
         NodeKey {
@@ -74,7 +85,92 @@ impl NodeKey {
             end: node.range.end,
             dim: node.token.dim,
             rule_name: node.rule_name,
+            precision: node.token.precision,
             kind_key,
         }
     }
 }
+
+/// Spans where `nodes` from different rules are the same node but for the
+/// rule name, with those rules in the order they first produced it. Rules
+/// sharing a name are one rule here, as they are in the stash.
+pub(crate) fn identical_nodes<'n>(nodes: impl IntoIterator<Item = &'n Node>) -> Vec<(Range<usize>, Vec<&'static str>)> {
+    let mut index: HashMap<NodeKey, usize> = HashMap::new();
+    let mut groups: Vec<(Range<usize>, Vec<&'static str>)> = Vec::new();
+    for node in nodes {
+        let key = NodeKey { rule_name: "", ..NodeKey::from_node(node) };
+        let slot = *index.entry(key).or_insert_with(|| {
+            groups.push((node.range.start..node.range.end, Vec::new()));
+            groups.len() - 1
+        });
+        let rules = &mut groups[slot].1;
+        if !rules.contains(&node.rule_name) {
+            rules.push(node.rule_name);
+        }
+    }
+    groups.retain(|(_, rules)| rules.len() > 1);
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NumeralData, Range, RegexGroups, Stash, Token, TokenKind};
+    use std::sync::Arc;
+
+    fn node(kind: TokenKind, precision: Precision, evidence: Vec<&'static str>) -> Node {
+        named("test", kind, precision, evidence)
+    }
+
+    fn named(rule_name: &'static str, kind: TokenKind, precision: Precision, evidence: Vec<&'static str>) -> Node {
+        let dim = if matches!(kind, TokenKind::RegexMatch(_)) { Dimension::RegexMatch } else { Dimension::Numeral };
+        Node {
+            range: Range { start: 0, end: 5 },
+            token: Token { dim, kind, precision },
+            rule_name,
+            evidence,
+            children: Arc::from([]),
+        }
+    }
+
+    fn numeral(value: f64, grain: Option<u32>, multipliable: bool) -> TokenKind {
+        TokenKind::Numeral(NumeralData { value, grain, multipliable })
+    }
+
+    #[test]
+    fn union_dedups_by_node_key() {
+        let regex = |text: &str| TokenKind::RegexMatch(RegexGroups::from_text(text));
+        let nodes = [
+            node(numeral(5.0, None, false), Precision::Exact, vec![]),
+            node(numeral(5.0, Some(1), false), Precision::Exact, vec![]),
+            node(numeral(5.0, None, true), Precision::Exact, vec![]),
+            node(numeral(5.0, None, false), Precision::Approximate, vec![]),
+            node(regex("CEST"), Precision::Exact, vec![]),
+            node(regex("cest"), Precision::Exact, vec![]),
+        ];
+        let keys: std::collections::HashSet<NodeKey> = nodes.iter().map(NodeKey::from_node).collect();
+        assert_eq!(keys.len(), nodes.len());
+
+        let mut first = Stash::empty();
+        nodes.iter().cloned().for_each(|n| first.insert(n));
+        let mut second = Stash::empty();
+        second.insert(node(numeral(5.0, None, false), Precision::Exact, vec!["other route"]));
+        second.insert(node(regex("CEST"), Precision::Exact, vec![]));
+        let union = first.union(&second);
+        assert_eq!(union.get_nodes().len(), nodes.len());
+        assert!(union.get_nodes().iter().all(|n| n.evidence.is_empty()));
+    }
+
+    #[test]
+    fn identical_nodes_differ_only_by_rule() {
+        let nodes = [
+            named("five", numeral(5.0, None, false), Precision::Exact, vec![]),
+            named("5", numeral(5.0, None, false), Precision::Exact, vec!["other route"]),
+            named("five", numeral(5.0, None, false), Precision::Exact, vec![]),
+            named("about 5", numeral(5.0, None, false), Precision::Approximate, vec![]),
+            named("six", numeral(6.0, None, false), Precision::Exact, vec![]),
+        ];
+        assert_eq!(identical_nodes(&nodes), [(0..5, vec!["five", "5"])]);
+        assert_eq!(identical_nodes(&nodes[2..]), []);
+    }
+}
@@ -0,0 +1,64 @@
+//! Emoji as word separators.
+//!
+//! Chat messages put emoji between and around words ("tomorrow 🎉 at 5",
+//! "friday👍🏽"). Rules spell out the whitespace between their items and the
+//! trigger scan splits words on whitespace, so the parser sees the input
+//! with every emoji replaced by spaces of the same byte length. That covers
+//! the characters emoji sequences are built from too: zero-width joiners
+//! ("👩‍💻"), skin-tone modifiers, variation selectors and keycaps. Offsets
+//! stay those of the input, and entity bodies are sliced from the text as
+//! written.
+
+use std::borrow::Cow;
+
+/// Whether `c` is an emoji or part of an emoji sequence.
+pub(crate) fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1F000}'..='\u{1FAFF}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2B00}'..='\u{2BFF}'
+            | '\u{231A}'..='\u{231B}'
+            | '\u{23E9}'..='\u{23FA}'
+            | '\u{200D}'
+            | '\u{20E3}'
+            | '\u{FE0E}'..='\u{FE0F}'
+            | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+/// `text` with every emoji character replaced by as many spaces as it has
+/// bytes.
+pub(crate) fn blank_emoji(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(is_emoji) {
+        return Cow::Borrowed(text);
+    }
+    let mut blanked = String::with_capacity(text.len());
+    for c in text.chars() {
+        if is_emoji(c) {
+            blanked.extend(std::iter::repeat_n(' ', c.len_utf8()));
+        } else {
+            blanked.push(c);
+        }
+    }
+    Cow::Owned(blanked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emoji_become_spaces_of_the_same_length() {
+        for text in
+            ["tomorrow 🎉 at 5", "friday👍🏽", "meet 👩\u{200d}💻 at 3", "5\u{fe0f}\u{20e3} ☕ pm", "🇸🇪 midsummer"]
+        {
+            let blanked = blank_emoji(text);
+            assert_eq!(blanked.len(), text.len(), "{text:?}");
+            assert!(!blanked.chars().any(is_emoji), "{text:?}");
+            assert!(blanked.char_indices().all(|(idx, c)| c == ' ' || text[idx..].starts_with(c)), "{text:?}");
+        }
+        assert_eq!(blank_emoji("friday👍🏽at 5"), "friday        at 5");
+        assert!(matches!(blank_emoji("café at 5 – 6"), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,72 @@
+//! Panic isolation for rule code.
+//!
+//! Rule productions and normalization are plain closures and functions; a bug
+//! in one of them must not take down the caller. The parser runs them through
+//! [`catch`], drops whatever the failing call would have produced, and records
+//! an [`InternalError`] naming the rule so the failure stays visible.
+//!
+//! Note that the process panic hook still runs, so the panic message is
+//! printed to stderr as usual.
+
+use std::any::Any;
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// The step of the pipeline in which an [`InternalError`] occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ErrorStage {
+    /// A rule's production closure, during saturation.
+    Production,
+    /// Normalizing a node into a value, during resolution.
+    Resolution,
+}
+
+impl fmt::Display for ErrorStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorStage::Production => f.write_str("production"),
+            ErrorStage::Resolution => f.write_str("resolution"),
+        }
+    }
+}
+
+/// A panic caught inside the engine. Parsing carries on as if the failing
+/// call had produced nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InternalError {
+    /// Name of the rule whose code panicked.
+    pub rule: String,
+    /// Where the panic happened.
+    pub stage: ErrorStage,
+    /// The panic message.
+    pub message: String,
+}
+
+impl fmt::Display for InternalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rule \"{}\" panicked during {}: {}", self.rule, self.stage, self.message)
+    }
+}
+
+impl std::error::Error for InternalError {}
+
+/// Run `f`, turning a panic into an `InternalError` for `rule`.
+pub(crate) fn catch<T>(rule: &str, stage: ErrorStage, f: impl FnOnce() -> T) -> Result<T, InternalError> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|payload| InternalError {
+        rule: rule.to_string(),
+        stage,
+        message: panic_message(payload.as_ref()),
+    })
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
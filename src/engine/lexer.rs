@@ -0,0 +1,82 @@
+//! Lexical tokens (the optional first pass).
+//!
+//! Regex patterns match anywhere in the raw input, so every rule that joins
+//! two items has to spell out the whitespace between them (`\s+at\s+`) and
+//! each lookup rescans the whole input. Rules can instead match a fixed
+//! sequence of words with `Pattern::Words` (`words!("at")`): the input is
+//! split once into [`Lexeme`]s and the words are compared against them, with
+//! any whitespace in between.
+//!
+//! Lexemes are runs of letters (with inner apostrophes, "o'clock") or runs
+//! of digits; any other character that is not whitespace is a lexeme of its
+//! own. Letters and digits are split apart, so "3pm" is "3" and "pm" just
+//! like "3 pm". The parser only tokenizes inputs that reach a rule using
+//! `Words`.
+
+use std::ops::Range;
+
+/// One word, number or punctuation mark of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Lexeme {
+    /// Byte range in the input.
+    pub range: Range<usize>,
+    /// The text, lowercased, with typographic apostrophes made straight.
+    pub lower: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Letter,
+    Digit,
+    Other,
+}
+
+fn class(c: char) -> Class {
+    if c.is_alphabetic() {
+        Class::Letter
+    } else if c.is_numeric() {
+        Class::Digit
+    } else {
+        Class::Other
+    }
+}
+
+/// Split `text` into lexemes, skipping whitespace.
+pub(crate) fn tokenize(text: &str) -> Vec<Lexeme> {
+    let mut lexemes = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let kind = class(c);
+        let mut end = start + c.len_utf8();
+        while let Some(&(idx, next)) = chars.peek() {
+            let apostrophe = kind == Class::Letter
+                && matches!(next, '\'' | '’')
+                && text[idx + next.len_utf8()..].chars().next().is_some_and(char::is_alphabetic);
+            if kind == Class::Other || (class(next) != kind && !apostrophe) {
+                break;
+            }
+            end = idx + next.len_utf8();
+            chars.next();
+        }
+        lexemes.push(Lexeme { range: start..end, lower: text[start..end].to_lowercase().replace('’', "'") });
+    }
+    lexemes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_words_numbers_and_punctuation() {
+        let lower = |text: &str| tokenize(text).into_iter().map(|l| l.lower).collect::<Vec<_>>().join("|");
+
+        assert_eq!(lower("Friday at  3pm"), "friday|at|3|pm");
+        assert_eq!(lower("5 O’clock, 10:30"), "5|o'clock|,|10|:|30");
+        assert_eq!(lower("the 'day' after"), "the|'|day|'|after");
+        assert_eq!(tokenize("  at\n9").iter().map(|l| l.range.clone()).collect::<Vec<_>>(), [2..4, 5..6]);
+    }
+}
@@ -17,9 +17,10 @@
 //! ## Design notes
 //!
 //! - `PassMetrics::nodes` is primarily for debugging and may allocate.
-//! - Fields prefixed with `_` are collected for potential future reporting but
-//!   are not currently surfaced in user-facing output.
+//! - The public, serializable view of these structs is `crate::Metrics`
+//!   (built in `api.rs`); keep the two in sync when adding counters.
 
+use super::guard::InternalError;
 use crate::{Node, ResolvedToken};
 use std::time::Duration;
 
@@ -33,6 +34,8 @@ pub struct RunMetrics {
     pub saturation: SaturationMetrics,
     /// Time spent resolving tokens after saturation.
     pub resolve: Duration,
+    /// Number of rules that survived bucket/phrase gating for this input.
+    pub active_rules: usize,
     /// Regex profiling summary collected when profiling is enabled.
     pub regex_profile: Option<RegexProfileSummary>,
 }
@@ -55,14 +58,15 @@ pub struct PassMetrics {
     pub duration: Duration,
     /// Number of new nodes added to the stash during the pass.
     pub produced: usize,
-    /// New nodes produced in this pass (for debugging).
+    /// New nodes produced in this pass; only recorded for verbose parses
+    /// and with `RUSTLING_DEBUG_RULES` set.
     pub nodes: Vec<Node>,
     /// Number of rules considered (attempted) during this pass.
-    pub _rules_considered: usize,
+    pub rules_considered: usize,
     /// Number of rules that had at least one first-pattern match.
-    pub _rules_seeded: usize,
+    pub rules_seeded: usize,
     /// Number of regex first-pattern hits across all rules.
-    pub _regex_first_pattern_hits: usize,
+    pub regex_first_pattern_hits: usize,
 }
 
 /// Aggregated regex profiling details for the most expensive rules.
@@ -98,4 +102,8 @@ pub struct RunResult {
     pub tokens: Vec<ResolvedToken>,
     /// Timing measurements for the run.
     pub metrics: RunMetrics,
+    /// Rules that produced at least one node during saturation.
+    pub fired_rules: Vec<&'static str>,
+    /// Panics caught in rule productions and normalization.
+    pub errors: Vec<InternalError>,
 }
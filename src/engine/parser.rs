@@ -43,16 +43,24 @@
 //! Setting `RUSTLING_DEBUG_RULES=1` prints useful trace information about rule
 //! activation and resolution.
 
-use super::compiled_rules::{
-    BUCKET_HAS_AMPM, BUCKET_HAS_COLON, BUCKET_HAS_DIGITS, BUCKET_MONTHISH, BUCKET_ORDINALISH, BUCKET_WEEKDAYISH,
-    BucketMask, CompiledRules, DimensionSet, RuleId,
-};
+use super::compiled_rules::{CompiledRules, DimensionSet, RuleId};
 use super::dedup::NodeKey;
+use super::guard::{ErrorStage, InternalError, catch};
+use super::lexer::{Lexeme, tokenize};
 use super::metrics::{PassMetrics, RegexProfileSummary, RegexRuleProfile, RunMetrics, RunResult, SaturationMetrics};
-use super::resolve::resolve_node;
+use super::resolve::{
+    claim_identifiers, disambiguate_year_vs_clock, drop_lowercase_names, enforce_coverage, infer_meridiem,
+    mark_hypotheticals, mark_identifiers, merge_across_gaps, resolve_node, suppress_covered_numerals,
+};
+use super::span::slice;
 use super::trigger::TriggerInfo;
-use crate::{Context, Dimension, Node, Options, Pattern, Range, ResolvedToken, Rule, Stash, Token, TokenKind};
+use crate::alias::alias_nodes;
+use crate::{
+    Context, Dimension, Node, Options, OverlapPolicy, Pattern, Precision, Range, RegexGroups, ResolvedToken, Rule,
+    RuleGroups, Stash, Token, TokenKind,
+};
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
 // Move the parser/partial-match implementation to module scope so other modules
@@ -97,18 +105,28 @@ pub struct Parser<'a> {
     seen: HashSet<NodeKey>,
     /// Compiled rules (shared reference).
     compiled: CompiledRules<'a>,
-    /// Cached list of rules that start with a `Regex` pattern.
+    /// Cached list of rules that start with a `Regex` (or `Words`) pattern.
     regex_rules: Vec<&'a Rule>,
     /// Cached list of rules that start with a `Predicate` pattern.
     predicate_rules: Vec<&'a Rule>,
+    /// Panics caught while running rule code.
+    errors: Mutex<Vec<InternalError>>,
+    /// The input split into lexemes, on the first `Words` lookup.
+    lexemes: OnceLock<Vec<Lexeme>>,
+    /// Whether passes keep the nodes they produce in their metrics.
+    record_nodes: bool,
 }
 
 impl<'a> Parser<'a> {
     /// Create a new `Parser` for `input` using pre-compiled rules.
     pub fn new_compiled(input: &'a str, compiled: CompiledRules<'a>) -> Self {
         // Scan input to get coarse buckets + key phrases.
-        let trigger_info = TriggerInfo::scan(input);
+        Self::new_scanned(input, compiled, &TriggerInfo::scan(input))
+    }
 
+    /// Create a new `Parser` for `input`, activating rules from a scan of
+    /// some other text (for a window of a document, the whole document).
+    pub(crate) fn new_scanned(input: &'a str, compiled: CompiledRules<'a>, trigger_info: &TriggerInfo) -> Self {
         if std::env::var_os("RUSTLING_DEBUG_RULES").is_some() {
             eprintln!("[trigger_scan] buckets={:?} phrases={:?}", trigger_info.buckets, trigger_info.phrases);
         }
@@ -117,24 +135,8 @@ impl<'a> Parser<'a> {
         let mut active_rule_ids: HashSet<RuleId> = compiled.index.always_on.iter().copied().collect();
 
         // Add rules whose bucket requirements are satisfied by the input
-        // Direct checks avoid HashMap overhead
-        if trigger_info.buckets.contains(BucketMask::HAS_DIGITS) {
-            active_rule_ids.extend(&compiled.index.by_bucket[BUCKET_HAS_DIGITS]);
-        }
-        if trigger_info.buckets.contains(BucketMask::HAS_COLON) {
-            active_rule_ids.extend(&compiled.index.by_bucket[BUCKET_HAS_COLON]);
-        }
-        if trigger_info.buckets.contains(BucketMask::HAS_AMPM) {
-            active_rule_ids.extend(&compiled.index.by_bucket[BUCKET_HAS_AMPM]);
-        }
-        if trigger_info.buckets.contains(BucketMask::WEEKDAYISH) {
-            active_rule_ids.extend(&compiled.index.by_bucket[BUCKET_WEEKDAYISH]);
-        }
-        if trigger_info.buckets.contains(BucketMask::MONTHISH) {
-            active_rule_ids.extend(&compiled.index.by_bucket[BUCKET_MONTHISH]);
-        }
-        if trigger_info.buckets.contains(BucketMask::ORDINALISH) {
-            active_rule_ids.extend(&compiled.index.by_bucket[BUCKET_ORDINALISH]);
+        for bucket in trigger_info.buckets.iter() {
+            active_rule_ids.extend(&compiled.index.by_bucket[bucket.index()]);
         }
 
         // Phrase gating - filter out rules whose phrase requirements are not met.
@@ -162,6 +164,13 @@ impl<'a> Parser<'a> {
                 }
             }
 
+            // Check derived_phrases (OR logic, substrings of the input)
+            if !meta.derived_phrases.is_empty() && !meta.derived_phrases.iter().any(|&p| trigger_info.lower.contains(p))
+            {
+                phrase_filtered += 1;
+                return false;
+            }
+
             true
         });
 
@@ -179,7 +188,7 @@ impl<'a> Parser<'a> {
             .iter()
             .enumerate()
             .filter(|(id, _)| active_rule_ids.contains(id))
-            .filter(|(_, r)| matches!(r.pattern.first(), Some(Pattern::Regex(_))))
+            .filter(|(_, r)| matches!(r.pattern.first(), Some(Pattern::Regex(_) | Pattern::Words(_))))
             .map(|(_, r)| *r)
             .collect();
 
@@ -192,6 +201,8 @@ impl<'a> Parser<'a> {
             .map(|(_, r)| *r)
             .collect();
 
+        let (regex_rules, predicate_rules) = (Self::by_priority(regex_rules), Self::by_priority(predicate_rules));
+
         if std::env::var_os("RUSTLING_DEBUG_RULES").is_some() {
             eprintln!("[regex_rules] {} regex rules, {} predicate rules", regex_rules.len(), predicate_rules.len());
             eprintln!("[regex_rules] Rules with regex first pattern:");
@@ -200,7 +211,24 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Parser { input, stash: Stash::empty(), seen: HashSet::new(), compiled, regex_rules, predicate_rules }
+        Parser {
+            input,
+            stash: Stash::empty(),
+            seen: HashSet::new(),
+            compiled,
+            regex_rules,
+            predicate_rules,
+            errors: Mutex::new(Vec::new()),
+            lexemes: OnceLock::new(),
+            record_nodes: std::env::var_os("RUSTLING_DEBUG_RULES").is_some(),
+        }
+    }
+
+    /// `rules` in the order a pass applies them: highest priority first and,
+    /// among equal priorities, latent rules last, otherwise in rule-set order.
+    fn by_priority(mut rules: Vec<&'a Rule>) -> Vec<&'a Rule> {
+        rules.sort_by_key(|rule| (std::cmp::Reverse(rule.priority), rule.groups.contains(RuleGroups::LATENT)));
+        rules
     }
 
     /// Create a new `Parser` for `input` using `rules`.
@@ -216,6 +244,21 @@ impl<'a> Parser<'a> {
         Self::new_compiled(input, CompiledRules::new(rules))
     }
 
+    /// Keep the nodes each pass produces in [`PassMetrics::nodes`], as with
+    /// `RUSTLING_DEBUG_RULES` set.
+    pub(crate) fn record_nodes(&mut self) {
+        self.record_nodes = true;
+    }
+
+    /// Deactivate every rule belonging to one of `groups`.
+    pub(crate) fn disable_groups(&mut self, groups: RuleGroups) {
+        if groups.is_empty() {
+            return;
+        }
+        self.regex_rules.retain(|rule| !rule.groups.intersects(groups));
+        self.predicate_rules.retain(|rule| !rule.groups.intersects(groups));
+    }
+
     pub(crate) fn active_rule_names(&self) -> Vec<&'static str> {
         let mut names: Vec<&'static str> =
             self.regex_rules.iter().chain(self.predicate_rules.iter()).map(|r| r.name).collect();
@@ -231,11 +274,13 @@ impl<'a> Parser<'a> {
     /// position: 10 (start of "5")
     /// Pattern::Regex("\\d+") -> Node at 10..11
     /// Pattern::Predicate(is_time) -> Nodes pulled from stash at same offset
+    /// Pattern::Words("at") -> Node at 8..12 (" at " when items follow)
     /// ```
     fn lookup_item(
         &self,
         pat: &Pattern,
         position: usize,
+        last: bool,
         rule_name: &'static str,
         profiler: &mut RegexProfiler,
     ) -> Vec<Node> {
@@ -251,13 +296,17 @@ impl<'a> Parser<'a> {
                     }
                     let m = caps.get(0).unwrap();
                     if m.start() == position {
-                        let groups: Vec<String> =
-                            (0..caps.len()).filter_map(|i| caps.get(i).map(|g| g.as_str().to_lowercase())).collect();
+                        let groups = RegexGroups::from_captures(&caps);
                         res.push(Node {
                             range: Range { start: m.start(), end: m.end() },
-                            token: Token { dim: Dimension::RegexMatch, kind: TokenKind::RegexMatch(groups) },
+                            token: Token {
+                                dim: Dimension::RegexMatch,
+                                kind: TokenKind::RegexMatch(groups),
+                                precision: Precision::Exact,
+                            },
                             rule_name: "<regex>",
                             evidence: Vec::new(),
+                            children: Arc::from([]),
                         });
                     }
                 }
@@ -272,15 +321,35 @@ impl<'a> Parser<'a> {
                 .into_iter()
                 .filter(|n| n.range.start == position && pred(&n.token))
                 .collect(),
+            Pattern::Words(words) => {
+                let lexemes = self.lexemes();
+                let idx = lexemes.partition_point(|l| l.range.start < position);
+                let Some(first) = lexemes.get(idx) else { return Vec::new() };
+                if !slice(self.input, position..first.range.start).chars().all(char::is_whitespace) {
+                    return Vec::new();
+                }
+                // Absorb the whitespace after the previous item.
+                self.match_words(words, idx, last)
+                    .map(|node| Node { range: Range { start: position, end: node.range.end }, ..node })
+                    .into_iter()
+                    .collect()
+            }
         }
     }
 
     /// Find nodes that match `pat` anywhere in the input.
     ///
     /// Used to seed partial matches for rules whose first pattern can match at
-    /// any position. The regex branch scans the raw input, while the predicate
-    /// branch leverages every node already in the stash.
-    fn lookup_item_anywhere(&self, pat: &Pattern, rule_name: &'static str, profiler: &mut RegexProfiler) -> Vec<Node> {
+    /// any position. The regex branch scans the raw input, the words branch
+    /// its lexemes, while the predicate branch leverages every node already
+    /// in the stash.
+    fn lookup_item_anywhere(
+        &self,
+        pat: &Pattern,
+        last: bool,
+        rule_name: &'static str,
+        profiler: &mut RegexProfiler,
+    ) -> Vec<Node> {
         match pat {
             Pattern::Regex(re) => {
                 let mut res = Vec::new();
@@ -292,13 +361,17 @@ impl<'a> Parser<'a> {
                         match_count += 1;
                     }
                     let m = caps.get(0).unwrap();
-                    let groups: Vec<String> =
-                        (0..caps.len()).filter_map(|i| caps.get(i).map(|g| g.as_str().to_lowercase())).collect();
+                    let groups = RegexGroups::from_captures(&caps);
                     res.push(Node {
                         range: Range { start: m.start(), end: m.end() },
-                        token: Token { dim: Dimension::RegexMatch, kind: TokenKind::RegexMatch(groups) },
+                        token: Token {
+                            dim: Dimension::RegexMatch,
+                            kind: TokenKind::RegexMatch(groups),
+                            precision: Precision::Exact,
+                        },
                         rule_name: "<regex>",
                         evidence: Vec::new(),
+                        children: Arc::from([]),
                     });
                 }
                 if let Some(start) = start {
@@ -309,7 +382,40 @@ impl<'a> Parser<'a> {
             Pattern::Predicate(pred) => {
                 self.stash.to_pos_ordered_list().into_iter().filter(|n| pred(&n.token)).collect()
             }
+            Pattern::Words(words) => {
+                (0..self.lexemes().len()).filter_map(|idx| self.match_words(words, idx, last)).collect()
+            }
+        }
+    }
+
+    /// The input's lexemes, split on first use.
+    fn lexemes(&self) -> &[Lexeme] {
+        self.lexemes.get_or_init(|| tokenize(self.input))
+    }
+
+    /// Match `words` against the lexemes from `idx` on. The node also covers
+    /// the whitespace after the words unless they are the `last` item of the
+    /// rule, so the next item can start where it ends.
+    fn match_words(&self, words: &str, idx: usize, last: bool) -> Option<Node> {
+        let wanted = tokenize(words);
+        let found = self.lexemes().get(idx..idx + wanted.len())?;
+        if wanted.is_empty() || !found.iter().zip(&wanted).all(|(lexeme, word)| lexeme.lower == word.lower) {
+            return None;
         }
+        let (start, end) = (found[0].range.start, found[found.len() - 1].range.end);
+        let rest = slice(self.input, end..);
+        let trailing = if last { 0 } else { rest.len() - rest.trim_start().len() };
+        Some(Node {
+            range: Range { start, end: end + trailing },
+            token: Token {
+                dim: Dimension::RegexMatch,
+                kind: TokenKind::RegexMatch(RegexGroups::from_text(slice(self.input, start..end))),
+                precision: Precision::Exact,
+            },
+            rule_name: "<words>",
+            evidence: Vec::new(),
+            children: Arc::from([]),
+        })
     }
 
     /// Attempt to match a rule's first pattern anywhere and return initial
@@ -325,7 +431,7 @@ impl<'a> Parser<'a> {
             return Vec::new();
         }
         let first = &rule.pattern[0];
-        self.lookup_item_anywhere(first, rule.name, profiler)
+        self.lookup_item_anywhere(first, rule.pattern.len() == 1, rule.name, profiler)
             .into_iter()
             .map(|node| PartialMatch { rule, next_idx: 1, position: node.range.end, route: vec![node] })
             .collect()
@@ -355,11 +461,28 @@ impl<'a> Parser<'a> {
             }
 
             let pat = &m.rule.pattern[m.next_idx];
-            let nodes = self.lookup_item(pat, m.position, m.rule.name, profiler);
+            let last = m.next_idx + 1 == m.rule.pattern.len();
+            // Skipped separators stand in for `\s+`: the next item may touch the
+            // previous one ("13-15"), but never run into it mid-word ("march3").
+            let mut nodes = if m.rule.skip_separators && joins_words(self.input, m.position) {
+                Vec::new()
+            } else {
+                self.lookup_item(pat, m.position, last, m.rule.name, profiler)
+            };
+            if m.rule.skip_separators {
+                let rest = slice(self.input, m.position..);
+                let gap = rest.len() - rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',').len();
+                if gap > 0 {
+                    nodes.extend(self.lookup_item(pat, m.position + gap, last, m.rule.name, profiler));
+                }
+            }
 
             // For each matching node, create a new partial match
             // Push in reverse order so we explore them in forward order (stack is LIFO)
             for node in nodes.into_iter().rev() {
+                if Self::already_consumed(&m.route, &node) {
+                    continue;
+                }
                 let mut new_route = m.route.clone();
                 new_route.push(node.clone());
                 stack.push(PartialMatch {
@@ -374,6 +497,24 @@ impl<'a> Parser<'a> {
         results
     }
 
+    /// Whether `node` would consume input `route` already consumed: it
+    /// overlaps a node of the route, or is the same zero-width node again.
+    /// Items are looked up where the previous one ended, so this only
+    /// catches nodes whose spans are off (zero-width matches, spans snapped
+    /// back from corrected text); it keeps one stash node from being used
+    /// twice in a route however it got there.
+    fn already_consumed(route: &[Node], node: &Node) -> bool {
+        route.iter().any(|prev| {
+            if node.range.start == node.range.end || prev.range.start == prev.range.end {
+                prev.range.start == node.range.start
+                    && prev.range.end == node.range.end
+                    && NodeKey::from_node(prev) == NodeKey::from_node(node)
+            } else {
+                prev.range.start < node.range.end && node.range.start < prev.range.end
+            }
+        })
+    }
+
     /// Convert a completed `PartialMatch` into a `Node` by invoking the rule's
     /// production callback.
     ///
@@ -387,18 +528,28 @@ impl<'a> Parser<'a> {
         let tokens: Vec<Token> = m.route.iter().map(|n| n.token.clone()).collect();
         let debug = std::env::var_os("RUSTLING_DEBUG_RULES").is_some();
 
-        match (m.rule.production)(&tokens) {
-            Some(tok) => {
+        let produced =
+            catch(m.rule.name, ErrorStage::Production, || (m.rule.production)(&tokens)).unwrap_or_else(|err| {
+                self.record_error(err);
+                None
+            });
+
+        match produced {
+            Some(mut tok) => {
+                // Hedging is contagious: "tomorrow at about 3pm" is as approximate as "about 3pm".
+                if tokens.iter().any(|t| t.precision == Precision::Approximate) {
+                    tok.precision = Precision::Approximate;
+                }
                 if let (Some(first), Some(last)) = (m.route.first(), m.route.last()) {
                     if debug {
-                        let span_text = &self.input[first.range.start..last.range.end.min(self.input.len())];
+                        let span_text = slice(self.input, first.range.start..last.range.end);
                         eprintln!(
                             "[rule:production_ok] name=\"{}\" span={}..{} text=\"{}\" token={:?}",
                             m.rule.name, first.range.start, last.range.end, span_text, tok,
                         );
                     }
                     // Collect evidence: rule names from the route plus nested evidence
-                    let mut evidence = Vec::new();
+                    let mut evidence: Vec<&'static str> = m.rule.hint.into_iter().collect();
                     for node in &m.route {
                         evidence.push(node.rule_name);
                         evidence.extend_from_slice(&node.evidence);
@@ -408,6 +559,7 @@ impl<'a> Parser<'a> {
                         token: tok,
                         rule_name: m.rule.name,
                         evidence,
+                        children: Arc::from(m.route.as_slice()),
                     });
                 }
                 None
@@ -421,43 +573,127 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Keep a caught panic for the run result. Saturation retries the same
+    /// match on every pass, so repeats of an identical error are dropped.
+    fn record_error(&self, err: InternalError) {
+        let mut errors = self.errors.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !errors.contains(&err) {
+            errors.push(err);
+        }
+    }
+
     /// Apply an ordered set of rules once and return the nodes produced.
     ///
     /// Designed to be called from `saturate` with different rule subsets to
     /// keep the staging clear in logs or profilers.
-    fn apply_rules_once(&self, rule_set: &[&Rule], profiler: &mut RegexProfiler) -> (Vec<Node>, usize, usize, usize) {
+    ///
+    /// The pass ends early, after the rule that produced it, once a node
+    /// satisfies `stop`.
+    #[cfg(not(feature = "parallel"))]
+    fn apply_rules_once(
+        &self,
+        rule_set: &[&Rule],
+        profiler: &mut RegexProfiler,
+        stop: &dyn Fn(&Node) -> bool,
+    ) -> (Vec<Node>, usize, usize, usize) {
         let mut discovered = Vec::new();
-        let debug = std::env::var_os("RUSTLING_DEBUG_RULES").is_some();
+        let mut rules_considered = 0;
         let mut rules_seeded = 0;
         let mut regex_first_pattern_hits = 0;
 
         for rule in rule_set {
-            let starts = self.seed_first_pattern_anywhere(rule, profiler);
-            let starts_count = starts.len();
-
-            // Count seeded rules (those with at least one first-pattern match)
-            if starts_count > 0 {
-                rules_seeded += 1;
-                // Count regex hits if the first pattern is a regex
-                if matches!(rule.pattern.first(), Some(Pattern::Regex(_))) {
-                    regex_first_pattern_hits += starts_count;
-                }
+            let (nodes, starts_count) = self.apply_rule(rule, profiler);
+            let stopped = nodes.iter().any(stop);
+            discovered.extend(nodes);
+            rules_considered += 1;
+            Self::count_seeds(rule, starts_count, &mut rules_seeded, &mut regex_first_pattern_hits);
+            if stopped {
+                break;
             }
+        }
+        (discovered, rules_considered, rules_seeded, regex_first_pattern_hits)
+    }
 
-            if debug && starts_count > 0 {
-                eprintln!("[rule:seed] name=\"{}\" initial_matches={}", rule.name, starts_count);
-            }
-            let full = self.match_all(starts, profiler);
-            if debug && !full.is_empty() {
-                eprintln!("[rule:full_matches] name=\"{}\" count={}", rule.name, full.len());
+    /// Parallel variant of `apply_rules_once`: rules are spread across the
+    /// rayon pool, each with its own regex profiler, and the results are
+    /// merged back in rule order so the stash grows exactly as it would
+    /// sequentially, including stopping after the first rule whose nodes
+    /// satisfy `stop`.
+    #[cfg(feature = "parallel")]
+    fn apply_rules_once(
+        &self,
+        rule_set: &[&Rule],
+        profiler: &mut RegexProfiler,
+        stop: &dyn Fn(&Node) -> bool,
+    ) -> (Vec<Node>, usize, usize, usize) {
+        use rayon::prelude::*;
+
+        let profiling = profiler.enabled();
+        let per_rule: Vec<(Vec<Node>, usize, RegexProfiler)> = rule_set
+            .par_iter()
+            .map(|rule| {
+                let mut local = RegexProfiler::new(profiling);
+                let (nodes, starts_count) = self.apply_rule(rule, &mut local);
+                (nodes, starts_count, local)
+            })
+            .collect();
+
+        let mut discovered = Vec::new();
+        let mut rules_considered = 0;
+        let mut rules_seeded = 0;
+        let mut regex_first_pattern_hits = 0;
+        for (rule, (nodes, starts_count, local)) in rule_set.iter().zip(per_rule) {
+            let stopped = nodes.iter().any(stop);
+            discovered.extend(nodes);
+            profiler.merge(local);
+            rules_considered += 1;
+            Self::count_seeds(rule, starts_count, &mut rules_seeded, &mut regex_first_pattern_hits);
+            if stopped {
+                break;
             }
-            for m in full {
-                if let Some(node) = self.produce_node(&m) {
-                    discovered.push(node);
-                }
+        }
+        (discovered, rules_considered, rules_seeded, regex_first_pattern_hits)
+    }
+
+    /// Seed, match and produce a single rule; returns the nodes produced and
+    /// the number of first-pattern matches it was seeded with.
+    fn apply_rule(&self, rule: &Rule, profiler: &mut RegexProfiler) -> (Vec<Node>, usize) {
+        let debug = std::env::var_os("RUSTLING_DEBUG_RULES").is_some();
+        let starts = self.seed_first_pattern_anywhere(rule, profiler);
+        let starts_count = starts.len();
+
+        if debug && starts_count > 0 {
+            eprintln!("[rule:seed] name=\"{}\" initial_matches={}", rule.name, starts_count);
+        }
+        let full = self.match_all(starts, profiler);
+        if debug && !full.is_empty() {
+            eprintln!("[rule:full_matches] name=\"{}\" count={}", rule.name, full.len());
+        }
+        let nodes = full.iter().filter(|m| self.context_allows(m)).filter_map(|m| self.produce_node(m)).collect();
+        (nodes, starts_count)
+    }
+
+    /// Whether the text around a complete match passes the rule's
+    /// `not_preceded_by` and `not_followed_by` guards.
+    fn context_allows(&self, m: &PartialMatch) -> bool {
+        let (Some(first), Some(last)) = (m.route.first(), m.route.last()) else {
+            return true;
+        };
+        let rejected_before = m.rule.not_preceded_by.is_some_and(|re| re.is_match(slice(self.input, ..last.range.end)));
+        let rejected_after =
+            m.rule.not_followed_by.is_some_and(|re| re.is_match(slice(self.input, first.range.start..)));
+        !rejected_before && !rejected_after
+    }
+
+    fn count_seeds(rule: &Rule, starts_count: usize, rules_seeded: &mut usize, regex_first_pattern_hits: &mut usize) {
+        // Count seeded rules (those with at least one first-pattern match)
+        if starts_count > 0 {
+            *rules_seeded += 1;
+            // Count regex hits if the first pattern is a regex
+            if matches!(rule.pattern.first(), Some(Pattern::Regex(_))) {
+                *regex_first_pattern_hits += starts_count;
             }
         }
-        (discovered, rule_set.len(), rules_seeded, regex_first_pattern_hits)
     }
 
     /// Compute which dimensions are present in the stash.
@@ -468,6 +704,7 @@ impl<'a> Parser<'a> {
                 Dimension::Time => dims |= DimensionSet::TIME,
                 Dimension::Numeral => dims |= DimensionSet::NUMERAL,
                 Dimension::RegexMatch => dims |= DimensionSet::REGEX,
+                Dimension::Identifier => dims |= DimensionSet::IDENTIFIER,
             }
         }
         dims
@@ -484,6 +721,7 @@ impl<'a> Parser<'a> {
             Dimension::Time => dims_in_stash.contains(DimensionSet::TIME),
             Dimension::Numeral => dims_in_stash.contains(DimensionSet::NUMERAL),
             Dimension::RegexMatch => dims_in_stash.contains(DimensionSet::REGEX),
+            Dimension::Identifier => dims_in_stash.contains(DimensionSet::IDENTIFIER),
         })
     }
 
@@ -499,18 +737,25 @@ impl<'a> Parser<'a> {
     ///                │ predicate + regex passes
     ///                └── repeat until fixed point
     /// ```
-    fn saturate(&mut self, profiler: &mut RegexProfiler) -> SaturationMetrics {
+    ///
+    /// With [`Options::stop_at_full_span_time`], saturation instead ends with
+    /// the first pass that produces a time covering the whole input, right
+    /// after the rule that produced it.
+    fn saturate(&mut self, profiler: &mut RegexProfiler, context: &Context, options: &Options) -> SaturationMetrics {
         let mut metrics = SaturationMetrics::default();
         let saturation_start = Instant::now();
-        let debug = std::env::var_os("RUSTLING_DEBUG_RULES").is_some();
+        let input = self.input;
+        let stop =
+            |node: &Node| options.stop_at_full_span_time && Self::is_full_span_time(input, node, context, options);
 
         // Initial regex-first pass.
         let regex_start = Instant::now();
         let (discovered, rules_considered, rules_seeded, regex_first_pattern_hits) =
-            self.apply_rules_once(&self.regex_rules, profiler);
+            self.apply_rules_once(&self.regex_rules, profiler, &stop);
+        let stopped = discovered.iter().any(stop);
         let mut newly_added = Stash::empty();
         let mut produced = 0;
-        for node in discovered {
+        for node in alias_nodes(input, context, options).into_iter().chain(discovered) {
             let key = NodeKey::from_node(&node);
             if !self.seen.contains(&key) {
                 self.seen.insert(key);
@@ -518,20 +763,24 @@ impl<'a> Parser<'a> {
                 produced += 1;
             }
         }
-        let nodes: Vec<Node> = if debug { newly_added.get_nodes() } else { Vec::new() };
+        let nodes: Vec<Node> = if self.record_nodes { newly_added.get_nodes() } else { Vec::new() };
         metrics.initial_regex = PassMetrics {
             duration: regex_start.elapsed(),
             produced,
             nodes,
-            _rules_considered: rules_considered,
-            _rules_seeded: rules_seeded,
-            _regex_first_pattern_hits: regex_first_pattern_hits,
+            rules_considered,
+            rules_seeded,
+            regex_first_pattern_hits,
         };
         if newly_added.null() {
             metrics.total = saturation_start.elapsed();
             return metrics;
         }
         self.stash = self.stash.union(&newly_added);
+        if stopped {
+            metrics.total = saturation_start.elapsed();
+            return metrics;
+        }
 
         // Saturation: predicate-first rules then regex rules.
         let mut all_saturate_rules: Vec<&Rule> = Vec::new();
@@ -547,7 +796,8 @@ impl<'a> Parser<'a> {
                 all_saturate_rules.iter().filter(|rule| Self::deps_satisfied(rule, dims_in_stash)).copied().collect();
 
             let (discovered, rules_considered, rules_seeded, regex_first_pattern_hits) =
-                self.apply_rules_once(&saturate_rules, profiler);
+                self.apply_rules_once(&saturate_rules, profiler, &stop);
+            let stopped = discovered.iter().any(stop);
             let mut newly_added = Stash::empty();
             let mut produced = 0;
             for node in discovered {
@@ -559,33 +809,69 @@ impl<'a> Parser<'a> {
                 }
             }
             let duration = iteration_start.elapsed();
-            let nodes: Vec<Node> = if debug { newly_added.get_nodes() } else { Vec::new() };
+            let nodes: Vec<Node> = if self.record_nodes { newly_added.get_nodes() } else { Vec::new() };
             metrics.iterations.push(PassMetrics {
                 duration,
                 produced,
                 nodes,
-                _rules_considered: rules_considered,
-                _rules_seeded: rules_seeded,
-                _regex_first_pattern_hits: regex_first_pattern_hits,
+                rules_considered,
+                rules_seeded,
+                regex_first_pattern_hits,
             });
             if newly_added.null() {
                 break;
             }
             self.stash = self.stash.union(&newly_added);
+            if stopped {
+                break;
+            }
         }
 
         metrics.total = saturation_start.elapsed();
         metrics
     }
 
+    /// Whether `node` is a time that resolves and covers all of `input`
+    /// except surrounding whitespace and closing punctuation.
+    fn is_full_span_time(input: &str, node: &Node, context: &Context, options: &Options) -> bool {
+        let start = input.len() - input.trim_start().len();
+        let end = input.trim_end_matches(|c: char| c.is_whitespace() || matches!(c, '.' | ',' | '!' | '?' | ';')).len();
+        node.token.dim == Dimension::Time
+            && node.range.start <= start
+            && node.range.end >= end
+            && catch(node.rule_name, ErrorStage::Resolution, || resolve_node(context, options, node.clone()))
+                .is_ok_and(|resolved| resolved.is_some())
+    }
+
     /// Resolve nodes, then filter out spans that are fully contained within a
     /// larger match of the same dimension.
     ///
     /// Important: we filter *after* resolving so that unresolved catch-all
     /// nodes (like raw-input) can't suppress resolvable, more specific parses.
     fn resolve_filtered(&self, context: &Context, options: &Options) -> Vec<ResolvedToken> {
-        let mut resolved: Vec<ResolvedToken> =
-            self.stash.get_nodes().into_iter().filter_map(|node| resolve_node(context, options, node)).collect();
+        let latent_rules: HashSet<&str> = self
+            .compiled
+            .rules
+            .iter()
+            .filter(|rule| rule.groups.contains(RuleGroups::LATENT))
+            .map(|rule| rule.name)
+            .collect();
+        let mut resolved: Vec<ResolvedToken> = self
+            .stash
+            .get_nodes()
+            .into_iter()
+            .filter_map(|node| {
+                let rule = node.rule_name;
+                catch(rule, ErrorStage::Resolution, || resolve_node(context, options, node)).unwrap_or_else(|err| {
+                    self.record_error(err);
+                    None
+                })
+            })
+            .map(|mut rt| {
+                rt.latent |= latent_rules.contains(rt.node.rule_name);
+                rt
+            })
+            .collect();
 
         // Build priority lookup from rule names.
         let mut rule_priority: HashMap<&str, u16> = HashMap::new();
@@ -606,32 +892,27 @@ impl<'a> Parser<'a> {
                 .then(priority_b.cmp(&priority_a))
         });
 
-        let mut filtered: Vec<ResolvedToken> = Vec::new();
-        let mut last_kept_dim = None;
-        let mut last_kept_range: Option<Range> = None;
+        let resolved = disambiguate_year_vs_clock(self.input, resolved);
+        let resolved = claim_identifiers(self.input, resolved, &options.identifier_patterns);
+        let resolved = mark_identifiers(self.input, resolved, &options.identifier_markers);
 
-        for rt in resolved {
-            if last_kept_dim != Some(rt.node.token.dim) {
-                last_kept_dim = Some(rt.node.token.dim);
-                last_kept_range = None;
+        let resolved = match options.overlap_policy {
+            OverlapPolicy::KeepAll => resolved,
+            OverlapPolicy::KeepLongest => drop_subsumed(resolved),
+            OverlapPolicy::KeepHighestPriority => {
+                let priority = |rt: &ResolvedToken| rule_priority.get(rt.node.rule_name).copied().unwrap_or(0);
+                drop_outranked(resolved, priority)
             }
+        };
 
-            let is_subsumed = last_kept_range
-                .as_ref()
-                .map(|range| {
-                    range.start <= rt.node.range.start
-                        && range.end >= rt.node.range.end
-                        && (range.start != rt.node.range.start || range.end != rt.node.range.end)
-                })
-                .unwrap_or(false);
+        let resolved = merge_across_gaps(self.input, resolved, context, options);
+        let resolved = infer_meridiem(self.input, resolved);
+        let resolved = if options.latent_hypotheticals { mark_hypotheticals(self.input, resolved) } else { resolved };
 
-            if !is_subsumed {
-                last_kept_range = Some(rt.node.range.clone());
-                filtered.push(rt);
-            }
-        }
+        let resolved = if options.capitalized_names { drop_lowercase_names(self.input, resolved) } else { resolved };
+        let resolved = if options.suppress_covered_numerals { suppress_covered_numerals(resolved) } else { resolved };
 
-        filtered
+        enforce_coverage(self.input, resolved, options.coverage)
     }
 
     /// Run the parser (saturate the stash and resolve nodes into `ResolvedToken`s)
@@ -639,7 +920,7 @@ impl<'a> Parser<'a> {
     pub fn run_with_metrics(mut self, context: &Context, options: &Options) -> RunResult {
         let total_start = Instant::now();
         let mut regex_profiler = RegexProfiler::new(options.regex_profiling.enabled);
-        let saturation = self.saturate(&mut regex_profiler);
+        let saturation = self.saturate(&mut regex_profiler, context, options);
         let resolve_start = Instant::now();
         let all_tokens = self.resolve_filtered(context, options);
         // Classifier deactivated for now - return all tokens
@@ -648,8 +929,15 @@ impl<'a> Parser<'a> {
         let resolve = resolve_start.elapsed();
         let total = total_start.elapsed();
         let regex_profile = regex_profiler.finish(options.regex_profiling.max_rules);
+        let active_rules = self.regex_rules.len() + self.predicate_rules.len();
 
-        RunResult { all_tokens, tokens, metrics: RunMetrics { total, saturation, resolve, regex_profile } }
+        RunResult {
+            all_tokens,
+            tokens,
+            fired_rules: self.stash.rule_names(),
+            errors: self.errors.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner()),
+            metrics: RunMetrics { total, saturation, resolve, active_rules, regex_profile },
+        }
     }
 
     /// Run the parser (saturate the stash and resolve nodes into `ResolvedToken`s).
@@ -663,6 +951,95 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// Drop candidates strictly contained in a kept candidate of the same dimension.
+///
+/// Expects `resolved` sorted by `(dim, start, end desc)` so the last kept
+/// candidate is always the widest one seen so far.
+fn drop_subsumed(resolved: Vec<ResolvedToken>) -> Vec<ResolvedToken> {
+    let mut filtered: Vec<ResolvedToken> = Vec::new();
+    let mut last_kept_dim = None;
+    let mut last_kept_range: Option<Range> = None;
+
+    for rt in resolved {
+        if last_kept_dim != Some(rt.node.token.dim) {
+            last_kept_dim = Some(rt.node.token.dim);
+            last_kept_range = None;
+        }
+
+        let is_subsumed = last_kept_range
+            .as_ref()
+            .map(|range| {
+                range.start <= rt.node.range.start
+                    && range.end >= rt.node.range.end
+                    && (range.start != rt.node.range.start || range.end != rt.node.range.end)
+            })
+            .unwrap_or(false);
+
+        if !is_subsumed {
+            last_kept_range = Some(rt.node.range.clone());
+            filtered.push(rt);
+        }
+    }
+
+    filtered
+}
+
+/// Drop candidates that overlap a same-dimension candidate with a higher rule
+/// priority. Equal priorities fall back to [`drop_subsumed`] semantics, so a
+/// strictly wider span still wins.
+///
+/// Candidates are visited strongest first (priority, then span length), so
+/// each one only needs checking against the spans already kept, which sit
+/// sorted by start.
+fn drop_outranked(resolved: Vec<ResolvedToken>, priority: impl Fn(&ResolvedToken) -> u16) -> Vec<ResolvedToken> {
+    let priorities: Vec<u16> = resolved.iter().map(&priority).collect();
+    let mut order: Vec<usize> = (0..resolved.len()).collect();
+    order.sort_by_key(|&i| {
+        let range = &resolved[i].node.range;
+        (
+            resolved[i].node.token.dim as u8,
+            std::cmp::Reverse(priorities[i]),
+            std::cmp::Reverse(range.end - range.start),
+            range.start,
+        )
+    });
+
+    let mut keep = vec![false; resolved.len()];
+    let mut kept: Vec<(Range, u16)> = Vec::new();
+    let mut longest = 0;
+    let mut dim = None;
+    for i in order {
+        let (a, pa) = (&resolved[i].node.range, priorities[i]);
+        if dim != Some(resolved[i].node.token.dim) {
+            dim = Some(resolved[i].node.token.dim);
+            kept.clear();
+            longest = 0;
+        }
+
+        // Kept spans starting at or after `a.end` cannot overlap it, nor can
+        // any starting more than the longest kept span before `a.start`.
+        let upto = kept.partition_point(|(b, _)| b.start < a.end);
+        let outranked = kept[..upto].iter().rev().take_while(|(b, _)| b.start + longest > a.start).any(|(b, pb)| {
+            let strictly_contains = b.start <= a.start && b.end >= a.end && (b.start, b.end) != (a.start, a.end);
+            b.end > a.start && (*pb > pa || strictly_contains)
+        });
+        if !outranked {
+            kept.insert(upto, (a.clone(), pa));
+            longest = longest.max(a.end - a.start);
+            keep[i] = true;
+        }
+    }
+
+    resolved.into_iter().zip(keep).filter_map(|(rt, keep)| keep.then_some(rt)).collect()
+}
+
+/// Whether `position` splits a run of letters or digits ("march|3").
+fn joins_words(input: &str, position: usize) -> bool {
+    let before = slice(input, ..position).chars().next_back();
+    let after = slice(input, position..).chars().next();
+    matches!((before, after), (Some(b), Some(a)) if b.is_alphanumeric() && a.is_alphanumeric())
+}
+
 #[derive(Default)]
 struct RegexRuleStats {
     evaluations: u64,
@@ -698,6 +1075,19 @@ impl RegexProfiler {
         self.total_matches += matches;
     }
 
+    /// Fold in the stats of a profiler used on another thread.
+    #[cfg(feature = "parallel")]
+    fn merge(&mut self, other: RegexProfiler) {
+        for (rule_name, stats) in other.stats {
+            let entry = self.stats.entry(rule_name).or_default();
+            entry.evaluations += stats.evaluations;
+            entry.matches += stats.matches;
+            entry.total_time += stats.total_time;
+        }
+        self.total_time += other.total_time;
+        self.total_matches += other.total_matches;
+    }
+
     fn finish(self, max_rules: usize) -> Option<RegexProfileSummary> {
         if !self.enabled || self.stats.is_empty() {
             return None;
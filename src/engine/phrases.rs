@@ -0,0 +1,114 @@
+//! Trigger phrases derived from rule regexes.
+//!
+//! Rules may list `required_phrases`/`optional_phrases` by hand, but hand
+//! lists drift from the patterns they describe. Rules that list none get
+//! `derived_phrases` here from their leading regex when the rule set is
+//! built, so phrase gating in `Parser::new_compiled` covers them too. Derived
+//! phrases are matched as substrings of the lowercased input rather than
+//! against the scanner's fixed key-phrase list. Hand-written lists always win.
+//!
+//! Derivation is deliberately conservative: a phrase is only derived when no
+//! match of the regex can avoid containing it, i.e. the pattern starts with a
+//! literal ("tomorrow", "noon" in `(?i)\bnoon`) or a group of literal
+//! alternatives ("(morning|evening)"). A trailing optional letter is dropped
+//! ("tomorrows?" gives "tomorrow"). Anything else (optional groups, character
+//! classes, top-level alternation, literals under two letters) leaves the
+//! rule ungated. A rule starting with a `Pattern::Words` item is gated on its
+//! first word.
+
+use crate::{Pattern, Rule};
+
+/// Fill in `derived_phrases` for rules without hand-written phrases.
+///
+/// The phrase lists are leaked to get the `'static` lifetime rules carry, so
+/// call this once per rule set rather than per parse.
+pub(crate) fn derive_phrases(rules: &mut [Rule]) {
+    for rule in rules {
+        if !rule.required_phrases.is_empty() || !rule.optional_phrases.is_empty() {
+            continue;
+        }
+        let words = match rule.pattern.first() {
+            Some(Pattern::Regex(regex)) => leading_words(regex.as_str()),
+            Some(Pattern::Words(words)) => first_word(words).map(|word| vec![word]),
+            _ => None,
+        };
+        let Some(words) = words else { continue };
+        let words: Vec<&'static str> = words.into_iter().map(|w| &*Box::leak(w.into_boxed_str())).collect();
+        rule.derived_phrases = Box::leak(words.into_boxed_slice());
+    }
+}
+
+/// Literals one of which every match of `source` contains.
+pub(crate) fn leading_words(source: &str) -> Option<Vec<String>> {
+    if has_top_level_alternation(source) {
+        return None;
+    }
+    let rest = source.strip_prefix("(?i)").unwrap_or(source);
+    let rest = rest.strip_prefix(r"\b").unwrap_or(rest);
+
+    let words: Vec<&str> = if let Some(group) = rest.strip_prefix('(') {
+        let end = group.find(')')?;
+        let after = &group[end + 1..];
+        if after.starts_with(['?', '*']) || after.starts_with("{0") {
+            return None;
+        }
+        group[..end].strip_prefix("?:").unwrap_or(&group[..end]).split('|').collect()
+    } else {
+        let end = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        // A quantifier only applies to the letter before it.
+        let end = if rest[end..].starts_with(['?', '*', '{']) { end.saturating_sub(1) } else { end };
+        vec![&rest[..end]]
+    };
+
+    if !words.iter().all(|w| w.len() >= 2 && w.chars().all(|c| c.is_ascii_alphabetic())) {
+        return None;
+    }
+    Some(words.into_iter().map(str::to_ascii_lowercase).collect())
+}
+
+/// The first word of a `Pattern::Words` phrase, when it is a literal of two
+/// letters or more.
+fn first_word(words: &str) -> Option<String> {
+    let word = words.split_whitespace().next()?;
+    (word.len() >= 2 && word.chars().all(|c| c.is_ascii_alphabetic())).then(|| word.to_ascii_lowercase())
+}
+
+/// Whether `source` has a `|` outside any group, making every leading
+/// literal optional.
+fn has_top_level_alternation(source: &str) -> bool {
+    let mut depth = 0usize;
+    let mut chars = source.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_only_literals_every_match_contains() {
+        let words = |source: &str| leading_words(source).map(|w| w.join(","));
+
+        assert_eq!(words(r"(?i)\btomorrow\b").as_deref(), Some("tomorrow"));
+        assert_eq!(words(r"(?i)\b(morning|Evening)\s+").as_deref(), Some("morning,evening"));
+        assert_eq!(words(r"(?i)noon").as_deref(), Some("noon"));
+        assert_eq!(words(r"(?i)\btomorrows?").as_deref(), Some("tomorrow"));
+        assert_eq!(words(r"(?i)\btoni(ght|te)").as_deref(), Some("toni"));
+        // Optional groups, short literals, classes and alternation.
+        assert_eq!(words(r"(?i)\b(the\s+)?day"), None);
+        assert_eq!(words(r"(?i)\b(a|an)\s+"), None);
+        assert_eq!(words(r"(?i)\b[a-z]+day\b"), None);
+        assert_eq!(words(r"(?i)\btoday\b|\bnow\b"), None);
+    }
+}
@@ -24,8 +24,20 @@
 //! - The dimension dispatch calls small, dimension-specific functions/modules.
 //! - Dimension-specific tests live alongside the relevant rule sets.
 
-use crate::rules::time::normalize::{format_time_value, normalize};
-use crate::{Context, Dimension, Node, Options, ResolvedToken, Token, TokenKind};
+use chrono::{NaiveTime, Timelike};
+
+use super::lexer::tokenize;
+use super::span::slice;
+use crate::rules::time::helpers::timezone::{lookup_zone, stated_timezone};
+use crate::rules::time::helpers::{constraint_from_expr, intersect_time_exprs, value_grain};
+use crate::rules::time::normalize::normalize;
+use crate::rules::time::predicates::{is_day_expr, is_time_of_day_expr};
+use crate::time_expr::{Constraint, Grain, Holiday, PartOfDay, TimeExpr, TimeValue};
+use crate::{
+    Context, Coverage, Dimension, Identifier, IdentifierPattern, Node, Numeral, Options, PartialDate, Precision, Range,
+    Recurrence, RegexGroups, ResolvedToken, SymbolicTime, Time, Token, TokenKind, Value,
+};
+use std::sync::Arc;
 
 /// Rough equivalent of Haskell `resolveNode`.
 ///
@@ -39,13 +51,74 @@ use crate::{Context, Dimension, Node, Options, ResolvedToken, Token, TokenKind};
 pub(crate) fn resolve_node(context: &Context, options: &Options, node: Node) -> Option<ResolvedToken> {
     // In real Duckling, `resolve` is per-dimension.
     // Here we just hardcode something for the Time dimension.
-    let (value, latent) = resolve(context, options, &node.token)?;
+    let (resolved, latent) = resolve(context, options, &node.token)?;
+    let value = resolved.to_string();
+    let mut node = node;
+    let mut alternatives = Vec::new();
+    if let Some(secondary) = timezone_mismatch(context, options, &node.token, &resolved) {
+        node.evidence.push("hint:timezone-mismatch");
+        alternatives.push(secondary);
+    }
+    if zoned_endpoints_disagree(context, options, &node.token, &resolved) {
+        node.evidence.push("hint:timezone-mismatch");
+    }
 
     if std::env::var_os("RUSTLING_DEBUG_RULES").is_some() {
         eprintln!("[resolve] dim={:?} range={:?} value=\"{}\" latent={}", node.token.dim, node.range, value, latent);
     }
 
-    Some(ResolvedToken { node, value, latent })
+    Some(ResolvedToken { node, value, resolved, latent, alternatives })
+}
+
+/// The second reading of a time stated in two timezones ("9am ET / 7am PT"),
+/// when it doesn't name the same instant as the first.
+fn timezone_mismatch(context: &Context, options: &Options, token: &Token, resolved: &Value) -> Option<Value> {
+    let TokenKind::TimeExpr(TimeExpr::DualTimezone { secondary, .. }) = &token.kind else { return None };
+    let primary = resolved.as_time()?;
+    let secondary = resolve_time(context, options, secondary, token.precision)?;
+    (secondary.value != primary.value).then_some(Value::Time(secondary))
+}
+
+/// Whether a range with endpoints in different zones comes out empty ("9am
+/// ET to 6am PT") or backwards: its end only follows the start on the next
+/// day, more than half a day later ("11am ET to 7am PT").
+fn zoned_endpoints_disagree(context: &Context, options: &Options, token: &Token, resolved: &Value) -> bool {
+    let TokenKind::TimeExpr(TimeExpr::IntervalBetween { start, end }) = &token.kind else { return false };
+    let (TimeExpr::InTimezone { zone: start_zone, .. }, TimeExpr::InTimezone { zone: end_zone, .. }) =
+        (start.as_ref(), end.as_ref())
+    else {
+        return false;
+    };
+    let Some(TimeValue::Interval { start, end }) = resolved.as_time().map(|time| time.value.clone()) else {
+        return false;
+    };
+    let Some(zone) = lookup_zone(end_zone) else { return false };
+    let day_there = |dt| zone.local_to_zone(dt, context.local_timezone(options)).date();
+    let length = end - start;
+    // Ends are exclusive, a minute past the time stated.
+    start_zone != end_zone
+        && (length <= chrono::Duration::minutes(1)
+            || (day_there(end) > day_there(start) && length > chrono::Duration::hours(12)))
+}
+
+/// Month and day of a date stated without a year ("June 14", "14th of June").
+fn partial_date(expr: &TimeExpr) -> Option<PartialDate> {
+    fn on_reference(expr: &TimeExpr) -> Option<&Constraint> {
+        match expr {
+            TimeExpr::Intersect { expr, constraint } if matches!(**expr, TimeExpr::Reference) => Some(constraint),
+            _ => None,
+        }
+    }
+
+    match expr {
+        TimeExpr::MonthDay { month, day } => Some(PartialDate { month: *month, day: *day }),
+        TimeExpr::Intersect { expr, constraint } => match (on_reference(expr)?, constraint) {
+            (Constraint::Month(month), Constraint::DayOfMonth(day))
+            | (Constraint::DayOfMonth(day), Constraint::Month(month)) => Some(PartialDate { month: *month, day: *day }),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 /// Super-simple "resolve" that returns a dummy value.
@@ -53,40 +126,729 @@ pub(crate) fn resolve_node(context: &Context, options: &Options, node: Node) ->
 /// Later, you'll have per-dimension logic here.
 ///
 /// ```text
-/// Token ──┬─ Time       -> hard-coded YYYY-MM-DD string
-///         ├─ Numeral    -> stringified numeric value (no trailing .0)
-///         └─ RegexMatch -> None (not a semantic value)
+/// Token ──┬─ Time       -> Value::Time (normalized against the reference),
+///         │               or Value::PartialDate for a yearless date when enabled,
+///         │               or Value::Times for an enumerated list,
+///         │               or Value::Recurrence for a repeating time,
+///         │               or Value::Symbolic when resolution is disabled
+///         ├─ Numeral    -> Value::Numeral
+///         └─ RegexMatch,
+///            Identifier -> None (identifiers are claimed by `claim_identifiers`)
 /// ```
 ///
 /// When porting more Duckling dimensions, keep this function thin and move the
 /// rules for each dimension into its own module to keep compilation units small
 /// and testable.
-fn resolve(context: &Context, _options: &Options, token: &Token) -> Option<(String, bool)> {
+fn resolve(context: &Context, options: &Options, token: &Token) -> Option<(Value, bool)> {
     match token.dim {
         Dimension::Time => match &token.kind {
-            TokenKind::TimeExpr(expr) => {
-                let value = normalize(expr, context.reference_time)?;
-                Some((format_time_value(&value), false))
+            TokenKind::TimeExpr(expr) if !options.resolve || context.reference_time.is_none() => {
+                Some((Value::Symbolic(SymbolicTime { expr: expr.clone(), precision: token.precision }), false))
             }
+            TokenKind::TimeExpr(expr) => Some((resolve_time_expr(context, options, expr, token.precision)?, false)),
             _ => None,
         },
-        Dimension::RegexMatch => None,
-        Dimension::Numeral => {
-            // Extract numeral value from the token kind and return as string.
-            match &token.kind {
-                TokenKind::Numeral(data) => {
-                    let v = data.value;
-                    let s = if v.fract() == 0.0 {
-                        // whole number: print without decimal point
-                        format!("{}", v as i64)
-                    } else {
-                        // fractional: keep as float
-                        format!("{}", v)
-                    };
-                    Some((s, false))
-                }
-                _ => None,
+        Dimension::RegexMatch | Dimension::Identifier => None,
+        Dimension::Numeral => match &token.kind {
+            TokenKind::Numeral(data) => {
+                Some((Value::Numeral(Numeral { value: data.value, precision: token.precision }), false))
+            }
+            _ => None,
+        },
+    }
+}
+
+/// Resolve a time expression to its value, also used to re-resolve a stored
+/// [`SymbolicTime`].
+pub(crate) fn resolve_time_expr(
+    context: &Context,
+    options: &Options,
+    expr: &TimeExpr,
+    precision: Precision,
+) -> Option<Value> {
+    if let Some(date) = options.partial_dates.then(|| partial_date(expr)).flatten() {
+        return Some(Value::PartialDate(date));
+    }
+    match expr {
+        TimeExpr::List(items) => {
+            let mut times =
+                items.iter().map(|expr| resolve_time(context, options, expr, precision)).collect::<Option<Vec<_>>>()?;
+            // In date order, not as stated: "the 1st and 15th" on Feb 12 is
+            // Feb 15, then Mar 1.
+            times.sort_by_key(|time| match time.value {
+                TimeValue::Instant(start)
+                | TimeValue::Interval { start, .. }
+                | TimeValue::OpenAfter(start)
+                | TimeValue::OpenBefore(start) => start,
+            });
+            Some(Value::Times(times))
+        }
+        TimeExpr::Recurrence { every, occurrence } => {
+            let next = resolve_time(context, options, expr, precision)?;
+            // A quarter has no RRULE frequency of its own: every 3 months.
+            let (frequency, interval) = match every {
+                Grain::Quarter => (Grain::Month, 3),
+                grain => (*grain, 1),
+            };
+            let (by_day, by_set_pos) = match occurrence.as_ref() {
+                TimeExpr::NthWeekdayOf { n, weekday, .. } => (Some(*weekday), Some(*n)),
+                _ => (None, None),
+            };
+            let by_month_day = match occurrence.as_ref() {
+                TimeExpr::List(items) => items
+                    .iter()
+                    .filter_map(|item| match item {
+                        TimeExpr::Intersect { constraint: Constraint::DayOfMonth(day), .. } => Some(*day),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            Some(Value::Recurrence(Recurrence { next, frequency, interval, by_day, by_set_pos, by_month_day }))
+        }
+        expr => Some(Value::Time(resolve_time(context, options, expr, precision)?)),
+    }
+}
+
+fn resolve_time(context: &Context, options: &Options, expr: &TimeExpr, precision: Precision) -> Option<Time> {
+    let value = normalize(expr, context.reference_time?, context, options)?;
+    let timezone = stated_timezone(expr, &value, context.local_timezone(options));
+    let time = Time {
+        value,
+        precision,
+        grain: value_grain(expr),
+        timezone,
+        holiday: holiday_of(expr),
+        part_of_day: part_of_day_of(expr),
+    };
+    Some(if options.round_to_grain { time.widen() } else { time })
+}
+
+/// The holiday `expr` names, looking only through wrappers that keep its
+/// day: "Christmas at 6pm" is Christmas, "3 days before Christmas" is not.
+fn holiday_of(expr: &TimeExpr) -> Option<Holiday> {
+    match expr {
+        TimeExpr::Holiday { holiday, .. } => Some(*holiday),
+        TimeExpr::Intersect { expr, .. }
+        | TimeExpr::IntervalOf { expr, .. }
+        | TimeExpr::InTimezone { expr, .. }
+        | TimeExpr::DualTimezone { primary: expr, .. } => holiday_of(expr),
+        _ => None,
+    }
+}
+
+/// The part of the day `expr` narrows to ("tomorrow evening", "monday
+/// afternoon at 3"), through the same wrappers as [`holiday_of`].
+fn part_of_day_of(expr: &TimeExpr) -> Option<PartOfDay> {
+    match expr {
+        TimeExpr::PartOfDay(part) | TimeExpr::Intersect { constraint: Constraint::PartOfDay(part), .. } => Some(*part),
+        TimeExpr::Intersect { expr, .. }
+        | TimeExpr::IntervalOf { expr, .. }
+        | TimeExpr::InTimezone { expr, .. }
+        | TimeExpr::DualTimezone { primary: expr, .. } => part_of_day_of(expr),
+        _ => None,
+    }
+}
+
+/// Drop numerals whose span lies entirely within a resolved entity of another
+/// semantic dimension (e.g. the `5` inside "at 5pm").
+///
+/// Enabled by [`Options::suppress_covered_numerals`]. Partial overlaps and
+/// adjacent spans are kept; only full coverage counts.
+pub(crate) fn suppress_covered_numerals(tokens: Vec<ResolvedToken>) -> Vec<ResolvedToken> {
+    let covers: Vec<(usize, usize)> = tokens
+        .iter()
+        .filter(|rt| !matches!(rt.node.token.dim, Dimension::Numeral | Dimension::RegexMatch))
+        .map(|rt| (rt.node.range.start, rt.node.range.end))
+        .collect();
+
+    tokens
+        .into_iter()
+        .filter(|rt| {
+            rt.node.token.dim != Dimension::Numeral
+                || !covers.iter().any(|&(start, end)| start <= rt.node.range.start && rt.node.range.end <= end)
+        })
+        .collect()
+}
+
+/// Month and weekday names that are also common English words.
+const AMBIGUOUS_NAMES: &[&str] = &["may", "march", "mar", "sat", "sun", "wed", "mon"];
+
+/// Words that add no date context around a name ("in may", "on sat").
+const NAME_FILLERS: &[&str] = &["in", "on", "at", "of", "the", "during", "by", "until", "till", "from", "to"];
+
+/// Drop times whose only date word is a lowercase ambiguous name ("may",
+/// "march", "sat") with no date context around it: "in may" goes, while
+/// "May", "may 5", "next sat" and "sat at 5" stay.
+///
+/// Enabled by [`Options::capitalized_names`].
+pub(crate) fn drop_lowercase_names(input: &str, tokens: Vec<ResolvedToken>) -> Vec<ResolvedToken> {
+    tokens
+        .into_iter()
+        .filter(|rt| {
+            if rt.node.token.dim != Dimension::Time {
+                return true;
+            }
+            let body = slice(input, rt.node.range.start..rt.node.range.end);
+            let lexemes = tokenize(body);
+            let lowercase_name = lexemes.iter().any(|lexeme| {
+                AMBIGUOUS_NAMES.contains(&lexeme.lower.as_str()) && slice(body, lexeme.range.clone()) == lexeme.lower
+            });
+            let context = lexemes.iter().any(|lexeme| {
+                !AMBIGUOUS_NAMES.contains(&lexeme.lower.as_str()) && !NAME_FILLERS.contains(&lexeme.lower.as_str())
+            });
+            !lowercase_name || context
+        })
+        .collect()
+}
+
+/// `tokens` if they cover as much of `input` as `coverage` asks, otherwise
+/// nothing. Coverage is the share of the letters and digits of `input` that
+/// lie inside some entity.
+///
+/// Enabled by [`Options::coverage`].
+pub(crate) fn enforce_coverage(input: &str, tokens: Vec<ResolvedToken>, coverage: Coverage) -> Vec<ResolvedToken> {
+    let min_ratio = match coverage {
+        Coverage::Any => return tokens,
+        Coverage::FullSpan => 1.0,
+        Coverage::MinRatio(ratio) => ratio,
+    };
+    let spans: Vec<&Range> =
+        tokens.iter().filter(|rt| rt.node.token.dim != Dimension::RegexMatch).map(|rt| &rt.node.range).collect();
+    let (mut content, mut covered) = (0usize, 0usize);
+    for (idx, _) in input.char_indices().filter(|(_, c)| c.is_alphanumeric()) {
+        content += 1;
+        covered += usize::from(spans.iter().any(|span| span.start <= idx && idx < span.end));
+    }
+    if content == 0 || covered as f32 >= min_ratio * content as f32 { tokens } else { Vec::new() }
+}
+
+/// Claim the spans matched by `patterns` as [`Value::Identifier`]s ("order
+/// #5678", "ABC-123", "1Z999AA10123456784"), dropping the times and
+/// numerals read from any part of them. The first pattern to match a span
+/// wins, and matches overlapping an earlier one are skipped. Matches glued
+/// to a longer code ("W23-2" in "2025-W23-2") and ones that read as a UTC
+/// offset ("GMT-5") are left to the time rules.
+pub(crate) fn claim_identifiers(
+    input: &str,
+    mut tokens: Vec<ResolvedToken>,
+    patterns: &[IdentifierPattern],
+) -> Vec<ResolvedToken> {
+    let mut claimed: Vec<ResolvedToken> = Vec::new();
+    for pattern in patterns {
+        for found in pattern.regex.find_iter(input) {
+            let overlaps = |rt: &ResolvedToken| rt.node.range.start < found.end() && found.start() < rt.node.range.end;
+            let glued = |c: char| c.is_alphanumeric() || c == '-';
+            let zone = found.as_str().split(['-', '+']).next().is_some_and(|prefix| lookup_zone(prefix).is_some());
+            if found.is_empty()
+                || zone
+                || slice(input, ..found.start()).ends_with(glued)
+                || slice(input, found.end()..).starts_with(glued)
+                || claimed.iter().any(overlaps)
+            {
+                continue;
             }
+            let code = found.as_str().to_string();
+            claimed.push(ResolvedToken {
+                node: Node {
+                    range: Range { start: found.start(), end: found.end() },
+                    token: Token {
+                        dim: Dimension::Identifier,
+                        kind: TokenKind::RegexMatch(RegexGroups::from_text(&code)),
+                        precision: Precision::Exact,
+                    },
+                    rule_name: "<identifier>",
+                    evidence: Vec::new(),
+                    children: Arc::from([]),
+                },
+                value: code.clone(),
+                resolved: Value::Identifier(Identifier { kind: pattern.kind.clone(), code }),
+                latent: false,
+                alternatives: Vec::new(),
+            });
         }
     }
+    if claimed.is_empty() {
+        return tokens;
+    }
+
+    tokens.retain(|rt| {
+        !matches!(rt.node.token.dim, Dimension::Time | Dimension::Numeral)
+            || !claimed
+                .iter()
+                .any(|id| rt.node.range.start < id.node.range.end && id.node.range.start < rt.node.range.end)
+    });
+    claimed.sort_by_key(|rt| rt.node.range.start);
+    tokens.extend(claimed);
+    tokens
+}
+
+/// Words allowed between an identifier marker and its number ("order no.
+/// 5678", "invoice number 12").
+const IDENTIFIER_CONNECTORS: &[&str] = &["no", "nr", "num", "number", "."];
+
+/// Treat numbers right after an identifier marker ("room 237", "flight 1234",
+/// "order #5678") as identifiers: numerals become latent, with `identifier`
+/// recorded in their evidence, and times read from
+/// the number are dropped so the rest of the sentence ("room 12 tomorrow")
+/// can be found on its own.
+///
+/// Markers come from [`Options::identifier_markers`] and are compared with
+/// the lowercased word (or symbol) before the number, optionally followed by
+/// one of [`IDENTIFIER_CONNECTORS`].
+pub(crate) fn mark_identifiers(input: &str, tokens: Vec<ResolvedToken>, markers: &[String]) -> Vec<ResolvedToken> {
+    if markers.is_empty() {
+        return tokens;
+    }
+    let follows_marker = |start: usize| {
+        if !slice(input, start..).starts_with(|c: char| c.is_ascii_digit()) {
+            return false;
+        }
+        for lexeme in tokenize(slice(input, ..start)).iter().rev().take(3) {
+            if markers.iter().any(|marker| marker.to_lowercase() == lexeme.lower) {
+                return true;
+            }
+            if !IDENTIFIER_CONNECTORS.contains(&lexeme.lower.as_str()) {
+                break;
+            }
+        }
+        false
+    };
+
+    tokens
+        .into_iter()
+        .filter_map(|mut rt| {
+            if !matches!(rt.node.token.dim, Dimension::Time | Dimension::Numeral) {
+                return Some(rt);
+            }
+            if !follows_marker(rt.node.range.start) {
+                return Some(rt);
+            }
+            if rt.node.token.dim == Dimension::Time {
+                return None;
+            }
+            rt.latent = true;
+            rt.node.evidence.push("identifier");
+            Some(rt)
+        })
+        .collect()
+}
+
+/// Cues that make the rest of their sentence conditional, with the evidence
+/// recorded on times they downgrade. Phrases are matched word by word.
+const CONDITIONAL_CUES: &[(&str, &str)] = &[
+    ("if", "hypothetical:if"),
+    ("unless", "hypothetical:unless"),
+    ("suppose", "hypothetical:suppose"),
+    ("supposing", "hypothetical:supposing"),
+    ("assuming", "hypothetical:assuming"),
+    ("imagine", "hypothetical:imagine"),
+    ("hypothetically", "hypothetical:hypothetically"),
+    ("in case", "hypothetical:in case"),
+];
+
+/// Counterfactual mood cues, which make their whole sentence hypothetical
+/// wherever they appear ("Monday would have worked").
+const MOOD_CUES: &[(&str, &str)] = &[
+    ("would have", "hypothetical:would have"),
+    ("could have", "hypothetical:could have"),
+    ("might have", "hypothetical:might have"),
+    ("should have", "hypothetical:should have"),
+    ("would've", "hypothetical:would've"),
+    ("could've", "hypothetical:could've"),
+    ("might've", "hypothetical:might've"),
+    ("should've", "hypothetical:should've"),
+    ("wish", "hypothetical:wish"),
+];
+
+/// Mark times in conditional or hypothetical sentences as latent ("if we met
+/// yesterday", "had it been Monday", "Tuesday would have worked"), recording
+/// the cue in their evidence.
+///
+/// Enabled by [`Options::latent_hypotheticals`]. A time is downgraded when
+/// its sentence has a [`CONDITIONAL_CUES`] entry before it, starts with an
+/// inverted conditional ("had", "were", "should"), or has a [`MOOD_CUES`]
+/// entry anywhere.
+pub(crate) fn mark_hypotheticals(input: &str, tokens: Vec<ResolvedToken>) -> Vec<ResolvedToken> {
+    let words = |text: &str| -> Vec<String> {
+        text.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '’'))
+            .filter(|w| !w.is_empty())
+            .map(|w| w.to_lowercase().replace('’', "'"))
+            .collect()
+    };
+    let contains = |words: &[String], phrase: &str| {
+        let phrase: Vec<&str> = phrase.split(' ').collect();
+        words.windows(phrase.len()).any(|window| window.iter().zip(&phrase).all(|(w, p)| w == p))
+    };
+    let is_boundary = |c: char| matches!(c, '.' | '!' | '?' | ';' | '\n');
+
+    tokens
+        .into_iter()
+        .map(|mut rt| {
+            if rt.node.token.dim != Dimension::Time {
+                return rt;
+            }
+            let (start, end) = (rt.node.range.start, rt.node.range.end);
+            let sentence_start = slice(input, ..start).rfind(is_boundary).map_or(0, |i| i + 1);
+            let sentence_end = slice(input, end..).find(is_boundary).map_or(input.len(), |i| end + i);
+            let before = words(slice(input, sentence_start..start));
+            let sentence = words(slice(input, sentence_start..sentence_end));
+
+            let cue = CONDITIONAL_CUES
+                .iter()
+                .find(|(phrase, _)| contains(&before, phrase))
+                .or_else(|| MOOD_CUES.iter().find(|(phrase, _)| contains(&sentence, phrase)))
+                .map(|&(_, evidence)| evidence)
+                .or_else(|| {
+                    matches!(before.first().map(String::as_str), Some("had" | "were" | "should"))
+                        .then_some("hypothetical:inversion")
+                });
+            if let Some(cue) = cue {
+                rt.latent = true;
+                rt.node.evidence.push(cue);
+            }
+            rt
+        })
+        .collect()
+}
+
+/// Words that settle whether a clock time is AM or PM on their own.
+const MERIDIEM_WORDS: &[&str] = &[
+    "am",
+    "pm",
+    "morning",
+    "afternoon",
+    "evening",
+    "night",
+    "tonight",
+    "noon",
+    "midnight",
+    "lunch",
+    "hours",
+    "hrs",
+    "h",
+];
+
+/// Read bare clock times ("at 1", "11") as AM or PM from the time before them
+/// in the same sentence, recording `meridiem:inferred` in their evidence.
+///
+/// "the meeting is 9-10, call at 1" puts the call at 13:00 and "dinner at
+/// 10pm, drinks at 11" the drinks at 23:00: the bare time takes the first of
+/// its two readings on the earlier time's day that does not precede it. Times
+/// whose text names AM/PM or a part of the day are left alone, as are bare
+/// times with no earlier time of day in their sentence or no reading after
+/// it. The reading it replaces is kept as an alternative.
+pub(crate) fn infer_meridiem(input: &str, mut tokens: Vec<ResolvedToken>) -> Vec<ResolvedToken> {
+    let is_boundary = |c: char| matches!(c, '.' | '!' | '?' | ';' | '\n');
+    let clock_start = |rt: &ResolvedToken| match rt.resolved.as_time()? {
+        time if !matches!(time.grain, Grain::Hour | Grain::Minute | Grain::Second) => None,
+        Time { value: TimeValue::Instant(start) | TimeValue::Interval { start, .. }, .. } => Some(*start),
+        _ => None,
+    };
+
+    for idx in 0..tokens.len() {
+        let rt = &tokens[idx];
+        if rt.node.token.dim != Dimension::Time
+            || !is_bare_clock_text(slice(input, rt.node.range.start..rt.node.range.end))
+        {
+            continue;
+        }
+        let TokenKind::TimeExpr(expr) = &rt.node.token.kind else { continue };
+        let (Some((hour, minute)), Some(current)) = (bare_clock(expr), clock_start(rt)) else { continue };
+        let sentence_start = slice(input, ..rt.node.range.start).rfind(is_boundary).map_or(0, |i| i + 1);
+
+        // The time of day ending closest before this one.
+        let Some(anchor) = tokens
+            .iter()
+            .filter(|other| {
+                other.node.token.dim == Dimension::Time
+                    && other.node.range.start >= sentence_start
+                    && other.node.range.end <= rt.node.range.start
+            })
+            .filter_map(|other| Some((other.node.range.clone(), clock_start(other)?)))
+            .max_by(|(a, _), (b, _)| a.end.cmp(&b.end).then(b.start.cmp(&a.start)))
+            .map(|(_, start)| start)
+        else {
+            continue;
+        };
+
+        let readings =
+            [hour, hour + 12].map(|h| NaiveTime::from_hms_opt(h, minute, 0).map(|t| anchor.date().and_time(t)));
+        let Some(inferred) = readings.into_iter().flatten().find(|reading| *reading >= anchor) else { continue };
+        if inferred == current {
+            continue;
+        }
+
+        let rt = &mut tokens[idx];
+        let Value::Time(time) = &rt.resolved else { continue };
+        let time = Time { value: time.value.clone().map(|dt| dt + (inferred - current)), ..time.clone() };
+        let previous = std::mem::replace(&mut rt.resolved, Value::Time(time));
+        rt.value = rt.resolved.to_string();
+        rt.alternatives.push(previous);
+        rt.node.evidence.push("meridiem:inferred");
+    }
+    tokens
+}
+
+/// Hour (0-11) and minute of a clock time stated without AM/PM and anchored
+/// at the reference: "at 1", "11:30", "1ish".
+fn bare_clock(expr: &TimeExpr) -> Option<(u32, u32)> {
+    match expr {
+        TimeExpr::AmbiguousTime { hour, minute } => Some((hour % 12, *minute)),
+        TimeExpr::Shift { expr, amount: 0, grain: Grain::Hour } => bare_clock(expr),
+        TimeExpr::Intersect { expr, constraint: Constraint::TimeOfDay(time) }
+            if matches!(**expr, TimeExpr::Reference) && (1..=12).contains(&time.hour()) =>
+        {
+            Some((time.hour() % 12, time.minute()))
+        }
+        _ => None,
+    }
+}
+
+/// Whether `text` states a clock time without settling AM or PM: no
+/// [`MERIDIEM_WORDS`] ("3pm", "at 9 in the morning") and no zero-padded
+/// 24-hour form ("09:30").
+fn is_bare_clock_text(text: &str) -> bool {
+    let text = text.to_lowercase().replace('.', "");
+    !text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).any(|word| {
+        let suffix = word.trim_start_matches(|c: char| c.is_ascii_digit());
+        MERIDIEM_WORDS.contains(&suffix) || (word.starts_with('0') && word.len() == 2)
+    })
+}
+
+/// Whether `word` makes a bare four-digit number read as a clock time
+/// (`1`: "at 2017", "1930 h", "tonight 2130") or as a year (`-1`: "in
+/// 2017", "since 1930", "March 2017").
+fn clock_cue(word: &str) -> i32 {
+    match word {
+        "at" | "@" | "around" | "about" | "approximately" | "roughly" | "hours" | "hrs" | "h" | "today" | "tonight"
+        | "tomorrow" | "yesterday" | "morning" | "afternoon" | "evening" | "night" | "o'clock" => 1,
+        "in" | "since" | "of" | "year" | "during" | "born" | "january" | "february" | "march" | "april" | "may"
+        | "june" | "july" | "august" | "september" | "october" | "november" | "december" | "monday" | "tuesday"
+        | "wednesday" | "thursday" | "friday" | "saturday" | "sunday" => -1,
+        _ => 0,
+    }
+}
+
+/// Positive when the words around `range` suggest a clock time, negative for a
+/// year. The adjacent words count double; other words anywhere in the input
+/// count once.
+fn clock_vs_year_score(input: &str, range: &Range) -> i32 {
+    let words = |text: &str| -> Vec<String> {
+        text.split(|c: char| !(c.is_alphanumeric() || c == '@' || c == '\''))
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let before = words(slice(input, ..range.start));
+    let after = words(slice(input, range.end..));
+    let adjacent = before.last().map_or(0, |w| clock_cue(w)) + after.first().map_or(0, |w| clock_cue(w));
+    adjacent + before.iter().chain(after.iter()).map(|w| clock_cue(w)).sum::<i32>()
+}
+
+/// Settle four-digit numbers read both as a year and as a latent clock time
+/// ("2017": the year, or 20:17) from the words around them. The year wins
+/// unless clock cues outweigh year cues; the losing reading is kept as an
+/// alternative on the winner rather than as a separate result.
+pub(crate) fn disambiguate_year_vs_clock(input: &str, tokens: Vec<ResolvedToken>) -> Vec<ResolvedToken> {
+    const YEAR_RULE: &str = "yyyy (year-only)";
+    const CLOCK_RULE: &str = "hhmm (latent)";
+
+    let mut losers = vec![false; tokens.len()];
+    let mut alternatives: Vec<Vec<Value>> = vec![Vec::new(); tokens.len()];
+    for (year, year_token) in tokens.iter().enumerate().filter(|(_, rt)| rt.node.rule_name == YEAR_RULE) {
+        let clock = tokens.iter().position(|rt| {
+            rt.node.rule_name == CLOCK_RULE
+                && (rt.node.range.start, rt.node.range.end) == (year_token.node.range.start, year_token.node.range.end)
+        });
+        let Some(clock) = clock else { continue };
+
+        let (winner, loser) =
+            if clock_vs_year_score(input, &year_token.node.range) > 0 { (clock, year) } else { (year, clock) };
+        losers[loser] = true;
+        alternatives[winner].push(tokens[loser].resolved.clone());
+    }
+
+    tokens
+        .into_iter()
+        .zip(losers.into_iter().zip(alternatives))
+        .filter_map(|(mut rt, (lost, alternatives))| {
+            rt.alternatives.extend(alternatives);
+            (!lost).then_some(rt)
+        })
+        .collect()
+}
+
+/// Rule name reported for entities joined by [`merge_across_gaps`].
+const MERGE_RULE: &str = "<day> … <time-of-day> (merged)";
+
+/// Join a day and a time of day separated only by a few unrecognized words
+/// ("tomorrow maybe at 5"), up to `Options::merge_gap` words. The merged
+/// entity carries both parts as children and `merge:gap` in its evidence;
+/// the parts themselves are dropped.
+///
+/// Expects `tokens` sorted by `(dim, start, end desc)`, and keeps that order.
+pub(crate) fn merge_across_gaps(
+    input: &str,
+    tokens: Vec<ResolvedToken>,
+    context: &Context,
+    options: &Options,
+) -> Vec<ResolvedToken> {
+    if options.merge_gap == 0 {
+        return tokens;
+    }
+
+    let times: Vec<&ResolvedToken> = tokens.iter().filter(|rt| rt.node.token.dim == Dimension::Time).collect();
+    let mut merged: Vec<ResolvedToken> = Vec::new();
+    let mut consumed_until = 0;
+    for first in &times {
+        if first.node.range.start < consumed_until {
+            continue;
+        }
+        // The widest time starting closest after `first`.
+        let Some(second) = times
+            .iter()
+            .filter(|rt| rt.node.range.start >= first.node.range.end)
+            .min_by(|a, b| a.node.range.start.cmp(&b.node.range.start).then(b.node.range.end.cmp(&a.node.range.end)))
+        else {
+            continue;
+        };
+        let gap = slice(input, first.node.range.end..second.node.range.start);
+        let words = gap.split_whitespace().count();
+        if words > options.merge_gap || !gap.split_whitespace().all(|w| w.chars().all(char::is_alphabetic)) {
+            continue;
+        }
+        if let Some(rt) = merge_pair(context, options, first, second) {
+            consumed_until = rt.node.range.end;
+            merged.push(rt);
+        }
+    }
+    if merged.is_empty() {
+        return tokens;
+    }
+
+    let mut tokens: Vec<ResolvedToken> = tokens
+        .into_iter()
+        .filter(|rt| {
+            rt.node.token.dim != Dimension::Time
+                || !merged
+                    .iter()
+                    .any(|m| m.node.range.start <= rt.node.range.start && rt.node.range.end <= m.node.range.end)
+        })
+        .collect();
+    tokens.extend(merged);
+    tokens.sort_by(|a, b| {
+        (a.node.token.dim as u8)
+            .cmp(&(b.node.token.dim as u8))
+            .then(a.node.range.start.cmp(&b.node.range.start))
+            .then(b.node.range.end.cmp(&a.node.range.end))
+    });
+    tokens
+}
+
+/// Intersect a day with a following (or preceding) time of day.
+fn merge_pair(
+    context: &Context,
+    options: &Options,
+    first: &ResolvedToken,
+    second: &ResolvedToken,
+) -> Option<ResolvedToken> {
+    let (day, time) = if is_day_expr(&first.node.token) && is_time_of_day_expr(&second.node.token) {
+        (first, second)
+    } else if is_time_of_day_expr(&first.node.token) && is_day_expr(&second.node.token) {
+        (second, first)
+    } else {
+        return None;
+    };
+    let TokenKind::TimeExpr(day_expr) = &day.node.token.kind else { return None };
+    let TokenKind::TimeExpr(time_expr) = &time.node.token.kind else { return None };
+    constraint_from_expr(time_expr)?;
+    let expr = intersect_time_exprs(day_expr, time_expr)?;
+
+    let mut evidence = vec![first.node.rule_name, second.node.rule_name, "merge:gap"];
+    evidence.extend_from_slice(&first.node.evidence);
+    evidence.extend_from_slice(&second.node.evidence);
+    let precision = if first.node.token.precision == Precision::Exact {
+        second.node.token.precision
+    } else {
+        first.node.token.precision
+    };
+    let node = Node {
+        range: Range { start: first.node.range.start, end: second.node.range.end },
+        token: Token { dim: Dimension::Time, kind: TokenKind::TimeExpr(expr), precision },
+        rule_name: MERGE_RULE,
+        evidence,
+        children: std::sync::Arc::from([first.node.clone(), second.node.clone()]),
+    };
+    resolve_node(context, options, node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time_expr::{TimeExpr, TimeValue};
+    use crate::{NumeralData, Range};
+    use std::sync::Arc;
+
+    fn resolved(dim: Dimension, start: usize, end: usize) -> ResolvedToken {
+        let precision = Precision::Exact;
+        let (kind, resolved) = match dim {
+            Dimension::Numeral => (
+                TokenKind::Numeral(NumeralData { value: 5.0, grain: None, multipliable: false }),
+                Value::Numeral(Numeral { value: 5.0, precision }),
+            ),
+            _ => (
+                TokenKind::TimeExpr(TimeExpr::Reference),
+                Value::Time(Time {
+                    value: TimeValue::Instant(Default::default()),
+                    precision,
+                    grain: Grain::Second,
+                    timezone: None,
+                    holiday: None,
+                    part_of_day: None,
+                }),
+            ),
+        };
+        let node = Node {
+            range: Range { start, end },
+            token: Token { dim, kind, precision },
+            rule_name: "test",
+            evidence: Vec::new(),
+            children: Arc::from([]),
+        };
+        ResolvedToken { node, value: resolved.to_string(), resolved, latent: false, alternatives: Vec::new() }
+    }
+
+    fn spans(tokens: &[ResolvedToken]) -> Vec<(Dimension, usize, usize)> {
+        tokens.iter().map(|rt| (rt.node.token.dim, rt.node.range.start, rt.node.range.end)).collect()
+    }
+
+    #[test]
+    fn covered_numeral_is_dropped() {
+        let out = suppress_covered_numerals(vec![resolved(Dimension::Time, 0, 6), resolved(Dimension::Numeral, 3, 4)]);
+        assert_eq!(spans(&out), vec![(Dimension::Time, 0, 6)]);
+    }
+
+    #[test]
+    fn numeral_with_identical_span_is_dropped() {
+        let out = suppress_covered_numerals(vec![resolved(Dimension::Numeral, 0, 4), resolved(Dimension::Time, 0, 4)]);
+        assert_eq!(spans(&out), vec![(Dimension::Time, 0, 4)]);
+    }
+
+    #[test]
+    fn partially_overlapping_and_adjacent_numerals_are_kept() {
+        let out = suppress_covered_numerals(vec![
+            resolved(Dimension::Time, 2, 6),
+            resolved(Dimension::Numeral, 0, 3),
+            resolved(Dimension::Numeral, 6, 8),
+        ]);
+        assert_eq!(spans(&out).len(), 3);
+    }
+
+    #[test]
+    fn numerals_do_not_cover_each_other() {
+        let out =
+            suppress_covered_numerals(vec![resolved(Dimension::Numeral, 0, 8), resolved(Dimension::Numeral, 0, 3)]);
+        assert_eq!(spans(&out).len(), 2);
+    }
 }
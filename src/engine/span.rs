@@ -0,0 +1,71 @@
+//! Byte spans over the input.
+//!
+//! Node ranges are byte offsets into the original input. Most come straight
+//! from regex matches and land on character boundaries, but ranges that are
+//! computed (clamped ends, spans mapped back from the typo-corrected text,
+//! gaps skipped between pattern items) can fall inside a multi-byte
+//! character, and indexing a `str` there panics. Every place where a span
+//! meets the input goes through [`slice`] instead, which widens the span to
+//! the characters it touches and clamps it to the input. The text is returned
+//! as written, case included.
+
+use std::ops::{Bound, Range, RangeBounds};
+
+/// `range` clamped to `text` and widened to the nearest character
+/// boundaries (the start moves back, the end moves forward).
+pub(crate) fn snap(text: &str, range: impl RangeBounds<usize>) -> Range<usize> {
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => text.len(),
+    };
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let mut end = end.min(text.len());
+    while !text.is_char_boundary(end) {
+        end += 1;
+    }
+    let mut start = start.min(end);
+    while !text.is_char_boundary(start) {
+        start -= 1;
+    }
+    start..end
+}
+
+/// The text of `text` covered by `range`, see [`snap`]. Never panics.
+pub(crate) fn slice(text: &str, range: impl RangeBounds<usize>) -> &str {
+    &text[snap(text, range)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices_widen_to_character_boundaries() {
+        let text = "Café 🎉 at 5";
+        assert_eq!(slice(text, 0..3), "Caf");
+        assert_eq!(slice(text, 0..4), "Café");
+        assert_eq!(slice(text, 4..5), "é");
+        assert_eq!(slice(text, 7..8), "🎉");
+        assert_eq!(slice(text, 10..), " at 5");
+        assert_eq!(slice(text, ..100), text);
+        assert_eq!(slice(text, 50..60), "");
+
+        // Combining marks are separate characters, so they can be split off.
+        let combining = "cafe\u{301} at 5";
+        assert_eq!(slice(combining, 0..5), "cafe\u{301}");
+        assert_eq!(slice(combining, 0..4), "cafe");
+
+        for end in 0..=text.len() + 2 {
+            for start in 0..=end {
+                let range = snap(text, start..end);
+                assert!(text.is_char_boundary(range.start) && text.is_char_boundary(range.end));
+                assert!(range.start <= start && range.end >= end.min(text.len()));
+            }
+        }
+    }
+}
@@ -26,8 +26,183 @@
 //!   to reduce the active rule set without making the scan itself expensive.
 
 use super::compiled_rules::BucketMask;
+use super::emoji::blank_emoji;
 use std::collections::HashSet;
 
+/// Words and phrases the scan reports in [`TriggerInfo::phrases`] when
+/// they occur in the input; rules gate on them through their required and
+/// optional phrases.
+const KEY_PHRASES: &[&str] = &[
+    "tomorrow",
+    "yesterday",
+    "today",
+    "next",
+    "last",
+    "this",
+    "now",
+    "from",
+    "by",
+    "to",
+    "until",
+    "through",
+    "thru",
+    "between",
+    "after",
+    "before",
+    "since",
+    "eod",
+    "eom",
+    "bom",
+    "month",
+    "before last",
+    "after next",
+    "at",
+    "on",
+    "in",
+    "for",
+    "of",
+    "ago",
+    "hence",
+    "back",
+    "following",
+    "thanksgiving",
+    "christmas",
+    "xmas",
+    "boss",
+    "black",
+    "friday",
+    "mlk",
+    "martin",
+    "new",
+    "year",
+    "eve",
+    "summer",
+    "fall",
+    "autumn",
+    "winter",
+    "spring",
+    "asap",
+    "soon",
+    "immediately",
+    "moment",
+    "atm",
+    "ides",
+    "ide",
+    "tmrw",
+    "tommorow",
+    "tomorrows",
+    "ystrday",
+    "yestrday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "mon",
+    "tue",
+    "wed",
+    "thu",
+    "fri",
+    "sat",
+    "sun",
+    "week",
+    "weekend",
+    "wkend",
+    "month",
+    "quarter",
+    "qtr",
+    "qr",
+    "half",
+    "past",
+    "after",
+    "to",
+    "till",
+    "through",
+    "thru",
+    "before",
+    "of",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "noon",
+    "midnight",
+    "midnite",
+    "mid",
+    "eod",
+    "end",
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "morning",
+    "afternoon",
+    "evening",
+    "night",
+    "tonight",
+    "overnight",
+    "late",
+    "early",
+    "mid",
+    "beginning",
+    "middle",
+    "start",
+    "every",
+    "each",
+    "about",
+    "around",
+    "approximately",
+    "roughly",
+    "exactly",
+    "sharp",
+    "later",
+    "latest",
+    // Optional phrases of rules that nothing above reports: "@ 3pm",
+    // "EOY", "upcoming qtr", "an hour and a half", "one week ago".
+    "luther",
+    "eoy",
+    "boy",
+    "during",
+    "one",
+    "current",
+    "upcoming",
+    "previous",
+    "and",
+    "from now",
+    "@",
+    "king",
+    "yr",
+    "coming",
+    "civil",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+];
+
+/// Whether the scan reports `phrase` when it occurs, so a rule can gate on
+/// it.
+pub(crate) fn is_key_phrase(phrase: &str) -> bool {
+    phrase == "ish" || KEY_PHRASES.contains(&phrase)
+}
+
 /// Input characteristics detected from the raw input.
 ///
 /// This is used to quickly gate rule activation before saturation.
@@ -35,6 +210,9 @@ use std::collections::HashSet;
 pub struct TriggerInfo {
     pub buckets: BucketMask,
     pub phrases: HashSet<String>,
+    /// The ASCII-lowercased input, for matching phrases derived from rule
+    /// regexes.
+    pub lower: String,
 }
 
 impl TriggerInfo {
@@ -46,7 +224,8 @@ impl TriggerInfo {
     pub fn scan(input: &str) -> Self {
         let mut buckets = BucketMask::empty();
         let mut phrases = HashSet::new();
-        let lower = input.to_ascii_lowercase();
+        // Emoji separate words ("friday👍🏽at 5"), like whitespace.
+        let lower = blank_emoji(input).to_ascii_lowercase();
 
         // Buckets
         if input.bytes().any(|b| b.is_ascii_digit()) {
@@ -89,8 +268,15 @@ impl TriggerInfo {
             "sat",
             "sun",
         ];
+        // Hyphens and dashes split words too, so "mid-June" yields "mid" and
+        // "june", and "Monday–Friday" both weekdays.
+        let has_word = |word: &str| {
+            lower
+                .split(|c: char| c.is_whitespace() || matches!(c, '-' | '–' | '—'))
+                .any(|w| w.trim_matches(|c: char| !c.is_alphabetic()) == word)
+        };
         for wd in WEEKDAYS {
-            if lower.split_whitespace().any(|w| w.trim_matches(|c: char| !c.is_alphabetic()) == *wd) {
+            if has_word(wd) {
                 buckets |= BucketMask::WEEKDAYISH;
                 break;
             }
@@ -118,168 +304,178 @@ impl TriggerInfo {
             "jul",
             "aug",
             "sep",
+            "sept",
             "oct",
             "nov",
             "dec",
         ];
         for month in MONTHS {
-            if lower.split_whitespace().any(|w| w.trim_matches(|c: char| !c.is_alphabetic()) == *month) {
+            if has_word(month) {
                 buckets |= BucketMask::MONTHISH;
                 break;
             }
         }
 
-        // Ordinal detection
+        // Ordinal detection: "3rd", "15th", "third", "twenty-first"
         const ORDINALS: &[&str] = &[
-            "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth", "tenth", "1st",
-            "2nd", "3rd", "4th", "5th",
+            "first",
+            "second",
+            "third",
+            "fourth",
+            "fifth",
+            "sixth",
+            "seventh",
+            "eighth",
+            "ninth",
+            "tenth",
+            "eleventh",
+            "twelfth",
+            "thirteenth",
+            "fourteenth",
+            "fifteenth",
+            "sixteenth",
+            "seventeenth",
+            "eighteenth",
+            "nineteenth",
+            "twentieth",
+            "thirtieth",
         ];
-        for ord in ORDINALS {
-            if lower.split_whitespace().any(|w| w.trim_matches(|c: char| !c.is_ascii_alphanumeric()) == *ord) {
-                buckets |= BucketMask::ORDINALISH;
+        let is_ordinal = |w: &str| {
+            let suffix = w.trim_start_matches(|c: char| c.is_ascii_digit());
+            let numeric = suffix.len() < w.len() && matches!(suffix, "st" | "nd" | "rd" | "th");
+            numeric || w.rsplit('-').next().is_some_and(|last| ORDINALS.contains(&last))
+        };
+        if lower.split_whitespace().any(|w| is_ordinal(w.trim_matches(|c: char| !c.is_ascii_alphanumeric()))) {
+            buckets |= BucketMask::ORDINALISH;
+        }
+
+        // Itinerary shorthand: "dep"/"arr" markers and compact dates ("14MAR", "06JUN25")
+        let is_transport_word = |w: &str| {
+            let letters = w.trim_start_matches(|c: char| c.is_ascii_digit());
+            let digits = w.len() - letters.len();
+            matches!(w, "dep" | "arr")
+                || ((1..=2).contains(&digits) && letters.get(..3).is_some_and(|m| MONTHS.contains(&m)))
+        };
+        if lower.split_whitespace().any(|w| is_transport_word(w.trim_matches(|c: char| !c.is_ascii_alphanumeric()))) {
+            buckets |= BucketMask::TRANSPORT;
+        }
+
+        // Currency symbols and ISO codes ("$5", "20 EUR")
+        const CURRENCY_CODES: &[&str] = &["usd", "eur", "gbp", "jpy", "sek", "chf", "cad", "aud"];
+        if input.contains(['$', '€', '£', '¥', '₹'])
+            || lower.split_whitespace().any(|w| CURRENCY_CODES.contains(&w.trim_matches(|c: char| !c.is_alphabetic())))
+        {
+            buckets |= BucketMask::CURRENCY;
+        }
+
+        // Quantities with a unit glued or next to the number ("5km", "3 kg", "20%")
+        const UNITS: &[&str] = &["km", "m", "cm", "mm", "mi", "ft", "kg", "g", "lb", "lbs", "oz", "l", "ml", "%"];
+        let is_unit = |w: &str| UNITS.contains(&w.trim_end_matches(|c: char| c.is_ascii_punctuation() && c != '%'));
+        let words: Vec<&str> = lower.split_whitespace().collect();
+        if words.iter().enumerate().any(|(idx, w)| {
+            let unit = w.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+            let has_number = unit.len() < w.len();
+            (has_number && is_unit(unit))
+                || (has_number && unit.is_empty() && words.get(idx + 1).is_some_and(|next| is_unit(next)))
+        }) {
+            buckets |= BucketMask::UNITS;
+        }
+
+        // Phone-like runs: seven or more digits, contiguous or joined by phone
+        // separators ("555-123-4567"). Across spaces only groups of three or
+        // more digits, a "+1" country code or a "(555)" area code carry the
+        // run on, so dates like "10 5 2013" are not phone numbers.
+        let mut run = 0;
+        for word in input.split_whitespace().map(|w| w.trim_end_matches([',', ';', ':'])) {
+            let phone_chars = word.chars().all(|c| c.is_ascii_digit() || matches!(c, '-' | '.' | '(' | ')' | '+'));
+            let digits = word.chars().filter(char::is_ascii_digit).count();
+            let group = digits >= 3 || (digits > 0 && word.starts_with(['+', '(']));
+            run = if phone_chars && group { run + digits } else { 0 };
+            if run >= 7 {
+                buckets |= BucketMask::PHONEISH;
                 break;
             }
         }
 
+        // Email addresses and URLs
+        if input.contains('@') || lower.contains("://") || lower.contains("www.") {
+            buckets |= BucketMask::EMAIL_URL;
+        }
+
         // Key phrases
-        const KEY_PHRASES: &[&str] = &[
-            "tomorrow",
-            "yesterday",
-            "today",
-            "next",
-            "last",
-            "this",
-            "now",
-            "from",
-            "by",
-            "to",
-            "until",
-            "through",
-            "thru",
-            "between",
-            "after",
-            "before",
-            "since",
-            "eod",
-            "eom",
-            "bom",
-            "month",
-            "before last",
-            "after next",
-            "at",
-            "on",
-            "in",
-            "for",
-            "of",
-            "ago",
-            "hence",
-            "back",
-            "following",
-            "thanksgiving",
-            "christmas",
-            "xmas",
-            "boss",
-            "black",
-            "friday",
-            "mlk",
-            "martin",
-            "new",
-            "year",
-            "eve",
-            "summer",
-            "fall",
-            "autumn",
-            "winter",
-            "spring",
-            "asap",
-            "soon",
-            "immediately",
-            "moment",
-            "atm",
-            "ides",
-            "ide",
-            "tmrw",
-            "tommorow",
-            "tomorrows",
-            "ystrday",
-            "yestrday",
-            "monday",
-            "tuesday",
-            "wednesday",
-            "thursday",
-            "friday",
-            "saturday",
-            "sunday",
-            "mon",
-            "tue",
-            "wed",
-            "thu",
-            "fri",
-            "sat",
-            "sun",
-            "week",
-            "weekend",
-            "wkend",
-            "month",
-            "quarter",
-            "qtr",
-            "qr",
-            "half",
-            "past",
-            "after",
-            "to",
-            "till",
-            "through",
-            "thru",
-            "before",
-            "of",
-            "day",
-            "hour",
-            "minute",
-            "second",
-            "noon",
-            "midnight",
-            "midnite",
-            "mid",
-            "eod",
-            "end",
-            "january",
-            "february",
-            "march",
-            "april",
-            "may",
-            "june",
-            "july",
-            "august",
-            "september",
-            "october",
-            "november",
-            "december",
-            "morning",
-            "afternoon",
-            "evening",
-            "night",
-            "tonight",
-            "late",
-            "early",
-            "mid",
-            "beginning",
-        ];
         for phrase in KEY_PHRASES {
-            if phrase.contains(' ') {
+            if phrase.contains(' ') || !phrase.chars().all(char::is_alphabetic) {
                 // For multi-word phrases like "before last" or "after next",
-                // do a simple substring match on the lowercased input.
+                // and symbols like "@", do a simple substring match on the
+                // lowercased input.
                 if lower.contains(phrase) {
                     phrases.insert(phrase.to_string());
                 }
             } else {
                 // For single-word phrases, match against normalized whitespace tokens.
-                if lower.split_whitespace().any(|w| w.trim_matches(|c: char| !c.is_alphabetic()) == *phrase) {
+                if has_word(phrase) {
                     phrases.insert(phrase.to_string());
                 }
             }
         }
 
-        TriggerInfo { buckets, phrases }
+        // "ish" is usually glued to the previous word ("3pm-ish", "150ish").
+        if lower.contains("ish") {
+            phrases.insert("ish".to_string());
+        }
+
+        TriggerInfo { buckets, phrases, lower }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_detects_entity_markers() {
+        let buckets = |input: &str| TriggerInfo::scan(input).buckets;
+
+        assert!(buckets("it costs $5").contains(BucketMask::CURRENCY));
+        assert!(buckets("20 EUR tomorrow").contains(BucketMask::CURRENCY));
+        assert!(buckets("run 5km").contains(BucketMask::UNITS));
+        assert!(buckets("3 kg of flour").contains(BucketMask::UNITS));
+        assert!(buckets("call +1 (555) 123-4567").contains(BucketMask::PHONEISH));
+        assert!(buckets("call 555 123 4567, thanks").contains(BucketMask::PHONEISH));
+        assert!(buckets("ref 5551234567").contains(BucketMask::PHONEISH));
+        assert!(!buckets("10 5 2013").contains(BucketMask::PHONEISH));
+        assert!(!buckets("on 3 12 2013 at 5").contains(BucketMask::PHONEISH));
+        assert!(buckets("mail bob@example.com").contains(BucketMask::EMAIL_URL));
+        assert!(buckets("see https://example.com").contains(BucketMask::EMAIL_URL));
+
+        let plain = buckets("tomorrow at 5pm for 3 days");
+        assert!(!plain.intersects(BucketMask::CURRENCY | BucketMask::UNITS | BucketMask::PHONEISH));
+        assert!(!plain.contains(BucketMask::EMAIL_URL));
+
+        let chat = TriggerInfo::scan("friday👍🏽at 5 🎉tomorrow👩\u{200d}💻 3rd🎉");
+        assert!(chat.buckets.contains(BucketMask::WEEKDAYISH | BucketMask::ORDINALISH));
+        assert!(chat.phrases.contains("tomorrow") && chat.phrases.contains("at"));
+
+        let hyphenated = TriggerInfo::scan("mid-June");
+        assert!(hyphenated.buckets.contains(BucketMask::MONTHISH));
+        assert!(hyphenated.phrases.contains("mid"));
+    }
+
+    #[test]
+    fn scan_reports_symbol_and_multi_word_key_phrases() {
+        let phrases = |input: &str| {
+            let mut phrases: Vec<String> = TriggerInfo::scan(input).phrases.into_iter().collect();
+            phrases.sort();
+            phrases
+        };
+
+        assert_eq!(phrases("tonight @ 3:20"), ["@", "tonight"]);
+        assert_eq!(phrases("three hours from now"), ["from", "from now", "now", "three"]);
+        assert_eq!(phrases("EOY"), ["eoy"]);
+        assert_eq!(phrases("upcoming qtr"), ["qtr", "upcoming"]);
+        assert_eq!(phrases("one and a half hours"), ["and", "half", "one"]);
+        assert_eq!(phrases("Martin Luther King Jr. Day"), ["day", "king", "luther", "martin"]);
+        // Letter phrases match whole words only.
+        assert!(phrases("someone handled it").is_empty());
     }
 }
@@ -0,0 +1,218 @@
+//! Typo tolerance (opt-in input rewriting).
+//!
+//! Misspelled weekdays, months and relative day words ("wednseday",
+//! "febuary", "tommorow") are common enough in free text to be worth
+//! catching. When enabled through `Options::typo_tolerance`, each word of the
+//! input within a small edit distance of exactly one [`VOCABULARY`] word is
+//! rewritten to it before rule matching. The parser only ever sees the
+//! corrected text; [`Corrections`] maps spans back to the original input so
+//! entities still point at what was written.
+//!
+//! The vocabulary is limited to words of six letters or more: shorter ones
+//! sit too close to ordinary English ("march" and "match").
+
+use std::ops::Range;
+
+/// Words that near misses are corrected to.
+const VOCABULARY: &[&str] = &[
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "mondays",
+    "tuesdays",
+    "wednesdays",
+    "thursdays",
+    "fridays",
+    "saturdays",
+    "sundays",
+    "january",
+    "february",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "tomorrow",
+    "yesterday",
+    "tonight",
+    "weekend",
+    "afternoon",
+];
+
+/// One rewritten word, as byte ranges in the original and corrected text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Edit {
+    original: Range<usize>,
+    corrected: Range<usize>,
+}
+
+/// The input with near-miss words rewritten.
+#[derive(Debug, Clone)]
+pub(crate) struct Corrections {
+    /// The corrected input the parser runs on.
+    pub text: String,
+    edits: Vec<Edit>,
+}
+
+impl Corrections {
+    /// Map a span of the corrected text back to the original input. A span
+    /// starting or ending inside a rewritten word covers the whole word.
+    pub(crate) fn to_original(&self, span: Range<usize>) -> Range<usize> {
+        self.map(span.start, false)..self.map(span.end, true)
+    }
+
+    /// Whether any rewritten word lies in `span` of the original input.
+    pub(crate) fn touches(&self, span: &Range<usize>) -> bool {
+        self.edits.iter().any(|edit| edit.original.start < span.end && span.start < edit.original.end)
+    }
+
+    /// Apply `edits` (byte ranges of `input` and their replacements) to
+    /// `input`. Edits out of bounds, off character boundaries or
+    /// overlapping an earlier one are dropped; `None` when none are left.
+    pub(crate) fn apply(input: &str, mut edits: Vec<(Range<usize>, String)>) -> Option<Corrections> {
+        edits.sort_by_key(|(range, _)| (range.start, range.end));
+        let mut text = String::with_capacity(input.len());
+        let mut kept = Vec::new();
+        let mut copied = 0;
+        for (range, replacement) in edits {
+            let valid = range.start >= copied
+                && range.start <= range.end
+                && input.is_char_boundary(range.start)
+                && input.get(range.end..).is_some();
+            if !valid || (range.is_empty() && replacement.is_empty()) {
+                continue;
+            }
+            text.push_str(&input[copied..range.start]);
+            let start = text.len();
+            text.push_str(&replacement);
+            kept.push(Edit { original: range.clone(), corrected: start..text.len() });
+            copied = range.end;
+        }
+        if kept.is_empty() {
+            return None;
+        }
+        text.push_str(&input[copied..]);
+        Some(Corrections { text, edits: kept })
+    }
+
+    fn map(&self, pos: usize, is_end: bool) -> usize {
+        let mut original = pos;
+        for edit in &self.edits {
+            // A span starting where text was deleted starts after it.
+            if pos < edit.corrected.start || (pos == edit.corrected.start && (is_end || !edit.corrected.is_empty())) {
+                break;
+            }
+            if pos < edit.corrected.end {
+                return if is_end { edit.original.end } else { edit.original.start };
+            }
+            original = edit.original.end + (pos - edit.corrected.end);
+        }
+        original
+    }
+}
+
+/// Rewrite near misses of [`VOCABULARY`] words in `input`; `None` when
+/// nothing needed correcting.
+pub(crate) fn correct_typos(input: &str) -> Option<Corrections> {
+    let edits = words(input).filter_map(|word| Some((word.clone(), correction(&input[word])?.to_string()))).collect();
+    Corrections::apply(input, edits)
+}
+
+/// Byte ranges of the runs of ASCII letters in `input`.
+fn words(input: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let bytes = input.as_bytes();
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        while pos < bytes.len() && !bytes[pos].is_ascii_alphabetic() {
+            pos += 1;
+        }
+        let start = pos;
+        while pos < bytes.len() && bytes[pos].is_ascii_alphabetic() {
+            pos += 1;
+        }
+        (start < pos).then_some(start..pos)
+    })
+}
+
+/// The single vocabulary word `word` is a near miss of: one edit away for
+/// words under eight letters, two for longer ones, with the first letter
+/// right. Known words and ties are left alone.
+fn correction(word: &str) -> Option<&'static str> {
+    if word.len() < 5 {
+        return None;
+    }
+    let word = word.to_ascii_lowercase();
+    if VOCABULARY.contains(&word.as_str()) {
+        return None;
+    }
+    let max = if word.len() < 8 { 1 } else { 2 };
+    let mut best: Option<(&'static str, usize)> = None;
+    let mut tied = false;
+    for &candidate in VOCABULARY {
+        if candidate.as_bytes()[0] != word.as_bytes()[0] || candidate.len().abs_diff(word.len()) > max {
+            continue;
+        }
+        let distance = edit_distance(word.as_bytes(), candidate.as_bytes());
+        if distance > max {
+            continue;
+        }
+        match best {
+            Some((_, best_distance)) if distance > best_distance => {}
+            Some((_, best_distance)) if distance == best_distance => tied = true,
+            _ => {
+                best = Some((candidate, distance));
+                tied = false;
+            }
+        }
+    }
+    if tied { None } else { best.map(|(candidate, _)| candidate) }
+}
+
+/// Edit distance counting insertions, deletions, substitutions and swaps of
+/// adjacent letters ("wednseday") as one edit each.
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    rows[0] = (0..=b.len()).collect();
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn near_misses_are_rewritten_and_spans_mapped_back() {
+        let input = "Febuary 3 or wednseday, tommorow";
+        let fixed = correct_typos(input).unwrap();
+        assert_eq!(fixed.text, "february 3 or wednesday, tomorrow");
+
+        // "february 3" ends one byte later than "Febuary 3".
+        assert_eq!(fixed.to_original(0..10), 0..9);
+        assert_eq!(fixed.to_original(14..23), 13..22);
+        assert_eq!(fixed.to_original(2..5), 0..7);
+        assert!(fixed.touches(&(0..9)));
+        assert!(!fixed.touches(&(8..12)));
+
+        // Known words, short words and ordinary English stay as written.
+        assert!(correct_typos("Friday, tonight or next weekend").is_none());
+        assert!(correct_typos("a match in march").is_none());
+        assert!(correct_typos("mourning the morning").is_none());
+    }
+}
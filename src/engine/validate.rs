@@ -0,0 +1,235 @@
+//! Static checks over rule definitions.
+//!
+//! Most mistakes in a rule's definition fail quietly: a phrase the trigger
+//! scan never reports, or a bucket bit it never sets, keeps the rule from
+//! ever activating. A regex that does not compile panics only when the rule
+//! set is first built, on the first parse. [`check_rule`] finds the quiet
+//! mistakes and [`rule_patterns`] names the rule behind a bad regex. Bucket
+//! and phrase gating is checked against the leading regex's syntax tree:
+//! what every match of it contains, and the shortest input it matches. The
+//! tests below build and check every built-in rule, so `cargo test` fails
+//! with the rule's name; rule files are checked as they load.
+
+use super::compiled_rules::BucketMask;
+use super::guard::panic_message;
+use super::trigger::{TriggerInfo, is_key_phrase};
+use crate::{Pattern, Rule};
+use regex::Regex;
+use regex_syntax::hir::{Class, Hir, HirKind};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// Highest rule priority. Priorities only order rules against each other,
+/// so anything above this is a typo rather than a stronger preference.
+pub(crate) const MAX_PRIORITY: u16 = 100;
+
+/// The pattern of rule `name`, built by `build`. A panic while building it,
+/// such as a regex that does not compile, is re-raised naming the rule.
+pub(crate) fn rule_patterns(name: &str, build: impl FnOnce() -> Vec<Pattern>) -> Vec<Pattern> {
+    catch_unwind(AssertUnwindSafe(build))
+        .unwrap_or_else(|payload| panic!("rule \"{name}\": {}", panic_message(payload.as_ref())))
+}
+
+/// The first problem with `rule` that would keep it from ever matching,
+/// or make it match everywhere.
+#[cfg_attr(not(feature = "rule-files"), allow(dead_code))]
+pub(crate) fn check_rule(rule: &Rule) -> Result<(), String> {
+    if rule.name.trim().is_empty() {
+        return Err("empty name".to_string());
+    }
+    match rule.pattern.first() {
+        None => return Err("empty pattern".to_string()),
+        Some(Pattern::Regex(re)) if rule.pattern.len() == 1 && re.is_match("") => {
+            return Err(format!("regex {:?} matches the empty string", re.as_str()));
+        }
+        Some(Pattern::Words(words)) if words.trim().is_empty() => return Err("empty words".to_string()),
+        Some(_) => {}
+    }
+    if rule.priority > MAX_PRIORITY {
+        return Err(format!("priority {} is above {MAX_PRIORITY}", rule.priority));
+    }
+    let unknown = rule.buckets & !BucketMask::all().bits();
+    if unknown != 0 {
+        return Err(format!("unknown bucket bits {unknown:#x}"));
+    }
+    let phrases = rule.required_phrases.iter().chain(rule.optional_phrases);
+    if let Some(phrase) = phrases.into_iter().find(|phrase| !is_key_phrase(phrase)) {
+        return Err(format!("phrase \"{phrase}\" is not a key phrase of the trigger scan"));
+    }
+    match rule.pattern.first() {
+        Some(Pattern::Regex(re)) => check_gating(rule, re),
+        _ => Ok(()),
+    }
+}
+
+/// A bucket the trigger scan sets from single characters: the bucket, what
+/// sets it, its name and the character test.
+type CharBucket = (BucketMask, &'static str, &'static str, fn(char) -> bool);
+
+/// Bucket bits or required phrases that keep `rule` from running on input
+/// its leading regex `re` matches.
+fn check_gating(rule: &Rule, re: &Regex) -> Result<(), String> {
+    let Ok(hir) = regex_syntax::parse(re.as_str()) else { return Ok(()) };
+    let buckets = BucketMask::from_bits_truncate(rule.buckets);
+    // A regex needing a digit or a colon belongs in that bucket, unless the
+    // rule is in a word bucket (month, weekday, transport, ...) its text may
+    // set instead, which this check cannot see. `\d` counts as a digit, as
+    // rules parse ASCII digits only.
+    let char_buckets = BucketMask::HAS_DIGITS | BucketMask::HAS_COLON;
+    let needs: [CharBucket; 2] = [
+        (BucketMask::HAS_DIGITS, "a digit", "HAS_DIGITS", char::is_numeric),
+        (BucketMask::HAS_COLON, "a colon", "HAS_COLON", |c| c == ':'),
+    ];
+    let needed: Vec<_> = needs.into_iter().filter(|(_, _, _, is)| always_contains(&hir, *is)).collect();
+    if let Some((_, what, name, _)) = needed.first() {
+        if char_buckets.contains(buckets) && !needed.iter().any(|(bucket, ..)| buckets.contains(*bucket)) {
+            return Err(format!("regex {:?} needs {what} but the rule does not set {name}", re.as_str()));
+        }
+    }
+
+    // Later pattern items match more input, so only a lone regex gives a
+    // whole match to scan.
+    if rule.pattern.len() > 1 {
+        return Ok(());
+    }
+    let mut sample = String::new();
+    shortest_match(&hir, &mut sample);
+    if !re.is_match(&sample) {
+        return Ok(());
+    }
+    let scan = TriggerInfo::scan(&sample);
+    if !buckets.is_empty() && !buckets.intersects(scan.buckets) {
+        return Err(format!("matches {sample:?}, which sets none of its buckets"));
+    }
+    if let Some(phrase) = rule.required_phrases.iter().find(|phrase| !scan.phrases.contains(**phrase)) {
+        return Err(format!("matches {sample:?} without its required phrase \"{phrase}\""));
+    }
+    Ok(())
+}
+
+/// Whether every match of `hir` contains a character satisfying `is`.
+fn always_contains(hir: &Hir, is: fn(char) -> bool) -> bool {
+    match hir.kind() {
+        HirKind::Literal(lit) => String::from_utf8_lossy(&lit.0).chars().any(is),
+        HirKind::Class(Class::Unicode(class)) => class.ranges().iter().all(|r| (r.start()..=r.end()).all(is)),
+        HirKind::Class(Class::Bytes(class)) => {
+            class.ranges().iter().all(|r| (r.start()..=r.end()).all(|b| is(b.into())))
+        }
+        HirKind::Repetition(rep) => rep.min > 0 && always_contains(&rep.sub, is),
+        HirKind::Capture(cap) => always_contains(&cap.sub, is),
+        HirKind::Concat(items) => items.iter().any(|item| always_contains(item, is)),
+        HirKind::Alternation(items) => items.iter().all(|item| always_contains(item, is)),
+        HirKind::Empty | HirKind::Look(_) => false,
+    }
+}
+
+/// Append the shortest text `hir` matches, ignoring look-arounds and taking
+/// the first alternative. Classes give a space or a lowercase letter where
+/// they have one, so the text reads like input.
+fn shortest_match(hir: &Hir, out: &mut String) {
+    match hir.kind() {
+        HirKind::Literal(lit) => out.push_str(&String::from_utf8_lossy(&lit.0)),
+        HirKind::Class(Class::Unicode(class)) => {
+            let contains = |c: char| class.ranges().iter().any(|r| (r.start()..=r.end()).contains(&c));
+            if let Some(first) = class.ranges().first().map(|r| r.start()) {
+                out.push([' ', first.to_ascii_lowercase()].into_iter().find(|&c| contains(c)).unwrap_or(first));
+            }
+        }
+        HirKind::Class(Class::Bytes(class)) => out.extend(class.ranges().first().map(|r| char::from(r.start()))),
+        HirKind::Repetition(rep) => (0..rep.min).for_each(|_| shortest_match(&rep.sub, out)),
+        HirKind::Capture(cap) => shortest_match(&cap.sub, out),
+        HirKind::Concat(items) => items.iter().for_each(|item| shortest_match(item, out)),
+        HirKind::Alternation(items) => items.iter().take(1).for_each(|item| shortest_match(item, out)),
+        HirKind::Empty | HirKind::Look(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Token;
+    use crate::rules::time::rules::{get, transport};
+    use crate::time_expr::TimeExpr;
+
+    /// Every problem in `rules`, as `rule "<name>": <problem>`.
+    fn check_rules(rules: &[Rule]) -> Vec<String> {
+        let problems = rules.iter().filter_map(|rule| Some((rule.name, check_rule(rule).err()?)));
+        problems.map(|(name, problem)| format!("rule \"{name}\": {problem}")).collect()
+    }
+
+    #[test]
+    fn built_in_rules_pass_the_checks() {
+        let problems = check_rules(&get());
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+        let problems = check_rules(&transport());
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+
+    #[test]
+    #[allow(clippy::invalid_regex)]
+    fn bad_rules_are_reported_by_name() {
+        let unreachable = rule! {
+            name: "lunch",
+            pattern: [re!(r"(?i)\blunch\b")],
+            optional_phrases: ["lunch"],
+            prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+        };
+        let everywhere = rule! {
+            name: "anything",
+            pattern: [re!(r"\s*")],
+            prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+        };
+        let unknown_bucket = rule! {
+            name: "fortnight",
+            pattern: [re!(r"(?i)\bfortnight\b")],
+            buckets: 1 << 30,
+            prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+        };
+        let overeager = rule! {
+            name: "soonish",
+            pattern: [re!(r"(?i)\bsoonish\b")],
+            priority: 1000,
+            prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+        };
+        let colon_bucket = rule! {
+            name: "hours",
+            pattern: [re!(r"(?i)\b(\d+)h\b")],
+            buckets: BucketMask::HAS_COLON.bits(),
+            prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+        };
+        let digit_bucket = rule! {
+            name: "noon",
+            pattern: [re!(r"(?i)\b(?:at\s+)?noon\b")],
+            required_phrases: ["noon"],
+            buckets: BucketMask::HAS_DIGITS.bits(),
+            prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+        };
+        let all_phrases = rule! {
+            name: "mid-day",
+            pattern: [re!(r"(?i)mid\s?day")],
+            required_phrases: ["mid", "day"],
+            prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+        };
+        assert_eq!(
+            check_rules(&[unreachable, everywhere, unknown_bucket, overeager, colon_bucket, digit_bucket, all_phrases]),
+            [
+                "rule \"lunch\": phrase \"lunch\" is not a key phrase of the trigger scan",
+                "rule \"anything\": regex \"\\\\s*\" matches the empty string",
+                "rule \"fortnight\": unknown bucket bits 0x40000000",
+                "rule \"soonish\": priority 1000 is above 100",
+                "rule \"hours\": regex \"(?i)\\\\b(\\\\d+)h\\\\b\" needs a digit but the rule does not set HAS_DIGITS",
+                "rule \"noon\": matches \"noon\", which sets none of its buckets",
+                "rule \"mid-day\": matches \"midday\" without its required phrase \"mid\"",
+            ]
+        );
+
+        let panic = catch_unwind(|| {
+            rule! {
+                name: "unbalanced",
+                pattern: [re!(r"(at")],
+                prod: |_tokens: &[Token]| -> Option<TimeExpr> { None }
+            }
+        })
+        .unwrap_err();
+        assert!(panic_message(panic.as_ref()).starts_with("rule \"unbalanced\": "));
+    }
+}
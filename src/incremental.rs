@@ -0,0 +1,169 @@
+//! Incremental re-parsing after a text edit.
+//!
+//! Editors re-parse on every keystroke, and a full [`parse_with`] of a long
+//! document redoes saturation over text that did not change. [`reparse_with`]
+//! takes the previous [`ParseResult`] and one [`TextEdit`], re-parses a
+//! window of whole lines around the edit, and keeps the other entities with
+//! their offsets shifted past the edit.
+//!
+//! Which rules run depends on words found anywhere in the text ("the",
+//! "at", month names), so the window is parsed with the rules of the whole
+//! document, and an edit that switches rules on or off anywhere falls back
+//! to a full parse. So does every edit under options that judge the input as
+//! a whole ([`Options::coverage`], [`Options::stop_at_full_span_time`]).
+//!
+//! Matches can run across a line break ("tomorrow at 5pm\non Monday"), so
+//! the window has a line of context on each side and grows while an entity
+//! of the previous parse crosses its edges, or one found in it runs from the
+//! edited lines into the context. The post-resolution passes (AM/PM
+//! inference, negation) look no further than the sentence or clause around a
+//! match, which the window then contains.
+
+use crate::api::{active_rules, dimension_rank, parse_window};
+use crate::{Context, Coverage, Entity, Options, ParseResult, engine, parse_with};
+use std::ops::Range;
+use std::time::Instant;
+
+/// A replacement of `range` (byte offsets into the previous text) by `text`.
+/// An insertion has an empty range, a deletion an empty `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl TextEdit {
+    /// Replace `range` with `text`.
+    pub fn new(range: Range<usize>, text: impl Into<String>) -> Self {
+        Self { range, text: text.into() }
+    }
+}
+
+/// Apply `edit` to the text of `previous` and return the parse of the new
+/// text, re-running saturation only over the lines around the edit (or
+/// over everything, when the edit changes which rules run).
+///
+/// `previous` must be the result of parsing its text with the same
+/// `context` and `options`. An edit range outside the text is clamped to
+/// it, and one inside a character is widened to the whole character.
+pub fn reparse_with(previous: &ParseResult, edit: &TextEdit, context: &Context, options: &Options) -> ParseResult {
+    let started = Instant::now();
+    let old = previous.text.as_str();
+    let edited = engine::snap(old, edit.range.clone());
+    let text = format!("{}{}{}", &old[..edited.start], edit.text, &old[edited.end..]);
+    let inserted = edited.start..edited.start + edit.text.len();
+    let whole_input = options.coverage != Coverage::Any || options.stop_at_full_span_time;
+    if whole_input || active_rules(old, options) != active_rules(&text, options) {
+        return parse_with(&text, context, options);
+    }
+
+    // Previous entities in new offsets. The ones the edit cut into are
+    // re-parsed, so the window starts out covering them.
+    let map = |offset: usize| match offset {
+        offset if offset <= edited.start => offset,
+        offset if offset >= edited.end => offset - edited.end + inserted.end,
+        _ => inserted.end,
+    };
+    let (kept, cut): (Vec<&Entity>, Vec<&Entity>) =
+        previous.results.iter().partition(|e| e.end <= edited.start || e.start >= edited.end);
+    let kept: Vec<Entity> = kept.into_iter().cloned().map(|e| moved(e, map, &text)).collect();
+    let dirty = cut.iter().fold(inserted.clone(), |span, e| span.start.min(map(e.start))..span.end.max(map(e.end)));
+
+    let (mut before, mut after) = (1, 1);
+    let (window, local) = loop {
+        let mut window = lines_around(&text, dirty.clone(), before, after);
+        while let Some(entity) = kept.iter().find(|e| crosses(e.start..e.end, &window)) {
+            window = lines_around(&text, window.start.min(entity.start)..window.end.max(entity.end), 0, 0);
+        }
+        let local = parse_window(&text, window.clone(), context, options);
+        let spans = || local.results.iter().map(|e| window.start + e.start..window.start + e.end);
+        let first_break = text[window.clone()].find('\n').map(|idx| window.start + idx);
+        let last_break = text[window.clone()].rfind('\n').map(|idx| window.start + idx);
+        let grow_before = window.start > 0 && first_break.is_some_and(|at| spans().any(|s| s.start < at && at < s.end));
+        let grow_after =
+            window.end < text.len() && last_break.is_some_and(|at| spans().any(|s| s.start < at && at < s.end));
+        if !grow_before && !grow_after {
+            break (window, local);
+        }
+        before += usize::from(grow_before);
+        after += usize::from(grow_after);
+    };
+
+    let mut results: Vec<Entity> = kept
+        .into_iter()
+        .filter(|e| e.end <= window.start || e.start >= window.end)
+        .chain(local.results.into_iter().map(|e| moved(e, |offset| offset + window.start, &text)))
+        .collect();
+    results.sort_by_key(|e| (dimension_rank(&e.name), e.start));
+
+    ParseResult { text, results, elapsed: started.elapsed() }
+}
+
+/// Whether `span` lies partly inside and partly outside `window`.
+fn crosses(span: Range<usize>, window: &Range<usize>) -> bool {
+    let inside = |offset: usize| window.start < offset && offset < window.end;
+    (span.start < window.start && span.end > window.start) || (inside(span.start) && span.end > window.end)
+}
+
+/// The lines holding `span`, with `before` more lines above and `after`
+/// more below.
+fn lines_around(text: &str, span: Range<usize>, before: usize, after: usize) -> Range<usize> {
+    let mut start = text[..span.start].rfind('\n').map_or(0, |idx| idx + 1);
+    for _ in 0..before {
+        start = text[..start.saturating_sub(1)].rfind('\n').map_or(0, |idx| idx + 1);
+    }
+    let mut end = text[span.end..].find('\n').map_or(text.len(), |idx| span.end + idx);
+    for _ in 0..after {
+        end = text.get(end + 1..).and_then(|rest| rest.find('\n')).map_or(text.len(), |idx| end + 1 + idx);
+    }
+    start..end
+}
+
+/// `entity` with its offsets moved by `map` into `text`.
+fn moved(mut entity: Entity, map: impl Fn(usize) -> usize, text: &str) -> Entity {
+    entity.start = map(entity.start);
+    entity.end = map(entity.end);
+    entity.body = engine::slice(text, entity.start..entity.end).to_string();
+    entity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn summary(result: &ParseResult) -> Vec<(String, usize, usize, String)> {
+        result.results.iter().map(|e| (e.body.clone(), e.start, e.end, e.value.clone())).collect()
+    }
+
+    #[test]
+    fn reparse_matches_a_full_parse() {
+        let reference = NaiveDate::from_ymd_opt(2013, 2, 12).and_then(|d| d.and_hms_opt(4, 30, 0));
+        let context = Context { reference_time: reference, ..Default::default() };
+        let options = Options::default();
+        let text = "call me tomorrow at 5pm\nthe meeting is 9-10, then lunch at 1\nnot on Friday\nroom 237 next week";
+
+        let edits = [
+            TextEdit::new(20..23, "6pm"),
+            TextEdit::new(24..24, "on Monday "),
+            TextEdit::new(23..24, " "),
+            TextEdit::new(60..74, ""),
+            TextEdit::new(0..text.len(), "March 3"),
+            TextEdit::new(text.len()..text.len(), " at noon"),
+            TextEdit::new(16..40, "\n"),
+        ];
+        for edit in edits {
+            let previous = parse_with(text, &context, &options);
+            let incremental = reparse_with(&previous, &edit, &context, &options);
+            let full = parse_with(&incremental.text, &context, &options);
+            assert_eq!(summary(&incremental), summary(&full), "after {edit:?}");
+        }
+
+        // Edits chain: each result is the previous parse of the next edit.
+        let mut result = parse_with("", &context, &options);
+        for (idx, c) in "see you friday at 3\nor the 4th".char_indices() {
+            result = reparse_with(&result, &TextEdit::new(idx..idx, c.to_string()), &context, &options);
+        }
+        assert_eq!(summary(&result), summary(&parse_with(&result.text, &context, &options)));
+    }
+}
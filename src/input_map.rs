@@ -0,0 +1,107 @@
+//! Offsets into rich text the input was extracted from.
+//!
+//! Callers that strip HTML or Markdown before parsing get spans into the
+//! stripped text. With an [`InputMap`] from stripped offsets back to the
+//! original, [`parse_mapped`] reports spans into the original instead.
+
+use crate::{Context, Options, ParseResult, parse_with};
+use std::ops::Range;
+
+/// Where each byte of the parsed text came from in the original text.
+///
+/// Implemented for closures, so any `Fn(usize) -> usize` is a valid map,
+/// and for offset tables (`[usize]`, `Vec<usize>`) holding the original
+/// offset of every byte of the parsed text.
+pub trait InputMap {
+    /// Offset in the original text of the byte at `offset` in the parsed
+    /// text.
+    fn to_source(&self, offset: usize) -> usize;
+
+    /// The original span of `span`: from its first byte to just past its
+    /// last, so markup right after the match is left out.
+    fn to_source_span(&self, span: Range<usize>) -> Range<usize> {
+        if span.is_empty() {
+            let start = self.to_source(span.start);
+            return start..start;
+        }
+        self.to_source(span.start)..self.to_source(span.end - 1) + 1
+    }
+}
+
+impl<F> InputMap for F
+where
+    F: Fn(usize) -> usize,
+{
+    fn to_source(&self, offset: usize) -> usize {
+        self(offset)
+    }
+}
+
+/// Offsets past the end of the table continue from its last entry.
+impl InputMap for [usize] {
+    fn to_source(&self, offset: usize) -> usize {
+        match (self.get(offset), self.last()) {
+            (Some(&source), _) => source,
+            (None, Some(&last)) => last + 1 + (offset - self.len()),
+            (None, None) => offset,
+        }
+    }
+}
+
+impl InputMap for Vec<usize> {
+    fn to_source(&self, offset: usize) -> usize {
+        self.as_slice().to_source(offset)
+    }
+}
+
+/// Parse `text`, extracted from some richer original, and report entity
+/// spans as offsets into the original through `map`. Bodies stay the
+/// matched text of `text`.
+pub fn parse_mapped(text: &str, map: &(impl InputMap + ?Sized), context: &Context, options: &Options) -> ParseResult {
+    let mut result = parse_with(text, context, options);
+    for entity in &mut result.results {
+        let span = map.to_source_span(entity.start..entity.end);
+        (entity.start, entity.end) = (span.start, span.end);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    /// Strip tags, recording the original offset of every kept byte.
+    fn strip_tags(html: &str) -> (String, Vec<usize>) {
+        let (mut text, mut map, mut in_tag) = (String::new(), Vec::new(), false);
+        for (idx, c) in html.char_indices() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                _ if !in_tag => {
+                    text.push(c);
+                    map.extend(idx..idx + c.len_utf8());
+                }
+                _ => {}
+            }
+        }
+        (text, map)
+    }
+
+    #[test]
+    fn spans_point_into_the_original() {
+        let reference = NaiveDate::from_ymd_opt(2013, 2, 12).and_then(|d| d.and_hms_opt(4, 30, 0));
+        let context = Context { reference_time: reference, ..Default::default() };
+        let html = "<p>Lunch <b>tomorrow</b> at <i>noon</i>, café at <em>3pm</em></p>";
+        let (text, map) = strip_tags(html);
+
+        let result = parse_mapped(&text, &map, &context, &Options::default());
+        let spans: Vec<&str> = result.times().map(|t| &html[t.entity.start..t.entity.end]).collect();
+        assert_eq!(spans, ["tomorrow</b> at <i>noon", "at <em>3pm", "at <em>3pm"]);
+        assert_eq!(result.times().next().unwrap().entity.body, "tomorrow at noon");
+
+        // A fixed prefix, as a closure.
+        let shifted = parse_mapped("at 3pm", &|offset: usize| offset + 100, &context, &Options::default());
+        assert_eq!((shifted.results[0].start, shifted.results[0].end), (100, 106));
+    }
+}
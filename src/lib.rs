@@ -4,18 +4,51 @@ use regex::Regex;
 
 #[macro_use]
 mod macros;
+mod alias;
 mod api;
+mod calendar;
 mod engine;
+mod incremental;
+mod input_map;
+mod locale;
+mod preprocess;
+#[cfg(feature = "rule-files")]
+mod rule_file;
 mod rules;
-
+mod template;
 mod time_expr;
+mod value;
 
+pub use alias::Alias;
+#[cfg(feature = "fuzzing")]
+pub use api::fuzz_parse;
 pub use api::{
-    Context, Entity, NodeSummary, Options, ParseDetails, ParseResult, RegexProfilingOptions, parse, parse_verbose_with,
-    parse_with,
+    Context, Coverage, DEFAULT_IDENTIFIER_MARKERS, DEFAULT_MAX_INPUT_LEN, DayOfMonthPolicy, Derivation, Entity,
+    Explanation, Hemisphere, IdenticalNodes, IdentifierEntity, IdentifierPattern, Metrics, NextMonthPolicy,
+    NodeSummary, NumeralEntity, Options, OverlapPolicy, ParseDetails, ParseError, ParseResult, ParseResultVerbose,
+    ParseWarning, PassStats, RegexProfilingOptions, RuleCoverage, RuleGroups, RuleSet, ShadowedRule, TimeEntity,
+    WarmUpReport, WarningKind, normalize, normalize_with, parse, parse_verbose_with, parse_with, try_parse_with,
+    warm_up,
+};
+#[cfg(feature = "calendars")]
+pub use calendar::IslamicCalendar;
+pub use calendar::{BusinessCalendar, Coordinates, Daylight, HolidayProvider, SolarCalculator};
+pub use chrono_tz::Tz;
+pub use engine::{ErrorStage, InternalError};
+pub use incremental::{TextEdit, reparse_with};
+pub use input_map::{InputMap, parse_mapped};
+pub use locale::FallbackLocale;
+pub use preprocess::{Preprocessor, StripMarkup, Synonyms};
+#[cfg(feature = "rule-files")]
+pub use rule_file::RuleFileError;
+pub use template::{FilledSlot, Slot, SlotFill, SlotKind, SlotValue, Template};
+pub use time_expr::{
+    BusinessBoundary, Constraint, Direction, Holiday, MonthPart, PartOfDay, Season, SolarEvent, TimeExpr,
+};
+pub use value::{
+    CanonicalFormat, Grain, Identifier, Numeral, PartialDate, Precision, Recurrence, SymbolicTime, Time, TimeValue,
+    Timezone, Value,
 };
-
-use crate::time_expr::TimeExpr;
 
 // --- Internal types ---------------------------------------------------------
 
@@ -24,6 +57,9 @@ pub(crate) enum Dimension {
     Time,
     RegexMatch,
     Numeral,
+    /// Codes matched by [`Options::identifier_patterns`], claimed after
+    /// resolution rather than produced by rules.
+    Identifier,
     // later: Number, AmountOfMoney, ...
 }
 
@@ -31,6 +67,8 @@ pub(crate) enum Dimension {
 pub(crate) struct Token {
     pub dim: Dimension,
     pub kind: TokenKind,
+    /// Whether the phrase was hedged ("around 3pm", "150ish").
+    pub precision: Precision,
 }
 
 #[derive(Debug, Clone)]
@@ -44,7 +82,48 @@ pub(crate) struct NumeralData {
 pub(crate) enum TokenKind {
     Numeral(NumeralData),
     TimeExpr(TimeExpr),
-    RegexMatch(Vec<String>),
+    RegexMatch(RegexGroups),
+}
+
+/// Capture groups of a regex match, lowercased so productions can match
+/// them case-insensitively. Unmatched optional groups are skipped, so
+/// indices count matched groups only. Derefs to the lowercased groups; the
+/// text as written ("CEST", proper nouns) is kept alongside.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct RegexGroups {
+    lower: Vec<String>,
+    raw: Vec<String>,
+}
+
+impl RegexGroups {
+    pub(crate) fn from_captures(caps: &regex::Captures<'_>) -> Self {
+        let raw: Vec<String> = caps.iter().flatten().map(|g| g.as_str().to_string()).collect();
+        Self { lower: raw.iter().map(|g| g.to_lowercase()).collect(), raw }
+    }
+
+    /// A single group holding `text`, for matches made without a regex.
+    pub(crate) fn from_text(text: &str) -> Self {
+        Self { lower: vec![text.to_lowercase()], raw: vec![text.to_string()] }
+    }
+
+    /// The groups with their original casing.
+    pub(crate) fn raw(&self) -> &[String] {
+        &self.raw
+    }
+}
+
+impl std::ops::Deref for RegexGroups {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.lower
+    }
+}
+
+impl std::fmt::Debug for RegexGroups {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.lower.fmt(f)
+    }
 }
 
 // Trait to convert rule production results into tokens
@@ -54,13 +133,25 @@ pub(crate) trait IntoToken {
 
 impl IntoToken for TimeExpr {
     fn into_token(self) -> Option<Token> {
-        Some(Token { dim: Dimension::Time, kind: TokenKind::TimeExpr(self) })
+        Some(Token { dim: Dimension::Time, kind: TokenKind::TimeExpr(self), precision: Precision::Exact })
     }
 }
 
 impl IntoToken for NumeralData {
     fn into_token(self) -> Option<Token> {
-        Some(Token { dim: Dimension::Numeral, kind: TokenKind::Numeral(self) })
+        Some(Token { dim: Dimension::Numeral, kind: TokenKind::Numeral(self), precision: Precision::Exact })
+    }
+}
+
+/// Production output tagged with an explicit precision, used by rules such as
+/// "about <time-of-day>" or "hhmm-ish".
+pub(crate) struct WithPrecision<T>(pub T, pub Precision);
+
+impl<T: IntoToken> IntoToken for WithPrecision<T> {
+    fn into_token(self) -> Option<Token> {
+        let mut token = self.0.into_token()?;
+        token.precision = self.1;
+        Some(token)
     }
 }
 
@@ -76,6 +167,14 @@ pub(crate) enum Pattern {
     /// Match an already-discovered `Token` using a predicate function. This
     /// allows rules to combine previously found tokens (from the `Stash`).
     Predicate(fn(&Token) -> bool),
+
+    /// Match a sequence of words against the input's lexemes (see
+    /// `engine/lexer.rs`), case-insensitively and with any whitespace between
+    /// them. Whitespace separating it from neighbouring items is absorbed, so
+    /// `[pred!(is_day_expr), words!("at"), pred!(is_time_of_day_expr)]` needs
+    /// no `\s+` items. Produces a `RegexMatch` whose only group is the text
+    /// of the words.
+    Words(&'static str),
 }
 
 pub(crate) type Production = Box<dyn Fn(&[Token]) -> Option<Token> + Send + Sync>;
@@ -93,12 +192,38 @@ pub(crate) struct Rule {
     pub required_phrases: &'static [&'static str],
     /// Optional phrases - ANY one must appear in input for this rule to activate (OR logic).
     pub optional_phrases: &'static [&'static str],
+    /// Literals derived from the leading regex of rules without phrase lists
+    /// (see `engine::derive_phrases`); ANY one must appear in the input.
+    pub derived_phrases: &'static [&'static str],
     /// Bucket mask - rule only activates if input has matching buckets.
     pub buckets: u32,
     /// Required dimensions in stash before this rule activates.
     pub deps: &'static [Dimension],
-    /// Priority for deterministic tie-breaking (higher = preferred).
+    /// Priority for deterministic tie-breaking (higher = preferred), at
+    /// most 100.
     pub priority: u16,
+    /// Semantic hint (`"hint:<name>"`) added to the evidence of nodes this
+    /// rule produces, e.g. `"hint:dob"` for "born on <date>".
+    pub hint: Option<&'static str>,
+    /// Rule families this rule belongs to, for switching it off through
+    /// `Options::disabled_groups`.
+    pub groups: RuleGroups,
+    /// Let consecutive pattern items be separated by whitespace and commas
+    /// without a separator item (`re!(r"\s+")`) between them, so
+    /// `[pred!(is_month_expr), pred!(is_day_of_month_numeral)]` matches both
+    /// "March 3" and "March, 3". Items may also touch ("13-15"), but not
+    /// where both sides are letters or digits ("march3").
+    pub skip_separators: bool,
+    /// Reject a match when the input up to its end ends with this regex, so
+    /// `r"(?i)\bthe\s+sun"` keeps "sun" in "the sun" from being a Sunday.
+    /// The regex sees the match along with the text before it, and can name
+    /// the word matched; the `regex` crate has no lookbehind to do this
+    /// inside the pattern.
+    pub not_preceded_by: Option<&'static Regex>,
+    /// Reject a match when the input from its start starts with this regex,
+    /// e.g. `r"(?i)march\s+forward"`: a lookahead over the match and the
+    /// text after it.
+    pub not_followed_by: Option<&'static Regex>,
 }
 
 impl std::fmt::Debug for Rule {
@@ -126,8 +251,12 @@ pub(crate) struct Range {
 #[derive(Debug, Clone)]
 pub(crate) struct ResolvedToken {
     pub node: Node,
-    pub value: String, // for now, resolved value is just a String
+    /// Canonical string form of `resolved`.
+    pub value: String,
+    pub resolved: Value,
     pub latent: bool,
+    /// Readings of the same span that lost disambiguation.
+    pub alternatives: Vec<Value>,
 }
 
 /// Basic parse tree node produced by rules. `Node` pairs a `Token` with the
@@ -143,6 +272,11 @@ pub(crate) struct Node {
     /// This is derived from the matched route (the tokens consumed by the rule),
     /// and is used as classifier "features".
     pub evidence: Vec<&'static str>,
+    /// The route this node was produced from (empty for raw regex matches).
+    ///
+    /// Shared rather than cloned so the derivation tree stays cheap to carry
+    /// through saturation; only the explain/debug paths walk it.
+    pub children: std::sync::Arc<[Node]>,
 }
 
 // --- Stash: lightweight container for discovered nodes ----------------------
@@ -168,6 +302,12 @@ impl Stash {
         self.nodes.clone()
     }
 
+    /// Names of the rules that produced these nodes, in first-seen order.
+    pub fn rule_names(&self) -> Vec<&'static str> {
+        let mut seen = std::collections::HashSet::new();
+        self.nodes.iter().map(|n| n.rule_name).filter(|name| seen.insert(*name)).collect()
+    }
+
     /// Return nodes sorted by `(start, end)`.
     pub fn to_pos_ordered_list(&self) -> Vec<Node> {
         let mut v = self.nodes.clone();
@@ -180,39 +320,22 @@ impl Stash {
         self.to_pos_ordered_list().into_iter().filter(|n| n.range.start >= position).collect()
     }
 
-    /// Union two stashes; keeps nodes deduplicated by (start,end,dim[,numeral value]).
+    /// Union two stashes, keeping the first of any nodes with the same
+    /// [`NodeKey`](engine::NodeKey), ordered by `(start, end)`.
     ///
-    /// When two nodes share the same position and dimension they are
-    /// de-duplicated; for `Numeral` tokens the numeric value is also
-    /// compared to avoid merging distinct numbers.
+    /// Many rules can produce multiple distinct `Time` values for the same
+    /// span (e.g. raw-input vs a normalized holiday description), and those
+    /// must not be collapsed before resolution; the key tells them apart.
     pub fn union(&self, other: &Stash) -> Stash {
-        let mut combined = self.nodes.clone();
-        combined.extend(other.nodes.clone());
-
-        // Deduplicate by position + dimension + token content.
-        //
-        // This must stay in sync with `Parser::node_key` semantics: many rules
-        // can produce multiple distinct `Time` values for the same span (e.g.
-        // raw-input vs a normalized holiday description), and we must not
-        // collapse them before resolution.
+        let mut seen = std::collections::HashSet::new();
+        let mut combined: Vec<Node> = self
+            .nodes
+            .iter()
+            .chain(&other.nodes)
+            .filter(|n| seen.insert(engine::NodeKey::from_node(n)))
+            .cloned()
+            .collect();
         combined.sort_by_key(|n| (n.range.start, n.range.end));
-        combined.dedup_by(|a, b| {
-            if a.range.start != b.range.start
-                || a.range.end != b.range.end
-                || a.token.dim != b.token.dim
-                || a.rule_name != b.rule_name
-                || a.evidence != b.evidence
-            {
-                return false;
-            }
-
-            match (&a.token.kind, &b.token.kind) {
-                (crate::TokenKind::Numeral(da), crate::TokenKind::Numeral(db)) => da.value == db.value,
-                (crate::TokenKind::TimeExpr(ea), crate::TokenKind::TimeExpr(eb)) => ea == eb,
-                (crate::TokenKind::RegexMatch(ga), crate::TokenKind::RegexMatch(gb)) => ga.first() == gb.first(),
-                _ => false,
-            }
-        });
 
         Stash { nodes: combined }
     }
@@ -278,3 +401,19 @@ impl Stash {
 //         Dimension::Time => "time",
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regex_groups_keep_the_original_casing() {
+        let re = Regex::new(r"(?i)(\d+)(x)?\s*(CEST|pst)").unwrap();
+        let groups = RegexGroups::from_captures(&re.captures("at 9 cest").unwrap());
+        assert_eq!(&*groups, ["9 cest", "9", "cest"]);
+
+        let groups = RegexGroups::from_captures(&re.captures("at 9 CEST").unwrap());
+        assert_eq!(groups[2], "cest");
+        assert_eq!(groups.raw(), ["9 CEST", "9", "CEST"]);
+    }
+}
@@ -0,0 +1,165 @@
+//! A second language for spans the primary rules find nothing in.
+//!
+//! An English app with Spanish-speaking users sees "mañana a las 5", where
+//! the English rules find no more than the number 5. With a
+//! [`FallbackLocale`] in [`Options::fallback_locale`], every sentence or line
+//! the primary parse found nothing but numerals and latent matches in is
+//! parsed again with the fallback rules, if the locale's detection hook
+//! accepts it. What they find replaces those matches, tagged with the locale
+//! in [`Entity::locale`].
+
+use crate::api::dimension_rank;
+use crate::{Context, Entity, Options, ParseResult, RuleSet, parse_with};
+use std::ops::Range;
+
+/// Rules for a second language, set through
+/// [`Options::with_fallback_locale`].
+#[derive(Debug, Clone)]
+pub struct FallbackLocale {
+    /// Tag reported in [`Entity::locale`] for what these rules find, e.g.
+    /// `"es"`.
+    pub locale: String,
+    pub rule_set: RuleSet,
+    /// Whether a sentence is in this language; `None` tries the rules on
+    /// every sentence the primary rules found nothing in.
+    pub detect: Option<fn(&str) -> bool>,
+}
+
+impl FallbackLocale {
+    pub fn new(locale: impl Into<String>, rule_set: RuleSet) -> Self {
+        Self { locale: locale.into(), rule_set, detect: None }
+    }
+
+    /// Only try the rules on sentences `detect` accepts.
+    pub fn with_detector(mut self, detect: fn(&str) -> bool) -> Self {
+        self.detect = Some(detect);
+        self
+    }
+}
+
+/// Whether `entity` is more than a number or a latent match, which digits
+/// in any language yield.
+fn is_finding(entity: &Entity) -> bool {
+    !entity.latent && entity.name != "numeral"
+}
+
+/// Re-parse the sentences of `result` without a finding with the fallback
+/// locale of `options`, if any.
+pub(crate) fn apply_fallback(result: &mut ParseResult, context: &Context, options: &Options) {
+    let Some(fallback) = &options.fallback_locale else { return };
+    let fallback_options = Options { rule_set: Some(fallback.rule_set), fallback_locale: None, ..options.clone() };
+    let mut found: Vec<Entity> = Vec::new();
+    for span in sentences(&result.text) {
+        let overlaps = |e: &Entity| e.start < span.end && span.start < e.end;
+        let sentence = &result.text[span.clone()];
+        if result.results.iter().any(|e| is_finding(e) && overlaps(e))
+            || !fallback.detect.is_none_or(|detect| detect(sentence))
+        {
+            continue;
+        }
+        let local = parse_with(sentence, context, &fallback_options);
+        if !local.results.iter().any(is_finding) {
+            continue;
+        }
+        result.results.retain(|e| !overlaps(e));
+        found.extend(local.results.into_iter().map(|mut e| {
+            (e.start, e.end) = (e.start + span.start, e.end + span.start);
+            e.locale = Some(fallback.locale.clone());
+            e
+        }));
+    }
+    if !found.is_empty() {
+        result.results.extend(found);
+        result.results.sort_by_key(|e| (dimension_rank(&e.name), e.start));
+    }
+}
+
+/// The sentences and lines of `text`, trimmed: it is split at line breaks
+/// and after `.`, `!`, `?` or `;` followed by whitespace, so "5.30" stays
+/// whole.
+fn sentences(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        let next_is_space = chars.peek().is_none_or(|&(_, next)| next.is_whitespace());
+        let end = match c {
+            '\n' => idx,
+            '.' | '!' | '?' | ';' if next_is_space => idx + c.len_utf8(),
+            _ => continue,
+        };
+        spans.push(start..end);
+        start = idx + c.len_utf8();
+    }
+    spans.push(start..text.len());
+    spans
+        .into_iter()
+        .filter_map(|span| {
+            let sentence = &text[span.clone()];
+            let trimmed = sentence.trim_start();
+            let start = span.start + sentence.len() - trimmed.len();
+            let end = start + trimmed.trim_end().len();
+            (start < end).then_some(start..end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::time::helpers::shift::shift_by_grain;
+    use crate::rules::time::helpers::time_expr_with_hms;
+    use crate::time_expr::{Grain, TimeExpr};
+    use crate::{Rule, Token};
+    use chrono::NaiveDate;
+    use once_cell::sync::Lazy;
+
+    /// A scrap of Spanish: "mañana a las <hour>".
+    static SPANISH: Lazy<Vec<Rule>> = Lazy::new(|| {
+        vec![rule! {
+            name: "mañana a las <hour>",
+            pattern: [re!(r"(?i)\bmañana\s+a\s+las\s+(\d{1,2})\b")],
+            prod: |tokens: &[Token]| -> Option<TimeExpr> {
+                let crate::TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+                let hour: i64 = groups.get(1)?.parse().ok()?;
+                crate::rules::time::helpers::intersect_time_exprs(
+                    &shift_by_grain(TimeExpr::Reference, 1, Grain::Day),
+                    &time_expr_with_hms(hour, 0, 0)?,
+                )
+            }
+        }]
+    });
+
+    fn is_spanish(sentence: &str) -> bool {
+        sentence.contains('ñ')
+    }
+
+    #[test]
+    fn sentences_without_entities_take_the_fallback_locale() {
+        let reference = NaiveDate::from_ymd_opt(2013, 2, 12).and_then(|d| d.and_hms_opt(4, 30, 0));
+        let context = Context { reference_time: reference, ..Default::default() };
+        let spanish = FallbackLocale::new("es", RuleSet { rules: &SPANISH });
+        let options = Options::default().with_fallback_locale(spanish.clone().with_detector(is_spanish));
+        let summary = |text: &str, options: &Options| -> Vec<(String, String, Option<String>)> {
+            let result = parse_with(text, &context, options);
+            result.results.into_iter().filter(is_finding).map(|e| (e.body, e.value, e.locale)).collect()
+        };
+        let es = Some("es".to_string());
+
+        assert_eq!(summary("mañana a las 5", &Options::default()), []);
+        assert_eq!(summary("mañana a las 5", &options), [("mañana a las 5".into(), "2013-02-13 05:00:00".into(), es)]);
+
+        // Only sentences the primary rules found nothing in are retried.
+        let text = "See you tomorrow at 3. Si no, mañana a las 5\nor friday";
+        let found = summary(text, &options);
+        assert_eq!(
+            found.iter().map(|(body, _, locale)| (body.as_str(), locale.is_some())).collect::<Vec<_>>(),
+            [("tomorrow at 3", false), ("mañana a las 5", true), ("friday", false)]
+        );
+
+        // The detection hook gates the retry.
+        let never = Options::default().with_fallback_locale(spanish.with_detector(|_| false));
+        assert_eq!(summary("mañana a las 5", &never), []);
+        assert_eq!(sentences(" a. b 5.30!\n\nc "), [1..3, 4..11, 13..14]);
+    }
+}
@@ -21,6 +21,13 @@ macro_rules! pred {
     };
 }
 
+#[macro_export]
+macro_rules! words {
+    ($words:literal) => {
+        $crate::Pattern::Words($words)
+    };
+}
+
 #[macro_export]
 macro_rules! rule {
     (
@@ -31,12 +38,17 @@ macro_rules! rule {
         $(, buckets: $buckets:expr)?
         $(, deps: [ $($dep:expr),* $(,)? ])?
         $(, priority: $priority:expr)?
+        $(, hint: $hint:literal)?
+        $(, group: $group:expr)?
+        $(, skip_separators: $skip:expr)?
+        $(, not_preceded_by: $before:literal)?
+        $(, not_followed_by: $after:literal)?
         , prod: |$tokens_expr:ident : &[$tok_ty_expr:ty]| -> $ret_ty:ty $body_expr:block
         $(,)?
     ) => {{
         $crate::Rule {
             name: $name,
-            pattern: vec![ $($pat),* ],
+            pattern: $crate::engine::rule_patterns($name, || vec![ $($pat),* ]),
             production: Box::new(move |$tokens_expr: &[$tok_ty_expr]| {
                 use $crate::IntoToken;
                 let result: $ret_ty = $body_expr;
@@ -44,9 +56,26 @@ macro_rules! rule {
             }),
             required_phrases: &[ $($($req_phrase),*)? ],
             optional_phrases: &[ $($($opt_phrase),*)? ],
+            derived_phrases: &[],
             buckets: { 0 $(| $buckets)? },
             deps: &[ $($($dep),*)? ],
             priority: { 0 $(+ $priority)? },
+            hint: {
+                let hints: &[&'static str] = &[$(concat!("hint:", $hint))?];
+                hints.first().copied()
+            },
+            groups: { $crate::RuleGroups::empty() $(| $group)? },
+            skip_separators: { false $(|| $skip)? },
+            not_preceded_by: $crate::rule!(@guard $(concat!("(?:", $before, ")$"))?),
+            not_followed_by: $crate::rule!(@guard $(concat!("^(?:", $after, ")"))?),
         }
     }};
+    (@guard) => {
+        None
+    };
+    (@guard $pat:expr) => {{
+        static GUARD: once_cell::sync::Lazy<regex::Regex> =
+            once_cell::sync::Lazy::new(|| regex::Regex::new($pat).unwrap());
+        Some(&*GUARD)
+    }};
 }
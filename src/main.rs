@@ -1,10 +1,16 @@
+mod bench;
+mod bench_inputs;
+mod corpus;
 mod debug_report;
+mod repl;
+mod sweep;
 
-use astorion::{Context, Options, parse_verbose_with};
+use astorion::{Context, Options, RuleSet, parse_verbose_with};
 use chrono::NaiveDateTime;
 use std::io::{self, IsTerminal, Read};
 
-const DEFAULT_REFERENCE: &str = "2013-02-12T04:30:00";
+pub(crate) const DEFAULT_REFERENCE: &str = "2013-02-12T04:30:00";
+const DEFAULT_BENCH_ITERATIONS: usize = 20;
 
 fn main() {
     let config = match parse_args() {
@@ -15,13 +21,49 @@ fn main() {
         }
     };
 
-    let ctx = Context { reference_time: config.reference_time };
+    let ctx = Context { reference_time: Some(config.reference_time), ..Default::default() };
     let mut opts = Options::default();
     if config.regex_profile {
         opts.enable_regex_profiling_mut();
     }
+    if config.transport {
+        opts.enable_transport_shorthand_mut();
+    }
+    if config.typos {
+        opts.enable_typo_tolerance_mut();
+    }
+    if let Some(path) = &config.rules {
+        match load_rules(path) {
+            Ok(rules) => opts.set_rule_set(rules),
+            Err(err) => {
+                eprintln!("{err}");
+                std::process::exit(2);
+            }
+        }
+    }
+    if let Some(args) = &config.corpus {
+        std::process::exit(corpus::run(args, &opts));
+    }
+    if config.bench {
+        let samples = bench::run(&ctx, &opts, config.bench_iterations);
+        debug_report::print_bench(&samples, config.bench_iterations, config.color);
+        return;
+    }
+    if let Some(range) = &config.sweep {
+        let rows = sweep::run(&config.input, range, &opts);
+        debug_report::print_sweep(&config.input, &rows, config.color);
+        return;
+    }
+    if config.repl {
+        repl::run(ctx, opts, config.color);
+        return;
+    }
+    if let Err(err) = opts.check_input(&config.input) {
+        eprintln!("{err}");
+        std::process::exit(2);
+    }
     let res = parse_verbose_with(&config.input, &ctx, &opts);
-    debug_report::print_run(&config.input, &res.details, config.color);
+    debug_report::print_run(&config.input, &res, config.color, config.explain);
 }
 
 struct CliConfig {
@@ -29,6 +71,15 @@ struct CliConfig {
     reference_time: NaiveDateTime,
     color: bool,
     regex_profile: bool,
+    transport: bool,
+    typos: bool,
+    rules: Option<String>,
+    explain: bool,
+    repl: bool,
+    sweep: Option<sweep::SweepRange>,
+    bench: bool,
+    bench_iterations: usize,
+    corpus: Option<corpus::CorpusArgs>,
 }
 
 fn parse_args() -> Result<CliConfig, String> {
@@ -36,7 +87,20 @@ fn parse_args() -> Result<CliConfig, String> {
     let mut reference_time = parse_reference(DEFAULT_REFERENCE)?;
     let mut color = io::stdout().is_terminal();
     let mut regex_profile = false;
-    let mut args = std::env::args().skip(1).peekable();
+    let mut transport = false;
+    let mut typos = false;
+    let mut rules: Option<String> = None;
+    let mut explain = false;
+    let mut repl = false;
+    let mut sweep_reference: Option<String> = None;
+    let mut sweep_step = "1d".to_string();
+    let mut bench = false;
+    let mut bench_iterations = DEFAULT_BENCH_ITERATIONS;
+    let mut snapshot: Option<String> = None;
+    let mut update = false;
+    let argv = std::env::args().skip(1).collect::<Vec<_>>();
+    let (corpus, rest) = corpus::split_subcommand(&argv)?;
+    let mut args = rest.iter().cloned().peekable();
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
@@ -51,6 +115,29 @@ fn parse_args() -> Result<CliConfig, String> {
             "--color" => color = true,
             "--no-color" => color = false,
             "--regex-profile" => regex_profile = true,
+            "--transport" => transport = true,
+            "--typos" => typos = true,
+            "--explain" => explain = true,
+            "--repl" => repl = true,
+            "--bench" => bench = true,
+            "--update" => update = true,
+            "--snapshot" => {
+                snapshot = Some(args.next().ok_or_else(|| "error: --snapshot expects a file".to_string())?);
+            }
+            "--bench-iterations" => {
+                let value = args.next().ok_or_else(|| "error: --bench-iterations expects a value".to_string())?;
+                bench_iterations = parse_iterations(&value)?;
+            }
+            "--sweep-reference" => {
+                let value = args.next().ok_or_else(|| "error: --sweep-reference expects a value".to_string())?;
+                sweep_reference = Some(value);
+            }
+            "--sweep-step" => {
+                sweep_step = args.next().ok_or_else(|| "error: --sweep-step expects a value".to_string())?;
+            }
+            "--rules" => {
+                rules = Some(args.next().ok_or_else(|| "error: --rules expects a file".to_string())?);
+            }
             "--reference" => {
                 let value = args.next().ok_or_else(|| "error: --reference expects a value".to_string())?;
                 reference_time = parse_reference(&value)?;
@@ -76,6 +163,19 @@ fn parse_args() -> Result<CliConfig, String> {
                 let value = arg.trim_start_matches("--reference=");
                 reference_time = parse_reference(value)?;
             }
+            _ if arg.starts_with("--sweep-reference=") => {
+                sweep_reference = Some(arg.trim_start_matches("--sweep-reference=").to_string());
+            }
+            _ if arg.starts_with("--sweep-step=") => {
+                sweep_step = arg.trim_start_matches("--sweep-step=").to_string();
+            }
+            _ if arg.starts_with("--bench-iterations=") => {
+                let value = arg.trim_start_matches("--bench-iterations=");
+                bench_iterations = parse_iterations(value)?;
+            }
+            _ if arg.starts_with("--snapshot=") => {
+                snapshot = Some(arg.trim_start_matches("--snapshot=").to_string());
+            }
             _ if arg.starts_with("--input=") => {
                 let value = arg.trim_start_matches("--input=");
                 if input.is_some() {
@@ -97,6 +197,53 @@ fn parse_args() -> Result<CliConfig, String> {
         }
     }
 
+    if bench && repl {
+        return Err("error: --bench and --repl cannot be combined".to_string());
+    }
+    if corpus.is_some() && (bench || repl) {
+        return Err("error: corpus cannot be combined with --bench or --repl".to_string());
+    }
+    if (update || snapshot.is_some()) && !matches!(corpus, Some((corpus::Command::Run, _))) {
+        return Err("error: --update and --snapshot need `corpus run`".to_string());
+    }
+    let step = sweep::parse_step(&sweep_step)?;
+    let sweep = sweep_reference.map(|range| sweep::parse_range(&range, step)).transpose()?;
+    if sweep.is_some() && (bench || repl || corpus.is_some()) {
+        return Err("error: --sweep-reference cannot be combined with --bench, --repl or corpus".to_string());
+    }
+    let corpus = corpus.map(|(command, file)| corpus::CorpusArgs {
+        command,
+        file: file.into(),
+        snapshot: snapshot.map(Into::into),
+        update,
+        reference_time,
+    });
+    if bench || repl || corpus.is_some() {
+        if input.is_some() {
+            let flag = match &corpus {
+                Some(args) => args.command.name(),
+                None if bench => "--bench",
+                None => "--repl",
+            };
+            return Err(format!("error: {flag} does not take input"));
+        }
+        return Ok(CliConfig {
+            input: String::new(),
+            reference_time,
+            color,
+            regex_profile,
+            transport,
+            typos,
+            rules,
+            explain,
+            repl,
+            sweep,
+            bench,
+            bench_iterations,
+            corpus,
+        });
+    }
+
     let input = match input {
         Some(value) => value,
         None => read_stdin_input()?,
@@ -106,7 +253,21 @@ fn parse_args() -> Result<CliConfig, String> {
         return Err(format!("error: no input provided\n\n{}", help_text()));
     }
 
-    Ok(CliConfig { input, reference_time, color, regex_profile })
+    Ok(CliConfig {
+        input,
+        reference_time,
+        color,
+        regex_profile,
+        transport,
+        typos,
+        rules,
+        explain,
+        repl,
+        sweep,
+        bench,
+        bench_iterations,
+        corpus,
+    })
 }
 
 fn read_stdin_input() -> Result<String, String> {
@@ -115,11 +276,29 @@ fn read_stdin_input() -> Result<String, String> {
     Ok(buffer)
 }
 
-fn parse_reference(value: &str) -> Result<NaiveDateTime, String> {
+pub(crate) fn parse_reference(value: &str) -> Result<NaiveDateTime, String> {
     NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
         .map_err(|_| format!("error: invalid --reference '{value}' (expected YYYY-MM-DDTHH:MM:SS)"))
 }
 
+#[cfg(feature = "rule-files")]
+fn load_rules(path: &str) -> Result<RuleSet, String> {
+    let text = std::fs::read_to_string(path).map_err(|err| format!("error: failed to read {path}: {err}"))?;
+    RuleSet::from_toml(&text).map_err(|err| format!("error: {path}: {err}"))
+}
+
+#[cfg(not(feature = "rule-files"))]
+fn load_rules(_path: &str) -> Result<RuleSet, String> {
+    Err("error: --rules requires the `rule-files` feature".to_string())
+}
+
+fn parse_iterations(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("error: invalid --bench-iterations '{value}' (expected a positive integer)")),
+    }
+}
+
 fn print_help() {
     println!("{}", help_text());
 }
@@ -133,6 +312,10 @@ Duckling-style parsing engine CLI.
 Usage:
   astorion [OPTIONS] [--] <input...>
   astorion [OPTIONS] --input <text>
+  astorion [OPTIONS] --bench
+  astorion [OPTIONS] --repl
+  astorion corpus run <file> [--update] [--snapshot <file>] [OPTIONS]
+  astorion corpus coverage <file> [OPTIONS]
 
 Options:
   -i, --input <text>         Input text to parse. If omitted, reads remaining args
@@ -142,15 +325,46 @@ Options:
   --color                    Force ANSI color output.
   --no-color                 Disable ANSI color output.
     --regex-profile            Collect regex timing stats (slower; CLI only).
+  --transport                Also recognize itinerary shorthand (14MAR,
+                             06JUN25 0830, dep 0735 arr 0950).
+  --typos                    Correct misspelled weekdays, months and day words
+                             (wednseday, febuary, tommorow) before parsing.
+  --rules <file>             Add the rules in a TOML rule file (needs the
+                             rule-files feature).
+  --explain                  Show the rule chain and derivation tree (with the
+                             pre-normalization expression) for each result.
+  --repl                     Start an interactive session; rules stay compiled
+                             between lines. Type :help inside for commands.
+  --sweep-reference <a>..<b> Parse the input once per reference time between a
+                             and b (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS) and
+                             print a table, marking discontinuities.
+  --sweep-step <n><unit>     Sweep step: m, h, d or w. Default: 1d
+  --bench                    Time the built-in representative inputs instead of
+                             parsing user input.
+  --bench-iterations <n>     Iterations per input in --bench mode. Default: {default_iterations}
+  --update                   Write the `corpus run` snapshot instead of comparing.
+  --snapshot <file>          Snapshot file for `corpus run`. Default: <file>.snap
   -h, --help                 Show this help message.
   -V, --version              Print version information.
 
+Corpus commands:
+  corpus run <file>          Parse every line of a corpus file and compare the
+                             results against its snapshot (<file>.snap). Blank
+                             lines and '#' comments are skipped; '@reference
+                             <timestamp>' sets the reference time for the lines
+                             that follow.
+  corpus coverage <file>     List the rules a corpus never fires, fires but
+                             never returns, or only fires alike other rules.
+  Both must come first; to parse text starting with \"corpus run\", use --.
+
 Exit codes:
   0  Success.
   1  Internal error.
   2  Invalid arguments or missing input.
+  3  Corpus snapshot mismatch (corpus run).
 ",
         version = env!("CARGO_PKG_VERSION"),
-        default_reference = DEFAULT_REFERENCE
+        default_reference = DEFAULT_REFERENCE,
+        default_iterations = DEFAULT_BENCH_ITERATIONS
     )
 }
@@ -0,0 +1,149 @@
+//! Input rewriting before rule matching.
+//!
+//! Apps often know things about their text the rules do not: it is HTML,
+//! its users write "EOW" for "end of the week", its keyboards produce "’".
+//! A [`Preprocessor`] describes such rewrites as [`TextEdit`]s of the text
+//! it is given; the engine applies them in order before trigger scanning and
+//! maps entity spans back through every layer, so entities still point at
+//! what was written.
+
+use crate::TextEdit;
+use regex::Regex;
+use std::fmt;
+use std::ops::Range;
+
+/// A rewrite of the input applied before parsing, set through
+/// [`Options::with_preprocessor`](crate::Options::with_preprocessor).
+///
+/// Implemented for closures, so any `Fn(&str) -> Vec<TextEdit>` is a
+/// preprocessor. Edits are byte ranges of the text passed in; those off
+/// character boundaries or overlapping an earlier one are dropped. Keep
+/// edits within a line: [`reparse_with`](crate::reparse_with) preprocesses
+/// the changed lines on their own.
+pub trait Preprocessor: Send + Sync {
+    /// The edits turning `text` into what the rules should see.
+    fn edits(&self, text: &str) -> Vec<TextEdit>;
+}
+
+impl<F> Preprocessor for F
+where
+    F: Fn(&str) -> Vec<TextEdit> + Send + Sync,
+{
+    fn edits(&self, text: &str) -> Vec<TextEdit> {
+        self(text)
+    }
+}
+
+impl fmt::Debug for dyn Preprocessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<preprocessor>")
+    }
+}
+
+/// Whole words or phrases rewritten to others, matched without regard to
+/// ASCII case: `Synonyms::new().with("EOW", "end of the week")`.
+#[derive(Debug, Clone, Default)]
+pub struct Synonyms {
+    pairs: Vec<(String, String)>,
+}
+
+impl Synonyms {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rewrite `word` to `replacement`. Earlier pairs win where matches
+    /// overlap.
+    pub fn with(mut self, word: impl Into<String>, replacement: impl Into<String>) -> Self {
+        self.pairs.push((word.into().to_ascii_lowercase(), replacement.into()));
+        self
+    }
+}
+
+impl Preprocessor for Synonyms {
+    fn edits(&self, text: &str) -> Vec<TextEdit> {
+        let lower = text.to_ascii_lowercase();
+        let mut edits: Vec<TextEdit> = Vec::new();
+        for (word, replacement) in &self.pairs {
+            for range in whole_words(&lower, word) {
+                if !edits.iter().any(|e| e.range.start < range.end && range.start < e.range.end) {
+                    edits.push(TextEdit::new(range, replacement.clone()));
+                }
+            }
+        }
+        edits
+    }
+}
+
+/// Where `word` occurs in `lower` as whole words. Both are ASCII-lowercased,
+/// which keeps every byte offset of the text they came from in place.
+pub(crate) fn whole_words<'a>(lower: &'a str, word: &'a str) -> impl Iterator<Item = Range<usize>> + 'a {
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    lower.match_indices(word).filter(move |_| !word.is_empty()).map(|(start, word)| start..start + word.len()).filter(
+        move |range| !is_word(lower[..range.start].chars().next_back()) && !is_word(lower[range.end..].chars().next()),
+    )
+}
+
+/// HTML and XML tags removed and the common character references
+/// (`&amp;`, `&nbsp;`, ...) decoded, for text taken from markup.
+///
+/// Tags become a space, so "Mon<br>Tue" stays two words.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StripMarkup;
+
+impl Preprocessor for StripMarkup {
+    fn edits(&self, text: &str) -> Vec<TextEdit> {
+        static MARKUP: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+            Regex::new(r"(?i)</?[a-z][^<>\n]*>|&(?:amp|lt|gt|quot|apos|nbsp|#39);").unwrap()
+        });
+        MARKUP
+            .find_iter(text)
+            .map(|m| {
+                let replacement = match m.as_str().to_ascii_lowercase().as_str() {
+                    "&amp;" => "&",
+                    "&lt;" => "<",
+                    "&gt;" => ">",
+                    "&quot;" => "\"",
+                    "&apos;" | "&#39;" => "'",
+                    _ => " ",
+                };
+                TextEdit::new(m.range(), replacement)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Options, parse_with};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn preprocessed_entities_point_at_the_original_text() {
+        let reference = NaiveDate::from_ymd_opt(2013, 2, 12).and_then(|d| d.and_hms_opt(4, 30, 0));
+        let context = Context { reference_time: reference, ..Default::default() };
+        let options = Options::default()
+            .with_preprocessor(StripMarkup)
+            .with_preprocessor(Synonyms::new().with("EOW", "end of the week").with("tmrw", "tomorrow"));
+        let summary = |text: &str, options: &Options| -> Vec<(usize, usize, String)> {
+            let result = parse_with(text, &context, options);
+            result.results.into_iter().filter(|e| e.name == "time").map(|e| (e.start, e.end, e.body)).collect()
+        };
+
+        assert_eq!(summary("due EOW", &Options::default()), []);
+        assert_eq!(summary("due EOW", &options), [(4, 7, "EOW".to_string())]);
+        assert_eq!(summary("<p>Ship <b>tmrw</b> at 5</p>", &options), [(11, 24, "tmrw</b> at 5".to_string())]);
+
+        // Closures are preprocessors too.
+        let mut shouting = options.clone();
+        shouting.add_preprocessor(|text: &str| {
+            text.match_indices("!!").map(|(i, m)| TextEdit::new(i..i + m.len(), "")).collect::<Vec<_>>()
+        });
+        assert_eq!(summary("eow!! or friday", &shouting), [(0, 3, "eow".to_string()), (9, 15, "friday".to_string())]);
+
+        // Only whole words are rewritten.
+        let edits = Synonyms::new().with("eow", "end of week").edits("meow, EOW.");
+        assert_eq!(edits, [TextEdit::new(6..9, "end of week")]);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::debug_report;
+use astorion::{Context, Entity, Options, parse_with};
+use chrono_tz::Tz;
+use std::io::{self, BufRead, Write};
+
+/// Dimensions accepted by `:set dims`.
+const KNOWN_DIMS: &[&str] = &["time", "numeral", "identifier"];
+
+/// Mutable REPL settings, changed with `:set`.
+struct Session {
+    ctx: Context,
+    opts: Options,
+    /// Dimensions to display; `None` shows everything.
+    dims: Option<Vec<String>>,
+}
+
+/// What the loop does after a `:command`.
+#[derive(Debug, PartialEq)]
+enum Reply {
+    /// Print the text and read the next line.
+    Continue(String),
+    Quit,
+}
+
+/// Read lines from stdin and parse each one until EOF or `:quit`.
+///
+/// The default ruleset is compiled once up front, so every line after the
+/// first is parsed without paying rule construction costs again.
+pub fn run(ctx: Context, opts: Options, color: bool) {
+    let mut session = Session { ctx, opts, dims: None };
+    parse_with("", &session.ctx, &session.opts);
+
+    println!("astorion {} REPL. Type :help for commands, :quit to exit.", env!("CARGO_PKG_VERSION"));
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("astorion> ");
+        let _ = io::stdout().flush();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(err)) => {
+                eprintln!("error: failed to read stdin: {err}");
+                break;
+            }
+            None => {
+                println!();
+                break;
+            }
+        };
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(command) = line.strip_prefix(':') {
+            match run_command(&mut session, command) {
+                Ok(Reply::Continue(text)) if text.is_empty() => continue,
+                Ok(Reply::Continue(text)) => {
+                    println!("{text}");
+                    continue;
+                }
+                Ok(Reply::Quit) => break,
+                Err(err) => {
+                    eprintln!("{err}");
+                    continue;
+                }
+            }
+        }
+
+        let res = parse_with(line, &session.ctx, &session.opts);
+        debug_report::print_entities(&session.visible(&res.results), res.elapsed, color);
+    }
+}
+
+impl Session {
+    /// The entities of the dimensions picked with `:set dims`.
+    fn visible<'a>(&self, entities: &'a [Entity]) -> Vec<&'a Entity> {
+        entities.iter().filter(|ent| self.dims.as_ref().is_none_or(|dims| dims.contains(&ent.name))).collect()
+    }
+}
+
+/// Execute a `:command` (without the colon).
+fn run_command(session: &mut Session, command: &str) -> Result<Reply, String> {
+    let mut parts = command.split_whitespace();
+    match parts.next() {
+        Some("q" | "quit" | "exit") => return Ok(Reply::Quit),
+        Some("h" | "help") => return Ok(Reply::Continue(help_text())),
+        Some("show") => {
+            let dims = session.dims.as_ref().map_or_else(|| "all".to_string(), |dims| dims.join(","));
+            let reference = session
+                .ctx
+                .reference_time
+                .map_or_else(|| "none".to_string(), |dt| dt.format("%Y-%m-%dT%H:%M:%S").to_string());
+            let timezone = session.opts.local_timezone.map_or_else(|| "UTC".to_string(), |tz| tz.name().to_string());
+            return Ok(Reply::Continue(format!("reference = {reference}\ntimezone  = {timezone}\ndims      = {dims}")));
+        }
+        Some("ref") => match (parts.next(), parts.next()) {
+            (Some(value), None) => session.ctx.reference_time = Some(crate::parse_reference(value)?),
+            _ => return Err("error: usage: :ref <YYYY-MM-DDTHH:MM:SS>".to_string()),
+        },
+        Some("tz") => match (parts.next(), parts.next()) {
+            (Some(value), None) => session.opts.local_timezone = parse_timezone(value)?,
+            _ => return Err("error: usage: :tz <IANA zone|utc>".to_string()),
+        },
+        Some("set") => match (parts.next(), parts.next(), parts.next()) {
+            (Some("reference"), Some(value), None) => {
+                session.ctx.reference_time = Some(crate::parse_reference(value)?);
+            }
+            (Some("dims"), Some(value), None) => session.dims = parse_dims(value)?,
+            _ => return Err("error: usage: :set reference <YYYY-MM-DDTHH:MM:SS> | :set dims <dim,...|all>".to_string()),
+        },
+        _ => return Err(format!("error: unknown command ':{command}' (try :help)")),
+    }
+    Ok(Reply::Continue(String::new()))
+}
+
+/// `utc` clears the override, so resolution runs in UTC again.
+fn parse_timezone(value: &str) -> Result<Option<Tz>, String> {
+    if value.eq_ignore_ascii_case("utc") {
+        return Ok(None);
+    }
+    value.parse::<Tz>().map(Some).map_err(|_| format!("error: unknown timezone '{value}' (expected e.g. Europe/Paris)"))
+}
+
+fn parse_dims(value: &str) -> Result<Option<Vec<String>>, String> {
+    if value == "all" {
+        return Ok(None);
+    }
+
+    let mut dims = Vec::new();
+    for dim in value.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        if !KNOWN_DIMS.contains(&dim) {
+            return Err(format!("error: unknown dimension '{dim}' (expected one of: {})", KNOWN_DIMS.join(", ")));
+        }
+        dims.push(dim.to_string());
+    }
+    if dims.is_empty() {
+        return Err("error: :set dims expects at least one dimension".to_string());
+    }
+    Ok(Some(dims))
+}
+
+fn help_text() -> String {
+    format!(
+        "Commands:
+  :set reference <ts>     Change the reference time (YYYY-MM-DDTHH:MM:SS).
+  :ref <ts>               Same as :set reference.
+  :tz <zone|utc>          Resolve in an IANA timezone, or in UTC again.
+  :set dims <d1,d2|all>   Only show the given dimensions ({dims}).
+  :show                   Print the current settings.
+  :help                   Show this message.
+  :quit                   Exit (Ctrl-D also works).
+
+Any other line is parsed with the current settings.",
+        dims = KNOWN_DIMS.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session() -> Session {
+        let reference = crate::parse_reference(crate::DEFAULT_REFERENCE).unwrap();
+        Session {
+            ctx: Context { reference_time: Some(reference), ..Default::default() },
+            opts: Options::default(),
+            dims: None,
+        }
+    }
+
+    fn values(session: &Session, input: &str) -> Vec<String> {
+        let res = parse_with(input, &session.ctx, &session.opts);
+        session.visible(&res.results).iter().map(|ent| format!("{}={}", ent.name, ent.value)).collect()
+    }
+
+    #[test]
+    fn reference_commands_move_later_parses() {
+        let mut session = session();
+        assert_eq!(values(&session, "tomorrow"), ["time=2013-02-13 00:00:00"]);
+
+        assert_eq!(run_command(&mut session, "ref 2014-01-01T00:00:00"), Ok(Reply::Continue(String::new())));
+        assert_eq!(values(&session, "tomorrow"), ["time=2014-01-02 00:00:00"]);
+        assert_eq!(run_command(&mut session, "set reference 2015-06-01T00:00:00"), Ok(Reply::Continue(String::new())));
+        assert_eq!(values(&session, "tomorrow"), ["time=2015-06-02 00:00:00"]);
+
+        assert!(run_command(&mut session, "ref tomorrow").unwrap_err().contains("invalid --reference 'tomorrow'"));
+        assert!(run_command(&mut session, "ref").unwrap_err().starts_with("error: usage: :ref"));
+        assert_eq!(values(&session, "tomorrow"), ["time=2015-06-02 00:00:00"]);
+    }
+
+    #[test]
+    fn tz_command_sets_and_clears_the_local_timezone() {
+        let mut session = session();
+        run_command(&mut session, "tz Etc/GMT+2").unwrap();
+        assert_eq!(session.opts.local_timezone, Some(Tz::Etc__GMTPlus2));
+        assert_eq!(values(&session, "15:00 GMT")[0], "time=2013-02-12 13:00:00");
+
+        let show = run_command(&mut session, "show").unwrap();
+        assert_eq!(
+            show,
+            Reply::Continue("reference = 2013-02-12T04:30:00\ntimezone  = Etc/GMT+2\ndims      = all".to_string())
+        );
+
+        assert!(run_command(&mut session, "tz Mars/Olympus").unwrap_err().contains("unknown timezone 'Mars/Olympus'"));
+        assert_eq!(session.opts.local_timezone, Some(Tz::Etc__GMTPlus2));
+        run_command(&mut session, "tz utc").unwrap();
+        assert_eq!(session.opts.local_timezone, None);
+    }
+
+    #[test]
+    fn dims_filter_the_printed_entities() {
+        let mut session = session();
+        let all = values(&session, "tomorrow at 5pm for 42 guests");
+        assert!(all.iter().any(|v| v.starts_with("time=")) && all.iter().any(|v| v.starts_with("numeral=")));
+
+        run_command(&mut session, "set dims numeral").unwrap();
+        assert!(values(&session, "tomorrow at 5pm for 42 guests").iter().all(|v| v.starts_with("numeral=")));
+        assert!(run_command(&mut session, "set dims weather").unwrap_err().contains("unknown dimension 'weather'"));
+        run_command(&mut session, "set dims identifier").unwrap();
+        assert_eq!(values(&session, "order #5678 ships tomorrow"), ["identifier=#5678"]);
+        run_command(&mut session, "set dims all").unwrap();
+        assert_eq!(values(&session, "tomorrow at 5pm for 42 guests"), all);
+    }
+
+    #[test]
+    fn quit_help_and_unknown_commands() {
+        let mut session = session();
+        assert_eq!(run_command(&mut session, "quit"), Ok(Reply::Quit));
+        assert_eq!(run_command(&mut session, "q"), Ok(Reply::Quit));
+        assert!(
+            matches!(run_command(&mut session, "help"), Ok(Reply::Continue(text)) if text.contains(":tz <zone|utc>"))
+        );
+        assert_eq!(
+            run_command(&mut session, "frobnicate"),
+            Err("error: unknown command ':frobnicate' (try :help)".to_string())
+        );
+    }
+}
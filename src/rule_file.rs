@@ -0,0 +1,359 @@
+//! Declarative rule files, for adding rules without writing Rust.
+//!
+//! A rule file is TOML with one `[[rule]]` table per rule: a name, a pattern
+//! of regexes, word sequences and predicate references, and a production
+//! template naming one of a few common `TimeExpr` shapes:
+//!
+//! ```toml
+//! [[rule]]
+//! name = "fortnight from now"
+//! pattern = [{ regex = '(?i)\bfortnight from now\b' }]
+//! produce = { shift = { amount = 14, grain = "day" } }
+//!
+//! [[rule]]
+//! name = "<day> after lunch"
+//! pattern = [{ predicate = "day" }, { regex = '(?i)\s+after lunch\b' }]
+//! produce = { at_time = { expr = 0, hour = 13 } }
+//!
+//! [[rule]]
+//! name = "<day> before lunch"
+//! pattern = [{ predicate = "day" }, { words = "before lunch" }]
+//! produce = { at_time = { expr = 0, hour = 11 } }
+//! ```
+//!
+//! A `words` item matches whole words case-insensitively, with any whitespace
+//! between them and around the item.
+//!
+//! Pattern items are indexed from 0 in the templates. Each loaded rule is
+//! built through [`RuleBuilder`] and joins the default rules in a
+//! [`RuleSet`]; the rules live for the rest of the process, so load a file
+//! once rather than per parse.
+
+use crate::api::RuleSet;
+use crate::rules::time::helpers::shift::{shift_by_grain, shift_datetime_by_grain};
+use crate::rules::time::helpers::{intersect_time_exprs, time_expr_with_hms};
+use crate::rules::time::predicates::*;
+use crate::time_expr::{Constraint, Grain, TimeExpr};
+use crate::{IntoToken, Pattern, Production, Rule, RuleGroups, Token};
+use chrono::Weekday;
+use regex::Regex;
+use serde::Deserialize;
+use std::fmt;
+
+/// A rule file that could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleFileError {
+    /// Name of the offending rule, when the file itself parsed.
+    pub rule: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for RuleFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.rule {
+            Some(rule) => write!(f, "rule \"{rule}\": {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for RuleFileError {}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleFile {
+    #[serde(default)]
+    rule: Vec<RuleSpec>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleSpec {
+    name: String,
+    pattern: Vec<PatternSpec>,
+    produce: Template,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum PatternSpec {
+    Regex(String),
+    Words(String),
+    Predicate(String),
+}
+
+/// Production templates; `expr` fields are pattern item indices.
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum Template {
+    /// The time matched by a predicate item, unchanged.
+    Copy(usize),
+    /// `amount` grains from the reference, or from item `expr`.
+    Shift { amount: i32, grain: String, expr: Option<usize> },
+    /// A fixed day of the year.
+    MonthDay { month: u32, day: u32 },
+    /// A clock time, today or on the day of item `expr`.
+    AtTime {
+        hour: i64,
+        #[serde(default)]
+        minute: i64,
+        expr: Option<usize>,
+    },
+    /// The next such weekday.
+    Weekday(String),
+    /// Two items combined, like "Friday" and "3pm".
+    Intersect(usize, usize),
+    /// From item `start` to item `end`.
+    Interval { start: usize, end: usize },
+}
+
+/// Builds a runtime [`Rule`] piece by piece; the counterpart of `rule!` for
+/// rules not known at compile time. Names and regexes are leaked to get the
+/// `'static` lifetimes rules carry.
+pub(crate) struct RuleBuilder {
+    name: &'static str,
+    pattern: Vec<Pattern>,
+}
+
+impl RuleBuilder {
+    pub(crate) fn new(name: &str) -> Self {
+        Self { name: Box::leak(name.to_string().into_boxed_str()), pattern: Vec::new() }
+    }
+
+    pub(crate) fn regex(mut self, regex: Regex) -> Self {
+        self.pattern.push(Pattern::Regex(Box::leak(Box::new(regex))));
+        self
+    }
+
+    pub(crate) fn words(mut self, words: &str) -> Self {
+        self.pattern.push(Pattern::Words(Box::leak(words.to_string().into_boxed_str())));
+        self
+    }
+
+    pub(crate) fn predicate(mut self, predicate: fn(&Token) -> bool) -> Self {
+        self.pattern.push(Pattern::Predicate(predicate));
+        self
+    }
+
+    pub(crate) fn production(self, production: Production) -> Rule {
+        Rule {
+            name: self.name,
+            pattern: self.pattern,
+            production,
+            required_phrases: &[],
+            optional_phrases: &[],
+            derived_phrases: &[],
+            buckets: 0,
+            deps: &[],
+            priority: 0,
+            hint: None,
+            groups: RuleGroups::empty(),
+            skip_separators: false,
+            not_preceded_by: None,
+            not_followed_by: None,
+        }
+    }
+}
+
+impl RuleSet {
+    /// The default rules plus those in the TOML rule file `text`.
+    pub fn from_toml(text: &str) -> Result<RuleSet, RuleFileError> {
+        let file: RuleFile =
+            toml::from_str(text).map_err(|err| RuleFileError { rule: None, message: err.to_string() })?;
+        let mut rules = crate::rules::time::rules::get();
+        for spec in file.rule {
+            let rule = build(&spec)
+                .and_then(|rule| crate::engine::check_rule(&rule).map(|()| rule))
+                .map_err(|message| RuleFileError { rule: Some(spec.name.clone()), message })?;
+            rules.push(rule);
+        }
+        Ok(RuleSet { rules: Box::leak(rules.into_boxed_slice()) })
+    }
+}
+
+fn build(spec: &RuleSpec) -> Result<Rule, String> {
+    if spec.pattern.is_empty() {
+        return Err("empty pattern".to_string());
+    }
+    let mut builder = RuleBuilder::new(&spec.name);
+    for item in &spec.pattern {
+        builder = match item {
+            PatternSpec::Regex(source) => builder.regex(Regex::new(source).map_err(|err| err.to_string())?),
+            PatternSpec::Words(words) if words.trim().is_empty() => return Err("empty words".to_string()),
+            PatternSpec::Words(words) => builder.words(words),
+            PatternSpec::Predicate(name) => builder.predicate(predicate(name)?),
+        };
+    }
+    check_template(&spec.produce, spec.pattern.len())?;
+    let template = spec.produce.clone();
+    Ok(builder.production(Box::new(move |tokens: &[Token]| produce(&template, tokens)?.into_token())))
+}
+
+fn predicate(name: &str) -> Result<fn(&Token) -> bool, String> {
+    Ok(match name {
+        "time" => is_time_expr,
+        "day" => is_day_expr,
+        "time-of-day" => is_time_of_day_expr,
+        "month" => is_month_expr,
+        "weekday" => is_weekday_expr,
+        "year" => is_year_expr,
+        "duration" => is_duration_expr,
+        _ => return Err(format!("unknown predicate \"{name}\"")),
+    })
+}
+
+fn grain(name: &str) -> Option<Grain> {
+    Some(match name.trim_end_matches('s') {
+        "second" => Grain::Second,
+        "minute" => Grain::Minute,
+        "hour" => Grain::Hour,
+        "day" => Grain::Day,
+        "week" => Grain::Week,
+        "month" => Grain::Month,
+        "quarter" => Grain::Quarter,
+        "year" => Grain::Year,
+        _ => return None,
+    })
+}
+
+fn weekday(name: &str) -> Option<Weekday> {
+    name.parse().ok()
+}
+
+/// Reject templates that could never produce, at load time rather than on
+/// every match.
+fn check_template(template: &Template, items: usize) -> Result<(), String> {
+    let index = |idx: usize| if idx < items { Ok(()) } else { Err(format!("no pattern item {idx}")) };
+    match template {
+        Template::Copy(expr) => index(*expr),
+        Template::Shift { amount, grain: name, expr } => {
+            let unit = grain(name).ok_or_else(|| format!("unknown grain \"{name}\""))?;
+            // Past chrono's range of years the shift resolves to nothing, for
+            // any reference time.
+            let origin = chrono::NaiveDate::from_ymd_opt(2000, 1, 1).and_then(|d| d.and_hms_opt(0, 0, 0));
+            origin
+                .and_then(|origin| shift_datetime_by_grain(origin, *amount, unit))
+                .ok_or_else(|| format!("shift of {amount} {name} is out of range"))?;
+            expr.map_or(Ok(()), index)
+        }
+        Template::MonthDay { month, day } => {
+            chrono::NaiveDate::from_ymd_opt(2000, *month, *day).ok_or_else(|| format!("no such day {month}/{day}"))?;
+            Ok(())
+        }
+        Template::AtTime { hour, minute, expr } => {
+            time_expr_with_hms(*hour, *minute, 0).ok_or_else(|| format!("no such time {hour}:{minute:02}"))?;
+            expr.map_or(Ok(()), index)
+        }
+        Template::Weekday(name) => weekday(name).map(|_| ()).ok_or_else(|| format!("unknown weekday \"{name}\"")),
+        Template::Intersect(lhs, rhs) => index(*lhs).and(index(*rhs)),
+        Template::Interval { start, end } => index(*start).and(index(*end)),
+    }
+}
+
+fn produce(template: &Template, tokens: &[Token]) -> Option<TimeExpr> {
+    let expr = |idx: usize| get_time_expr(tokens.get(idx)?).cloned();
+    match template {
+        Template::Copy(idx) => expr(*idx),
+        Template::Shift { amount, grain: name, expr: from } => {
+            let base = match from {
+                Some(idx) => expr(*idx)?,
+                None => TimeExpr::Reference,
+            };
+            Some(shift_by_grain(base, *amount, grain(name)?))
+        }
+        Template::MonthDay { month, day } => Some(TimeExpr::MonthDay { month: *month, day: *day }),
+        Template::AtTime { hour, minute, expr: day } => {
+            let time = time_expr_with_hms(*hour, *minute, 0)?;
+            match day {
+                Some(idx) => intersect_time_exprs(&expr(*idx)?, &time),
+                None => Some(time),
+            }
+        }
+        Template::Weekday(name) => Some(TimeExpr::Intersect {
+            expr: Box::new(TimeExpr::Reference),
+            constraint: Constraint::DayOfWeek(weekday(name)?),
+        }),
+        Template::Intersect(lhs, rhs) => intersect_time_exprs(&expr(*lhs)?, &expr(*rhs)?),
+        Template::Interval { start, end } => {
+            Some(TimeExpr::IntervalBetween { start: Box::new(expr(*start)?), end: Box::new(expr(*end)?) })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Options, parse_with};
+    use chrono::NaiveDate;
+
+    const RULES: &str = r#"
+[[rule]]
+name = "fortnight from now"
+pattern = [{ regex = '(?i)\bfortnight from now\b' }]
+produce = { shift = { amount = 14, grain = "day" } }
+
+[[rule]]
+name = "<day> after lunch"
+pattern = [{ predicate = "day" }, { regex = '(?i)\s+after lunch\b' }]
+produce = { at_time = { expr = 0, hour = 13 } }
+
+[[rule]]
+name = "<day> before lunch"
+pattern = [{ predicate = "day" }, { words = "before lunch" }]
+produce = { at_time = { expr = 0, hour = 11 } }
+"#;
+
+    #[test]
+    fn loaded_rules_parse_alongside_the_defaults() {
+        let rules = RuleSet::from_toml(RULES).unwrap();
+        let ctx = Context {
+            reference_time: NaiveDate::from_ymd_opt(2013, 2, 12).unwrap().and_hms_opt(4, 30, 0),
+            ..Default::default()
+        };
+        let options = Options::default().with_rule_set(rules);
+        let value = |input: &str| parse_with(input, &ctx, &options).results[0].value.clone();
+
+        assert_eq!(value("a fortnight from now"), "2013-02-26 04:30:00");
+        assert_eq!(value("tomorrow after lunch"), "2013-02-13 13:00:00");
+        assert_eq!(value("tomorrow  Before lunch"), "2013-02-13 11:00:00");
+        assert_eq!(value("friday"), "2013-02-15 00:00:00");
+    }
+
+    #[test]
+    fn invalid_rules_name_the_rule() {
+        let err = RuleSet::from_toml(
+            r#"
+[[rule]]
+name = "broken"
+pattern = [{ predicate = "weekday" }]
+produce = { copy = 3 }
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.rule.as_deref(), Some("broken"));
+        assert_eq!(err.message, "no pattern item 3");
+        assert!(RuleSet::from_toml("[[rule]]\nname = 1").unwrap_err().rule.is_none());
+
+        let err = RuleSet::from_toml(
+            r#"
+[[rule]]
+name = "anything"
+pattern = [{ regex = '(?i)(later)?' }]
+produce = { shift = { amount = 1, grain = "hour" } }
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), r#"rule "anything": regex "(?i)(later)?" matches the empty string"#);
+
+        let err = RuleSet::from_toml(
+            r#"
+[[rule]]
+name = "eons from now"
+pattern = [{ words = "eons from now" }]
+produce = { shift = { amount = 1000000, grain = "years" } }
+"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), r#"rule "eons from now": shift of 1000000 years is out of range"#);
+    }
+}
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 use once_cell::sync::Lazy;
 
-use crate::{NumeralData, Rule, Token, TokenKind};
+use crate::engine::BucketMask;
+use crate::{NumeralData, Precision, Rule, Token, TokenKind, WithPrecision};
 
 use crate::{
     rules::numeral::helpers::{
@@ -147,7 +148,7 @@ fn rule_powers_of_ten() -> Rule {
     rule! {
         name: "powers of tens",
         pattern: [
-            re!(r"(?i)(hundred|thousand|l(?:ac|a?kh?|k)?|million|(?:k|c)r(?:ore)?|koti|billion)s?")
+            re!(r"(?i)\b(hundred|thousand|l(?:ac|a?kh?|k)?|million|(?:k|c)r(?:ore)?|koti|billion)s?\b")
         ],
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             let mut m = first_match_lower(tokens)?;
@@ -415,6 +416,7 @@ fn rule_decimals() -> Rule {
         pattern: [
             re!(r"(\d*\.\d+)")
         ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             if tokens.is_empty() { return None; }
             match &tokens[0].kind {
@@ -434,6 +436,7 @@ fn rule_fractions() -> Rule {
         pattern: [
             re!(r"(\d+)/(\d+)")
         ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             match &tokens.first() {
                 Some(Token { kind: TokenKind::RegexMatch(groups), .. }) => {
@@ -463,6 +466,7 @@ fn rule_integers() -> Rule {
         pattern: [
             re!(r"(\d+)")
         ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             if tokens.is_empty() { return None; }
             match &tokens[0].kind {
@@ -481,6 +485,7 @@ fn rule_ordinal_digits() -> Rule {
     rule! {
         name: "ordinal digits",
         pattern: [re!(r"(?i)\b(\d+)(st|nd|rd|th)\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             if tokens.is_empty() {
                 return None;
@@ -559,6 +564,7 @@ fn rule_commas() -> Rule {
         pattern: [
             re!(r"(\d+(,\d\d\d)+(\.\d+)?)")
         ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             if tokens.is_empty() { return None; }
             match &tokens[0].kind {
@@ -581,6 +587,7 @@ fn rule_suffixes() -> Rule {
             // (e.g., ".0012G").
             re!(r"(?i)(\d+\.\d+|\d+|\.\d+)\s*([kmg])\b")
         ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             if tokens.is_empty() { return None; }
             match &tokens[0].kind {
@@ -624,6 +631,7 @@ fn rule_negative_prefix() -> Rule {
                 \b                          # stop before trailing digits/letters
             ")
         ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<NumeralData> {
             match tokens.first().map(|t| &t.kind) {
                 Some(TokenKind::RegexMatch(groups)) => {
@@ -682,6 +690,43 @@ fn rule_negative_words() -> Rule {
     }
 }
 
+/// Hedged numbers ("about 20", "around a dozen") resolve to the same value,
+/// marked approximate.
+fn rule_approximate_prefix() -> Rule {
+    rule! {
+        name: "about|around <number>",
+        pattern: [
+            re!(r"(?i)\b(?:about|around|approximately|roughly)\s+"),
+            pred!(is_positive)
+        ],
+        optional_phrases: ["about", "around", "approximately", "roughly"],
+        prod: |tokens: &[Token]| -> Option<WithPrecision<NumeralData>> {
+            match &tokens.get(1)?.kind {
+                TokenKind::Numeral(nd) => Some(WithPrecision(nd.clone(), Precision::Approximate)),
+                _ => None,
+            }
+        },
+    }
+}
+
+/// "<number>ish" ("20ish", "fifty-ish").
+fn rule_approximate_suffix() -> Rule {
+    rule! {
+        name: "<number>ish",
+        pattern: [
+            pred!(is_positive),
+            re!(r"(?i)-?ish\b")
+        ],
+        required_phrases: ["ish"],
+        prod: |tokens: &[Token]| -> Option<WithPrecision<NumeralData>> {
+            match &tokens.first()?.kind {
+                TokenKind::Numeral(nd) => Some(WithPrecision(nd.clone(), Precision::Approximate)),
+                _ => None,
+            }
+        },
+    }
+}
+
 fn rule_dozen() -> Rule {
     rule! {
         name: "a dozen of",
@@ -737,6 +782,8 @@ pub fn get() -> Vec<Rule> {
         rule_negative_prefix(),
         rule_negative(),
         rule_negative_words(),
+        rule_approximate_prefix(),
+        rule_approximate_suffix(),
         rule_legal_parentheses(),
         rule_dozen(),
         rule_dozen_multiplication(),
@@ -111,6 +111,8 @@ fn numeral_examples_matching() {
         (3000000000.0, "three thousand millions"),
         (45.0, "forty-five (45)"),
         (45.0, "45 (forty five)"),
+        (20.0, "about 20"),
+        (150.0, "150ish"),
     ];
 
     let rules = numeral::rules::get();
@@ -41,9 +41,11 @@ pub fn start_of(grain: Grain, dt: NaiveDateTime) -> NaiveDateTime {
     }
 }
 
+/// The `grain`-long interval containing `dt`; its end saturates at chrono's
+/// last representable datetime.
 pub fn interval_of(grain: Grain, dt: NaiveDateTime) -> TimeValue {
     let start = start_of(grain, dt);
-    let end = shift_datetime_by_grain(start, 1, grain);
+    let end = shift_datetime_by_grain(start, 1, grain).unwrap_or(NaiveDateTime::MAX);
     TimeValue::Interval { start, end }
 }
 
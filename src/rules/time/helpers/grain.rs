@@ -14,10 +14,11 @@ pub fn container_grain_for_expr(expr: &TimeExpr) -> Grain {
             Constraint::DayOfMonth(_) => Grain::Month,
             Constraint::DayOfWeek(_) => Grain::Week,
             Constraint::Day(_) => Grain::Day,
-            Constraint::TimeOfDay(_) | Constraint::PartOfDay(_) => Grain::Day,
+            Constraint::TimeOfDay(_) | Constraint::PartOfDay(_) | Constraint::Solar(_) => Grain::Day,
         },
-        TimeExpr::MonthPart { .. } => Grain::Month,
-        TimeExpr::MonthDay { .. } => Grain::Day,
+        TimeExpr::MonthPart { .. } | TimeExpr::NamedMonth { .. } => Grain::Month,
+        TimeExpr::PeriodPart { of, .. } => container_grain_for_expr(of),
+        TimeExpr::MonthDay { .. } | TimeExpr::ShortYearDate { .. } => Grain::Day,
         TimeExpr::ClosestWeekdayTo { .. } => Grain::Day,
         TimeExpr::Absolute { month, day, .. } => {
             if *month == 1 && *day == 1 {
@@ -29,22 +30,113 @@ pub fn container_grain_for_expr(expr: &TimeExpr) -> Grain {
         TimeExpr::Interval { .. }
         | TimeExpr::IntervalBetween { .. }
         | TimeExpr::IntervalUntil { .. }
+        | TimeExpr::IntervalFromReference { .. }
         | TimeExpr::OpenAfter { .. }
         | TimeExpr::OpenBefore { .. } => Grain::Day,
         TimeExpr::Reference | TimeExpr::At(_) => Grain::Day,
         TimeExpr::LastWeekdayOfMonth { .. } => Grain::Day,
         TimeExpr::FirstWeekdayOfMonth { .. } => Grain::Day,
-        TimeExpr::NthWeekdayOfMonth { .. } => Grain::Day,
+        TimeExpr::NthWeekdayOfMonth { .. } | TimeExpr::NthWeekdayOf { .. } | TimeExpr::NthMondayToFridayOf { .. } => {
+            Grain::Day
+        }
         TimeExpr::NthWeekOf { .. } => Grain::Week,
         TimeExpr::NthLastOf { grain, .. } => *grain,
         // New variants
         TimeExpr::Holiday { .. } => Grain::Day,
-        TimeExpr::Season(_) => Grain::Month,
+        TimeExpr::Season(_) | TimeExpr::SeasonPart { .. } => Grain::Month,
         TimeExpr::SeasonPeriod { .. } => Grain::Month,
         TimeExpr::PartOfDay(_) => Grain::Day,
         TimeExpr::After(_) | TimeExpr::Before(_) => Grain::Day,
         TimeExpr::Duration(_) => Grain::Day,
         TimeExpr::AmbiguousTime { .. } => Grain::Minute,
+        TimeExpr::InTimezone { expr, .. } | TimeExpr::DualTimezone { primary: expr, .. } => {
+            container_grain_for_expr(expr)
+        }
+        TimeExpr::ShiftBusinessDays { .. } | TimeExpr::BusinessTime { .. } => Grain::Day,
+        TimeExpr::FiscalYear { quarter: Some(_), .. } => Grain::Quarter,
+        TimeExpr::FiscalYear { quarter: None, .. } => Grain::Year,
+        TimeExpr::ProvidedHoliday { .. } => Grain::Day,
+        TimeExpr::List(items) => items.first().map_or(Grain::Day, container_grain_for_expr),
+        TimeExpr::Recurrence { every, .. } => *every,
+    }
+}
+
+/// Finest unit a time expression resolves to: `Day` for "March 3", `Hour` for
+/// "3pm", `Minute` for "3:30pm". Times relative to now ("in 2 hours") keep
+/// `Second`, as they carry the reference's time of day.
+pub fn value_grain(expr: &TimeExpr) -> Grain {
+    fn finest(a: Grain, b: Grain) -> Grain {
+        if (a as u8) < (b as u8) { a } else { b }
+    }
+
+    fn clock_grain(minute: u32, second: u32) -> Grain {
+        match (minute, second) {
+            (0, 0) => Grain::Hour,
+            (_, 0) => Grain::Minute,
+            _ => Grain::Second,
+        }
+    }
+
+    match expr {
+        TimeExpr::Reference | TimeExpr::At(_) => Grain::Second,
+        TimeExpr::StartOf { grain, .. } | TimeExpr::IntervalOf { grain, .. } | TimeExpr::NthLastOf { grain, .. } => {
+            *grain
+        }
+        TimeExpr::Intersect { expr, constraint } => {
+            let constraint_grain = match constraint {
+                Constraint::Month(_) => Grain::Month,
+                Constraint::DayOfMonth(_) | Constraint::DayOfWeek(_) | Constraint::Day(_) => Grain::Day,
+                Constraint::TimeOfDay(time) => clock_grain(time.minute(), time.second()),
+                Constraint::PartOfDay(_) => Grain::Hour,
+                Constraint::Solar(_) => Grain::Minute,
+            };
+            // A bare constraint on the reference is as fine as the constraint.
+            match **expr {
+                TimeExpr::Reference => constraint_grain,
+                _ => finest(value_grain(expr), constraint_grain),
+            }
+        }
+        TimeExpr::Absolute { hour: None, .. } => Grain::Day,
+        TimeExpr::Absolute { minute: Some(minute), .. } => clock_grain(*minute, 0),
+        TimeExpr::Absolute { .. } => Grain::Hour,
+        TimeExpr::AmbiguousTime { minute, .. } => clock_grain(*minute, 0),
+        TimeExpr::BusinessTime { .. } => Grain::Minute,
+        TimeExpr::PartOfDay(_) => Grain::Hour,
+        TimeExpr::NthWeekOf { .. } => Grain::Week,
+        TimeExpr::Season(_) | TimeExpr::SeasonPart { .. } | TimeExpr::SeasonPeriod { .. } => Grain::Day,
+        TimeExpr::FiscalYear { quarter: Some(_), .. } => Grain::Quarter,
+        TimeExpr::FiscalYear { quarter: None, .. } => Grain::Year,
+        // Parts of weeks and months are whole days; of quarters and years, months.
+        TimeExpr::PeriodPart { of, .. } => match value_grain(of) {
+            Grain::Quarter | Grain::Year => Grain::Month,
+            _ => Grain::Day,
+        },
+        TimeExpr::Shift { expr, .. }
+        | TimeExpr::InTimezone { expr, .. }
+        | TimeExpr::DualTimezone { primary: expr, .. }
+        | TimeExpr::OpenAfter { expr }
+        | TimeExpr::OpenBefore { expr }
+        | TimeExpr::After(expr)
+        | TimeExpr::Before(expr)
+        | TimeExpr::Duration(expr) => value_grain(expr),
+        TimeExpr::IntervalUntil { target } => value_grain(target),
+        TimeExpr::IntervalBetween { start, .. } => value_grain(start),
+        TimeExpr::Interval { .. } | TimeExpr::IntervalFromReference { .. } => Grain::Second,
+        TimeExpr::List(items) => items.first().map_or(Grain::Day, value_grain),
+        TimeExpr::Recurrence { occurrence, .. } => value_grain(occurrence),
+        TimeExpr::MonthPart { .. }
+        | TimeExpr::NamedMonth { .. }
+        | TimeExpr::MonthDay { .. }
+        | TimeExpr::ShortYearDate { .. }
+        | TimeExpr::ClosestWeekdayTo { .. }
+        | TimeExpr::LastWeekdayOfMonth { .. }
+        | TimeExpr::FirstWeekdayOfMonth { .. }
+        | TimeExpr::NthWeekdayOfMonth { .. }
+        | TimeExpr::NthWeekdayOf { .. }
+        | TimeExpr::NthMondayToFridayOf { .. }
+        | TimeExpr::Holiday { .. }
+        | TimeExpr::ProvidedHoliday { .. }
+        | TimeExpr::ShiftBusinessDays { .. } => Grain::Day,
     }
 }
 
@@ -74,23 +166,24 @@ pub fn adjust_time_for_part_of_day(time: chrono::NaiveTime, part: PartOfDay) ->
     let second = time.second();
 
     let adjusted_hour = match part {
-        PartOfDay::Morning | PartOfDay::EarlyMorning => {
-            if hour == 12 {
-                0
-            } else {
-                hour
-            }
-        }
+        // An evening reading of a bare hour ("quarter past nine") moves back.
+        PartOfDay::Morning | PartOfDay::EarlyMorning => match hour {
+            12 => 0,
+            13.. => hour - 12,
+            _ => hour,
+        },
         PartOfDay::Afternoon | PartOfDay::AfterLunch | PartOfDay::AfterWork => match hour.cmp(&12) {
             std::cmp::Ordering::Equal => 12,
             std::cmp::Ordering::Less => hour + 12,
             std::cmp::Ordering::Greater => hour,
         },
-        PartOfDay::Evening | PartOfDay::Night | PartOfDay::Tonight | PartOfDay::LateTonight => match hour.cmp(&12) {
-            std::cmp::Ordering::Equal => 0,
-            std::cmp::Ordering::Less => hour + 12,
-            std::cmp::Ordering::Greater => hour,
-        },
+        PartOfDay::Evening | PartOfDay::Night | PartOfDay::Tonight | PartOfDay::LateTonight | PartOfDay::Overnight => {
+            match hour.cmp(&12) {
+                std::cmp::Ordering::Equal => 0,
+                std::cmp::Ordering::Less => hour + 12,
+                std::cmp::Ordering::Greater => hour,
+            }
+        }
         PartOfDay::Lunch => {
             if hour < 11 {
                 hour + 12
@@ -109,6 +202,7 @@ pub fn time_expr_with_year(expr: &TimeExpr, year: i32) -> Option<TimeExpr> {
         TimeExpr::MonthDay { month, day } => {
             Some(TimeExpr::Absolute { year, month: *month, day: *day, hour: None, minute: None })
         }
+        TimeExpr::Holiday { holiday, .. } => Some(TimeExpr::Holiday { holiday: *holiday, year: Some(year) }),
         TimeExpr::ClosestWeekdayTo { n, weekday, target } => {
             let target_with_year = time_expr_with_year(target.as_ref(), year)?;
             Some(TimeExpr::ClosestWeekdayTo { n: *n, weekday: *weekday, target: Box::new(target_with_year) })
@@ -123,17 +217,45 @@ pub fn time_expr_with_year(expr: &TimeExpr, year: i32) -> Option<TimeExpr> {
     }
 }
 
+/// The whole of `year` as a year-grain interval (negative years are BC).
+pub fn year_interval(year: i32) -> TimeExpr {
+    TimeExpr::IntervalOf {
+        expr: Box::new(TimeExpr::Absolute { year, month: 1, day: 1, hour: None, minute: None }),
+        grain: Grain::Year,
+    }
+}
+
+/// The year of a bare year expression: a `year_interval`, or January 1st of it.
+pub fn year_of_expr(expr: &TimeExpr) -> Option<i32> {
+    match expr {
+        TimeExpr::IntervalOf { expr, grain: Grain::Year } => year_of_expr(expr),
+        TimeExpr::Absolute { year, month: 1, day: 1, hour: None, minute: None } => Some(*year),
+        _ => None,
+    }
+}
+
 /// Create time expression with minute offset from hour token
 pub fn time_expr_minutes_offset(hour_token: &Token, minute_offset: i64) -> Option<TimeExpr> {
-    use crate::rules::time::helpers::parse::time_expr_with_minutes;
+    use crate::rules::time::helpers::parse::{integer_value, time_expr_with_minutes};
     use crate::rules::time::predicates::time_from_expr;
 
-    let time = time_from_expr(hour_token)?;
+    // Bare hours ("nine", "9", "9 o'clock") say nothing about am or pm and
+    // are read as afternoon or evening; hours a part of day already settled
+    // ("nine in the morning") are kept.
+    let (time, bare) = match &hour_token.kind {
+        crate::TokenKind::Numeral(_) => {
+            (chrono::NaiveTime::from_hms_opt(integer_value(hour_token)? as u32, 0, 0)?, true)
+        }
+        crate::TokenKind::TimeExpr(TimeExpr::Shift { amount: 0, grain: Grain::Hour, .. }) => {
+            (time_from_expr(hour_token)?, true)
+        }
+        _ => (time_from_expr(hour_token)?, false),
+    };
     let total_minutes = (time.hour() as i64 * 60 + time.minute() as i64 + minute_offset).rem_euclid(24 * 60);
     let mut hours = total_minutes / 60;
     let minutes = total_minutes % 60;
 
-    if time.hour() != 12 && hours > 0 && hours < 12 {
+    if bare && (1..12).contains(&time.hour()) && hours < 12 {
         hours += 12;
     }
 
@@ -1,7 +1,7 @@
 //! Parsing utilities for extracting values from tokens
 
 use crate::time_expr::{Constraint, Grain, PartOfDay, Season, TimeExpr};
-use crate::{Pattern, Token, TokenKind};
+use crate::{Pattern, Precision, Token, TokenKind};
 
 /// Extract integer value from a numeral token
 pub fn integer_value(token: &Token) -> Option<i64> {
@@ -100,6 +100,32 @@ pub fn regex_group_int_value(token: &Token, idx: usize) -> Option<i64> {
     }
 }
 
+/// Precision implied by a hedge word in capture group `idx` ("about", "ish", "sharp", ...).
+pub fn precision_from_group(token: &Token, idx: usize) -> Option<Precision> {
+    match &token.kind {
+        TokenKind::RegexMatch(groups) => match groups.get(idx)?.trim_start_matches('-') {
+            "exactly" | "sharp" => Some(Precision::Exact),
+            _ => Some(Precision::Approximate),
+        },
+        _ => None,
+    }
+}
+
+/// The `n` of a "first".."fifth" (or "1st".."5th") in group 1 of `token`,
+/// or -1 for "last".
+pub fn nth_from_word(token: &Token) -> Option<i32> {
+    let TokenKind::RegexMatch(groups) = &token.kind else { return None };
+    match groups.get(1)?.as_str() {
+        "first" | "1st" => Some(1),
+        "second" | "2nd" => Some(2),
+        "third" | "3rd" => Some(3),
+        "fourth" | "4th" => Some(4),
+        "fifth" | "5th" => Some(5),
+        "last" => Some(-1),
+        _ => None,
+    }
+}
+
 /// Parse grain from cycle text (e.g., "day", "week", "month")
 pub fn grain_from_cycle(cycle: &str) -> Option<Grain> {
     match cycle {
@@ -134,6 +160,9 @@ pub fn part_of_day_from_text(text: &str) -> Option<PartOfDay> {
     if normalized.contains("evening") {
         return Some(PartOfDay::Evening);
     }
+    if normalized.contains("overnight") {
+        return Some(PartOfDay::Overnight);
+    }
     if normalized.contains("night") {
         return Some(PartOfDay::Night);
     }
@@ -262,8 +291,11 @@ pub fn text_duration_pattern() -> &'static str {
 }
 
 /// Get timezone pattern for regex matching
+///
+/// IANA names must start with a tz database area, so slashed words like
+/// "and/or" aren't taken for zones.
 pub fn timezone_pattern() -> &'static str {
-    r"(?i)\b(YEKT|YEKST|YAKT|YAKST|WITA|WIT|WIB|WGT|WGST|WFT|WET|WEST|WAT|WAST|VUT|VLAT|VLAST|VET|UZT|UYT|UYST|UTC|ULAT|TVT|TMT|TLT|TKT|TJT|TFT|TAHT|SST|SRT|SGT|SCT|SBT|SAST|SAMT|RET|PYT|PYST|PWT|PST|PONT|PMST|PMDT|PKT|PHT|PHOT|PGT|PETT|PETST|PET|PDT|OMST|OMSST|NZST|NZDT|NUT|NST|NPT|NOVT|NOVST|NFT|NDT|NCT|MYT|MVT|MUT|MST|MSK|MSD|MMT|MHT|MDT|MAWT|MART|MAGT|MAGST|LINT|LHST|LHDT|KUYT|KST|KRAT|KRAST|KGT|JST|IST|IRST|IRKT|IRKST|IRDT|IOT|IDT|ICT|HOVT|HKT|GYT|GST|GMT|GILT|GFT|GET|GAMT|GALT|FNT|FKT|FKST|FJT|FJST|EST|EGT|EGST|EET|EEST|EDT|ECT|EAT|EAST|EASST|DAVT|ChST|CXT|CVT|CST|COT|CLT|CLST|CKT|CHAST|CHADT|CET|CEST|CDT|CCT|CAT|CAST|BTT|BST|BRT|BRST|BOT|BNT|AZT|AZST|AZOT|AZOST|AWST|AWDT|AST|ART|AQTT|ANAT|ANAST|AMT|AMST|ALMT|AKST|AKDT|AFT|AEST|AEDT|ADT|ACST|ACDT)\b"
+    r"(?i)\b((?:Africa|America|Antarctica|Arctic|Asia|Atlantic|Australia|Europe|Indian|Pacific|Etc)(?:/[a-z0-9_+\-]+)+|YEKT|YEKST|YAKT|YAKST|WITA|WIT|WIB|WGT|WGST|WFT|WET|WEST|WAT|WAST|VUT|VLAT|VLAST|VET|UZT|UYT|UYST|UTC|ULAT|TVT|TMT|TLT|TKT|TJT|TFT|TAHT|SST|SRT|SGT|SCT|SBT|SAST|SAMT|RET|PYT|PYST|PWT|PST|PONT|PMST|PMDT|PKT|PHT|PHOT|PGT|PETT|PETST|PET|PDT|OMST|OMSST|NZST|NZDT|NUT|NST|NPT|NOVT|NOVST|NFT|NDT|NCT|MYT|MVT|MUT|MST|MSK|MSD|MMT|MHT|MDT|MAWT|MART|MAGT|MAGST|LINT|LHST|LHDT|KUYT|KST|KRAT|KRAST|KGT|JST|IST|IRST|IRKT|IRKST|IRDT|IOT|IDT|ICT|HOVT|HKT|GYT|GST|GMT|GILT|GFT|GET|GAMT|GALT|FNT|FKT|FKST|FJT|FJST|EST|EGT|EGST|EET|EEST|EDT|ECT|EAT|EAST|EASST|DAVT|ChST|CXT|CVT|CST|COT|CLT|CLST|CKT|CHAST|CHADT|CET|CEST|CDT|CCT|CAT|CAST|BTT|BST|BRT|BRST|BOT|BNT|AZT|AZST|AZOT|AZOST|AWST|AWDT|AST|ART|AQTT|ANAT|ANAST|AMT|AMST|ALMT|AKST|AKDT|AFT|AEST|AEDT|ADT|ACST|ACDT|ET|CT|MT|PT)\b"
 }
 
 /// Create a Pattern from a regex string
@@ -1,4 +1,5 @@
-use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
 
 use crate::time_expr::{Grain, TimeExpr};
 
@@ -6,37 +7,51 @@ pub fn shift_by_grain(expr: TimeExpr, amount: i32, grain: Grain) -> TimeExpr {
     TimeExpr::Shift { expr: Box::new(expr), amount, grain }
 }
 
-pub fn shift_datetime_by_grain(dt: NaiveDateTime, amount: i32, grain: Grain) -> NaiveDateTime {
+/// `dt` moved by `amount` grains, or `None` when that leaves chrono's
+/// supported date range ("in 5000000 years").
+pub fn shift_datetime_by_grain(dt: NaiveDateTime, amount: i32, grain: Grain) -> Option<NaiveDateTime> {
+    let amount = amount as i64;
     match grain {
-        Grain::Second => dt + Duration::seconds(amount as i64),
-        Grain::Minute => dt + Duration::minutes(amount as i64),
-        Grain::Hour => dt + Duration::hours(amount as i64),
-        Grain::Day => dt + Duration::days(amount as i64),
-        Grain::Week => dt + Duration::weeks(amount as i64),
+        Grain::Second => dt.checked_add_signed(Duration::seconds(amount)),
+        Grain::Minute => dt.checked_add_signed(Duration::minutes(amount)),
+        Grain::Hour => dt.checked_add_signed(Duration::hours(amount)),
+        Grain::Day => dt.checked_add_signed(Duration::days(amount)),
+        Grain::Week => dt.checked_add_signed(Duration::weeks(amount)),
         Grain::Month => add_months(dt, amount),
         Grain::Quarter => add_months(dt, amount * 3),
         Grain::Year => add_months(dt, amount * 12),
     }
 }
 
-fn add_months(dt: NaiveDateTime, months: i32) -> NaiveDateTime {
-    let base_year = dt.date().year();
-    let base_month = dt.date().month() as i32;
-    let zero_based = base_month - 1 + months;
-    let year = base_year + zero_based.div_euclid(12);
-    let month_zero = zero_based.rem_euclid(12);
-    let month = (month_zero + 1) as u32;
-    let day = dt.date().day().min(days_in_month(year, month));
-    let date = NaiveDate::from_ymd_opt(year, month, day).unwrap_or_else(|| dt.date());
-    NaiveDateTime::new(date, dt.time())
+/// Like [`shift_datetime_by_grain`], for `dt` as wall-clock time in `zone`.
+///
+/// Second/minute/hour shifts advance absolute time, so "in 24 hours" across a
+/// DST transition lands an hour off the same wall-clock time. Day and larger
+/// shifts move the calendar and keep the wall-clock time ("tomorrow at 9am"
+/// stays at 9am). Without a zone this is plain naive arithmetic.
+pub fn shift_datetime_in_zone(dt: NaiveDateTime, amount: i32, grain: Grain, zone: Option<Tz>) -> Option<NaiveDateTime> {
+    let absolute = matches!(grain, Grain::Second | Grain::Minute | Grain::Hour);
+    match zone.and_then(|tz| tz.from_local_datetime(&dt).earliest()) {
+        Some(zoned) if absolute => {
+            Some(zoned.checked_add_signed(shift_datetime_by_grain(dt, amount, grain)? - dt)?.naive_local())
+        }
+        _ => shift_datetime_by_grain(dt, amount, grain),
+    }
+}
+
+fn add_months(dt: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let zero_based = dt.date().month0() as i64 + months;
+    let year = i32::try_from(dt.date().year() as i64 + zero_based.div_euclid(12)).ok()?;
+    let month = zero_based.rem_euclid(12) as u32 + 1;
+    let day = dt.date().day().min(days_in_month(year, month)?);
+    Some(NaiveDateTime::new(NaiveDate::from_ymd_opt(year, month, day)?, dt.time()))
 }
 
-fn days_in_month(year: i32, month: u32) -> u32 {
-    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
-    let first_next = NaiveDate::from_ymd_opt(next_year, next_month, 1)
-        .unwrap_or_else(|| NaiveDate::from_ymd_opt(year, month, 1).unwrap());
-    let last_day = first_next - Duration::days(1);
-    last_day.day()
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    // December of chrono's last year has no following month to count back from.
+    let first_next = first.checked_add_months(chrono::Months::new(1)).unwrap_or(NaiveDate::MAX);
+    Some(first_next.signed_duration_since(first).num_days() as u32)
 }
 
 #[cfg(test)]
@@ -50,7 +65,7 @@ mod tests {
     fn shift_datetime_by_month_clamps_day() {
         let dt = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().and_hms_opt(8, 0, 0).unwrap();
         let shifted = shift_datetime_by_grain(dt, 1, Grain::Month);
-        let expected = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().and_hms_opt(8, 0, 0).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().and_hms_opt(8, 0, 0);
         assert_eq!(shifted, expected);
     }
 
@@ -58,10 +73,33 @@ mod tests {
     fn shift_datetime_by_quarter_advances_three_months() {
         let dt = NaiveDate::from_ymd_opt(2023, 11, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
         let shifted = shift_datetime_by_grain(dt, 1, Grain::Quarter);
-        let expected = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2024, 2, 15).unwrap().and_hms_opt(0, 0, 0);
         assert_eq!(shifted, expected);
     }
 
+    #[test]
+    fn shift_datetime_out_of_range_is_none() {
+        let dt = NaiveDate::from_ymd_opt(2013, 2, 12).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(shift_datetime_by_grain(dt, 5_000_000, Grain::Year), None);
+        assert_eq!(shift_datetime_by_grain(dt, i32::MAX, Grain::Week), None);
+        assert_eq!(shift_datetime_by_grain(dt, i32::MIN, Grain::Month), None);
+    }
+
+    #[test]
+    fn shift_in_zone_uses_absolute_time_for_hours_and_civil_time_for_days() {
+        let ny = Some(chrono_tz::America::New_York);
+        let at = |m, d, h| NaiveDate::from_ymd_opt(2013, m, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+
+        // Spring forward (2013-03-10 02:00 -> 03:00): 24 hours is 23 wall-clock hours later.
+        assert_eq!(shift_datetime_in_zone(at(3, 9, 12), 24, Grain::Hour, ny), Some(at(3, 10, 13)));
+        assert_eq!(shift_datetime_in_zone(at(3, 9, 12), 1, Grain::Day, ny), Some(at(3, 10, 12)));
+        // Fall back (2013-11-03 02:00 -> 01:00): 24 hours is 25 wall-clock hours later.
+        assert_eq!(shift_datetime_in_zone(at(11, 2, 12), 24, Grain::Hour, ny), Some(at(11, 3, 11)));
+        assert_eq!(shift_datetime_in_zone(at(11, 2, 12), 1, Grain::Week, ny), Some(at(11, 9, 12)));
+        // Without a zone, shifts stay naive.
+        assert_eq!(shift_datetime_in_zone(at(3, 9, 12), 24, Grain::Hour, None), Some(at(3, 10, 12)));
+    }
+
     #[test]
     fn shift_by_grain_wraps_expression() {
         let expr = shift_by_grain(TimeExpr::Reference, -2, Grain::Week);
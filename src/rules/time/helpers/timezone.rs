@@ -1,15 +1,214 @@
-// The test suite implicitly treats the reference time as being in a fixed local timezone
-// of UTC-02:00 (e.g. `15:00 GMT` -> `13:00`). We keep values as naive local time.
-pub const LOCAL_TZ_OFFSET_HOURS: i32 = -2;
-
-pub fn tz_offset_hours(tz: &str) -> Option<i32> {
-    match tz.to_ascii_uppercase().as_str() {
-        "UTC" | "GMT" => Some(0),
-        "BST" => Some(1), // British Summer Time
-        "CET" => Some(1),
-        "IST" => Some(5), // India Standard Time (actually UTC+5:30, but using 5 for simplicity)
-        "PST" => Some(-8),
-        "CST" => Some(-6),
+use crate::Timezone;
+use crate::time_expr::{TimeExpr, TimeValue};
+use chrono::{Duration, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::{TZ_VARIANTS, Tz};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// IANA zones keyed by lowercased name.
+static IANA_ZONES: Lazy<HashMap<String, Tz>> =
+    Lazy::new(|| TZ_VARIANTS.iter().map(|tz| (tz.name().to_ascii_lowercase(), *tz)).collect());
+
+/// A timezone recognized in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    /// An abbreviation with a fixed offset from UTC, in minutes ("PST", "CEST").
+    Fixed(i32),
+    /// An IANA zone ("America/New_York"), whose offset depends on the date.
+    Iana(Tz),
+}
+
+/// Look up an abbreviation, IANA name or UTC offset ("+02:00"), ignoring case.
+pub fn lookup_zone(name: &str) -> Option<Zone> {
+    if name.contains('/') {
+        return IANA_ZONES.get(&name.to_ascii_lowercase()).copied().map(Zone::Iana);
+    }
+    if let Some(tz) = generic_zone(name) {
+        return Some(Zone::Iana(tz));
+    }
+    utc_offset_minutes(name).or_else(|| abbreviation_offset_minutes(name)).map(Zone::Fixed)
+}
+
+/// The IANA zone behind a North American generic abbreviation ("ET", "PT"),
+/// which names standard or daylight time depending on the date.
+fn generic_zone(abbr: &str) -> Option<Tz> {
+    match abbr.to_ascii_uppercase().as_str() {
+        "ET" => Some(Tz::America__New_York),
+        "CT" => Some(Tz::America__Chicago),
+        "MT" => Some(Tz::America__Denver),
+        "PT" => Some(Tz::America__Los_Angeles),
         _ => None,
     }
 }
+
+/// Offset in minutes of an ISO 8601 offset: "+02:00", "-0530", "+01".
+fn utc_offset_minutes(text: &str) -> Option<i32> {
+    let caps = regex!(r"^([+-])(\d{2}):?(\d{2})?$").captures(text)?;
+    let hours: i32 = caps.get(2)?.as_str().parse().ok()?;
+    let minutes: i32 = caps.get(3).map_or(Some(0), |m| m.as_str().parse().ok())?;
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+    let sign = if caps.get(1)?.as_str() == "-" { -1 } else { 1 };
+    Some(sign * (hours * 60 + minutes))
+}
+
+/// Offset from UTC in minutes for common timezone abbreviations.
+///
+/// Abbreviations shared by several zones resolve to the most common reading
+/// (`IST` is India, `CST` is US Central, `BST` is British Summer Time).
+fn abbreviation_offset_minutes(abbr: &str) -> Option<i32> {
+    let hours = |h: i32| Some(h * 60);
+    match abbr.to_ascii_uppercase().as_str() {
+        "UTC" | "GMT" | "WET" => hours(0),
+        "BST" | "CET" | "WEST" | "WAT" => hours(1),
+        "CEST" | "EET" | "CAT" | "SAST" => hours(2),
+        "EEST" | "MSK" | "EAT" => hours(3),
+        "GST" => hours(4),
+        "PKT" => hours(5),
+        "IST" => Some(5 * 60 + 30), // India Standard Time
+        "NPT" => Some(5 * 60 + 45),
+        "ICT" | "WIB" => hours(7),
+        "SGT" | "HKT" | "AWST" | "PHT" | "MYT" => hours(8),
+        "JST" | "KST" => hours(9),
+        "ACST" => Some(9 * 60 + 30),
+        "AEST" => hours(10),
+        "ACDT" => Some(10 * 60 + 30),
+        "AEDT" => hours(11),
+        "NZST" => hours(12),
+        "NZDT" => hours(13),
+        "NST" => Some(-(3 * 60 + 30)),
+        "NDT" => Some(-(2 * 60 + 30)),
+        "ADT" => hours(-3),
+        "AST" | "EDT" => hours(-4),
+        "EST" | "CDT" => hours(-5),
+        "CST" | "MDT" => hours(-6),
+        "MST" | "PDT" => hours(-7),
+        "PST" | "AKDT" => hours(-8),
+        "AKST" => hours(-9),
+        "HST" => hours(-10),
+        _ => None,
+    }
+}
+
+/// For abbreviations shared by several zones, the reading
+/// [`lookup_zone`] picks ("CST" is US Central, not China Standard Time).
+pub fn ambiguous_abbreviation_reading(abbr: &str) -> Option<&'static str> {
+    match abbr.to_ascii_uppercase().as_str() {
+        "IST" => Some("India Standard Time"),
+        "CST" => Some("US Central Standard Time"),
+        "BST" => Some("British Summer Time"),
+        "AST" => Some("Atlantic Standard Time"),
+        "GST" => Some("Gulf Standard Time"),
+        _ => None,
+    }
+}
+
+impl Zone {
+    /// Canonical identifier: the uppercased abbreviation or the IANA name.
+    fn canonical_name(self, name: &str) -> String {
+        match self {
+            Zone::Fixed(_) => name.to_ascii_uppercase(),
+            Zone::Iana(tz) => tz.name().to_string(),
+        }
+    }
+
+    /// Offset from UTC in minutes for wall-clock time `dt` in this zone.
+    ///
+    /// Times skipped by a DST transition use the offset in effect before it.
+    fn offset_minutes_at_wall_clock(self, dt: NaiveDateTime) -> i32 {
+        match self {
+            Zone::Fixed(minutes) => minutes,
+            Zone::Iana(tz) => {
+                let offset = match tz.offset_from_local_datetime(&dt).earliest() {
+                    Some(offset) => offset,
+                    None => tz.offset_from_utc_datetime(&dt),
+                };
+                offset.fix().local_minus_utc() / 60
+            }
+        }
+    }
+
+    /// Offset from UTC in minutes at the UTC instant `utc`.
+    fn offset_minutes_at_utc(self, utc: NaiveDateTime) -> i32 {
+        match self {
+            Zone::Fixed(minutes) => minutes,
+            Zone::Iana(tz) => tz.offset_from_utc_datetime(&utc).fix().local_minus_utc() / 60,
+        }
+    }
+
+    /// The local zone resolution runs in; `None` is UTC.
+    fn local(local: Option<Tz>) -> Zone {
+        local.map_or(Zone::Fixed(0), Zone::Iana)
+    }
+
+    /// Convert wall-clock time `dt` in this zone to wall-clock time in `to`,
+    /// with `to`'s UTC offset in minutes at that instant.
+    fn convert(self, dt: NaiveDateTime, to: Zone) -> (NaiveDateTime, i32) {
+        let utc = dt - Duration::minutes(self.offset_minutes_at_wall_clock(dt) as i64);
+        let offset = to.offset_minutes_at_utc(utc);
+        (utc + Duration::minutes(offset as i64), offset)
+    }
+
+    /// Convert wall-clock time `dt` in this zone to local time.
+    pub fn to_local(self, dt: NaiveDateTime, local: Option<Tz>) -> NaiveDateTime {
+        self.convert(dt, Zone::local(local)).0
+    }
+
+    /// Convert local time `dt` to wall-clock time in this zone.
+    pub fn local_to_zone(self, dt: NaiveDateTime, local: Option<Tz>) -> NaiveDateTime {
+        Zone::local(local).convert(dt, self).0
+    }
+}
+
+/// Wrap `expr` so it is read as wall-clock time in `zone`.
+///
+/// Returns `None` for unknown zones so rules don't fire on them.
+pub fn in_timezone(expr: TimeExpr, zone: &str) -> Option<TimeExpr> {
+    let name = lookup_zone(zone)?.canonical_name(zone);
+    Some(TimeExpr::InTimezone { expr: Box::new(expr), zone: name })
+}
+
+/// The outermost timezone `expr` was stated in, with `value` (local time)
+/// converted back to wall-clock time there.
+///
+/// The reported offset is the one in effect at the start of `value`.
+pub fn stated_timezone(expr: &TimeExpr, value: &TimeValue, local: Option<Tz>) -> Option<Timezone> {
+    let name = expr.find_map(&|e| match e {
+        TimeExpr::InTimezone { zone, .. } => Some(zone),
+        _ => None,
+    })?;
+    let zone = lookup_zone(name)?;
+    let start = match value {
+        TimeValue::Instant(dt) | TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => *dt,
+        TimeValue::Interval { start, .. } => *start,
+    };
+    let local = Zone::local(local);
+    Some(Timezone {
+        name: name.clone(),
+        utc_offset_seconds: local.convert(start, zone).1 * 60,
+        local: value.clone().map(|dt| local.convert(dt, zone).0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::time::helpers::parse::timezone_pattern;
+
+    #[test]
+    fn iana_names_are_looked_up_ignoring_case() {
+        assert_eq!(lookup_zone("america/new_york"), Some(Zone::Iana(Tz::America__New_York)));
+        assert_eq!(lookup_zone("Europe/Stockholm"), Some(Zone::Iana(Tz::Europe__Stockholm)));
+        assert_eq!(lookup_zone("and/or"), None);
+    }
+
+    #[test]
+    fn timezone_pattern_needs_an_iana_area() {
+        let re = regex::Regex::new(timezone_pattern()).unwrap();
+        assert!(re.is_match("9am America/New_York"));
+        assert!(re.is_match("noon etc/gmt+2"));
+        assert!(!re.is_match("3pm and/or 4pm"));
+        assert!(!re.is_match("w/c monday"));
+    }
+}
@@ -2,22 +2,29 @@ pub mod helpers;
 pub mod normalize;
 pub mod predicates;
 pub mod rules;
+pub mod rules_anniversaries;
+pub mod rules_business_days;
 pub mod rules_complex_intervals;
 pub mod rules_cycles;
 pub mod rules_date_composition;
 pub mod rules_digits;
 pub mod rules_durations;
+pub mod rules_fiscal_years;
 pub mod rules_holidays;
 pub mod rules_instants;
 pub mod rules_intersections;
 pub mod rules_interval_durations;
 pub mod rules_intervals;
+pub mod rules_iso8601;
+pub mod rules_lists;
+pub mod rules_log_formats;
 pub mod rules_misc;
 pub mod rules_month_parts;
 pub mod rules_months;
 pub mod rules_ordinals;
 pub mod rules_parts_of_day;
 pub mod rules_phrases;
+pub mod rules_recurrences;
 pub mod rules_seasons;
 pub mod rules_time_composition;
 pub mod rules_time_modifiers;
@@ -25,6 +32,7 @@ pub mod rules_time_of_day;
 pub mod rules_time_of_day_advanced;
 pub mod rules_time_shifts;
 pub mod rules_tod_combinations;
+pub mod rules_transport;
 pub mod rules_weekdays;
 pub mod rules_weekend;
 pub mod rules_year_and_formatting;
@@ -1,17 +1,29 @@
-use crate::time_expr::{Constraint, Grain, Holiday, MonthPart, PartOfDay, Season, TimeExpr, TimeValue};
+use crate::time_expr::{
+    BusinessBoundary, Constraint, Direction, Grain, Holiday, MonthPart, PartOfDay, SMALL_HOURS_END, Season, SolarEvent,
+    TimeExpr, TimeValue,
+};
+use crate::{Context, DayOfMonthPolicy, Hemisphere, NextMonthPolicy, Options};
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 
 use crate::rules::time::helpers::boundaries::{interval_of, start_of};
-use crate::rules::time::helpers::shift::shift_datetime_by_grain;
-
-pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue> {
+use crate::rules::time::helpers::grain::value_grain;
+use crate::rules::time::helpers::shift::{shift_datetime_by_grain, shift_datetime_in_zone};
+use crate::rules::time::helpers::timezone::lookup_zone;
+
+/// Resolve `expr` against `reference`.
+///
+/// `context` supplies the rest of the environment: its timezone makes sub-day
+/// shifts DST-aware and is the target of `InTimezone` conversions, and its
+/// calendar decides which days count as business days. `options` carries
+/// resolution preferences such as the fiscal-year start.
+pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime, context: &Context, options: &Options) -> Option<TimeValue> {
     match expr {
         TimeExpr::Reference => Some(TimeValue::Instant(reference)),
         TimeExpr::At(dt) => Some(TimeValue::Instant(*dt)),
         TimeExpr::Interval { start, end } => Some(TimeValue::Interval { start: *start, end: *end }),
         TimeExpr::Shift { expr, amount, grain } => {
             if *amount == 0 {
-                return normalize(expr.as_ref(), reference);
+                return normalize(expr.as_ref(), reference, context, options);
             }
             if *amount == -1 && *grain == Grain::Week {
                 if let TimeExpr::Intersect { expr: inner_expr, constraint: Constraint::DayOfWeek(target_dow) } =
@@ -29,7 +41,7 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
                     expr.as_ref()
                 {
                     if matches!(**inner_expr, TimeExpr::Reference) && reference.weekday() == *target_dow {
-                        return normalize(expr, reference);
+                        return normalize(expr, reference, context, options);
                     }
                 }
             }
@@ -43,8 +55,8 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
                     | TimeExpr::NthWeekdayOfMonth { .. }
                     | TimeExpr::LastWeekdayOfMonth { .. } => {
                         // Shift the reference time by the amount, then find the holiday
-                        let shifted_reference = shift_datetime_by_grain(reference, *amount, *grain);
-                        return normalize(expr, shifted_reference);
+                        let shifted_reference = shift_datetime_by_grain(reference, *amount, *grain)?;
+                        return normalize(expr, shifted_reference, context, options);
                     }
                     _ => {}
                 }
@@ -77,7 +89,9 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
                                 month: *month,
                                 weekday: *weekday,
                             };
-                            if let Some(TimeValue::Instant(dt)) = normalize(&current_year_expr, reference) {
+                            if let Some(TimeValue::Instant(dt)) =
+                                normalize(&current_year_expr, reference, context, options)
+                            {
                                 if dt.date() < reference.date() {
                                     // Current year's occurrence is in the past, use it
                                     return Some(TimeValue::Instant(dt));
@@ -89,7 +103,7 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
                                         month: *month,
                                         weekday: *weekday,
                                     };
-                                    return normalize(&prev_year_expr, reference);
+                                    return normalize(&prev_year_expr, reference, context, options);
                                 }
                             }
                         } else {
@@ -97,42 +111,62 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
                             let new_year = year.map(|y| y + amount).or_else(|| Some(reference.year() + amount));
                             let adjusted_expr =
                                 TimeExpr::NthWeekdayOfMonth { n: *n, year: new_year, month: *month, weekday: *weekday };
-                            return normalize(&adjusted_expr, reference);
+                            return normalize(&adjusted_expr, reference, context, options);
                         }
                     }
                     TimeExpr::LastWeekdayOfMonth { year, month, weekday } => {
                         let new_year = year.map(|y| y + amount).or_else(|| Some(reference.year() + amount));
                         let adjusted_expr =
                             TimeExpr::LastWeekdayOfMonth { year: new_year, month: *month, weekday: *weekday };
-                        return normalize(&adjusted_expr, reference);
+                        return normalize(&adjusted_expr, reference, context, options);
                     }
                     _ => {}
                 }
             }
 
-            match normalize(expr, reference)? {
-                TimeValue::Instant(dt) => Some(TimeValue::Instant(shift_datetime_by_grain(dt, *amount, *grain))),
+            let local = context.local_timezone(options);
+            match normalize(expr, reference, context, options)? {
+                TimeValue::Instant(dt) => Some(TimeValue::Instant(shift_datetime_in_zone(dt, *amount, *grain, local)?)),
                 TimeValue::Interval { start, end } => Some(TimeValue::Interval {
-                    start: shift_datetime_by_grain(start, *amount, *grain),
-                    end: shift_datetime_by_grain(end, *amount, *grain),
+                    start: shift_datetime_in_zone(start, *amount, *grain, local)?,
+                    end: shift_datetime_in_zone(end, *amount, *grain, local)?,
                 }),
-                TimeValue::OpenAfter(dt) => Some(TimeValue::OpenAfter(shift_datetime_by_grain(dt, *amount, *grain))),
-                TimeValue::OpenBefore(dt) => Some(TimeValue::OpenBefore(shift_datetime_by_grain(dt, *amount, *grain))),
+                TimeValue::OpenAfter(dt) => {
+                    Some(TimeValue::OpenAfter(shift_datetime_in_zone(dt, *amount, *grain, local)?))
+                }
+                TimeValue::OpenBefore(dt) => {
+                    Some(TimeValue::OpenBefore(shift_datetime_in_zone(dt, *amount, *grain, local)?))
+                }
             }
         }
-        TimeExpr::StartOf { expr, grain } => match normalize(expr, reference)? {
+        TimeExpr::StartOf { expr, grain } => match normalize(expr, reference, context, options)? {
             TimeValue::Instant(dt) => Some(TimeValue::Instant(start_of(*grain, dt))),
             TimeValue::Interval { start, .. } => Some(TimeValue::Instant(start_of(*grain, start))),
             TimeValue::OpenAfter(dt) => Some(TimeValue::OpenAfter(start_of(*grain, dt))),
             TimeValue::OpenBefore(dt) => Some(TimeValue::OpenBefore(start_of(*grain, dt))),
         },
-        TimeExpr::IntervalOf { expr, grain } => match normalize(expr, reference)? {
+        TimeExpr::IntervalOf { expr, grain } => match normalize(expr, reference, context, options)? {
             TimeValue::Instant(dt) => Some(interval_of(*grain, dt)),
             TimeValue::Interval { start, .. } => Some(interval_of(*grain, start)),
             TimeValue::OpenAfter(dt) => Some(interval_of(*grain, dt)),
             TimeValue::OpenBefore(dt) => Some(interval_of(*grain, dt)),
         },
         TimeExpr::Intersect { expr, constraint } => {
+            // An open interval narrowed to a day ("after 9 on Friday") stays
+            // open, anchored on that day.
+            let anchored = |inner: &TimeExpr| {
+                Box::new(TimeExpr::Intersect { expr: Box::new(inner.clone()), constraint: constraint.clone() })
+            };
+            match expr.as_ref() {
+                TimeExpr::After(inner) | TimeExpr::OpenAfter { expr: inner } => {
+                    return normalize(&TimeExpr::After(anchored(inner)), reference, context, options);
+                }
+                TimeExpr::Before(inner) | TimeExpr::OpenBefore { expr: inner } => {
+                    return normalize(&TimeExpr::Before(anchored(inner)), reference, context, options);
+                }
+                _ => {}
+            }
+
             // Special case: MonthDay + DayOfWeek constraint
             // We need to find the next year where month/day falls on the target weekday
             if let (TimeExpr::MonthDay { month, day }, Constraint::DayOfWeek(target_dow)) = (expr.as_ref(), constraint)
@@ -158,16 +192,55 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
                 }
             }
 
-            let base_value = normalize(expr, reference)?;
-            apply_constraint(base_value, constraint, reference)
+            if let (TimeExpr::Reference, Constraint::DayOfMonth(day)) = (expr.as_ref(), constraint) {
+                return normalize_bare_day_of_month(*day, reference, options.day_of_month);
+            }
+
+            let base_value = normalize(expr, reference, context, options)?;
+            apply_constraint(base_value, constraint, reference, context)
         }
         TimeExpr::MonthPart { month, part } => {
             let target_month = month.unwrap_or_else(|| reference.month());
             month_part_interval(target_month, *part, reference)
         }
+        TimeExpr::PeriodPart { part, of } => {
+            let (start, end) = period_bounds(of, reference, context, options)?;
+            let (start, end) = period_part_bounds(start, end, value_grain(of), *part)?;
+            Some(TimeValue::Interval { start, end })
+        }
+        TimeExpr::NthWeekdayOf { n, weekday, of } => {
+            let (start, end) = period_bounds(of, reference, context, options)?;
+            let (first_day, last_day) = (start.date(), end.date() - Duration::days(1));
+            let date = if *n > 0 {
+                let first = first_day + Duration::days(days_until(first_day.weekday(), *weekday));
+                first + Duration::weeks(*n as i64 - 1)
+            } else {
+                let last = last_day - Duration::days(days_until(*weekday, last_day.weekday()));
+                last - Duration::weeks(-*n as i64 - 1)
+            };
+            // "the fifth Monday" of a month with four names nothing.
+            if date < first_day || date > last_day {
+                return None;
+            }
+            Some(TimeValue::Instant(date.and_hms_opt(0, 0, 0)?))
+        }
+        TimeExpr::NthMondayToFridayOf { n, of } => {
+            let (start, end) = period_bounds(of, reference, context, options)?;
+            let (first_day, last_day) = (start.date(), end.date() - Duration::days(1));
+            let is_weekday = |day: &NaiveDate| !matches!(day.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun);
+            let date = if *n > 0 {
+                first_day.iter_days().take_while(|day| *day <= last_day).filter(is_weekday).nth(*n as usize - 1)?
+            } else {
+                std::iter::successors(Some(last_day), NaiveDate::pred_opt)
+                    .take_while(|day| *day >= first_day)
+                    .filter(is_weekday)
+                    .nth(-*n as usize - 1)?
+            };
+            Some(TimeValue::Instant(date.and_hms_opt(0, 0, 0)?))
+        }
         TimeExpr::IntervalUntil { target } => {
             // Create an interval from the reference time (now) until the target time
-            let target_value = normalize(target, reference)?;
+            let target_value = normalize(target, reference, context, options)?;
             match target_value {
                 TimeValue::Instant(end_dt) => Some(TimeValue::Interval { start: reference, end: end_dt }),
                 TimeValue::Interval { end, .. } => {
@@ -179,6 +252,11 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
                 }
             }
         }
+        TimeExpr::IntervalFromReference { amount, grain, direction } => {
+            let amount = if *direction == Direction::Past { -*amount } else { *amount };
+            let other = shift_datetime_by_grain(reference, amount, *grain)?;
+            Some(TimeValue::Interval { start: reference.min(other), end: reference.max(other) })
+        }
         TimeExpr::IntervalBetween { start, end } => {
             // Special handling for year-crossing MonthDay intervals
             // e.g., "this winter" = Dec 21 to Mar 21 crosses years
@@ -212,25 +290,53 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
             }
 
             // Create an interval between two time expressions
-            let start_value = normalize(start, reference)?;
-            let end_value = normalize(end, reference)?;
-
+            let start_value = normalize(start, reference, context, options)?;
             let start_dt = match start_value {
                 TimeValue::Instant(dt) => dt,
                 TimeValue::Interval { start, .. } => start,
                 TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => dt,
             };
 
-            let end_dt = match end_value {
+            let end_of = |value: TimeValue| match value {
                 TimeValue::Instant(dt) => dt,
                 TimeValue::Interval { end, .. } => end,
                 TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => dt,
             };
+            let mut end_dt = end_of(normalize(end, reference, context, options)?);
+
+            // A bare time-of-day end before the start is the first such time
+            // after it, when that crosses midnight ("Friday 11pm to 1am",
+            // "10pm to 2am") or the start is a time on a given day ("tomorrow
+            // 9am to 5pm"). Other backward readings, such as "between 2 and
+            // 4pm" with 2am tomorrow as the start, stay backward.
+            let timed_day = !is_bare_time_of_day(start)
+                && start
+                    .find_map(&|e| {
+                        matches!(e, TimeExpr::Intersect { constraint: Constraint::TimeOfDay(_), .. }).then_some(())
+                    })
+                    .is_some();
+            if end_dt < start_dt {
+                let rolled = match end.as_ref() {
+                    // Likewise in a zone of its own ("9am ET to 11am PT"), read
+                    // from the start as wall-clock time there.
+                    TimeExpr::InTimezone { expr, zone } if is_bare_time_of_day(expr) => {
+                        let zone = lookup_zone(zone)?;
+                        let local = context.local_timezone(options);
+                        let start_there = zone.local_to_zone(start_dt, local);
+                        normalize(expr, start_there, context, options).map(|v| v.map(|dt| zone.to_local(dt, local)))
+                    }
+                    _ if is_bare_time_of_day(end) => normalize(end, start_dt, context, options),
+                    _ => None,
+                };
+                if let Some(rolled) = rolled.map(end_of).filter(|end| timed_day || end.date() > start_dt.date()) {
+                    end_dt = rolled;
+                }
+            }
 
             Some(TimeValue::Interval { start: start_dt, end: end_dt })
         }
         TimeExpr::OpenAfter { expr } => {
-            let value = normalize(expr, reference)?;
+            let value = normalize(expr, reference, context, options)?;
             match value {
                 TimeValue::Instant(dt) => Some(TimeValue::OpenAfter(dt)),
                 TimeValue::Interval { start, .. } => Some(TimeValue::OpenAfter(start)),
@@ -239,10 +345,10 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
             }
         }
         TimeExpr::OpenBefore { expr } => {
-            let value = normalize(expr, reference)?;
+            let value = normalize(expr, reference, context, options)?;
             match value {
                 TimeValue::Instant(dt) => Some(TimeValue::OpenBefore(dt)),
-                TimeValue::Interval { end, .. } => Some(TimeValue::OpenBefore(end)),
+                TimeValue::Interval { start, .. } => Some(TimeValue::OpenBefore(start)),
                 TimeValue::OpenAfter(dt) => Some(TimeValue::OpenBefore(dt)),
                 TimeValue::OpenBefore(dt) => Some(TimeValue::OpenBefore(dt)),
             }
@@ -263,7 +369,7 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
         TimeExpr::ClosestWeekdayTo { n, weekday, target } => {
             let n = (*n).max(1) as i64;
 
-            let target_dt = match normalize(target.as_ref(), reference)? {
+            let target_dt = match normalize(target.as_ref(), reference, context, options)? {
                 TimeValue::Instant(dt) => dt,
                 TimeValue::Interval { start, .. } => start,
                 TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => dt,
@@ -314,6 +420,34 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
             let time = chrono::NaiveTime::from_hms_opt(hour.unwrap_or(0), minute.unwrap_or(0), 0)?;
             Some(TimeValue::Instant(NaiveDateTime::new(date, time)))
         }
+        TimeExpr::NamedMonth { month, offset } => {
+            let (year, current) = (reference.year(), reference.month());
+            let year = match (offset.signum(), options.next_month) {
+                (0, _) => year,
+                (_, NextMonthPolicy::FollowingYear) => year + offset,
+                (1, NextMonthPolicy::Upcoming) => {
+                    if *month > current {
+                        year
+                    } else {
+                        year + 1
+                    }
+                }
+                (_, NextMonthPolicy::Upcoming) => {
+                    if *month < current {
+                        year
+                    } else {
+                        year - 1
+                    }
+                }
+            };
+            Some(TimeValue::Instant(NaiveDate::from_ymd_opt(year, *month, 1)?.and_hms_opt(0, 0, 0)?))
+        }
+        TimeExpr::ShortYearDate { year, month, day } => {
+            let century = if *year < options.two_digit_year_pivot { 2000 } else { 1900 };
+            let year = century + *year as i32;
+            let date = TimeExpr::Absolute { year, month: *month, day: *day, hour: None, minute: None };
+            normalize(&date, reference, context, options)
+        }
         TimeExpr::LastWeekdayOfMonth { year, month, weekday } => {
             use chrono::Datelike;
 
@@ -534,8 +668,20 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
             }
         }
         // Holiday normalization
-        TimeExpr::Holiday { holiday, year } => normalize_holiday(*holiday, *year, reference),
-        TimeExpr::Season(season) => normalize_season(*season, reference),
+        TimeExpr::Holiday { holiday, year } => normalize_holiday(*holiday, *year, reference, context, options),
+        TimeExpr::Season(season) => normalize_season(*season, reference, context.hemisphere),
+        TimeExpr::SeasonPart { season, part } => {
+            let TimeValue::Interval { start, end } = normalize_season(*season, reference, context.hemisphere)? else {
+                return None;
+            };
+            let third = Duration::days((end - start).num_days() / 3);
+            let (start, end) = match part {
+                MonthPart::Early => (start, start + third),
+                MonthPart::Mid => (start + third, start + third * 2),
+                MonthPart::Late => (start + third * 2, end),
+            };
+            Some(TimeValue::Interval { start, end })
+        }
         TimeExpr::SeasonPeriod { offset } => normalize_season_period(*offset, reference),
         TimeExpr::PartOfDay(part_of_day) => {
             // Apply part of day constraint to today
@@ -543,7 +689,7 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
         }
         TimeExpr::After(expr) => {
             // Open-ended interval starting from expr
-            let value = normalize(expr, reference)?;
+            let value = normalize(expr, reference, context, options)?;
             match value {
                 TimeValue::Instant(dt) => Some(TimeValue::OpenAfter(dt)),
                 TimeValue::Interval { start, .. } => Some(TimeValue::OpenAfter(start)),
@@ -553,10 +699,10 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
         }
         TimeExpr::Before(expr) => {
             // Open-ended interval ending at expr
-            let value = normalize(expr, reference)?;
+            let value = normalize(expr, reference, context, options)?;
             match value {
                 TimeValue::Instant(dt) => Some(TimeValue::OpenBefore(dt)),
-                TimeValue::Interval { end, .. } => Some(TimeValue::OpenBefore(end)),
+                TimeValue::Interval { start, .. } => Some(TimeValue::OpenBefore(start)),
                 TimeValue::OpenAfter(dt) => Some(TimeValue::OpenBefore(dt)),
                 TimeValue::OpenBefore(dt) => Some(TimeValue::OpenBefore(dt)),
             }
@@ -564,7 +710,7 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
         TimeExpr::Duration(expr) => {
             // Duration expressions should be normalized within their context
             // For now, treat as instant
-            normalize(expr, reference)
+            normalize(expr, reference, context, options)
         }
         TimeExpr::AmbiguousTime { hour, minute } => {
             // Find the next occurrence of this time (could be AM or PM)
@@ -593,6 +739,94 @@ pub fn normalize(expr: &TimeExpr, reference: NaiveDateTime) -> Option<TimeValue>
 
             Some(TimeValue::Instant(next_time))
         }
+        TimeExpr::InTimezone { expr, zone: stated } => {
+            let stated = lookup_zone(stated)?;
+            Some(
+                normalize(expr, reference, context, options)?
+                    .map(|dt| stated.to_local(dt, context.local_timezone(options))),
+            )
+        }
+        TimeExpr::DualTimezone { primary, .. } => normalize(primary, reference, context, options),
+        TimeExpr::ShiftBusinessDays { expr, amount } => {
+            let shift = |dt| context.calendar.shift_business_days(dt, *amount);
+            match normalize(expr, reference, context, options)? {
+                TimeValue::Instant(dt) => Some(TimeValue::Instant(shift(dt)?)),
+                TimeValue::Interval { start, end } => {
+                    Some(TimeValue::Interval { start: shift(start)?, end: shift(end)? })
+                }
+                TimeValue::OpenAfter(dt) => Some(TimeValue::OpenAfter(shift(dt)?)),
+                TimeValue::OpenBefore(dt) => Some(TimeValue::OpenBefore(shift(dt)?)),
+            }
+        }
+        TimeExpr::BusinessTime { expr, boundary } => {
+            let day = match normalize(expr, reference, context, options)? {
+                TimeValue::Instant(dt) | TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => dt.date(),
+                TimeValue::Interval { start, .. } => start.date(),
+            };
+            let time = match boundary {
+                BusinessBoundary::Start => context.calendar.start_of_business,
+                BusinessBoundary::End => context.calendar.end_of_business,
+            };
+            Some(TimeValue::Instant(day.and_time(time)))
+        }
+        TimeExpr::FiscalYear { year, quarter } => {
+            let start_month = options.fiscal_year_start.map_or(1, |m| m.number_from_month());
+            // A fiscal year starting mid-year is named after the year it ends in.
+            let start_year = if start_month == 1 { *year } else { year - 1 };
+            let start = NaiveDate::from_ymd_opt(start_year, start_month, 1)?.and_hms_opt(0, 0, 0)?;
+            let (start, grain) = match quarter {
+                Some(q) => (shift_datetime_by_grain(start, *q as i32 - 1, Grain::Quarter)?, Grain::Quarter),
+                None => (start, Grain::Year),
+            };
+            Some(TimeValue::Interval { start, end: shift_datetime_by_grain(start, 1, grain)? })
+        }
+        TimeExpr::ProvidedHoliday { name, year } => {
+            let calendar = &context.calendar;
+            let date = match year {
+                Some(year) => calendar.resolve_holiday(name, *year)?,
+                // Pick the next occurrence, like other holidays.
+                None => match calendar.resolve_holiday(name, reference.year()) {
+                    Some(date) if date >= reference.date() => date,
+                    _ => calendar.resolve_holiday(name, reference.year() + 1)?,
+                },
+            };
+            Some(TimeValue::Instant(date.and_hms_opt(0, 0, 0)?))
+        }
+        // Lists have no single value; each item is normalized on its own.
+        TimeExpr::List(_) => None,
+        TimeExpr::Recurrence { every, occurrence } => {
+            // The first occurrence from today on: this period's, or the next
+            // one's. Of several occurrences per period ("the 1st and 15th"),
+            // the earliest.
+            let today = start_of(Grain::Day, reference);
+            let period = start_of(*every, reference);
+            let occurrences = match occurrence.as_ref() {
+                TimeExpr::List(items) => items.as_slice(),
+                single => std::slice::from_ref(single),
+            };
+            let start = |value: &TimeValue| match *value {
+                TimeValue::Instant(dt) | TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => dt,
+                TimeValue::Interval { start, .. } => start,
+            };
+            (0..=1).find_map(|k| {
+                let period_start = shift_datetime_by_grain(period, k, *every)?;
+                occurrences
+                    .iter()
+                    .filter_map(|occurrence| normalize(occurrence, period_start, context, options))
+                    .filter(|value| start(value) >= today)
+                    .min_by_key(start)
+            })
+        }
+    }
+}
+
+/// A time of day not anchored to any day ("1am"), possibly shifted to make
+/// it end-exclusive.
+fn is_bare_time_of_day(expr: &TimeExpr) -> bool {
+    match expr {
+        TimeExpr::Shift { expr, .. } => is_bare_time_of_day(expr),
+        TimeExpr::Intersect { expr, constraint: Constraint::TimeOfDay(_) } => matches!(**expr, TimeExpr::Reference),
+        _ => false,
     }
 }
 
@@ -621,6 +855,72 @@ fn month_part_bounds(year: i32, month: u32, part: MonthPart) -> Option<(NaiveDat
     Some((start_dt, end_dt))
 }
 
+/// The `[start, end)` span of the period `of` resolves to; an instant ("next
+/// month" resolves to its first day) spans its grain.
+fn period_bounds(
+    of: &TimeExpr,
+    reference: NaiveDateTime,
+    context: &Context,
+    options: &Options,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let value = match normalize(of, reference, context, options)? {
+        TimeValue::Instant(dt) => interval_of(value_grain(of), dt),
+        value => value,
+    };
+    match value {
+        TimeValue::Interval { start, end } => Some((start, end)),
+        _ => None,
+    }
+}
+
+/// `event` on `date`: sunrise and sunset are instants, dawn and dusk the half
+/// hours of twilight before and after them.
+fn solar_event_on(date: NaiveDate, event: SolarEvent, context: &Context) -> Option<TimeValue> {
+    let (sunrise, sunset) = context.daylight.sun_times(date)?;
+    let (sunrise, sunset) = (date.and_time(sunrise), date.and_time(sunset));
+    let twilight = Duration::minutes(30);
+    Some(match event {
+        SolarEvent::Dawn => TimeValue::Interval { start: sunrise - twilight, end: sunrise },
+        SolarEvent::Sunrise => TimeValue::Instant(sunrise),
+        SolarEvent::Sunset => TimeValue::Instant(sunset),
+        SolarEvent::Dusk => TimeValue::Interval { start: sunset, end: sunset + twilight },
+    })
+}
+
+/// Days from `from` forward to the next `to` (0 when they are the same day).
+fn days_until(from: chrono::Weekday, to: chrono::Weekday) -> i64 {
+    (to.num_days_from_monday() as i64 - from.num_days_from_monday() as i64).rem_euclid(7)
+}
+
+/// The early, middle or late part of the period `[start, end)` of `grain`.
+///
+/// Months split like named months (1st–10th, 11th–20th, 21st–end); other
+/// periods split into thirds of whole days (weeks) or months (quarters,
+/// years), rounded to the nearest unit.
+fn period_part_bounds(
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    grain: Grain,
+    part: MonthPart,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    if grain == Grain::Month {
+        return month_part_bounds(start.year(), start.month(), part);
+    }
+    let (unit, units) = match grain {
+        Grain::Quarter | Grain::Year => {
+            (Grain::Month, (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32)
+        }
+        _ => (Grain::Day, (end - start).num_days() as i32),
+    };
+    let third = (units + 1) / 3;
+    let (from, to) = match part {
+        MonthPart::Early => (0, third),
+        MonthPart::Mid => (third, units - third),
+        MonthPart::Late => (units - third, units),
+    };
+    Some((shift_datetime_by_grain(start, from, unit)?, shift_datetime_by_grain(start, to, unit)?))
+}
+
 fn month_part_interval(month: u32, part: MonthPart, reference: NaiveDateTime) -> Option<TimeValue> {
     let year = reference.year();
     let (start_this, end_this) = month_part_bounds(year, month, part)?;
@@ -672,6 +972,23 @@ fn normalize_month_day_with_weekday(
     None
 }
 
+/// A day of the month with no month given ("the 3rd"): the nearest month
+/// that has it, after or before the reference's day per `policy`.
+fn normalize_bare_day_of_month(day: u32, reference: NaiveDateTime, policy: DayOfMonthPolicy) -> Option<TimeValue> {
+    let (step, first) = match policy {
+        DayOfMonthPolicy::Upcoming => (1, if day > reference.day() { 0 } else { 1 }),
+        DayOfMonthPolicy::Past => (-1, if day < reference.day() { 0 } else { -1 }),
+    };
+    let start_month = reference.year() * 12 + reference.month0() as i32;
+
+    // Every day 1..=31 occurs within a few months either way.
+    (0..12).find_map(|n| {
+        let month_index = start_month + first + step * n;
+        let date = NaiveDate::from_ymd_opt(month_index.div_euclid(12), month_index.rem_euclid(12) as u32 + 1, day)?;
+        Some(TimeValue::Instant(date.and_hms_opt(0, 0, 0)?))
+    })
+}
+
 fn normalize_day_of_month_with_weekday(
     day: u32,
     target_dow: chrono::Weekday,
@@ -699,7 +1016,12 @@ fn normalize_day_of_month_with_weekday(
     None
 }
 
-fn apply_constraint(value: TimeValue, constraint: &Constraint, reference: NaiveDateTime) -> Option<TimeValue> {
+fn apply_constraint(
+    value: TimeValue,
+    constraint: &Constraint,
+    reference: NaiveDateTime,
+    context: &Context,
+) -> Option<TimeValue> {
     match constraint {
         Constraint::Month(target_month) => {
             match value {
@@ -965,17 +1287,20 @@ fn apply_constraint(value: TimeValue, constraint: &Constraint, reference: NaiveD
                     // Apply the time-of-day within the interval window.
                     // This is used for cases like "this afternoon at 2" where the part-of-day
                     // interval should disambiguate 2 -> 14:00.
+                    // A window on an earlier day ("yesterday evening at 8")
+                    // is meant in the past.
                     let pick_in_window =
                         |window_start: NaiveDateTime, window_end: NaiveDateTime| -> Option<NaiveDateTime> {
                             let mut best: Option<NaiveDateTime> = None;
                             let dates = [window_start.date(), window_end.date()];
+                            let past_day = window_end <= reference.date().and_time(NaiveTime::MIN);
 
                             for date in dates {
                                 let base = date.and_time(*time);
                                 for candidate in [base, base + Duration::hours(12)] {
                                     if candidate >= window_start
                                         && candidate < window_end
-                                        && candidate >= reference
+                                        && (candidate >= reference || past_day)
                                         && best.is_none_or(|b| candidate < b)
                                     {
                                         best = Some(candidate);
@@ -999,6 +1324,20 @@ fn apply_constraint(value: TimeValue, constraint: &Constraint, reference: NaiveD
                 }
             }
         }
+        Constraint::Solar(event) => {
+            let (date, now) = match value {
+                TimeValue::Instant(dt) => (dt.date(), dt == reference),
+                TimeValue::Interval { start, .. } => (start.date(), false),
+                TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => (dt.date(), false),
+            };
+            let today = solar_event_on(date, *event, context)?;
+            // A bare "sunset" once today's is over means tomorrow's.
+            let over = match today {
+                TimeValue::Interval { end, .. } => end <= reference,
+                TimeValue::Instant(dt) | TimeValue::OpenAfter(dt) | TimeValue::OpenBefore(dt) => dt < reference,
+            };
+            if now && over { solar_event_on(date.succ_opt()?, *event, context) } else { Some(today) }
+        }
         Constraint::PartOfDay(pod) => {
             let base_date = match value {
                 TimeValue::Instant(dt) => dt.date(),
@@ -1034,6 +1373,7 @@ fn apply_constraint(value: TimeValue, constraint: &Constraint, reference: NaiveD
                         | PartOfDay::Night
                         | PartOfDay::Tonight
                         | PartOfDay::LateTonight
+                        | PartOfDay::Overnight
                 );
 
                 let mut best: Option<NaiveDateTime> = None;
@@ -1080,9 +1420,14 @@ fn part_of_day_bounds(date: NaiveDate, pod: &PartOfDay) -> Option<(NaiveDateTime
             (chrono::NaiveTime::from_hms_opt(13, 0, 0)?, chrono::NaiveTime::from_hms_opt(17, 0, 0)?)
         }
         PartOfDay::Lunch => (chrono::NaiveTime::from_hms_opt(12, 0, 0)?, chrono::NaiveTime::from_hms_opt(14, 0, 0)?),
-        PartOfDay::Evening | PartOfDay::Night | PartOfDay::Tonight => {
+        PartOfDay::Evening => (chrono::NaiveTime::from_hms_opt(18, 0, 0)?, chrono::NaiveTime::from_hms_opt(0, 0, 0)?),
+        // The evening portion of the night; see `PartOfDay`.
+        PartOfDay::Night | PartOfDay::Tonight => {
             (chrono::NaiveTime::from_hms_opt(18, 0, 0)?, chrono::NaiveTime::from_hms_opt(0, 0, 0)?)
         }
+        PartOfDay::Overnight => {
+            (chrono::NaiveTime::from_hms_opt(18, 0, 0)?, chrono::NaiveTime::from_hms_opt(SMALL_HOURS_END, 0, 0)?)
+        }
         PartOfDay::LateTonight => {
             (chrono::NaiveTime::from_hms_opt(21, 0, 0)?, chrono::NaiveTime::from_hms_opt(0, 0, 0)?)
         }
@@ -1092,7 +1437,7 @@ fn part_of_day_bounds(date: NaiveDate, pod: &PartOfDay) -> Option<(NaiveDateTime
     };
 
     let start = NaiveDateTime::new(date, start_time);
-    let end = if end_time == chrono::NaiveTime::from_hms_opt(0, 0, 0)? {
+    let end = if end_time <= start_time {
         NaiveDateTime::new(date.checked_add_signed(chrono::Duration::days(1))?, end_time)
     } else {
         NaiveDateTime::new(date, end_time)
@@ -1130,7 +1475,13 @@ fn apply_part_of_day_to_reference(part_of_day: PartOfDay, reference: NaiveDateTi
 }
 
 /// Normalize a holiday to a specific date
-fn normalize_holiday(holiday: Holiday, year: Option<i32>, reference: NaiveDateTime) -> Option<TimeValue> {
+fn normalize_holiday(
+    holiday: Holiday,
+    year: Option<i32>,
+    reference: NaiveDateTime,
+    context: &Context,
+    options: &Options,
+) -> Option<TimeValue> {
     use Holiday::*;
     use chrono::Weekday;
 
@@ -1147,18 +1498,24 @@ fn normalize_holiday(holiday: Holiday, year: Option<i32>, reference: NaiveDateTi
         None => None,
     };
 
+    // Fixed-date holidays land on that day of the given year, if any
+    let fixed = |month: u32, day: u32| match resolved_year {
+        Some(year) => TimeExpr::Absolute { year, month, day, hour: None, minute: None },
+        None => TimeExpr::MonthDay { month, day },
+    };
+
     // Convert the holiday to its underlying TimeExpr representation
     let expr = match holiday {
         Thanksgiving => TimeExpr::NthWeekdayOfMonth { n: 4, year: resolved_year, month: 11, weekday: Weekday::Thu },
-        Christmas => TimeExpr::MonthDay { month: 12, day: 25 },
-        ChristmasEve => TimeExpr::MonthDay { month: 12, day: 24 },
-        NewYearsDay => TimeExpr::MonthDay { month: 1, day: 1 },
-        NewYearsEve => TimeExpr::MonthDay { month: 12, day: 31 },
-        IndependenceDay => TimeExpr::MonthDay { month: 7, day: 4 },
-        Halloween => TimeExpr::MonthDay { month: 10, day: 31 },
-        VeteransDay => TimeExpr::MonthDay { month: 11, day: 11 },
-        StPatricksDay => TimeExpr::MonthDay { month: 3, day: 17 },
-        EarthDay => TimeExpr::MonthDay { month: 4, day: 22 },
+        Christmas => fixed(12, 25),
+        ChristmasEve => fixed(12, 24),
+        NewYearsDay => fixed(1, 1),
+        NewYearsEve => fixed(12, 31),
+        IndependenceDay => fixed(7, 4),
+        Halloween => fixed(10, 31),
+        VeteransDay => fixed(11, 11),
+        StPatricksDay => fixed(3, 17),
+        EarthDay => fixed(4, 22),
         MLKDay => TimeExpr::NthWeekdayOfMonth { n: 3, year: resolved_year, month: 1, weekday: Weekday::Mon },
         PresidentsDay => TimeExpr::NthWeekdayOfMonth { n: 3, year: resolved_year, month: 2, weekday: Weekday::Mon },
         MemorialDay => TimeExpr::LastWeekdayOfMonth { year: resolved_year, month: 5, weekday: Weekday::Mon },
@@ -1166,19 +1523,28 @@ fn normalize_holiday(holiday: Holiday, year: Option<i32>, reference: NaiveDateTi
         ColumbusDay => TimeExpr::NthWeekdayOfMonth { n: 2, year: resolved_year, month: 10, weekday: Weekday::Mon },
         MothersDay => TimeExpr::NthWeekdayOfMonth { n: 2, year: resolved_year, month: 5, weekday: Weekday::Sun },
         FathersDay => TimeExpr::NthWeekdayOfMonth { n: 3, year: resolved_year, month: 6, weekday: Weekday::Sun },
-        BossDay => TimeExpr::MonthDay { month: 10, day: 16 },
+        BossDay => fixed(10, 16),
         BlackFriday => TimeExpr::LastWeekdayOfMonth { year: resolved_year, month: 11, weekday: Weekday::Fri },
     };
 
     // Normalize the underlying expression
-    normalize(&expr, reference)
+    normalize(&expr, reference, context, options)
 }
 
-fn normalize_season(season: Season, reference: NaiveDateTime) -> Option<TimeValue> {
+fn normalize_season(season: Season, reference: NaiveDateTime, hemisphere: Hemisphere) -> Option<TimeValue> {
     use chrono::NaiveDate;
 
     let year = reference.year();
 
+    // Southern-hemisphere seasons fall on the dates of the opposite northern one.
+    let season = match (hemisphere, season) {
+        (Hemisphere::Northern, season) => season,
+        (Hemisphere::Southern, Season::Spring) => Season::Fall,
+        (Hemisphere::Southern, Season::Summer) => Season::Winter,
+        (Hemisphere::Southern, Season::Fall) => Season::Spring,
+        (Hemisphere::Southern, Season::Winter) => Season::Summer,
+    };
+
     let mk_dt = |y: i32, m: u32, d: u32| {
         Some(NaiveDateTime::new(NaiveDate::from_ymd_opt(y, m, d)?, chrono::NaiveTime::from_hms_opt(0, 0, 0)?))
     };
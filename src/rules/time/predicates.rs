@@ -1,4 +1,5 @@
-use crate::time_expr::{Constraint, TimeExpr};
+use crate::rules::numeral::predicates::{is_integer, number_between};
+use crate::time_expr::{Constraint, Grain, SolarEvent, TimeExpr};
 use crate::{Dimension, Token, TokenKind};
 use chrono::Weekday;
 use once_cell::sync::Lazy;
@@ -151,6 +152,60 @@ pub fn is_time_of_day_expr(token: &Token) -> bool {
     time_from_expr(token).is_some()
 }
 
+/// Returns the event from a bare "sunrise", "dusk", ... token
+pub fn solar_event_from_expr(token: &Token) -> Option<SolarEvent> {
+    match &token.kind {
+        TokenKind::TimeExpr(TimeExpr::Intersect { expr, constraint: Constraint::Solar(event) })
+            if matches!(**expr, TimeExpr::Reference) =>
+        {
+            Some(*event)
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if the token is a bare solar event ("sunset")
+pub fn is_solar_event_expr(token: &Token) -> bool {
+    solar_event_from_expr(token).is_some()
+}
+
+/// Returns true if the token is a bare integer that can name an hour on a
+/// clock face (1-12)
+pub fn is_hour_numeral(token: &Token) -> bool {
+    is_integer(token) && number_between::<1, 13>(token)
+}
+
+/// Returns true if the token is a time of day or a bare hour a clock phrase
+/// can count from ("nine" in "quarter to nine", "9pm")
+pub fn is_clock_hour(token: &Token) -> bool {
+    is_time_of_day_expr(token) || is_hour_numeral(token)
+}
+
+/// Returns true if the token is a time stated in a timezone ("9am PST")
+pub fn is_zoned_time_expr(token: &Token) -> bool {
+    matches!(&token.kind, TokenKind::TimeExpr(TimeExpr::InTimezone { .. }))
+}
+
+/// Returns true if the token is a time naming a day or longer period rather
+/// than a time of day ("Friday", "tomorrow")
+pub fn is_day_expr(token: &Token) -> bool {
+    is_time_expr(token) && !is_time_of_day_expr(token)
+}
+
+/// Returns true if the token is a whole week ("next week", "this week")
+pub fn is_week_expr(token: &Token) -> bool {
+    matches!(&token.kind, TokenKind::TimeExpr(TimeExpr::IntervalOf { grain: Grain::Week, .. }))
+}
+
+/// Returns true if the token is a bare year, as built by `year_interval`
+pub fn is_year_expr(token: &Token) -> bool {
+    matches!(
+        &token.kind,
+        TokenKind::TimeExpr(TimeExpr::IntervalOf { expr, grain: Grain::Year })
+            if matches!(**expr, TimeExpr::Absolute { month: 1, day: 1, hour: None, minute: None, .. })
+    )
+}
+
 /// Returns true if the token is a TimeExpr::AmbiguousTime (e.g. "seven thirty").
 pub fn is_ambiguous_time_expr(token: &Token) -> bool {
     matches!(&token.kind, TokenKind::TimeExpr(TimeExpr::AmbiguousTime { .. }))
@@ -173,6 +228,30 @@ pub fn time_from_expr(token: &Token) -> Option<chrono::NaiveTime> {
     }
 }
 
+/// Returns true if the token is a time of day on no particular date ("5pm",
+/// "17:30"), as opposed to one already tied to a date ("May 5 at 5pm"),
+/// whose date [`time_from_expr`] would drop
+pub fn is_bare_time_of_day_expr(token: &Token) -> bool {
+    fn bare(expr: &TimeExpr) -> bool {
+        match expr {
+            TimeExpr::Intersect { expr, constraint: Constraint::TimeOfDay(_) } => matches!(**expr, TimeExpr::Reference),
+            TimeExpr::Shift { expr, amount: 0, .. } => bare(expr),
+            _ => false,
+        }
+    }
+
+    matches!(&token.kind, TokenKind::TimeExpr(expr) if bare(expr))
+}
+
+/// Returns true if the token constrains the time of day anywhere in its
+/// expression ("Friday at 5pm", "Jul 18 at 20:14"), not only at the top
+pub fn has_time_of_day_expr(token: &Token) -> bool {
+    get_time_expr(token).is_some_and(|expr| {
+        expr.find_map(&|e| matches!(e, TimeExpr::Intersect { constraint: Constraint::TimeOfDay(_), .. }).then_some(()))
+            .is_some()
+    })
+}
+
 /// Returns true if the token is a future shift expression (Shift or StartOf{Shift} with positive amount)
 pub fn is_future_shift_expr(token: &Token) -> bool {
     match &token.kind {
@@ -3,22 +3,29 @@ use crate::Rule;
 use crate::{
     rules::numeral,
     rules::time::{
+        rules_anniversaries::{self},
+        rules_business_days::{self},
         rules_complex_intervals::{self},
         rules_cycles::{self},
         rules_date_composition::{self},
         rules_digits::{self},
         rules_durations::{self},
+        rules_fiscal_years::{self},
         rules_holidays::{self},
         rules_instants::{self},
         rules_intersections::{self},
         rules_interval_durations::{self},
         rules_intervals::{self},
+        rules_iso8601::{self},
+        rules_lists::{self},
+        rules_log_formats::{self},
         rules_misc::{self},
         rules_month_parts::{self},
         rules_months::{self},
         rules_ordinals::{self},
         rules_parts_of_day::{self},
         rules_phrases::{self},
+        rules_recurrences::{self},
         rules_seasons::{self},
         rules_time_composition::{self},
         rules_time_modifiers::{self},
@@ -26,6 +33,7 @@ use crate::{
         rules_time_of_day_advanced::{self},
         rules_time_shifts::{self},
         rules_tod_combinations::{self},
+        rules_transport::{self},
         rules_weekdays::{self},
         rules_weekend::{self},
         rules_year_and_formatting::{self},
@@ -46,11 +54,13 @@ pub fn get() -> Vec<Rule> {
         rules_instants::rule_day_before_yesterday(),
         rules_instants::rule_now(),
         rules_instants::rule_asap(),
+        rules_instants::rule_from_now_on(),
         // === Weekdays ===
         rules_weekdays::rule_last_next_weekday(),
         rules_weekdays::rule_weekday(),
         rules_weekdays::rule_weekday_time(),
         rules_weekdays::rule_time_poss_weekday(),
+        rules_weekdays::rule_week_weekday(),
         rules_weekdays::rule_weekday_day_of_month(),
         rules_weekdays::rule_closest_weekday_to_month_day(),
         rules_weekdays::rule_nth_closest_weekday_to_month_day(),
@@ -59,6 +69,8 @@ pub fn get() -> Vec<Rule> {
         rules_weekdays::rule_nth_weekday_of_month_year(),
         rules_weekdays::rule_last_weekday_of_month_year(),
         rules_weekdays::rule_nth_weekday_of_relative_month(),
+        rules_weekdays::rule_nth_weekday_of_period(),
+        rules_weekdays::rule_nth_monday_to_friday_of_period(),
         rules_weekdays::rule_nth_weekday_after_time(),
         rules_weekdays::rule_first_weekday_of_month(),
         rules_weekdays::rule_weekday_comma_month_day(),
@@ -66,6 +78,7 @@ pub fn get() -> Vec<Rule> {
         rules_weekdays::rule_month_day_comma_weekday(),
         // === Seasons and Holidays ===
         rules_seasons::rule_season(),
+        rules_seasons::rule_season_part(),
         rules_seasons::rule_modifier_season(),
         rules_seasons::rule_relative_season(),
         rules_seasons::rule_christmas(),
@@ -76,11 +89,15 @@ pub fn get() -> Vec<Rule> {
         rules_holidays::rule_bosss_day(),
         rules_holidays::rule_mlk_day(),
         rules_holidays::rule_black_friday(),
+        rules_holidays::rule_provided_holiday(),
         // === Intervals ===
         rules_intervals::rule_interval_from_to(),
         rules_intervals::rule_interval_from_open(),
         rules_intervals::rule_interval_between_and(),
         rules_intervals::rule_interval_dash(),
+        rules_intervals::rule_interval_to_time_of_day(),
+        rules_intervals::rule_open_interval_tod_day(),
+        rules_intervals::rule_open_interval_day_tod(),
         rules_intervals::rule_interval_dash_on_date(),
         rules_intervals::rule_interval_through(),
         rules_intervals::rule_interval_through_open(),
@@ -90,6 +107,8 @@ pub fn get() -> Vec<Rule> {
         rules_intervals::rule_interval_after(),
         rules_intervals::rule_interval_since(),
         rules_intervals::rule_interval_by(),
+        rules_intervals::rule_interval_no_later_than(),
+        rules_intervals::rule_interval_at_the_latest(),
         rules_intervals::rule_interval_for_duration(),
         // === Parts of Day ===
         rules_parts_of_day::rule_part_of_days(),
@@ -123,13 +142,20 @@ pub fn get() -> Vec<Rule> {
         rules_month_parts::rule_end_of_specific_year(),
         rules_month_parts::rule_beginning_of_specific_year(),
         rules_month_parts::rule_beginning_of_year(),
+        rules_month_parts::rule_part_of_period(),
         // === Time of Day Combinations ===
-        rules_tod_combinations::rule_noon_midnight_eod(),
+        rules_tod_combinations::rule_noon_midnight(),
+        rules_tod_combinations::rule_solar_event(),
+        rules_tod_combinations::rule_day_at_solar_event(),
+        rules_tod_combinations::rule_solar_event_on_day(),
+        rules_tod_combinations::rule_solar_event_day(),
         rules_tod_combinations::rule_mid_day(),
         rules_tod_combinations::rule_early_morning(),
         rules_tod_combinations::rule_pod_in(),
         rules_tod_combinations::rule_tonight_time_of_day(),
         rules_tod_combinations::rule_time_of_day_tonight(),
+        rules_tod_combinations::rule_last_night_time_of_day(),
+        rules_tod_combinations::rule_time_of_day_last_night(),
         rules_tod_combinations::rule_after_partofday(),
         rules_tod_combinations::rule_time_pod(),
         rules_tod_combinations::rule_tod_this_pod(),
@@ -137,17 +163,16 @@ pub fn get() -> Vec<Rule> {
         rules_tod_combinations::rule_tod_precision(),
         rules_tod_combinations::rule_tod_pod(),
         // === Durations ===
-        rules_durations::rule_duration_in_within_after(),
+        rules_durations::rule_duration_in_after(),
+        rules_durations::rule_duration_within(),
         rules_durations::rule_in_a_duration(),
         rules_durations::rule_in_number_minutes(),
         rules_durations::rule_in_n_and_a_half_hours(),
         rules_durations::rule_in_couple_pair_few_duration(),
         rules_durations::rule_in_text_number_duration(),
-        rules_durations::rule_text_number_duration_ago(),
         rules_durations::rule_duration_ago(),
         rules_durations::rule_couple_pair_few_duration_ago(),
         // === Year Reference and Formatting ===
-        rules_year_and_formatting::rule_year_reference(),
         rules_year_and_formatting::rule_quarter_to_hod(),
         rules_year_and_formatting::rule_quarter_after_hod(),
         rules_year_and_formatting::rule_half_after_hod(),
@@ -156,8 +181,8 @@ pub fn get() -> Vec<Rule> {
         rules_year_and_formatting::rule_nth_week_of_month(),
         // === Cycles ===
         rules_cycles::rule_cycle_this_last_next(),
+        rules_cycles::rule_cycle_after_next_before_last(),
         rules_cycles::rule_cycle_this_last_next_qtr(),
-        rules_cycles::rule_upcoming_grain(),
         rules_cycles::rule_upcoming_grain_alt(),
         rules_cycles::rule_upcoming_n_cycles(),
         rules_cycles::rule_n_upcoming_cycles(),
@@ -178,6 +203,7 @@ pub fn get() -> Vec<Rule> {
         rules_time_composition::rule_cycle_the_ordinal_of_time(),
         rules_time_composition::rule_cycle_the_last_ordinal_of_time(),
         rules_time_composition::rule_cycle_the_of_time_grain(),
+        rules_time_composition::rule_week_of_date(),
         rules_time_composition::rule_cycle_the_of_time(),
         rules_time_composition::rule_cycle_ordinal_after_time(),
         rules_time_composition::rule_cycle_the_ordinal_after_time(),
@@ -210,6 +236,7 @@ pub fn get() -> Vec<Rule> {
         rules_interval_durations::rule_interval_from_time_for_duration(),
         rules_interval_durations::rule_interval_from_time_for_text_duration(),
         rules_interval_durations::rule_duration_last_next(),
+        rules_interval_durations::rule_duration_from_reference(),
         // === Time Modifiers ===
         rules_time_modifiers::rule_next_dow(),
         rules_time_modifiers::rule_last_dow(),
@@ -221,7 +248,6 @@ pub fn get() -> Vec<Rule> {
         rules_time_modifiers::rule_around_time(),
         rules_time_modifiers::rule_time_before_last_after_next(),
         rules_time_modifiers::rule_late_last_night(),
-        rules_time_modifiers::rule_yesterday_evening(),
         // === Miscellaneous ===
         rules_misc::rule_interval_by_the_end_of(),
         rules_misc::rule_nth_last_week_of_period(),
@@ -231,6 +257,8 @@ pub fn get() -> Vec<Rule> {
         rules_misc::rule_last_day_of_month(),
         rules_misc::rule_time_of_day_with_timezone(),
         rules_misc::rule_interval_dash_with_timezone(),
+        rules_misc::rule_dual_timezone_time(),
+        rules_misc::rule_dual_timezone_time_parenthesized(),
         rules_misc::rule_weekday_time_of_day_with_timezone(),
         rules_misc::rule_weekday_at_time_with_minutes_and_timezone(),
         rules_misc::rule_end_of_year(),
@@ -240,11 +268,15 @@ pub fn get() -> Vec<Rule> {
         rules_misc::rule_interval_from_time_for_duration_regex(),
         rules_misc::rule_year_numeric(),
         rules_misc::rule_year_bc(),
+        rules_misc::rule_in_year(),
+        rules_misc::rule_decade(),
+        rules_misc::rule_century(),
         rules_misc::rule_time_year_suffix(),
         rules_misc::rule_time_numeral_year_suffix(),
         rules_misc::rule_time_two_thousand_year_suffix(),
         // === Date Composition ===
         rules_date_composition::rule_month(),
+        rules_date_composition::rule_month_abbreviation_period(),
         rules_date_composition::rule_at_word_hour_minute(),
         rules_date_composition::rule_word_hour_minute_tens_units(),
         rules_date_composition::rule_word_hour_minute(),
@@ -276,6 +308,7 @@ pub fn get() -> Vec<Rule> {
         rules_complex_intervals::rule_interval_from_month_dd_dd(),
         rules_complex_intervals::rule_interval_from_dd_dd_month(),
         rules_complex_intervals::rule_interval_from_dd_dd_of_month(),
+        rules_complex_intervals::rule_interval_dotted_day_range(),
         rules_complex_intervals::rule_interval_year_latent(),
         rules_complex_intervals::rule_interval_slash(),
         rules_complex_intervals::rule_interval_tod_dash(),
@@ -295,6 +328,8 @@ pub fn get() -> Vec<Rule> {
         rules_time_of_day::rule_hh_in_the_ampm(),
         rules_time_of_day::rule_hh_oclock_ampm(),
         rules_time_of_day::rule_hh_oclock(),
+        rules_time_of_day::rule_military_hours(),
+        rules_time_of_day::rule_military_words(),
         rules_time_of_day::rule_numeral_ampm(),
         rules_time_of_day::rule_at_numeral_ampm(),
         rules_time_of_day::rule_hh(),
@@ -327,6 +362,7 @@ pub fn get() -> Vec<Rule> {
         rules_time_of_day_advanced::rule_hhmm(),
         rules_time_of_day_advanced::rule_one_hour_short_as_duration(),
         rules_time_of_day_advanced::rule_hhmm_ish(),
+        rules_time_of_day_advanced::rule_hour_ish(),
         rules_time_of_day_advanced::rule_hhhmm(),
         rules_time_of_day_advanced::rule_hhmm_latent(),
         rules_time_of_day_advanced::rule_hhmmss(),
@@ -354,12 +390,46 @@ pub fn get() -> Vec<Rule> {
         rules_months::rule_dd_dash_month_dash_yy(),
         rules_months::rule_month_year(),
         rules_months::rule_dom_month_name_year_numeric(),
+        rules_months::rule_this_next_last_month(),
         // === Phrase Rules ===
         rules_phrases::rule_ides_of_month(),
         rules_phrases::rule_at_integer_hour(),
         rules_phrases::rule_at_hour_minute(),
         rules_phrases::rule_at_tod(),
         rules_phrases::rule_month_day_at_tod(),
+        // === Birthdays and Anniversaries ===
+        rules_anniversaries::rule_date_of_birth(),
+        rules_anniversaries::rule_anniversary(),
+        // === Business Days ===
+        rules_business_days::rule_in_business_days(),
+        rules_business_days::rule_business_days_ago(),
+        rules_business_days::rule_next_business_day(),
+        rules_business_days::rule_business_days_before_after_time(),
+        rules_business_days::rule_business_boundary(),
+        rules_business_days::rule_business_boundary_day(),
+        rules_business_days::rule_day_business_boundary(),
+        // === Fiscal and School Years ===
+        rules_fiscal_years::rule_fiscal_year(),
+        rules_fiscal_years::rule_quarter_fiscal_year(),
+        rules_fiscal_years::rule_fiscal_year_quarter(),
+        rules_fiscal_years::rule_school_year(),
+        // === Enumerated Lists ===
+        rules_lists::rule_day_list_of_month(),
+        rules_lists::rule_month_day_list(),
+        rules_lists::rule_on_day_list(),
+        rules_lists::rule_day_list_latent(),
+        // === Recurrences ===
+        rules_recurrences::rule_every_nth_weekday_of_period(),
+        rules_recurrences::rule_nth_weekday_of_every_period(),
+        rules_recurrences::rule_day_list_of_every_month(),
+        // === ISO 8601 ===
+        rules_iso8601::rule_iso_timestamp(),
+        rules_iso8601::rule_iso_week_date(),
+        rules_iso8601::rule_iso_duration(),
+        // === Log formats ===
+        rules_log_formats::rule_syslog_timestamp(),
+        rules_log_formats::rule_apache_timestamp(),
+        rules_log_formats::rule_rfc2822_timestamp(),
         // === Intersections (MUST be after basic rules) ===
         rules_intersections::rule_intersect(),
         rules_intersections::rule_in_duration_at_time(), // Must be after rule_intersect()
@@ -377,5 +447,19 @@ pub fn get() -> Vec<Rule> {
 
     // rules.extend(periodic_holiday_rules());
 
+    crate::engine::derive_phrases(&mut rules);
+    rules
+}
+
+/// Opt-in itinerary shorthand ("14MAR", "dep 0735 arr 0950"), added to
+/// [`get`] when `Options::transport_shorthand` is enabled.
+pub fn transport() -> Vec<Rule> {
+    let mut rules = vec![
+        rules_transport::rule_compact_date(),
+        rules_transport::rule_compact_date_clock_time(),
+        rules_transport::rule_departure_or_arrival(),
+        rules_transport::rule_departure_arrival(),
+    ];
+    crate::engine::derive_phrases(&mut rules);
     rules
 }
@@ -0,0 +1,60 @@
+//! Birthday and anniversary phrasing ("born on June 14th 1987", "DOB 06/14/1987",
+//! "my anniversary is the 3rd of May").
+//!
+//! These dates are in the past, so a date without a year resolves to its most
+//! recent occurrence. The produced nodes carry a `dob`/`anniversary` hint.
+
+use crate::engine::BucketMask;
+use crate::rules::time::helpers::shift::shift_by_grain;
+use crate::rules::time::helpers::*;
+use crate::rules::time::predicates::*;
+use crate::time_expr::{Constraint, Grain, TimeExpr};
+use crate::{Rule, Token};
+
+/// Prefer the most recent occurrence of a yearless date; other dates are kept.
+///
+/// The next occurrence of a month/day is never before the reference, so one
+/// year before it is the latest one that is.
+fn past_date(expr: &TimeExpr) -> Option<TimeExpr> {
+    let yearless = match expr {
+        TimeExpr::MonthDay { .. } => true,
+        TimeExpr::Intersect { expr, constraint: Constraint::DayOfMonth(_) } => {
+            matches!(constraint_from_expr(expr), Some(Constraint::Month(_)))
+        }
+        TimeExpr::Intersect { expr, constraint: Constraint::Month(_) } => {
+            matches!(constraint_from_expr(expr), Some(Constraint::DayOfMonth(_)))
+        }
+        TimeExpr::Absolute { .. } => false,
+        // Times of day, weekdays, relative shifts, ... are not dates of birth.
+        _ => return None,
+    };
+    if yearless { Some(shift_by_grain(expr.clone(), -1, Grain::Year)) } else { Some(expr.clone()) }
+}
+
+/// "born on <date>", "date of birth: <date>", "DOB <date>"
+pub fn rule_date_of_birth() -> Rule {
+    rule! {
+        name: "born on <date>",
+        pattern: [
+            re!(r"(?i)\b(?:born(?:\s+on)?|date\s+of\s+birth(?:\s+is)?|d\.?o\.?b\.?)\s*:?\s*"),
+            pred!(is_time_expr),
+        ],
+        buckets: BucketMask::empty().bits(),
+        hint: "dob",
+        prod: |tokens: &[Token]| -> Option<TimeExpr> { past_date(get_time_expr(tokens.get(1)?)?) }
+    }
+}
+
+/// "my anniversary is the 3rd of May", "anniversary on June 14"
+pub fn rule_anniversary() -> Rule {
+    rule! {
+        name: "anniversary <date>",
+        pattern: [
+            re!(r"(?i)\banniversary\s+(?:is\s+|was\s+|falls\s+)?(?:on\s+)?"),
+            pred!(is_time_expr),
+        ],
+        buckets: BucketMask::empty().bits(),
+        hint: "anniversary",
+        prod: |tokens: &[Token]| -> Option<TimeExpr> { past_date(get_time_expr(tokens.get(1)?)?) }
+    }
+}
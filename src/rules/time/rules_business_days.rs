@@ -0,0 +1,151 @@
+//! Business-day shifts ("in 3 business days", "next working day",
+//! "2 working days before Friday") and office-hours vocabulary ("EOD", "COB",
+//! "start of business Monday").
+//!
+//! Which days count, and when business opens and closes, is decided at
+//! normalization by the context's [`BusinessCalendar`](crate::BusinessCalendar).
+
+use crate::engine::BucketMask;
+use crate::rules::time::helpers::*;
+use crate::rules::time::predicates::*;
+use crate::time_expr::{BusinessBoundary, Grain, TimeExpr};
+use crate::{Rule, Token, TokenKind};
+
+fn count_from_group(token: &Token, idx: usize) -> Option<i32> {
+    let TokenKind::RegexMatch(groups) = &token.kind else { return None };
+    match groups.get(idx)?.as_str() {
+        "a" | "an" => Some(1),
+        text => parse_integer_text(text),
+    }
+}
+
+/// The day `amount` business days away from `expr`.
+fn business_day(expr: TimeExpr, amount: i32) -> TimeExpr {
+    TimeExpr::StartOf {
+        expr: Box::new(TimeExpr::ShiftBusinessDays { expr: Box::new(expr), amount }),
+        grain: Grain::Day,
+    }
+}
+
+/// "in 3 business days", "within 2 working days"
+pub fn rule_in_business_days() -> Rule {
+    rule! {
+        name: "in <n> business days",
+        pattern: [re!(r"(?i)(?:in|within)\s+(\d{1,3}|an?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|fifteen|twenty|thirty)\s+(?:business|working|work)[\s-]?days?\b")],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let amount = count_from_group(tokens.first()?, 1)?;
+            Some(business_day(TimeExpr::Reference, amount))
+        }
+    }
+}
+
+/// "3 business days ago"
+pub fn rule_business_days_ago() -> Rule {
+    rule! {
+        name: "<n> business days ago",
+        pattern: [re!(r"(?i)(\d{1,3}|an?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|fifteen|twenty|thirty)\s+(?:business|working|work)[\s-]?days?\b\s+ago")],
+        optional_phrases: ["ago"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let amount = count_from_group(tokens.first()?, 1)?;
+            Some(business_day(TimeExpr::Reference, -amount))
+        }
+    }
+}
+
+/// "next business day", "previous working day", "last workday"
+pub fn rule_next_business_day() -> Rule {
+    rule! {
+        name: "next|previous business day",
+        pattern: [re!(r"(?i)(next|following|previous|last|prior)\s+(?:business|working|work)[\s-]?day\b")],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let amount = match groups.get(1)?.as_str() {
+                "next" | "following" => 1,
+                _ => -1,
+            };
+            Some(business_day(TimeExpr::Reference, amount))
+        }
+    }
+}
+
+/// "2 working days before Friday", "3 business days after March 1st"
+pub fn rule_business_days_before_after_time() -> Rule {
+    rule! {
+        name: "<n> business days before|after <time>",
+        pattern: [
+            re!(r"(?i)(\d{1,3}|an?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|fifteen|twenty|thirty)\s+(?:business|working|work)[\s-]?days?\b\s+(before|after|from)\s+"),
+            pred!(is_time_expr),
+        ],
+        optional_phrases: ["before", "after", "from"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let head = tokens.first()?;
+            let amount = count_from_group(head, 1)?;
+            let TokenKind::RegexMatch(groups) = &head.kind else { return None };
+            let amount = if groups.get(2)? == "before" { -amount } else { amount };
+            Some(business_day(get_time_expr(tokens.get(1)?)?.clone(), amount))
+        }
+    }
+}
+
+/// The boundary named by a matched "EOD"/"start of business" phrase.
+fn boundary_from_group(token: &Token, idx: usize) -> Option<BusinessBoundary> {
+    let TokenKind::RegexMatch(groups) = &token.kind else { return None };
+    let text = groups.get(idx)?;
+    if ["start", "open", "beginning"].iter().any(|p| text.starts_with(p)) {
+        Some(BusinessBoundary::Start)
+    } else {
+        Some(BusinessBoundary::End)
+    }
+}
+
+fn business_time(expr: TimeExpr, boundary: BusinessBoundary) -> TimeExpr {
+    TimeExpr::BusinessTime { expr: Box::new(expr), boundary }
+}
+
+/// "EOD", "COB", "end of day", "close of business", "start of business"
+pub fn rule_business_boundary() -> Rule {
+    rule! {
+        name: "EOD|COB|start of business",
+        pattern: [re!(r"(?i)(?:the\s+)?(eod|cob|end\s+of\s+(?:the\s+)?(?:business\s+|working\s+|work\s+)?day|(?:close|end|start|open|opening|beginning)\s+of\s+(?:the\s+)?business(?:\s+day)?)\b")],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            Some(business_time(TimeExpr::Reference, boundary_from_group(tokens.first()?, 1)?))
+        }
+    }
+}
+
+/// "EOD Friday", "COB on Monday", "start of business tomorrow"
+pub fn rule_business_boundary_day() -> Rule {
+    rule! {
+        name: "EOD|COB|start of business <day>",
+        pattern: [
+            re!(r"(?i)(?:the\s+)?(eod|cob|end\s+of\s+(?:the\s+)?(?:business\s+|working\s+|work\s+)?day|(?:close|end|start|open|opening|beginning)\s+of\s+(?:the\s+)?business(?:\s+day)?)\s+(?:on\s+)?"),
+            pred!(is_day_expr),
+        ],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let boundary = boundary_from_group(tokens.first()?, 1)?;
+            Some(business_time(get_time_expr(tokens.get(1)?)?.clone(), boundary))
+        }
+    }
+}
+
+/// "Friday EOD", "tomorrow by COB", "Monday at start of business"
+pub fn rule_day_business_boundary() -> Rule {
+    rule! {
+        name: "<day> EOD|COB|start of business",
+        pattern: [
+            pred!(is_day_expr),
+            re!(r"(?i)\s+(?:at\s+|by\s+)?(?:the\s+)?(eod|cob|end\s+of\s+(?:the\s+)?(?:business\s+|working\s+|work\s+)?day|(?:close|end|start|open|opening|beginning)\s+of\s+(?:the\s+)?business(?:\s+day)?)\b"),
+        ],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let boundary = boundary_from_group(tokens.get(1)?, 1)?;
+            Some(business_time(get_time_expr(tokens.first()?)?.clone(), boundary))
+        }
+    }
+}
@@ -1,16 +1,12 @@
 //! Complex date and time interval patterns
 
 use crate::time_expr::{Constraint, Grain, TimeExpr};
-use crate::{Rule, Token, TokenKind};
-use chrono::{NaiveTime, Timelike};
+use crate::{Rule, RuleGroups, Token, TokenKind};
+use chrono::{Datelike, NaiveTime, Timelike};
 
 use crate::{
     engine::BucketMask,
-    rules::time::{
-        helpers::timezone::{LOCAL_TZ_OFFSET_HOURS, tz_offset_hours},
-        helpers::*,
-        predicates::*,
-    },
+    rules::time::{helpers::producers::year_from, helpers::timezone::in_timezone, helpers::*, predicates::*},
 };
 
 fn time_of_day_constraint(expr: &TimeExpr) -> Option<Constraint> {
@@ -21,28 +17,35 @@ fn time_of_day_constraint(expr: &TimeExpr) -> Option<Constraint> {
     }
 }
 
+/// The exclusive end of a day range ending on `day` of `month`: the day
+/// after it, which for the 31st is in the next month.
+fn day_after(month: u32, day: u32) -> TimeExpr {
+    TimeExpr::Shift { expr: Box::new(TimeExpr::MonthDay { month, day }), amount: 1, grain: Grain::Day }
+}
+
 pub fn rule_interval_month_day_range_regex() -> Rule {
     rule! {
         name: "<month> <dd> - <dd> (interval, regex)",
         pattern: [
             pred!(is_month_expr),
-            re!(r"\s+"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
-            re!(r"(?i)\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::MONTHISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let month = month_from_expr(tokens.first()?)?;
-            let d1 = regex_group_int_value(tokens.get(2)?, 1)? as u32;
-            let d2 = regex_group_int_value(tokens.get(4)?, 1)? as u32;
+            let d1 = regex_group_int_value(tokens.get(1)?, 1)? as u32;
+            let d2 = regex_group_int_value(tokens.get(3)?, 1)? as u32;
             if !(1..=31).contains(&d1) || !(1..=31).contains(&d2) || d1 >= d2 {
                 return None;
             }
 
             // Create interval from MonthDay start to MonthDay end+1 (exclusive)
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -57,24 +60,24 @@ pub fn rule_interval_from_month_day_range_regex() -> Rule {
         name: "from <month> <dd> - <dd> (interval, regex)",
         pattern: [
             re!(r"(?i)from"),
-            re!(r"\s+"),
             pred!(is_month_expr),
-            re!(r"\s+"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
-            re!(r"(?i)\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::MONTHISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = month_from_expr(tokens.get(2)?)?;
-            let d1 = regex_group_int_value(tokens.get(4)?, 1)? as u32;
-            let d2 = regex_group_int_value(tokens.get(6)?, 1)? as u32;
+            let month = month_from_expr(tokens.get(1)?)?;
+            let d1 = regex_group_int_value(tokens.get(2)?, 1)? as u32;
+            let d2 = regex_group_int_value(tokens.get(4)?, 1)? as u32;
             if !(1..=31).contains(&d1) || !(1..=31).contains(&d2) || d1 >= d2 {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -89,24 +92,24 @@ pub fn rule_interval_from_dd_range_month_regex() -> Rule {
         name: "from <dd> - <dd> <month> (interval, regex)",
         pattern: [
             re!(r"(?i)from( the)?"),
-            re!(r"\s+"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
-            re!(r"(?i)\s*(?:\-|to( the)?|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to( the)?|th?ru|through|(un)?til(l)?)\s*"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
-            re!(r"\s+"),
             pred!(is_month_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::MONTHISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = month_from_expr(tokens.get(6)?)?;
-            let d1 = regex_group_int_value(tokens.get(2)?, 1)? as u32;
-            let d2 = regex_group_int_value(tokens.get(4)?, 1)? as u32;
+            let month = month_from_expr(tokens.get(4)?)?;
+            let d1 = regex_group_int_value(tokens.get(1)?, 1)? as u32;
+            let d2 = regex_group_int_value(tokens.get(3)?, 1)? as u32;
             if !(1..=31).contains(&d1) || !(1..=31).contains(&d2) || d1 >= d2 {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -121,26 +124,25 @@ pub fn rule_interval_from_dd_range_of_month_regex() -> Rule {
         name: "from <dd> - <dd> of <month> (interval, regex)",
         pattern: [
             re!(r"(?i)from( the)?"),
-            re!(r"\s+"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
-            re!(r"(?i)\s*(?:\-|to( the)?|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to( the)?|th?ru|through|(un)?til(l)?)\s*"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
-            re!(r"\s+"),
             re!(r"(?i)of"),
-            re!(r"\s+"),
             pred!(is_month_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::MONTHISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = month_from_expr(tokens.get(8)?)?;
-            let d1 = regex_group_int_value(tokens.get(2)?, 1)? as u32;
-            let d2 = regex_group_int_value(tokens.get(4)?, 1)? as u32;
+            let month = month_from_expr(tokens.get(5)?)?;
+            let d1 = regex_group_int_value(tokens.get(1)?, 1)? as u32;
+            let d2 = regex_group_int_value(tokens.get(3)?, 1)? as u32;
             if !(1..=31).contains(&d1) || !(1..=31).contains(&d2) || d1 >= d2 {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -155,25 +157,25 @@ pub fn rule_interval_month_day_sep_month_day_regex() -> Rule {
         name: "<month> <dd> - <month> <dd> (interval, regex)",
         pattern: [
             pred!(is_month_expr),
-            re!(r"\s+"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
-            re!(r"(?i)\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*"),
             pred!(is_month_expr),
-            re!(r"\s+"),
             re!(r"(?i)(\d{1,2})(?:st|nd|rd|th)?"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::MONTHISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let m1 = month_from_expr(tokens.first()?)?;
-            let d1 = regex_group_int_value(tokens.get(2)?, 1)? as u32;
-            let m2 = month_from_expr(tokens.get(4)?)?;
-            let d2 = regex_group_int_value(tokens.get(6)?, 1)? as u32;
+            let d1 = regex_group_int_value(tokens.get(1)?, 1)? as u32;
+            let m2 = month_from_expr(tokens.get(3)?)?;
+            let d2 = regex_group_int_value(tokens.get(4)?, 1)? as u32;
             if !(1..=31).contains(&d1) || !(1..=31).contains(&d2) {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month: m1, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month: m2, day: d2 + 1 };
+            let end_expr = day_after(m2, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -188,23 +190,24 @@ pub fn rule_interval_month_dd_dd() -> Rule {
         name: "<month> dd-dd (interval)",
         pattern: [
             pred!(is_month_expr),
-            re!(r"\s+"),
             pred!(is_day_of_month_expr),
-            re!(r"(?i)\-|to|th?ru|through|(un)?til(l)?"),
+            re!(r"(?i)[-–—]|to|th?ru|through|(un)?til(l)?"),
             pred!(is_day_of_month_expr)
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::MONTHISH | BucketMask::ORDINALISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let month = month_from_expr(tokens.first()?)?;
-            let d1 = day_of_month_from_expr(tokens.get(2)?)?;
-            let d2 = day_of_month_from_expr(tokens.get(4)?)?;
+            let d1 = day_of_month_from_expr(tokens.get(1)?)?;
+            let d2 = day_of_month_from_expr(tokens.get(3)?)?;
 
             if d1 >= d2 {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -219,14 +222,15 @@ pub fn rule_interval_dd_dd_month() -> Rule {
         name: "dd-dd <month> (interval)",
         pattern: [
             pred!(is_day_of_month_expr),
-            re!(r"(?i)\-|to|th?ru|through|(un)?til(l)?"),
+            re!(r"(?i)[-–—]|to|th?ru|through|(un)?til(l)?"),
             pred!(is_day_of_month_expr),
-            re!(r"\s+"),
             pred!(is_month_expr)
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::MONTHISH | BucketMask::ORDINALISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = month_from_expr(tokens.get(4)?)?;
+            let month = month_from_expr(tokens.get(3)?)?;
             let d1 = day_of_month_from_expr(tokens.first()?)?;
             let d2 = day_of_month_from_expr(tokens.get(2)?)?;
 
@@ -235,7 +239,7 @@ pub fn rule_interval_dd_dd_month() -> Rule {
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -250,10 +254,11 @@ pub fn rule_interval_day_to_month_day() -> Rule {
         name: "dd-dd <day month> (interval)",
         pattern: [
             pred!(is_day_of_month_expr),
-            re!(r"(?i)\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*"),
             pred!(is_month_day_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::MONTHISH | BucketMask::ORDINALISH).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let d1 = day_of_month_from_expr(tokens.first()?)?;
             let (month, d2) = month_day_from_expr(tokens.get(2)?)?;
@@ -263,12 +268,7 @@ pub fn rule_interval_day_to_month_day() -> Rule {
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_base = TimeExpr::MonthDay { month, day: d2 };
-            let end_expr = TimeExpr::Shift {
-                expr: Box::new(end_base),
-                amount: 1,
-                grain: Grain::Day,
-            };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -283,25 +283,25 @@ pub fn rule_interval_from_month_dd_dd() -> Rule {
         name: "from <month> dd-dd (interval)",
         pattern: [
             re!(r"(?i)from"),
-            re!(r"\s+"),
             pred!(is_month_expr),
-            re!(r"\s+"),
             pred!(is_day_of_month_expr),
-            re!(r"(?i)\-|to|th?ru|through|(un)?til(l)?"),
+            re!(r"(?i)[-–—]|to|th?ru|through|(un)?til(l)?"),
             pred!(is_day_of_month_expr)
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::MONTHISH | BucketMask::ORDINALISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = month_from_expr(tokens.get(2)?)?;
-            let d1 = day_of_month_from_expr(tokens.get(4)?)?;
-            let d2 = day_of_month_from_expr(tokens.get(6)?)?;
+            let month = month_from_expr(tokens.get(1)?)?;
+            let d1 = day_of_month_from_expr(tokens.get(2)?)?;
+            let d2 = day_of_month_from_expr(tokens.get(4)?)?;
 
             if d1 >= d2 {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -316,27 +316,25 @@ pub fn rule_interval_from_dd_dd_month() -> Rule {
         name: "from the <day-of-month> (ordinal or number) to the <day-of-month> (ordinal or number) <named-month> (interval)",
         pattern: [
             re!(r"(?i)from( the)?"),
-            re!(r"\s+"),
             pred!(is_day_of_month_expr),
-            re!(r"\s+"),
-            re!(r"(?i)\-|to( the)?|th?ru|through|(un)?til(l)?"),
-            re!(r"\s+"),
+            re!(r"(?i)[-–—]|to( the)?|th?ru|through|(un)?til(l)?"),
             pred!(is_day_of_month_expr),
-            re!(r"\s+"),
             pred!(is_month_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::MONTHISH | BucketMask::ORDINALISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = month_from_expr(tokens.get(8)?)?;
-            let d1 = day_of_month_from_expr(tokens.get(2)?)?;
-            let d2 = day_of_month_from_expr(tokens.get(6)?)?;
+            let month = month_from_expr(tokens.get(4)?)?;
+            let d1 = day_of_month_from_expr(tokens.get(1)?)?;
+            let d2 = day_of_month_from_expr(tokens.get(3)?)?;
 
             if d1 >= d2 {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -351,29 +349,79 @@ pub fn rule_interval_from_dd_dd_of_month() -> Rule {
         name: "from the <day-of-month> (ordinal or number) to the <day-of-month> (ordinal or number) of <named-month> (interval)",
         pattern: [
             re!(r"(?i)from( the)?"),
-            re!(r"\s+"),
             pred!(is_day_of_month_expr),
-            re!(r"\s+"),
-            re!(r"(?i)\-|to( the)?|th?ru|through|(un)?til(l)?"),
-            re!(r"\s+"),
+            re!(r"(?i)[-–—]|to( the)?|th?ru|through|(un)?til(l)?"),
             pred!(is_day_of_month_expr),
-            re!(r"\s+"),
             re!(r"(?i)of"),
-            re!(r"\s+"),
             pred!(is_month_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::MONTHISH | BucketMask::ORDINALISH).bits(),
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = month_from_expr(tokens.get(10)?)?;
-            let d1 = day_of_month_from_expr(tokens.get(2)?)?;
-            let d2 = day_of_month_from_expr(tokens.get(6)?)?;
+            let month = month_from_expr(tokens.get(5)?)?;
+            let d1 = day_of_month_from_expr(tokens.get(1)?)?;
+            let d2 = day_of_month_from_expr(tokens.get(3)?)?;
 
             if d1 >= d2 {
                 return None;
             }
 
             let start_expr = TimeExpr::MonthDay { month, day: d1 };
-            let end_expr = TimeExpr::MonthDay { month, day: d2 + 1 };
+            let end_expr = day_after(month, d2);
+
+            Some(TimeExpr::IntervalBetween {
+                start: Box::new(start_expr),
+                end: Box::new(end_expr),
+            })
+        }
+    }
+}
+
+/// Day-first dotted day ranges: "14.-16.3.", "14.3.-16.3.2025". The start
+/// takes the month of the end unless it has its own.
+pub fn rule_interval_dotted_day_range() -> Rule {
+    rule! {
+        name: "<dd>.(<mm>.) - <dd>.<mm>.(<yyyy>) (interval)",
+        pattern: [
+            re!(r"\b(\d{1,2})\.((?:\d{1,2}\.)?)\s*[-–—]\s*(\d{1,2})\.(\d{1,2})\.((?:\d{4}|\d{2})\b|)")
+        ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::INTERVALS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let d1 = regex_group_int_value(tokens.first()?, 1)? as u32;
+            let d2 = regex_group_int_value(tokens.first()?, 3)? as u32;
+            let m2 = regex_group_int_value(tokens.first()?, 4)? as u32;
+            let m1 = match groups.get(2)?.trim_end_matches('.') {
+                "" => m2,
+                month => month.parse().ok()?,
+            };
+            if (m1, d1) >= (m2, d2) {
+                return None;
+            }
+
+            let year = groups.get(5)?.parse::<i64>().ok().map(year_from);
+            // Validate against a leap year so "29.2." passes without one.
+            let end_date = chrono::NaiveDate::from_ymd_opt(year.unwrap_or(2000), m2, d2)?;
+            chrono::NaiveDate::from_ymd_opt(year.unwrap_or(2000), m1, d1)?;
+
+            let (start_expr, end_expr) = match year {
+                Some(year) => {
+                    let after = end_date.succ_opt()?;
+                    (
+                        TimeExpr::Absolute { year, month: m1, day: d1, hour: None, minute: None },
+                        TimeExpr::Absolute {
+                            year: after.year(),
+                            month: after.month(),
+                            day: after.day(),
+                            hour: None,
+                            minute: None,
+                        },
+                    )
+                }
+                None => (TimeExpr::MonthDay { month: m1, day: d1 }, day_after(m2, d2)),
+            };
 
             Some(TimeExpr::IntervalBetween {
                 start: Box::new(start_expr),
@@ -387,9 +435,10 @@ pub fn rule_interval_year_latent() -> Rule {
     rule! {
         name: "<year> (latent) - <year> (latent) (interval)",
         pattern: [
-            re!(r"(?i)(\d{4})\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*(\d{4})")
+            re!(r"(?i)(\d{4})\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*(\d{4})")
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::INTERVALS | RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let y1 = regex_group_int_value(tokens.first()?, 1)? as i32;
             let y2 = regex_group_int_value(tokens.first()?, 2)? as i32;
@@ -432,6 +481,7 @@ pub fn rule_interval_slash() -> Rule {
             re!(r"(?i)(\d{4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])\s+([01]?\d|2[0-3]):([0-5]\d):([0-5]\d)"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_year = regex_group_int_value(tokens.first()?, 1)? as i32;
             let start_month = regex_group_int_value(tokens.first()?, 2)? as u32;
@@ -468,10 +518,11 @@ pub fn rule_interval_tod_dash() -> Rule {
         name: "<time-of-day> - <time-of-day> (interval)",
         pattern: [
             pred!(is_time_of_day_expr),
-            re!(r"(?i)\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*"),
             pred!(is_time_of_day_expr),
         ],
         buckets: (BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_expr = get_time_expr(tokens.first()?)?.clone();
             let mut end_expr = get_time_expr(tokens.get(2)?)?.clone();
@@ -481,9 +532,10 @@ pub fn rule_interval_tod_dash() -> Rule {
             let end_time = time_from_expr(tokens.get(2)?);
 
             // If end time is earlier than start time (e.g., "8am to 6" where 6 is interpreted as 6am),
-            // adjust it to be in the afternoon/evening (add 12 hours)
+            // adjust it to be in the afternoon/evening (add 12 hours). When that is still before
+            // the start ("11pm - 1am") the interval crosses midnight instead.
             if let (Some(st), Some(et)) = (start_time, end_time) {
-                if et < st && et.hour() < 12 {
+                if et < st && et.hour() < 12 && et.hour() + 12 > st.hour() {
                     // End time is earlier and is in AM, shift to PM
                     let adjusted_hour = et.hour() + 12;
                     if let Some(adjusted_time) = chrono::NaiveTime::from_hms_opt(adjusted_hour, et.minute(), et.second()) {
@@ -530,48 +582,27 @@ pub fn rule_interval_tod_dash_tz() -> Rule {
         name: "<time-of-day> - <time-of-day> (interval) timezone",
         pattern: [
             pred!(is_time_of_day_expr),
-            re!(r"(?i)\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*"),
             pred!(is_time_of_day_expr),
-            re!(r"\s+"),
             pattern_regex(timezone_pattern()),
         ],
         buckets: (BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::INTERVALS | RuleGroups::TIMEZONES,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_expr = get_time_expr(tokens.first()?)?.clone();
             let end_expr = get_time_expr(tokens.get(2)?)?.clone();
-            let tz = first(&tokens[4..])?;
-
-            let tz_offset = tz_offset_hours(&tz)?;
-            let delta = LOCAL_TZ_OFFSET_HOURS - tz_offset;
-
-            let start_shifted = if delta == 0 {
-                start_expr
-            } else {
-                TimeExpr::Shift {
-                    expr: Box::new(start_expr),
-                    amount: delta,
-                    grain: Grain::Hour,
-                }
-            };
-            let end_shifted = if delta == 0 {
-                end_expr
-            } else {
-                TimeExpr::Shift {
-                    expr: Box::new(end_expr),
-                    amount: delta,
-                    grain: Grain::Hour,
-                }
-            };
-            let end_shifted = TimeExpr::Shift {
-                expr: Box::new(end_shifted),
+            let tz = first(&tokens[3..])?;
+            let end_expr = TimeExpr::Shift {
+                expr: Box::new(end_expr),
                 amount: 1,
                 grain: Grain::Minute,
             };
-
-            Some(TimeExpr::IntervalBetween {
-                start: Box::new(start_shifted),
-                end: Box::new(end_shifted),
-            })
+            let interval = TimeExpr::IntervalBetween {
+                start: Box::new(start_expr),
+                end: Box::new(end_expr),
+            };
+            in_timezone(interval, &tz)
         }
     }
 }
@@ -581,56 +612,40 @@ pub fn rule_interval_tod_tz_dash_tod_tz() -> Rule {
         name: "<time-of-day> <tz> - <time-of-day> <tz> (interval)",
         pattern: [
             pred!(is_time_of_day_expr),
-            re!(r"\s+"),
             pattern_regex(timezone_pattern()),
-            re!(r"(?i)\s*(?:\-|:|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|:|to|th?ru|through|(un)?til(l)?)\s*"),
             pred!(is_time_of_day_expr),
-            re!(r"\s+"),
             pattern_regex(timezone_pattern()),
         ],
-        buckets: (BucketMask::HAS_COLON).bits(),
+        buckets: BucketMask::empty().bits(),
+        group: RuleGroups::INTERVALS | RuleGroups::TIMEZONES,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_expr = get_time_expr(tokens.first()?)?.clone();
-            let start_tz = first(&tokens[2..])?;
-            let end_expr = get_time_expr(tokens.get(4)?)?.clone();
-            let end_tz = first(&tokens[6..])?;
-
-            // Verify both timezones are the same
-            if start_tz.to_lowercase() != end_tz.to_lowercase() {
-                return None;
-            }
-
-            let tz_offset = tz_offset_hours(&start_tz)?;
-            let delta = LOCAL_TZ_OFFSET_HOURS - tz_offset;
+            let start_tz = first(&tokens[1..])?;
+            let end_expr = get_time_expr(tokens.get(3)?)?.clone();
+            let end_tz = first(&tokens[4..])?;
 
-            let start_shifted = if delta == 0 {
-                start_expr
-            } else {
-                TimeExpr::Shift {
-                    expr: Box::new(start_expr),
-                    amount: delta,
-                    grain: Grain::Hour,
-                }
-            };
-            let end_shifted = if delta == 0 {
-                end_expr
-            } else {
-                TimeExpr::Shift {
-                    expr: Box::new(end_expr),
-                    amount: delta,
-                    grain: Grain::Hour,
-                }
-            };
-            let end_shifted = TimeExpr::Shift {
-                expr: Box::new(end_shifted),
+            let end_expr = TimeExpr::Shift {
+                expr: Box::new(end_expr),
                 amount: 1,
                 grain: Grain::Minute,
             };
-
-            Some(TimeExpr::IntervalBetween {
-                start: Box::new(start_shifted),
-                end: Box::new(end_shifted),
-            })
+            // Endpoints in different zones ("9am ET to 11am PT") are each
+            // converted from their own; resolution flags a range that comes
+            // out empty or backwards.
+            if start_tz.to_lowercase() != end_tz.to_lowercase() {
+                Some(TimeExpr::IntervalBetween {
+                    start: Box::new(in_timezone(start_expr, &start_tz)?),
+                    end: Box::new(in_timezone(end_expr, &end_tz)?),
+                })
+            } else {
+                let interval = TimeExpr::IntervalBetween {
+                    start: Box::new(start_expr),
+                    end: Box::new(end_expr),
+                };
+                in_timezone(interval, &start_tz)
+            }
         }
     }
 }
@@ -641,12 +656,13 @@ pub fn rule_interval_tod_dash_on_weekday() -> Rule {
         pattern: [
             re!(r"(?i)(from\s+)?"),
             pred!(is_time_of_day_expr),
-            re!(r"(?i)\s*(?:\-|to|th?ru|through|(un)?til(l)?)\s*"),
+            re!(r"(?i)\s*(?:[-–—]|to|th?ru|through|(un)?til(l)?)\s*"),
             pred!(is_time_of_day_expr),
             re!(r"(?i)\s+on\s+"),
             pred!(is_weekday_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_tod_expr = get_time_expr(tokens.get(1)?)?.clone();
             let end_tod_expr = get_time_expr(tokens.get(3)?)?.clone();
@@ -703,6 +719,7 @@ pub fn rule_interval_between_tod_and_tod_on_weekday() -> Rule {
             pred!(is_weekday_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_tod_expr = get_time_expr(tokens.get(1)?)?.clone();
             let end_tod_expr = get_time_expr(tokens.get(3)?)?.clone();
@@ -757,6 +774,7 @@ pub fn rule_interval_later_than_tod_but_before_tod() -> Rule {
             pred!(is_time_of_day_expr),
         ],
         buckets: (BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_expr = get_time_expr(tokens.get(1)?)?.clone();
             let end_expr = get_time_expr(tokens.get(3)?)?.clone();
@@ -788,6 +806,7 @@ pub fn rule_interval_later_than_tod_but_before_tod_on_weekday() -> Rule {
             pred!(is_weekday_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_tod_expr = get_time_expr(tokens.get(1)?)?.clone();
             let end_tod_expr = get_time_expr(tokens.get(3)?)?.clone();
@@ -843,6 +862,7 @@ pub fn rule_interval_weekday_from_tod_to_tod() -> Rule {
             pred!(is_time_of_day_expr),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday_expr = get_time_expr(tokens.first()?)?.clone();
             let start_tod_expr = get_time_expr(tokens.get(2)?)?.clone();
@@ -891,9 +911,10 @@ pub fn rule_interval_hour_dash_hour_ampm() -> Rule {
     rule! {
         name: "<hour>-<hour> am|pm",
         pattern: [
-            re!(r"(?i)(?:(?:from|around)\s+)?(\d{1,2})\s*(?:\-|to)\s*(\d{1,2})\s*(?:in\s+the\s+)?([ap])\.?m?\.?"),
+            re!(r"(?i)(?:(?:from|around)\s+)?(\d{1,2})\s*(?:[-–—]|to)\s*(\d{1,2})\s*(?:in\s+the\s+)?([ap])\.?m?\.?"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_hour = regex_group_int_value(tokens.first()?, 1)? as i64;
             let end_hour = regex_group_int_value(tokens.first()?, 2)? as i64;
@@ -949,9 +970,10 @@ pub fn rule_interval_weekday_hour_dash_hour_ampm() -> Rule {
         name: "<weekday> <hour>-<hour> am|pm",
         pattern: [
             pred!(is_weekday_expr),
-            re!(r"(?i)\s+(?:(?:from|around)\s+)?(\d{1,2})\s*(?:\-|to)\s*(\d{1,2})\s*(?:in\s+the\s+)?([ap])\.?m?\.?"),
+            re!(r"(?i)\s+(?:(?:from|around)\s+)?(\d{1,2})\s*(?:[-–—]|to)\s*(\d{1,2})\s*(?:in\s+the\s+)?([ap])\.?m?\.?"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday_expr = get_time_expr(tokens.first()?)?.clone();
 
@@ -1012,6 +1034,7 @@ pub fn rule_interval_tod_to_word_hour_ampm() -> Rule {
             re!(r"(?i)\s+(?:to|(?:un)?til(?:l)?)\s+(one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s+([ap])\.?\s?m?\.?"),
         ],
         buckets: (BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start_expr = get_time_expr(tokens.get(1)?)?.clone();
 
@@ -61,6 +61,36 @@ pub fn rule_cycle_this_last_next() -> Rule {
     }
 }
 
+/// "the <cycle> after next|before last" (the week after next, the year before last)
+pub fn rule_cycle_after_next_before_last() -> Rule {
+    rule! {
+        name: "the <cycle> after next|before last",
+        pattern: [re!(r"(?i)(?:the\s+)?(year|quarter|month|week|day)\s+(after next|before last)\b")],
+        optional_phrases: ["after next", "before last"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let grain = grain_from_cycle(groups.get(1)?)?;
+            let amount = if groups.get(2)? == "after next" { 2 } else { -2 };
+
+            let shifted = shift_by_grain(TimeExpr::Reference, amount, grain);
+            let expr = if grain == Grain::Week {
+                TimeExpr::IntervalOf {
+                    expr: Box::new(shifted),
+                    grain,
+                }
+            } else {
+                TimeExpr::StartOf {
+                    expr: Box::new(shifted),
+                    grain,
+                }
+            };
+
+            Some(expr)
+        }
+    }
+}
+
 /// "this|last|next qtr" - special handling for quarter abbreviation
 pub fn rule_cycle_this_last_next_qtr() -> Rule {
     rule! {
@@ -96,36 +126,6 @@ pub fn rule_cycle_this_last_next_qtr() -> Rule {
     }
 }
 
-/// "upcoming <grain>" (upcoming week, upcoming month)
-pub fn rule_upcoming_grain() -> Rule {
-    rule! {
-        name: "upcoming <cycle>",
-        pattern: [re!(r"(?i)upcoming\s+(year|quarter|month|week|day)s?\b")],
-        required_phrases: ["upcoming", "year", "quarter", "month", "week", "day"],
-        buckets: BucketMask::empty().bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let grain_str = first_match_lower(tokens)?;
-            let grain_word = grain_str.split_whitespace().nth(1)?;
-            let grain = grain_from_cycle(grain_word)?;
-
-            let shifted = shift_by_grain(TimeExpr::Reference, 1, grain);
-            let expr = if grain == Grain::Week {
-                TimeExpr::IntervalOf {
-                    expr: Box::new(shifted),
-                    grain,
-                }
-            } else {
-                TimeExpr::StartOf {
-                    expr: Box::new(shifted),
-                    grain,
-                }
-            };
-
-            Some(expr)
-        }
-    }
-}
-
 /// "upcoming qtr"
 pub fn rule_upcoming_grain_alt() -> Rule {
     rule! {
@@ -151,18 +151,18 @@ pub fn rule_upcoming_n_cycles() -> Rule {
         pattern: [
             re!(r"(?i)upcoming\s+"),
             pred!(|t: &Token| crate::rules::numeral::predicates::is_integer(t)),
-            re!(r"\s+"),
             re!(r"(?i)(year|quarter|month|week|day)s?\b"),
         ],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Numeral],
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let n = integer_value(tokens.get(1)?)? as i32;
             if n <= 0 {
                 return None;
             }
 
-            let grain_str = match &tokens.get(3)?.kind {
+            let grain_str = match &tokens.get(2)?.kind {
                 TokenKind::RegexMatch(groups) => groups.get(1)?,
                 _ => return None,
             };
@@ -185,19 +185,19 @@ pub fn rule_n_upcoming_cycles() -> Rule {
         name: "<integer> upcoming <cycle>",
         pattern: [
             pred!(|t: &Token| crate::rules::numeral::predicates::is_integer(t)),
-            re!(r"\s+"),
             re!(r"(?i)upcoming\s+"),
             re!(r"(?i)(year|quarter|month|week|day)s?\b"),
         ],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Numeral],
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let n = integer_value(tokens.first()?)? as i32;
             if n <= 0 {
                 return None;
             }
 
-            let grain_str = match &tokens.get(3)?.kind {
+            let grain_str = match &tokens.get(2)?.kind {
                 TokenKind::RegexMatch(groups) => groups.get(1)?,
                 _ => return None,
             };
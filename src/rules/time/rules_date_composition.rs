@@ -12,7 +12,7 @@ pub fn rule_at_word_hour_minute() -> Rule {
     rule! {
         name: "at <word-hour> <word-minute>",
         pattern: [re!(r"(?i)at\s+(one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s+(ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|twenty|thirty|forty|fifty)")],
-        buckets: (BucketMask::HAS_COLON).bits(),
+        buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let (hour_word, minute_word) = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => (groups.get(1)?, groups.get(2)?),
@@ -87,11 +87,15 @@ pub fn rule_word_hour_zero_minute() -> Rule {
 pub fn rule_time_expr_at_time_of_day() -> Rule {
     rule! {
         name: "<time> <time-of-day>",
-        pattern: [pred!(is_time_expr), re!(r"(?i)\s+"), pred!(is_time_of_day_expr)],
+        pattern: [pred!(is_time_expr), pred!(is_bare_time_of_day_expr)],
         buckets: (BucketMask::HAS_COLON).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            if has_time_of_day_expr(tokens.first()?) {
+                return None;
+            }
             let time_expr = get_time_expr(tokens.first()?)?.clone();
-            let time_of_day = time_from_expr(tokens.get(2)?)?;
+            let time_of_day = time_from_expr(tokens.get(1)?)?;
 
             Some(TimeExpr::Intersect {
                 expr: Box::new(time_expr),
@@ -106,9 +110,12 @@ pub fn rule_time_expr_explicit_at_time_of_day() -> Rule {
 
     rule! {
         name: "<time> at <time-of-day>",
-        pattern: [pred!(is_time), re!(r"(?i)\s+at\s+"), pred!(is_time_of_day_expr)],
+        pattern: [pred!(is_time), words!("at"), pred!(is_bare_time_of_day_expr)],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            if has_time_of_day_expr(tokens.first()?) {
+                return None;
+            }
             // Get time expression - works with both TimeExpr and TimeData
             let time_expr = get_time_expr(tokens.first()?)?. clone();
             let time_of_day = time_from_expr(tokens.get(2)?)?;
@@ -126,11 +133,15 @@ pub fn rule_at_time_on_time() -> Rule {
 
     rule! {
         name: "at <time-of-day> <time>",
-        pattern: [re!(r"(?i)at\s+"), pred!(is_time_of_day_expr), re!(r"(?i)\s+"), pred!(is_time)],
+        pattern: [re!(r"(?i)at\s+"), pred!(is_bare_time_of_day_expr), pred!(is_time)],
         buckets: (BucketMask::HAS_COLON).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            if has_time_of_day_expr(tokens.get(2)?) {
+                return None;
+            }
             let time_of_day = time_from_expr(tokens.get(1)?)?;
-            let time_expr = get_time_expr(tokens.get(3)?)?.clone();
+            let time_expr = get_time_expr(tokens.get(2)?)?.clone();
 
             Some(TimeExpr::Intersect {
                 expr: Box::new(time_expr),
@@ -143,13 +154,15 @@ pub fn rule_at_time_on_time() -> Rule {
 pub fn rule_ordinal_words_day_of_month() -> Rule {
     rule! {
         name: "ordinal words (day of month)",
-        pattern: [re!(r"(?i)\b(first|second|third|fourth|fifth|sixth|seventh|eighth|ninth|tenth|eleventh|twelfth|thirteenth|fourteenth|fifteenth|sixteenth|seventeenth|eighteenth|nineteenth|twentieth|twenty-first|twenty-second|twenty-third|twenty-fourth|twenty-fifth|twenty-sixth|twenty-seventh|twenty-eighth|twenty-ninth|thirtieth|thirty-first)\b")],
+        pattern: [re!(r"(?i)\b(first|second|third|fourth|fifth|sixth|seventh|eighth|ninth|tenth|eleventh|twelfth|thirteenth|fourteenth|fifteenth|sixteenth|seventeenth|eighteenth|nineteenth|twentieth|twenty[\s-]+(?:first|second|third|fourth|fifth|sixth|seventh|eighth|ninth)|thirtieth|thirty[\s-]+first)\b")],
         buckets: (BucketMask::HAS_COLON | BucketMask::ORDINALISH).bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let ordinal = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => groups.get(1).or_else(|| groups.first())?.to_lowercase(),
                 _ => return None,
             };
+            // "twenty first" and "twenty-first" alike.
+            let ordinal = ordinal.split(|c: char| c.is_whitespace() || c == '-').filter(|w| !w.is_empty()).collect::<Vec<_>>().join("-");
 
             let day = match ordinal.as_str() {
                 "first" => 1,
@@ -274,11 +287,12 @@ pub fn rule_month_the_ordinal_day() -> Rule {
 pub fn rule_ordinal_day_month() -> Rule {
     rule! {
         name: "<day-of-month> <month>",
-        pattern: [pred!(is_day_of_month_numeral), re!(r"\s+"), pred!(is_month_expr)],
+        pattern: [pred!(is_day_of_month_numeral), pred!(is_month_expr)],
         buckets: (BucketMask::ORDINALISH | BucketMask::MONTHISH).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let day = day_of_month_from_expr(tokens.first()?)?;
-            let month = month_from_expr(tokens.get(2)?)?;
+            let month = month_from_expr(tokens.get(1)?)?;
 
             Some(TimeExpr::MonthDay { month, day })
         }
@@ -339,7 +353,7 @@ pub fn rule_dd_month_no_space_regex() -> Rule {
 pub fn rule_month_day_no_space_regex() -> Rule {
     rule! {
         name: "monthdd (no space, regex)",
-        pattern: [re!(r"(?i)(january|february|march|april|may|june|july|august|september|october|november|december|jan|feb|mar|apr|may|jun|jul|aug|sep|sept|oct|nov|dec)([1-9]|[12]\d|3[01])")],
+        pattern: [re!(r"(?i)(january|february|march|april|may|june|july|august|september|october|november|december|jan|feb|mar|apr|may|jun|jul|aug|sep|sept|oct|nov|dec)\.?([1-9]|[12]\d|3[01])")],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::MONTHISH).bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let day = regex_group_int_value(tokens.first()?, 2)? as u32;
@@ -392,7 +406,7 @@ pub fn rule_weekday_comma_month_day() -> Rule {
 pub fn rule_weekday_comma_month_day_no_space() -> Rule {
     rule! {
         name: "<weekday>, <month><day>",
-        pattern: [re!(r"(?i)(monday|mon|tuesday|tue|tues|wednesday|wed|thursday|thu|thurs|friday|fri|saturday|sat|sunday|sun),\s*(january|jan|february|feb|march|mar|april|apr|may|june|jun|july|jul|august|aug|september|sept|sep|october|oct|november|nov|december|dec)(\d{1,2})")],
+        pattern: [re!(r"(?i)(monday|mon|tuesday|tue|tues|wednesday|wed|thursday|thu|thurs|friday|fri|saturday|sat|sunday|sun),\s*(january|jan|february|feb|march|mar|april|apr|may|june|jun|july|jul|august|aug|september|sept|sep|october|oct|november|nov|december|dec)\.?(\d{1,2})")],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH | BucketMask::MONTHISH).bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let groups = match &tokens.first()?.kind {
@@ -465,6 +479,9 @@ pub fn rule_month() -> Rule {
             Pattern::Regex(&MONTH_PATTERN_REGEX),
         ],
         buckets: (BucketMask::HAS_COLON | BucketMask::MONTHISH).bits(),
+        // "we march", "they may": the verb, not the month.
+        not_preceded_by: r"(?i)\b(?:i|we|you|they|he|she|let's|lets)\s+(?:march|may)",
+        not_followed_by: r"(?i)march\s+(?:forward|ahead|along|off|away|onwards?|past|through|towards?)\b|may\s+(?:be|have|not|also|well|need|want|help|get|take|make)\b",
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let month_match = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => groups.first()?.as_str(),
@@ -482,3 +499,27 @@ pub fn rule_month() -> Rule {
         }
     }
 }
+
+/// "Jan. 5", "Sept. 12, 2014": an abbreviated month with its period, when a
+/// day or year follows. A period ending the text or the sentence ("see you
+/// in Sept.") is left out of the span, and "named-month" takes the name.
+pub fn rule_month_abbreviation_period() -> Rule {
+    rule! {
+        name: "named-month (abbreviated, with period)",
+        pattern: [re!(r"(?i)\b(jan|feb|mar|apr|jun|jul|aug|sept|sep|oct|nov|dec)\.")],
+        buckets: BucketMask::MONTHISH.bits(),
+        not_followed_by: r"(?i)[a-z]+\.\s*(?:$|[^\s\d])",
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let month_match = match &tokens.first()?.kind {
+                TokenKind::RegexMatch(groups) => groups.get(1)?.to_lowercase(),
+                _ => return None,
+            };
+            let month = MONTH_NAME.get(month_match.as_str())?;
+
+            Some(TimeExpr::Intersect {
+                expr: Box::new(TimeExpr::Reference),
+                constraint: Constraint::Month(*month),
+            })
+        }
+    }
+}
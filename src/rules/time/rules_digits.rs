@@ -5,7 +5,7 @@ use crate::rules::time::helpers::producers::year_from;
 use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
 use crate::time_expr::{Constraint, TimeExpr};
-use crate::{Rule, Token};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 
 /// yyyy-mm-dd format
 pub fn rule_yyyy_mm_dd() -> Rule {
@@ -31,16 +31,10 @@ pub fn rule_yyyy() -> Rule {
         name: "yyyy (year-only)",
         pattern: [re!(r"\b(\d{4})\b")],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let year = regex_group_int_value(tokens.first()?, 1)? as i32;
-
-            Some(TimeExpr::Absolute {
-                year,
-                month: 1,
-                day: 1,
-                hour: None,
-                minute: None,
-            })
+            Some(year_interval(year))
         },
     }
 }
@@ -98,7 +92,8 @@ pub fn rule_mm_yyyy() -> Rule {
     }
 }
 
-/// month/day numeric (e.g., 12/25)
+/// month/day numeric (e.g., 12/25), or day first with a slash when the
+/// first number can't be a month (25/12; "14-6" reads more like a score)
 pub fn rule_month_day_numeric() -> Rule {
     rule! {
         name: "month/day numeric",
@@ -107,8 +102,11 @@ pub fn rule_month_day_numeric() -> Rule {
         ],
         buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = regex_group_int_value(tokens.first()?, 1)? as u32;
-            let day = regex_group_int_value(tokens.first()?, 2)? as u32;
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let first = regex_group_int_value(tokens.first()?, 1)? as u32;
+            let second = regex_group_int_value(tokens.first()?, 2)? as u32;
+            let (month, day) =
+                if first > 12 && groups.first()?.contains('/') { (second, first) } else { (first, second) };
 
             // Validate ranges
             if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
@@ -120,33 +118,34 @@ pub fn rule_month_day_numeric() -> Rule {
     }
 }
 
-/// month/day/year numeric (e.g., 12/25/2024)
+/// month/day/year numeric (e.g., 12/25/2024, 3/5/99), or day first when the
+/// first number can't be a month (31.12.25, 31/12/2025)
 pub fn rule_month_day_year_numeric() -> Rule {
     rule! {
         name: "month/day/year numeric",
         pattern: [
-            re!(r"(?i)(?:on\s+)?(\d{1,2})\s*[/\-.]\s*(\d{1,2})\s*[/\-.]\s*(\d{2,4})")
+            re!(r"(?i)(?:on\s+)?(\d{1,2})\s*[/\-.]\s*(\d{1,2})\s*[/\-.]\s*(\d{2,4})\b")
         ],
         buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let month = regex_group_int_value(tokens.first()?, 1)? as u32;
-            let day = regex_group_int_value(tokens.first()?, 2)? as u32;
-            let year_val = regex_group_int_value(tokens.first()?, 3)?;
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let first = regex_group_int_value(tokens.first()?, 1)? as u32;
+            let second = regex_group_int_value(tokens.first()?, 2)? as u32;
+            let year_text = groups.get(3)?;
+            let year_val: i64 = year_text.parse().ok()?;
 
-            let year = year_from(year_val);
+            let (month, day) = if first > 12 { (second, first) } else { (first, second) };
 
             // Validate ranges
             if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
                 return None;
             }
 
-            Some(TimeExpr::Absolute {
-                year,
-                month,
-                day,
-                hour: None,
-                minute: None,
-            })
+            if year_text.len() == 2 {
+                Some(TimeExpr::ShortYearDate { year: year_val as u32, month, day })
+            } else {
+                Some(TimeExpr::Absolute { year: year_from(year_val), month, day, hour: None, minute: None })
+            }
         }
     }
 }
@@ -157,6 +156,7 @@ pub fn rule_integer_day_of_month() -> Rule {
         name: "integer (day of month)",
         pattern: [re!(r"\b([1-9]|[12]\d|3[01])\b")],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let day = regex_group_int_value(tokens.first()?, 1)? as u32;
             if !(1..=31).contains(&day) {
@@ -196,18 +196,11 @@ pub fn rule_month_day_year() -> Rule {
 pub fn rule_year_ad() -> Rule {
     rule! {
         name: "in <year> AD",
-        pattern: [re!(r"(?i)(in\s+)?(\d{1,4})\s+(a\.?d\.?)")],
+        pattern: [re!(r"(?i)(?:in\s+)?(\d{1,4})\s+(?:ad|a\.d)\b\.?")],
         buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let year = regex_group_int_value(tokens.first()?, 2)? as i32;
-
-            Some(TimeExpr::Absolute {
-                year,
-                month: 1,
-                day: 1,
-                hour: None,
-                minute: None,
-            })
+            let year = regex_group_int_value(tokens.first()?, 1)? as i32;
+            Some(year_interval(year))
         }
     }
 }
@@ -219,14 +212,14 @@ pub fn rule_time_expr_at_tod() -> Rule {
         pattern: [
             pred!(is_time_expr),
             re!(r"(?i)\s+(?:at\s+)?"),
-            pred!(is_time_of_day_expr),
+            pred!(is_bare_time_of_day_expr),
         ],
         buckets: (BucketMask::HAS_COLON | BucketMask::HAS_AMPM).bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let date_expr = get_time_expr(tokens.first()?)?.clone();
-            if matches!(date_expr, TimeExpr::Intersect { constraint: Constraint::TimeOfDay(_), .. }) {
+            if has_time_of_day_expr(tokens.first()?) {
                 return None;
             }
+            let date_expr = get_time_expr(tokens.first()?)?.clone();
             let time = time_from_expr(tokens.get(2)?)?;
             Some(TimeExpr::Intersect {
                 expr: Box::new(date_expr),
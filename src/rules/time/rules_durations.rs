@@ -6,11 +6,11 @@ use crate::rules::time::helpers::*;
 use crate::time_expr::{Grain, TimeExpr};
 use crate::{Rule, Token, TokenKind};
 
-/// "in|within|after <duration>" (in 5 minutes, within 2 hours, after 3 days)
-pub fn rule_duration_in_within_after() -> Rule {
+/// "in|after <duration>" (in 5 minutes, after 3 days)
+pub fn rule_duration_in_after() -> Rule {
     rule! {
-        name: "in|within|after <duration>",
-        pattern: [re!(r"(?i)(in|within|after)\s+(\d+)\s*(?:more\s+)?(seconds?|minutes?|hours?|days?|weeks?|months?|years?|h)")],
+        name: "in|after <duration>",
+        pattern: [re!(r"(?i)(in|after)\s+(\d+)\s*(?:more\s+)?(seconds?|minutes?|hours?|days?|weeks?|months?|years?|h)")],
         buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let groups = match &tokens.first()?.kind {
@@ -34,28 +34,6 @@ pub fn rule_duration_in_within_after() -> Rule {
             };
 
             match qualifier.as_str() {
-                "within" => {
-                    let shifted = shift_by_grain(TimeExpr::Reference, amount, grain);
-                    let end_expr = match grain {
-                        Grain::Week | Grain::Day => TimeExpr::StartOf {
-                            expr: Box::new(shifted),
-                            grain: Grain::Day,
-                        },
-                        Grain::Hour => TimeExpr::StartOf {
-                            expr: Box::new(shifted),
-                            grain: Grain::Hour,
-                        },
-                        Grain::Minute => TimeExpr::StartOf {
-                            expr: Box::new(shifted),
-                            grain: Grain::Minute,
-                        },
-                        _ => shifted,
-                    };
-                    Some(TimeExpr::IntervalBetween {
-                        start: Box::new(TimeExpr::Reference),
-                        end: Box::new(end_expr),
-                    })
-                }
                 "after" => {
                     let shifted = shift_by_grain(TimeExpr::Reference, amount, grain);
                     let base_time = match grain {
@@ -94,6 +72,55 @@ pub fn rule_duration_in_within_after() -> Rule {
     }
 }
 
+/// "within <duration>" (within 2 hours, within 3 days): a deadline, from now
+/// until the duration has passed
+pub fn rule_duration_within() -> Rule {
+    rule! {
+        name: "within <duration>",
+        pattern: [re!(r"(?i)within\s+(\d+)\s*(?:more\s+)?(seconds?|minutes?|hours?|days?|weeks?|months?|years?|h)")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        hint: "deadline",
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let groups = match &tokens.first()?.kind {
+                TokenKind::RegexMatch(groups) => groups,
+                _ => return None,
+            };
+
+            let amount = groups.get(1)?.parse::<i32>().ok()?;
+            let unit = groups.get(2)?.to_lowercase();
+
+            let grain = match unit.as_str() {
+                "second" | "seconds" => Grain::Second,
+                "minute" | "minutes" => Grain::Minute,
+                "hour" | "hours" | "h" => Grain::Hour,
+                "day" | "days" => Grain::Day,
+                "week" | "weeks" => Grain::Week,
+                "month" | "months" => Grain::Month,
+                "year" | "years" => Grain::Year,
+                _ => return None,
+            };
+
+            let shifted = shift_by_grain(TimeExpr::Reference, amount, grain);
+            let target = match grain {
+                Grain::Week | Grain::Day => TimeExpr::StartOf {
+                    expr: Box::new(shifted),
+                    grain: Grain::Day,
+                },
+                Grain::Hour => TimeExpr::StartOf {
+                    expr: Box::new(shifted),
+                    grain: Grain::Hour,
+                },
+                Grain::Minute => TimeExpr::StartOf {
+                    expr: Box::new(shifted),
+                    grain: Grain::Minute,
+                },
+                _ => shifted,
+            };
+            Some(TimeExpr::IntervalUntil { target: Box::new(target) })
+        }
+    }
+}
+
 /// "in a/an <duration>" (in a day, in an hour)
 pub fn rule_in_a_duration() -> Rule {
     rule! {
@@ -248,45 +275,6 @@ pub fn rule_in_text_number_duration() -> Rule {
     }
 }
 
-/// "<text-number> <duration> ago" (two hours ago, five days ago)
-pub fn rule_text_number_duration_ago() -> Rule {
-    rule! {
-        name: "<text-number> <duration> ago",
-        pattern: [re!(r"(?i)(one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s+(seconds?|minutes?|hours?|days?|weeks?|months?|years?)\s+ago")],
-        required_phrases: ["ago", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve"],
-        buckets: BucketMask::empty().bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let groups = match &tokens.first()?.kind {
-                TokenKind::RegexMatch(groups) => groups,
-                _ => return None,
-            };
-
-            let number = groups.get(1)?.to_lowercase();
-            let amount = match number.as_str() {
-                "one" => -1, "two" => -2, "three" => -3, "four" => -4,
-                "five" => -5, "six" => -6, "seven" => -7, "eight" => -8,
-                "nine" => -9, "ten" => -10, "eleven" => -11, "twelve" => -12,
-                _ => return None,
-            };
-
-            let unit = groups.get(2)?.to_lowercase();
-            let grain = match unit.as_str() {
-                "second" | "seconds" => Grain::Second,
-                "minute" | "minutes" => Grain::Minute,
-                "hour" | "hours" => Grain::Hour,
-                "day" | "days" => Grain::Day,
-                "week" | "weeks" => Grain::Week,
-                "month" | "months" => Grain::Month,
-                "year" | "years" => Grain::Year,
-                _ => return None,
-            };
-
-            let expr = shift_by_grain(TimeExpr::Reference, amount, grain);
-            Some(expr)
-        }
-    }
-}
-
 /// "<number> <duration> ago" (5 minutes ago, 3 days ago)
 pub fn rule_duration_ago() -> Rule {
     rule! {
@@ -0,0 +1,81 @@
+//! Fiscal and school years ("FY2025", "fiscal year 2024", "Q2 FY25",
+//! "the 2024-25 school year").
+//!
+//! Fiscal years are resolved against `Options::fiscal_year_start`; school
+//! years run from September to September.
+
+use crate::engine::BucketMask;
+use crate::rules::time::helpers::*;
+use crate::time_expr::TimeExpr;
+use crate::{Rule, Token};
+use chrono::NaiveDate;
+
+/// First month of a school/academic year.
+const SCHOOL_YEAR_START_MONTH: u32 = 9;
+
+/// Expand a two-digit fiscal year ("FY25") into the 2000s.
+fn full_year(year: i64) -> i32 {
+    if year < 100 { 2000 + year as i32 } else { year as i32 }
+}
+
+/// "FY2025", "FY25", "FY '25", "fiscal year 2024", "fiscal 2024"
+pub fn rule_fiscal_year() -> Rule {
+    rule! {
+        name: "FY<year>",
+        pattern: [re!(r"(?i)\b(?:fy\s*'?|fiscal\s+(?:year\s+)?)(\d{4}|\d{2})\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let year = full_year(regex_group_int_value(tokens.first()?, 1)?);
+            Some(TimeExpr::FiscalYear { year, quarter: None })
+        }
+    }
+}
+
+/// "Q2 FY25", "Q3 of fiscal year 2024"
+pub fn rule_quarter_fiscal_year() -> Rule {
+    rule! {
+        name: "Q<n> FY<year>",
+        pattern: [re!(r"(?i)\bq([1-4])\s*(?:of\s+)?(?:fy\s*'?|fiscal\s+(?:year\s+)?)(\d{4}|\d{2})\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let quarter = regex_group_int_value(tokens.first()?, 1)? as u32;
+            let year = full_year(regex_group_int_value(tokens.first()?, 2)?);
+            Some(TimeExpr::FiscalYear { year, quarter: Some(quarter) })
+        }
+    }
+}
+
+/// "FY25 Q2", "FY2025Q2"
+pub fn rule_fiscal_year_quarter() -> Rule {
+    rule! {
+        name: "FY<year> Q<n>",
+        pattern: [re!(r"(?i)\bfy\s*'?(\d{4}|\d{2})\s*q([1-4])\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let year = full_year(regex_group_int_value(tokens.first()?, 1)?);
+            let quarter = regex_group_int_value(tokens.first()?, 2)? as u32;
+            Some(TimeExpr::FiscalYear { year, quarter: Some(quarter) })
+        }
+    }
+}
+
+/// "the 2024-25 school year", "2024/2025 academic year", "school year 2024-25"
+pub fn rule_school_year() -> Rule {
+    rule! {
+        name: "<yyyy>-<yy> school year",
+        pattern: [re!(r"(?i)(?:the\s+)?(?:(?:school|academic)\s+year\s+(\d{4})\s*[-–—/]\s*(\d{4}|\d{2})\b|(\d{4})\s*[-–—/]\s*(\d{4}|\d{2})\s+(?:school|academic)\s+year\b)")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            // Only one alternative participates, so its years are groups 1 and 2.
+            let first = regex_group_int_value(tokens.first()?, 1)? as i32;
+            let second = regex_group_int_value(tokens.first()?, 2)? as i32;
+            // "2024-25" must name consecutive years.
+            if second != first + 1 && second != (first + 1) % 100 {
+                return None;
+            }
+            let start = NaiveDate::from_ymd_opt(first, SCHOOL_YEAR_START_MONTH, 1)?.and_hms_opt(0, 0, 0)?;
+            let end = NaiveDate::from_ymd_opt(first + 1, SCHOOL_YEAR_START_MONTH, 1)?.and_hms_opt(0, 0, 0)?;
+            Some(TimeExpr::Interval { start, end })
+        }
+    }
+}
@@ -2,8 +2,8 @@
 
 use crate::engine::BucketMask;
 use crate::rules::time::helpers::shift::shift_by_grain;
-use crate::time_expr::{Grain, TimeExpr};
-use crate::{Rule, Token, TokenKind};
+use crate::time_expr::{Grain, Holiday, TimeExpr};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 use chrono::{Datelike, Weekday};
 
 /// "Thanksgiving" - 4th Thursday of November
@@ -13,13 +13,9 @@ pub fn rule_thanksgiving() -> Rule {
         pattern: [re!(r"(?i)thanksgiving(?:\s+day)?")],
         required_phrases: ["thanksgiving"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
-            Some(TimeExpr::NthWeekdayOfMonth {
-                n: 4,
-                year: None,
-                month: 11,
-                weekday: Weekday::Thu,
-            })
+            Some(TimeExpr::Holiday { holiday: Holiday::Thanksgiving, year: None })
         }
     }
 }
@@ -31,6 +27,7 @@ pub fn rule_bosss_day() -> Rule {
         pattern: [re!(r"(?i)boss'?s?(?:\s+day)?(?:\s+(\d{4}))?")],
         required_phrases: ["boss"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let groups = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => groups,
@@ -55,7 +52,7 @@ pub fn rule_bosss_day() -> Rule {
                     minute: None,
                 })
             } else {
-                Some(TimeExpr::MonthDay { month: 10, day: 16 })
+                Some(TimeExpr::Holiday { holiday: Holiday::BossDay, year: None })
             }
         }
     }
@@ -66,8 +63,9 @@ pub fn rule_mlk_day() -> Rule {
     rule! {
         name: "MLK day",
         pattern: [re!(r"(?i)(?:(last|next|this)\s+)?(?:martin\s+luther\s+king(?:\s+jr\.?)?(?:\s+day)?|MLK(?:\s+(?:jr\.?))?(?:\s+day)?|civil\s+rights\s+day)(?:\s+(?:of\s+)?(last\s+year|(\d{4})))?")],
-        required_phrases: ["martin", "luther", "king", "mlk", "civil"],
+        optional_phrases: ["martin", "mlk", "civil"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let groups = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => groups,
@@ -124,6 +122,7 @@ pub fn rule_black_friday() -> Rule {
         pattern: [re!(r"(?i)black\s+friday(?:\s+(?:of\s+)?(?:this\s+)?year)?(?:\s+(\d{4}))?")],
         required_phrases: ["black", "friday"],
         buckets: BucketMask::WEEKDAYISH.bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let groups = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => groups,
@@ -133,11 +132,45 @@ pub fn rule_black_friday() -> Rule {
             let year = groups.get(1)
                 .and_then(|s| if s.is_empty() { None } else { s.parse::<i32>().ok() });
 
-            Some(TimeExpr::LastWeekdayOfMonth {
-                year,
-                month: 11,
-                weekday: Weekday::Fri,
-            })
+            Some(TimeExpr::Holiday { holiday: Holiday::BlackFriday, year })
+        }
+    }
+}
+
+/// Canonical name of a holiday resolved by the holiday provider.
+fn provided_holiday_name(text: &str) -> Option<&'static str> {
+    let text = text.replace(['-', ' '], "");
+    let name = match text.as_str() {
+        "ramadan" | "ramadhan" | "ramzan" => "ramadan",
+        t if t.starts_with("eid") && t.ends_with("fitr") => "eid al-fitr",
+        t if t.starts_with("eid") && t.ends_with("adha") => "eid al-adha",
+        "islamicnewyear" | "hijrinewyear" => "islamic new year",
+        "diwali" | "deepavali" => "diwali",
+        t if t.ends_with("nukah") || t.ends_with("nukkah") => "hanukkah",
+        "chinesenewyear" | "lunarnewyear" => "chinese new year",
+        t if t.starts_with("roshhashana") => "rosh hashanah",
+        "yomkippur" => "yom kippur",
+        "passover" | "pesach" => "passover",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// "Ramadan", "Eid al-Fitr", "Diwali 2014", "Hanukkah", "Chinese New Year", ...
+///
+/// These follow lunar or lunisolar calendars, so their dates come from the
+/// context's holiday provider; without one they don't resolve.
+pub fn rule_provided_holiday() -> Rule {
+    rule! {
+        name: "<lunar holiday> [year]",
+        pattern: [re!(r"(?i)\b(ramadh?an|ramzan|eid(?:[\s-]+al|[\s-]+ul)?[\s-]+(?:fitr|adha)|(?:islamic|hijri)\s+new\s+year|diwali|deepavali|(?:c?h)anukk?ah|(?:chinese|lunar)\s+new\s+year|rosh\s+hashanah?|yom\s+kippur|passover|pesach)(?:\s+(\d{4}))?\b")],
+        buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let name = provided_holiday_name(groups.get(1)?)?;
+            let year = groups.get(2).and_then(|y| y.parse().ok());
+            Some(TimeExpr::ProvidedHoliday { name: name.to_string(), year })
         }
     }
 }
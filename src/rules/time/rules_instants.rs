@@ -4,12 +4,12 @@ use crate::rules::time::predicates::*;
 use crate::time_expr::{Grain, TimeExpr};
 use crate::{Rule, Token};
 
-/// "now", "right now", "immediately", "at the moment", "atm", etc.
+/// "right now", "just now", "at the moment", "atm", etc.
 pub fn rule_instants_right_now() -> Rule {
     rule! {
         name: "right now",
-        pattern: [re!(r"(?i)(?:((just|right)\s*)now|immediately|at\s+the\s+moment|at\s+this\s+moment|at\s+the\s+present\s+time|at\s+present|\batm\b)")],
-        optional_phrases: ["now", "immediately", "moment", "atm"],
+        pattern: [re!(r"(?i)(?:((just|right)\s*)now|at\s+the\s+moment|at\s+this\s+moment|at\s+the\s+present\s+time|at\s+present|\batm\b)")],
+        optional_phrases: ["now", "moment", "atm"],
         buckets: BucketMask::empty().bits(),
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
             Some(TimeExpr::Reference)
@@ -71,9 +71,10 @@ pub fn rule_day_after_tomorrow() -> Rule {
 pub fn rule_time_of_day_tomorrow() -> Rule {
     rule! {
         name: "<time-of-day> tomorrow",
-        pattern: [pred!(is_time_of_day_expr), re!(r"\s+"), re!(r"(?i)(tmrw?|tomm?or?rows?)")],
+        pattern: [pred!(is_bare_time_of_day_expr), re!(r"(?i)(tmrw?|tomm?or?rows?)")],
         optional_phrases: ["tomorrow", "tmrw"],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time_expr = get_time_expr(tokens.first()?)?.clone();
             let tomorrow = shift_by_grain(TimeExpr::Reference, 1, Grain::Day);
@@ -141,17 +142,30 @@ pub fn rule_now() -> Rule {
     }
 }
 
-/// "asap", "as soon as possible"
+/// "asap", "as soon as possible", "immediately": the reference instant,
+/// hinted as urgent.
 pub fn rule_asap() -> Rule {
     rule! {
         name: "asap",
-        pattern: [re!(r"(?i)(asap|as\s+soon\s+as\s+possible)")],
-        optional_phrases: ["asap", "soon"],
+        pattern: [re!(r"(?i)\b(?:asap|as\s+soon\s+as\s+possible|immediately)\b")],
+        optional_phrases: ["asap", "soon", "immediately"],
         buckets: BucketMask::empty().bits(),
+        hint: "asap",
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
-            Some(TimeExpr::After(Box::new(
-                TimeExpr::Reference,
-            )))
+            Some(TimeExpr::Reference)
+        }
+    }
+}
+
+/// "from now on", "from now onwards": everything after the reference.
+pub fn rule_from_now_on() -> Rule {
+    rule! {
+        name: "from now on",
+        pattern: [re!(r"(?i)\bfrom\s+now\s+on(?:wards?)?\b")],
+        required_phrases: ["now"],
+        buckets: BucketMask::empty().bits(),
+        prod: |_tokens: &[Token]| -> Option<TimeExpr> {
+            Some(TimeExpr::After(Box::new(TimeExpr::Reference)))
         }
     }
 }
@@ -80,15 +80,27 @@ pub fn rule_time_possessive_weekday() -> Rule {
 pub fn rule_weekday_in_time_expr() -> Rule {
     rule! {
         name: "<weekday> <time>",
-        pattern: [pred!(is_weekday_expr), re!(r"\s+"), pred!(is_time_expr)],
+        pattern: [pred!(is_weekday_expr), pred!(is_time_expr)],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let time_expr = get_time_expr(tokens.get(2)?)?.clone();
+            let time_expr = get_time_expr(tokens.get(1)?)?.clone();
             let weekday = weekday_from_expr(tokens.first()?)?;
-            Some(TimeExpr::Intersect {
-                expr: Box::new(time_expr),
-                constraint: Constraint::DayOfWeek(weekday),
-            })
+            // Only a bare weekday: "Fri, Jul 18" already carries a date,
+            // which intersecting by its weekday alone would drop.
+            if !matches!(get_time_expr(tokens.first()?)?, TimeExpr::Intersect { expr, .. } if **expr == TimeExpr::Reference) {
+                return None;
+            }
+            // A deadline ("EOD at the latest") runs from now; the weekday
+            // belongs inside it ("Tuesday EOD" at the latest).
+            if matches!(time_expr, TimeExpr::IntervalUntil { .. }) {
+                None
+            } else {
+                Some(TimeExpr::Intersect {
+                    expr: Box::new(time_expr),
+                    constraint: Constraint::DayOfWeek(weekday),
+                })
+            }
         }
     }
 }
@@ -235,7 +247,7 @@ pub fn rule_in_duration_at_time() -> Rule {
 pub fn rule_time_of_time() -> Rule {
     rule! {
         name: "<time> of <time>",
-        pattern: [pred!(is_time_expr), re!(r"(?i)\s+of\s+"), pred!(is_time_expr)],
+        pattern: [pred!(is_time_expr), words!("of"), pred!(is_time_expr)],
         buckets: (BucketMask::HAS_COLON).bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let lhs = get_time_expr(tokens.first()?)?;
@@ -4,8 +4,8 @@ use crate::engine::BucketMask;
 use crate::rules::time::helpers::shift::shift_by_grain;
 use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
-use crate::time_expr::{Grain, TimeExpr};
-use crate::{Rule, Token, TokenKind};
+use crate::time_expr::{Direction, Grain, TimeExpr};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 
 /// "for <duration> from <time>" (for 2 hours from 3pm)
 pub fn rule_interval_for_duration_from() -> Rule {
@@ -14,6 +14,7 @@ pub fn rule_interval_for_duration_from() -> Rule {
         pattern: [re!(r"(?i)for\s+"), pattern_regex(duration_pattern()), re!(r"\s+(from|starting\s+from|starting|beginning|after)\s+"), pred!(is_time_expr)],
         required_phrases: [],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let (amount, grain) = parse_duration(tokens.get(1)?)?;
             let time_expr = get_time_expr(tokens.get(3)?)?;
@@ -34,6 +35,7 @@ pub fn rule_interval_time_for_duration() -> Rule {
         pattern: [pred!(is_time_expr), re!(r"(?i)\s+for\s+"), pattern_regex(duration_pattern())],
         required_phrases: ["for"],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time_expr = get_time_expr(tokens.first()?)?;
             let (amount, grain) = parse_duration(tokens.get(2)?)?;
@@ -54,6 +56,7 @@ pub fn rule_interval_from_time_for_duration() -> Rule {
         pattern: [re!(r"(?i)(from|starting|beginning|after|starting from)"), pred!(is_time_expr), re!(r"(?i)for"), pattern_regex(duration_pattern())],
         required_phrases: [],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time_expr = get_time_expr(tokens.get(1)?)?;
             let (amount, grain) = parse_duration(tokens.get(3)?)?;
@@ -74,6 +77,7 @@ pub fn rule_interval_from_time_for_text_duration() -> Rule {
         pattern: [re!(r"(?i)(from|starting|beginning)\s+"), pred!(is_time_expr), re!(r"\s+for\s+(one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|twenty|thirty|forty|fifty)\s+(seconds?|minutes?|hours?|days?|weeks?|months?|years?)")],
         required_phrases: [],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time_expr = get_time_expr(tokens.get(1)?)?;
 
@@ -131,48 +135,52 @@ pub fn rule_interval_from_time_for_text_duration() -> Rule {
     }
 }
 
+/// Amount and grain of a counted-units match ("2 hours", "a week", "couple
+/// days").
+fn counted_units(token: &Token) -> Option<(i32, Grain)> {
+    let TokenKind::RegexMatch(groups) = &token.kind else { return None };
+
+    let amount_str = groups.get(1)?.to_lowercase();
+    let amount = match amount_str.as_str() {
+        "a" | "an" | "one" => 1,
+        "two" | "couple" => 2,
+        "three" | "few" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        _ => amount_str.parse::<i32>().ok()?,
+    };
+
+    let unit = groups.get(2)?.to_lowercase();
+    let grain = match unit.as_str() {
+        "second" | "seconds" => Grain::Second,
+        "minute" | "minutes" => Grain::Minute,
+        "hour" | "hours" => Grain::Hour,
+        "day" | "days" => Grain::Day,
+        "week" | "weeks" => Grain::Week,
+        "month" | "months" => Grain::Month,
+        "year" | "years" => Grain::Year,
+        _ => return None,
+    };
+    Some((amount, grain))
+}
+
 /// "last|past|next <duration>" (last 2 hours, next 3 days, past 5 minutes)
 pub fn rule_duration_last_next() -> Rule {
     rule! {
         name: "last|past|next <duration>",
         pattern: [re!(r"(?i)(last|past|next)\s+"), re!(r"(\d+|an?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|few|couple)\s+(seconds?|minutes?|hours?|days?|weeks?|months?|years?)")],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let qualifier = first(tokens)?.trim().to_lowercase();
-
-            let groups = match &tokens.get(1)?.kind {
-                TokenKind::RegexMatch(groups) => groups,
-                _ => return None,
-            };
-
-            let amount_str = groups.get(1)?.to_lowercase();
-            let amount = match amount_str.as_str() {
-                "a" | "an" | "one" => 1,
-                "two" | "couple" => 2,
-                "three" | "few" => 3,
-                "four" => 4,
-                "five" => 5,
-                "six" => 6,
-                "seven" => 7,
-                "eight" => 8,
-                "nine" => 9,
-                "ten" => 10,
-                "eleven" => 11,
-                "twelve" => 12,
-                _ => amount_str.parse::<i32>().ok()?,
-            };
-
-            let unit = groups.get(2)?.to_lowercase();
-            let grain = match unit.as_str() {
-                "second" | "seconds" => Grain::Second,
-                "minute" | "minutes" => Grain::Minute,
-                "hour" | "hours" => Grain::Hour,
-                "day" | "days" => Grain::Day,
-                "week" | "weeks" => Grain::Week,
-                "month" | "months" => Grain::Month,
-                "year" | "years" => Grain::Year,
-                _ => return None,
-            };
+            let (amount, grain) = counted_units(tokens.get(1)?)?;
 
             let expr = match qualifier.as_str() {
                 "last" | "past" => {
@@ -232,3 +240,28 @@ pub fn rule_duration_last_next() -> Rule {
         }
     }
 }
+
+/// "for the next <duration>" (for the next 3 weeks, over the coming 48 hours,
+/// in the last 30 days): unlike "next 3 weeks", counted from the reference
+/// itself rather than from the next grain boundary.
+pub fn rule_duration_from_reference() -> Rule {
+    rule! {
+        name: "for the next|last <duration>",
+        pattern: [
+            re!(r"(?i)(?:for|over|in|during|within|throughout)\s+the\s+(next|coming|upcoming|following|last|past|previous)\s+"),
+            re!(r"(\d+|an?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|few|couple)\s+(seconds?|minutes?|hours?|days?|weeks?|months?|years?)"),
+        ],
+        buckets: BucketMask::empty().bits(),
+        group: RuleGroups::INTERVALS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let direction = match groups.get(1)?.as_str() {
+                "last" | "past" | "previous" => Direction::Past,
+                _ => Direction::Future,
+            };
+            let (amount, grain) = counted_units(tokens.get(1)?)?;
+
+            Some(TimeExpr::IntervalFromReference { amount, grain, direction })
+        }
+    }
+}
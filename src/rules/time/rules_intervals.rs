@@ -1,10 +1,11 @@
 use crate::Dimension;
 /// Interval-based rules (from/to, between, dash ranges)
 use crate::engine::BucketMask;
+use crate::rules::time::helpers::value_grain;
 use crate::rules::time::predicates::*;
 use crate::time_expr::Grain;
-use crate::time_expr::TimeExpr;
-use crate::{Rule, Token, TokenKind};
+use crate::time_expr::{Constraint, TimeExpr};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 use chrono::Timelike;
 
 fn time_of_day_time(expr: &TimeExpr) -> Option<chrono::NaiveTime> {
@@ -98,12 +99,40 @@ fn time_of_day_precision(expr: &TimeExpr) -> Option<Grain> {
                 }
                 current = expr;
             }
+            TimeExpr::InTimezone { expr, .. } => current = expr,
             _ => return None,
         }
     }
 }
 
+/// Whether `expr` resolves to the first instant of a day or month ("May",
+/// "Friday", "July 15") rather than to an interval that already ends
+/// exclusively.
+fn is_date_instant(expr: &TimeExpr) -> bool {
+    match expr {
+        TimeExpr::MonthDay { .. }
+        | TimeExpr::ShortYearDate { .. }
+        | TimeExpr::Absolute { hour: None, .. }
+        | TimeExpr::Holiday { .. }
+        | TimeExpr::NthWeekdayOfMonth { .. }
+        | TimeExpr::LastWeekdayOfMonth { .. }
+        | TimeExpr::FirstWeekdayOfMonth { .. } => true,
+        // A day of any month ("the 5th of next month") starts that day.
+        TimeExpr::Intersect { constraint: Constraint::DayOfMonth(_), .. } => true,
+        TimeExpr::Intersect {
+            expr,
+            constraint: Constraint::Month(_) | Constraint::DayOfWeek(_) | Constraint::Day(_),
+        } => matches!(**expr, TimeExpr::Reference) || is_date_instant(expr),
+        _ => false,
+    }
+}
+
+/// The grain to shift an interval's end by so the interval is half-open:
+/// "from 3 to 5pm" ends at 6pm, "from March to May" on June 1st.
 fn end_exclusive_grain(start: &TimeExpr, end: &TimeExpr) -> Option<Grain> {
+    if is_date_instant(end) {
+        return Some(value_grain(end));
+    }
     let end_precision = time_of_day_precision(end)?;
     let start_precision = time_of_day_precision(start);
     Some(match start_precision {
@@ -112,6 +141,56 @@ fn end_exclusive_grain(start: &TimeExpr, end: &TimeExpr) -> Option<Grain> {
     })
 }
 
+/// `day` of the month `expr` names, when `expr` is a day of a month more
+/// specific than "the <day>" ("May 5", "the fifth of next month").
+fn with_day_of_month(expr: &TimeExpr, day: u32) -> Option<TimeExpr> {
+    match expr {
+        TimeExpr::MonthDay { month, .. } => Some(TimeExpr::MonthDay { month: *month, day }),
+        TimeExpr::Intersect { expr, constraint: Constraint::DayOfMonth(_) } if **expr != TimeExpr::Reference => {
+            Some(TimeExpr::Intersect { expr: expr.clone(), constraint: Constraint::DayOfMonth(day) })
+        }
+        _ => None,
+    }
+}
+
+/// The day of the month of a bare "the <day>".
+fn bare_day_of_month(expr: &TimeExpr) -> Option<u32> {
+    match expr {
+        TimeExpr::Intersect { expr, constraint: Constraint::DayOfMonth(day) } if **expr == TimeExpr::Reference => {
+            Some(*day)
+        }
+        _ => None,
+    }
+}
+
+/// The day of the month of a day-of-month expression of any form.
+fn day_of_month(expr: &TimeExpr) -> Option<u32> {
+    match expr {
+        TimeExpr::MonthDay { day, .. } | TimeExpr::Intersect { constraint: Constraint::DayOfMonth(day), .. } => {
+            Some(*day)
+        }
+        _ => None,
+    }
+}
+
+/// A day range with the month stated on one side only ("the third until
+/// the fifth of May", "May third through the fifth"): the bare day is a day
+/// of the other side's month.
+fn share_month(start: TimeExpr, end: TimeExpr) -> (TimeExpr, TimeExpr) {
+    let ordered = |first: u32, last: u32| first < last;
+    if let (Some(first), Some(last)) = (bare_day_of_month(&start), day_of_month(&end)) {
+        if let Some(start) = with_day_of_month(&end, first).filter(|_| ordered(first, last)) {
+            return (start, end);
+        }
+    }
+    if let (Some(first), Some(last)) = (day_of_month(&start), bare_day_of_month(&end)) {
+        if let Some(end) = with_day_of_month(&start, last).filter(|_| ordered(first, last)) {
+            return (start, end);
+        }
+    }
+    (start, end)
+}
+
 /// "from <time> to <time>"
 pub fn rule_interval_from_to() -> Rule {
     rule! {
@@ -125,10 +204,10 @@ pub fn rule_interval_from_to() -> Rule {
         required_phrases: ["from", "to"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let start = get_time_expr(tokens.get(1)?)?.clone();
             let end_token = tokens.get(3)?;
-            let end = get_time_expr(end_token)?.clone();
+            let (start, end) = share_month(get_time_expr(tokens.get(1)?)?.clone(), get_time_expr(end_token)?.clone());
 
             let end = if let Some(grain) = end_exclusive_grain(&start, &end) {
                 TimeExpr::Shift {
@@ -159,6 +238,7 @@ pub fn rule_interval_from_open() -> Rule {
         required_phrases: ["from"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start = get_time_expr(tokens.get(1)?)?.clone();
             Some(TimeExpr::After(Box::new(start)))
@@ -179,10 +259,10 @@ pub fn rule_interval_between_and() -> Rule {
         required_phrases: ["between"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let start = get_time_expr(tokens.get(1)?)?.clone();
             let end_token = tokens.get(3)?;
-            let end = get_time_expr(end_token)?.clone();
+            let (start, end) = share_month(get_time_expr(tokens.get(1)?)?.clone(), get_time_expr(end_token)?.clone());
 
             let end = if let Some(grain) = end_exclusive_grain(&start, &end) {
                 TimeExpr::Shift {
@@ -208,15 +288,15 @@ pub fn rule_interval_dash() -> Rule {
         name: "<time> - <time>",
         pattern: [
             pred!(is_time_expr),
-            re!(r"\s*-\s*"),
+            re!(r"\s*[-–—]\s*"),
             pred!(is_time_expr)
         ],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let start = get_time_expr(tokens.first()?)?.clone();
             let end_token = tokens.get(2)?;
-            let end = get_time_expr(end_token)?.clone();
+            let (start, end) = share_month(get_time_expr(tokens.first()?)?.clone(), get_time_expr(end_token)?.clone());
 
             // Duckling-style semantics: treat end as inclusive at the token's
             // resolution (minute or second), and convert to an end-exclusive
@@ -239,6 +319,38 @@ pub fn rule_interval_dash() -> Rule {
     }
 }
 
+/// "<time> to <time-of-day>" (e.g. "Friday 11pm to 1am")
+pub fn rule_interval_to_time_of_day() -> Rule {
+    rule! {
+        name: "<time> to <time-of-day>",
+        pattern: [
+            pred!(is_time_expr),
+            re!(r"(?i)\s+(?:to|until|till)\s+"),
+            pred!(is_bare_time_of_day_expr)
+        ],
+        buckets: BucketMask::empty().bits(),
+        deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let start = get_time_expr(tokens.first()?)?.clone();
+            let end = get_time_expr(tokens.get(2)?)?.clone();
+
+            // The end is inclusive at its own grain, whatever the start is
+            // tied to: "tomorrow 9am to 5pm" ends at 6pm.
+            let end = TimeExpr::Shift {
+                expr: Box::new(end.clone()),
+                amount: 1,
+                grain: time_of_day_precision(&end)?,
+            };
+
+            Some(TimeExpr::IntervalBetween {
+                start: Box::new(start),
+                end: Box::new(end),
+            })
+        }
+    }
+}
+
 /// "<time-of-day>-<time-of-day> <date>" (e.g. "1pm-2pm tomorrow")
 pub fn rule_interval_dash_on_date() -> Rule {
     fn reapply_zero_shifts(original: &TimeExpr, inner: TimeExpr) -> TimeExpr {
@@ -254,15 +366,16 @@ pub fn rule_interval_dash_on_date() -> Rule {
         name: "<time-of-day> - <time-of-day> <date>",
         pattern: [
             pred!(is_time_of_day_expr),
-            re!(r"\s*-\s*"),
+            re!(r"\s*[-–—]\s*"),
             pred!(is_time_of_day_expr),
-            re!(r"\s+"),
             pred!(is_future_shift_expr)
         ],
         buckets: BucketMask::HAS_DIGITS.bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let date_expr = get_time_expr(tokens.get(4)?)?.clone();
+            let date_expr = get_time_expr(tokens.get(3)?)?.clone();
 
             let start_time = time_from_expr(tokens.first()?)?;
             let end_time = time_from_expr(tokens.get(2)?)?;
@@ -313,10 +426,12 @@ pub fn rule_interval_through() -> Rule {
         optional_phrases: ["through", "thru"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start = get_time_expr(tokens.first()?)?.clone();
             let end_token = tokens.get(2)?;
             let end = maybe_disambiguate_end_time_of_day(&start, get_time_expr(end_token)?.clone());
+            let (start, end) = share_month(start, end);
 
             let end = if let Some(grain) = end_exclusive_grain(&start, &end) {
                 TimeExpr::Shift {
@@ -347,6 +462,7 @@ pub fn rule_interval_through_open() -> Rule {
         optional_phrases: ["through", "thru"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let end = get_time_expr(tokens.get(1)?)?.clone();
             Some(TimeExpr::Before(Box::new(end)))
@@ -354,22 +470,24 @@ pub fn rule_interval_through_open() -> Rule {
     }
 }
 
-/// "<time> until <time>"
+/// "<time> until|till <time>"
 pub fn rule_interval_until() -> Rule {
     rule! {
         name: "<time> until <time>",
         pattern: [
             pred!(is_time_expr),
-            re!(r"(?i)\s+until\s+"),
+            re!(r"(?i)\s+(?:until|till)\s+"),
             pred!(is_time_expr)
         ],
-        required_phrases: ["until"],
+        optional_phrases: ["until", "till"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start = get_time_expr(tokens.first()?)?.clone();
             let end_token = tokens.get(2)?;
             let end = maybe_disambiguate_end_time_of_day(&start, get_time_expr(end_token)?.clone());
+            let (start, end) = share_month(start, end);
 
             let end = if let Some(grain) = end_exclusive_grain(&start, &end) {
                 TimeExpr::Shift {
@@ -400,6 +518,7 @@ pub fn rule_interval_until_open() -> Rule {
         required_phrases: ["until"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let end = get_time_expr(tokens.get(1)?)?.clone();
             Some(TimeExpr::Before(Box::new(end)))
@@ -418,6 +537,7 @@ pub fn rule_interval_before() -> Rule {
         required_phrases: ["before"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let end = get_time_expr(tokens.get(1)?)?.clone();
 
@@ -437,6 +557,7 @@ pub fn rule_interval_after() -> Rule {
         required_phrases: ["after"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start = get_time_expr(tokens.get(1)?)?.clone();
 
@@ -445,6 +566,57 @@ pub fn rule_interval_after() -> Rule {
     }
 }
 
+/// "before|after <time-of-day> <day>" (e.g. "before 3pm tomorrow", "after 9 on Friday")
+pub fn rule_open_interval_tod_day() -> Rule {
+    rule! {
+        name: "before|after <time-of-day> <day>",
+        pattern: [
+            re!(r"(?i)(before|after)\s+"),
+            pred!(is_time_of_day_expr),
+            re!(r"(?i)\s+(?:on\s+)?"),
+            pred!(is_day_expr)
+        ],
+        optional_phrases: ["before", "after"],
+        buckets: BucketMask::empty().bits(),
+        deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            open_interval_on_day(tokens.first()?, tokens.get(3)?, tokens.get(1)?)
+        }
+    }
+}
+
+/// "<day> before|after <time-of-day>" (e.g. "tomorrow before 3pm", "on Friday after 9")
+pub fn rule_open_interval_day_tod() -> Rule {
+    rule! {
+        name: "<day> before|after <time-of-day>",
+        pattern: [
+            pred!(is_day_expr),
+            re!(r"(?i)\s+(before|after)\s+"),
+            pred!(is_time_of_day_expr)
+        ],
+        optional_phrases: ["before", "after"],
+        buckets: BucketMask::empty().bits(),
+        deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            open_interval_on_day(tokens.get(1)?, tokens.first()?, tokens.get(2)?)
+        }
+    }
+}
+
+/// `After`/`Before` (per the "before|after" in `keyword` group 1) of the time
+/// of day on `day`.
+fn open_interval_on_day(keyword: &Token, day: &Token, tod: &Token) -> Option<TimeExpr> {
+    let TokenKind::RegexMatch(groups) = &keyword.kind else { return None };
+    let constraint = Constraint::TimeOfDay(time_from_expr(tod)?);
+    let anchored = Box::new(TimeExpr::Intersect { expr: Box::new(get_time_expr(day)?.clone()), constraint });
+    match groups.get(1)?.as_str() {
+        "before" => Some(TimeExpr::Before(anchored)),
+        _ => Some(TimeExpr::After(anchored)),
+    }
+}
+
 /// "since <time>"
 pub fn rule_interval_since() -> Rule {
     rule! {
@@ -456,6 +628,7 @@ pub fn rule_interval_since() -> Rule {
         required_phrases: ["since"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let start = get_time_expr(tokens.get(1)?)?.clone();
 
@@ -475,14 +648,68 @@ pub fn rule_interval_by() -> Rule {
         required_phrases: ["by"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        hint: "deadline",
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let end = get_time_expr(tokens.get(1)?)?.clone();
 
-            // "by <time>" means an interval from now until that time.
-            Some(TimeExpr::IntervalBetween {
-                start: Box::new(TimeExpr::Reference),
-                end: Box::new(end),
-            })
+            // "by <time>" means an interval from now until that time; "by
+            // Friday at the latest" already is one.
+            if matches!(end, TimeExpr::IntervalUntil { .. }) {
+                Some(end)
+            } else {
+                Some(TimeExpr::IntervalBetween {
+                    start: Box::new(TimeExpr::Reference),
+                    end: Box::new(end),
+                })
+            }
+        }
+    }
+}
+
+/// "no later than <time>", "not later than 5pm"
+pub fn rule_interval_no_later_than() -> Rule {
+    rule! {
+        name: "no later than <time>",
+        pattern: [
+            re!(r"(?i)not?\s+later\s+than\s+"),
+            pred!(is_time_expr)
+        ],
+        required_phrases: ["later"],
+        buckets: BucketMask::empty().bits(),
+        deps: [Dimension::Time],
+        hint: "deadline",
+        group: RuleGroups::INTERVALS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let target = get_time_expr(tokens.get(1)?)?.clone();
+            Some(TimeExpr::IntervalUntil { target: Box::new(target) })
+        }
+    }
+}
+
+/// "<time> at the latest" ("Friday at the latest", "by Tuesday EOD at the
+/// latest")
+pub fn rule_interval_at_the_latest() -> Rule {
+    rule! {
+        name: "<time> at the latest",
+        pattern: [
+            pred!(is_time_expr),
+            re!(r"(?i),?\s+at\s+the\s+latest\b")
+        ],
+        required_phrases: ["latest"],
+        buckets: BucketMask::empty().bits(),
+        deps: [Dimension::Time],
+        hint: "deadline",
+        group: RuleGroups::INTERVALS,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let expr = get_time_expr(tokens.first()?)?;
+            match expr {
+                // Already a deadline ("by Friday", "until 5pm").
+                TimeExpr::IntervalUntil { .. } | TimeExpr::Before(_) | TimeExpr::OpenBefore { .. } => Some(expr.clone()),
+                TimeExpr::IntervalBetween { start, .. } if **start == TimeExpr::Reference => Some(expr.clone()),
+                TimeExpr::IntervalBetween { .. } | TimeExpr::After(_) | TimeExpr::OpenAfter { .. } => None,
+                _ => Some(TimeExpr::IntervalUntil { target: Box::new(expr.clone()) }),
+            }
         }
     }
 }
@@ -498,6 +725,7 @@ pub fn rule_interval_for_duration() -> Rule {
         required_phrases: ["for"],
         buckets: BucketMask::empty().bits(),
         deps: [Dimension::Time],
+        group: RuleGroups::INTERVALS,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let duration = get_duration_expr(tokens.get(1)?)?.clone();
 
@@ -0,0 +1,101 @@
+//! ISO 8601 literals as found in machine-generated text: timestamps
+//! ("2025-06-14T09:30:00Z", "2025-06-14T09:30+02:00"), week dates
+//! ("2025-W23", "2025-W23-2") and durations ("P3DT4H").
+//!
+//! Timestamps with an offset are read in that offset and converted to local
+//! time like any other stated timezone.
+
+use crate::engine::BucketMask;
+use crate::rules::time::helpers::shift::shift_by_grain;
+use crate::rules::time::helpers::timezone::in_timezone;
+use crate::time_expr::{Grain, TimeExpr};
+use crate::{Rule, Token, TokenKind};
+use chrono::{NaiveDate, Weekday};
+
+/// Full match of the first token.
+fn matched_text(token: &Token) -> Option<&str> {
+    let TokenKind::RegexMatch(groups) = &token.kind else { return None };
+    groups.first().map(String::as_str)
+}
+
+/// "2025-06-14T09:30:00Z", "2025-06-14T09:30+02:00", "2025-06-14 09:30:15"
+pub fn rule_iso_timestamp() -> Rule {
+    rule! {
+        name: "ISO 8601 timestamp",
+        pattern: [re!(r"(?i)\b\d{4}-\d{2}-\d{2}(?:t|\s+)\d{2}:\d{2}(?::\d{2}(?:\.\d+)?)?(?:z|[+-]\d{2}(?::?\d{2})?)?\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let caps = regex!(r"^(\d{4})-(\d{2})-(\d{2})(?:t|\s+)(\d{2}):(\d{2})(?::(\d{2}))?(?:\.\d+)?(z|[+-]\d{2}(?::?\d{2})?)?$")
+                .captures(matched_text(tokens.first()?)?)?;
+            let num = |idx: usize| caps.get(idx).map_or(Some(0), |m| m.as_str().parse::<u32>().ok());
+            let datetime = NaiveDate::from_ymd_opt(num(1)? as i32, num(2)?, num(3)?)?.and_hms_opt(num(4)?, num(5)?, num(6)?)?;
+            let at = TimeExpr::At(datetime);
+            match caps.get(7).map(|m| m.as_str()) {
+                None => Some(at),
+                Some("z") => in_timezone(at, "UTC"),
+                Some(offset) => in_timezone(at, offset),
+            }
+        }
+    }
+}
+
+/// "2025-W23" (the whole week), "2025-W23-2" (its Tuesday)
+pub fn rule_iso_week_date() -> Rule {
+    rule! {
+        name: "ISO 8601 week date",
+        pattern: [re!(r"(?i)\b(\d{4})-?w(\d{2})(?:-?([1-7]))?\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let year = groups.get(1)?.parse().ok()?;
+            let week = groups.get(2)?.parse().ok()?;
+            let monday = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)?;
+            match groups.get(3) {
+                Some(day) => {
+                    let date = monday + chrono::Duration::days(day.parse::<i64>().ok()? - 1);
+                    Some(TimeExpr::At(date.and_hms_opt(0, 0, 0)?))
+                }
+                None => {
+                    let start = monday.and_hms_opt(0, 0, 0)?;
+                    Some(TimeExpr::Interval { start, end: start + chrono::Duration::days(7) })
+                }
+            }
+        }
+    }
+}
+
+/// "P3DT4H", "in PT30M", "P1Y2M": the time that far from now
+pub fn rule_iso_duration() -> Rule {
+    rule! {
+        name: "ISO 8601 duration",
+        pattern: [re!(r"(?i)\b(?:in\s+)?p(?:(?:\d+[ymwd])+(?:t(?:\d+[hms])+)?|t(?:\d+[hms])+)\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let text = matched_text(tokens.first()?)?;
+            let (in_prefix, literal) = match text.rfind(char::is_whitespace) {
+                Some(idx) => (true, &text[idx + 1..]),
+                None => (false, text),
+            };
+            let caps = regex!(r"^p(?:(\d+)y)?(?:(\d+)m)?(?:(\d+)w)?(?:(\d+)d)?(?:t(?:(\d+)h)?(?:(\d+)m)?(?:(\d+)s)?)?$")
+                .captures(literal)?;
+            let grains = [Grain::Year, Grain::Month, Grain::Week, Grain::Day, Grain::Hour, Grain::Minute, Grain::Second];
+            let mut expr = TimeExpr::Reference;
+            let mut any = false;
+            for (idx, grain) in grains.into_iter().enumerate() {
+                if let Some(amount) = caps.get(idx + 1) {
+                    expr = shift_by_grain(expr, amount.as_str().parse().ok()?, grain);
+                    any = true;
+                }
+            }
+            // "P" and "PT" alone name no duration.
+            if !any {
+                None
+            } else if in_prefix {
+                Some(expr)
+            } else {
+                // A bare duration composes with "for <duration>".
+                Some(TimeExpr::Duration(Box::new(expr)))
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Enumerated days sharing a month ("the 3rd, 7th and 12th of May",
+//! "May 3, 7 and 12"), or of any month ("on the 1st and 15th").
+//!
+//! These produce a `TimeExpr::List`, resolved to one value per day.
+
+use crate::engine::BucketMask;
+use crate::rules::time::predicates::*;
+use crate::time_expr::{Constraint, TimeExpr};
+use crate::{Rule, RuleGroups, Token, TokenKind};
+
+/// The day numbers in the matched list (group 1).
+pub(crate) fn day_numbers(list: &Token) -> Option<Vec<u32>> {
+    let TokenKind::RegexMatch(groups) = &list.kind else { return None };
+    regex!(r"\d{1,2}")
+        .find_iter(groups.get(1)?)
+        .map(|m| m.as_str().parse::<u32>().ok().filter(|day| (1..=31).contains(day)))
+        .collect()
+}
+
+/// One `MonthDay` per day number in the matched list (group 1).
+fn month_days(list: &Token, month: u32) -> Option<TimeExpr> {
+    let days = day_numbers(list)?;
+    Some(TimeExpr::List(days.into_iter().map(|day| TimeExpr::MonthDay { month, day }).collect()))
+}
+
+/// One day of the month per day number in the matched list (group 1), each
+/// the next such day from the reference.
+pub(crate) fn days_of_month(list: &Token) -> Option<TimeExpr> {
+    let days = day_numbers(list)?;
+    Some(TimeExpr::List(
+        days.into_iter()
+            .map(|day| TimeExpr::Intersect {
+                expr: Box::new(TimeExpr::Reference),
+                constraint: Constraint::DayOfMonth(day),
+            })
+            .collect(),
+    ))
+}
+
+/// "on the 1st and 15th", "on the 1st, 10th and 20th": ordinals joined by
+/// "and" after "on" name days of the month.
+pub fn rule_on_day_list() -> Rule {
+    rule! {
+        name: "on <day>, <day> and <day>",
+        pattern: [
+            re!(r"(?i)on\s+(?:the\s+)?(\d{1,2}(?:st|nd|rd|th)(?:\s*,\s*(?:the\s+)?\d{1,2}(?:st|nd|rd|th))*\s*,?\s+(?:and|&)\s+(?:the\s+)?\d{1,2}(?:st|nd|rd|th))\b"),
+        ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            days_of_month(tokens.first()?)
+        }
+    }
+}
+
+/// "the 1st and 15th" without "on": latent, like a lone "the 15th"
+/// ("the 1st and 2nd place").
+pub fn rule_day_list_latent() -> Rule {
+    rule! {
+        name: "<day>, <day> and <day> (latent)",
+        pattern: [
+            re!(r"(?i)the\s+(\d{1,2}(?:st|nd|rd|th)(?:\s*,\s*(?:the\s+)?\d{1,2}(?:st|nd|rd|th))*\s*,?\s+(?:and|&)\s+(?:the\s+)?\d{1,2}(?:st|nd|rd|th))\b"),
+        ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::LATENT,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            days_of_month(tokens.first()?)
+        }
+    }
+}
+
+/// "(on) the 3rd, 7th and 12th of May", "3, 7 & 12 May"
+pub fn rule_day_list_of_month() -> Rule {
+    rule! {
+        name: "<day>, <day> and <day> of <month>",
+        pattern: [
+            re!(r"(?i)(?:on\s+)?(?:the\s+)?(\d{1,2}(?:st|nd|rd|th)?(?:\s*,\s*(?:the\s+)?\d{1,2}(?:st|nd|rd|th)?)*\s*,?\s+(?:and|&)\s+(?:the\s+)?\d{1,2}(?:st|nd|rd|th)?)\s+(?:of\s+)?"),
+            pred!(is_month_expr),
+        ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            month_days(tokens.first()?, month_from_expr(tokens.get(1)?)?)
+        }
+    }
+}
+
+/// "(on) May 3, 7 and 12", "May 3rd and 5th"
+pub fn rule_month_day_list() -> Rule {
+    rule! {
+        name: "<month> <day>, <day> and <day>",
+        pattern: [
+            pred!(is_month_expr),
+            re!(r"(?i)\s+(?:the\s+)?(\d{1,2}(?:st|nd|rd|th)?(?:\s*,\s*(?:the\s+)?\d{1,2}(?:st|nd|rd|th)?)*\s*,?\s+(?:and|&)\s+(?:the\s+)?\d{1,2}(?:st|nd|rd|th)?)\b"),
+        ],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            month_days(tokens.get(1)?, month_from_expr(tokens.first()?)?)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Timestamps as written by common log formats: syslog ("Jun 14 09:30:01"),
+//! Apache/NCSA ("[14/Jun/2025:09:30:01 +0200]") and RFC 2822 ("Sat, 14 Jun
+//! 2025 09:30:01 +0200").
+//!
+//! Stated offsets are read exactly and converted to local time like any
+//! other stated timezone; syslog lines carry no year and resolve like
+//! "June 14".
+
+use crate::engine::BucketMask;
+use crate::rules::time::helpers::timezone::in_timezone;
+use crate::rules::time::helpers::*;
+use crate::rules::time::predicates::MONTH_NAME;
+use crate::time_expr::TimeExpr;
+use crate::{Rule, Token, TokenKind};
+use chrono::NaiveDate;
+
+/// Full match of the first token.
+fn matched_text(token: &Token) -> Option<&str> {
+    let TokenKind::RegexMatch(groups) = &token.kind else { return None };
+    groups.first().map(String::as_str)
+}
+
+/// Datetime from year, month abbreviation, day and clock fields.
+fn datetime(year: &str, month: &str, day: &str, hour: &str, minute: &str, second: Option<&str>) -> Option<TimeExpr> {
+    let month = *MONTH_NAME.get(month)?;
+    let date = NaiveDate::from_ymd_opt(year.parse().ok()?, month, day.parse().ok()?)?;
+    let second = second.map_or(Some(0), |s| s.parse().ok())?;
+    Some(TimeExpr::At(date.and_hms_opt(hour.parse().ok()?, minute.parse().ok()?, second)?))
+}
+
+/// "Jun 14 09:30:01", "Jun  4 09:30:01"
+pub fn rule_syslog_timestamp() -> Rule {
+    rule! {
+        name: "syslog timestamp",
+        pattern: [re!(r"(?i)\b(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)\s{1,2}(\d{1,2})\s+(\d{2}):(\d{2}):(\d{2})\b")],
+        buckets: BucketMask::HAS_COLON.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let month = *MONTH_NAME.get(groups.get(1)?.as_str())?;
+            let day: u32 = groups.get(2)?.parse().ok().filter(|day| (1..=31).contains(day))?;
+            let num = |idx: usize| groups.get(idx).and_then(|g| g.parse::<i64>().ok());
+            let time = time_expr_with_hms(num(3)?, num(4)?, num(5)?)?;
+            intersect_time_exprs(&TimeExpr::MonthDay { month, day }, &time)
+        }
+    }
+}
+
+/// "[14/Jun/2025:09:30:01 +0200]"
+pub fn rule_apache_timestamp() -> Rule {
+    rule! {
+        name: "Apache log timestamp",
+        pattern: [re!(r"(?i)\[?\b\d{2}/[a-z]{3}/\d{4}:\d{2}:\d{2}:\d{2}\s+[+-]\d{4}\b\]?")],
+        buckets: BucketMask::HAS_COLON.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let caps = regex!(r"(\d{2})/([a-z]{3})/(\d{4}):(\d{2}):(\d{2}):(\d{2})\s+([+-]\d{4})")
+                .captures(matched_text(tokens.first()?)?)?;
+            let at = datetime(&caps[3], &caps[2], &caps[1], &caps[4], &caps[5], Some(&caps[6]))?;
+            in_timezone(at, &caps[7])
+        }
+    }
+}
+
+/// "Sat, 14 Jun 2025 09:30:01 +0200", "14 Jun 2025 09:30 GMT"
+pub fn rule_rfc2822_timestamp() -> Rule {
+    rule! {
+        name: "RFC 2822 timestamp",
+        pattern: [re!(r"(?i)\b(?:(?:mon|tue|wed|thu|fri|sat|sun),\s*)?\d{1,2}\s+(?:jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)\s+\d{4}\s+\d{2}:\d{2}(?::\d{2})?\s+(?:[+-]\d{4}|ut|gmt|z|[ecmp][sd]t)\b")],
+        buckets: BucketMask::HAS_COLON.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let caps = regex!(r"(\d{1,2})\s+([a-z]{3})\s+(\d{4})\s+(\d{2}):(\d{2})(?::(\d{2}))?\s+(\S+)$")
+                .captures(matched_text(tokens.first()?)?)?;
+            let second = caps.get(6).map(|m| m.as_str());
+            let at = datetime(&caps[3], &caps[2], &caps[1], &caps[4], &caps[5], second)?;
+            // RFC 2822 spells UTC "UT"; the US zone names are fixed offsets.
+            match &caps[7] {
+                "ut" | "z" => in_timezone(at, "UTC"),
+                zone => in_timezone(at, zone),
+            }
+        }
+    }
+}
@@ -1,18 +1,13 @@
 use crate::Dimension;
 use crate::time_expr::{Constraint, Grain, TimeExpr};
-use crate::{Rule, Token, TokenKind};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 /// Miscellaneous time rules (timezones, nth patterns, year formatting)
 use chrono::{NaiveTime, Timelike};
 
 use crate::{
     engine::BucketMask,
     rules::numeral::predicates::number_between,
-    rules::time::{
-        helpers::shift::shift_by_grain,
-        helpers::timezone::{LOCAL_TZ_OFFSET_HOURS, tz_offset_hours},
-        helpers::*,
-        predicates::*,
-    },
+    rules::time::{helpers::shift::shift_by_grain, helpers::timezone::in_timezone, helpers::*, predicates::*},
 };
 
 pub fn rule_interval_by_the_end_of() -> Rule {
@@ -20,6 +15,7 @@ pub fn rule_interval_by_the_end_of() -> Rule {
         name: "by the end of <time>",
         pattern: [re!(r"(?i)by (the )?end of\s*"), pred!(is_time_expr)],
         buckets: (BucketMask::HAS_COLON).bits(),
+        hint: "deadline",
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let target_token = tokens.get(1)?;
 
@@ -296,42 +292,74 @@ pub fn rule_time_of_day_with_timezone() -> Rule {
         name: "<time-of-day> <timezone>",
         pattern: [
             pred!(is_time_of_day_expr),
-            re!(r"\s+"),
             pattern_regex(timezone_pattern()),
         ],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::TIMEZONES,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time_expr = get_time_expr(tokens.first()?)?.clone();
-            let tz = first(&tokens[2..])?;
+            let tz = first(&tokens[1..])?;
 
-            let tz_offset = tz_offset_hours(&tz)?;
-            let delta = LOCAL_TZ_OFFSET_HOURS - tz_offset;
+            in_timezone(time_expr, &tz)
+        }
+    }
+}
 
-            if delta == 0 {
-                Some(time_expr)
-            } else {
-                let shifted = TimeExpr::Shift {
-                    expr: Box::new(time_expr),
-                    amount: delta,
-                    grain: Grain::Hour,
-                };
-                Some(shifted)
-            }
+/// "9am ET / 6am PT", "9am ET | 6am PT": one time in two timezones
+pub fn rule_dual_timezone_time() -> Rule {
+    rule! {
+        name: "<time> <timezone> / <time> <timezone>",
+        pattern: [
+            pred!(is_zoned_time_expr),
+            re!(r"\s*[/|]\s*"),
+            pred!(is_zoned_time_expr),
+        ],
+        buckets: BucketMask::empty().bits(),
+        group: RuleGroups::TIMEZONES,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            dual_timezone(tokens.first()?, tokens.get(2)?)
+        }
+    }
+}
+
+/// "15:00 CET (9am ET)"
+pub fn rule_dual_timezone_time_parenthesized() -> Rule {
+    rule! {
+        name: "<time> <timezone> (<time> <timezone>)",
+        pattern: [
+            pred!(is_zoned_time_expr),
+            re!(r"\s*\(\s*"),
+            pred!(is_zoned_time_expr),
+            re!(r"\s*\)"),
+        ],
+        buckets: BucketMask::empty().bits(),
+        group: RuleGroups::TIMEZONES,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            dual_timezone(tokens.first()?, tokens.get(2)?)
         }
     }
 }
 
+fn dual_timezone(primary: &Token, secondary: &Token) -> Option<TimeExpr> {
+    Some(TimeExpr::DualTimezone {
+        primary: Box::new(get_time_expr(primary)?.clone()),
+        secondary: Box::new(get_time_expr(secondary)?.clone()),
+    })
+}
+
 pub fn rule_interval_dash_with_timezone() -> Rule {
     rule! {
         name: "<time> - <time> <timezone>",
         pattern: [
             pred!(is_time_expr),
-            re!(r"\s*-\s*"),
+            re!(r"\s*[-–—]\s*"),
             pred!(is_time_expr),
-            re!(r"\s+"),
             pattern_regex(timezone_pattern()),
         ],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::TIMEZONES,
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             // Only apply this rule for time-of-day ranges.
             let start_token = tokens.first()?;
@@ -365,19 +393,8 @@ pub fn rule_interval_dash_with_timezone() -> Rule {
                 end: Box::new(end),
             };
 
-            let tz = first(&tokens[4..])?;
-            let tz_offset = tz_offset_hours(&tz)?;
-            let delta = LOCAL_TZ_OFFSET_HOURS - tz_offset;
-
-            if delta == 0 {
-                Some(interval)
-            } else {
-                Some(TimeExpr::Shift {
-                    expr: Box::new(interval),
-                    amount: delta,
-                    grain: Grain::Hour,
-                })
-            }
+            let tz = first(&tokens[3..])?;
+            in_timezone(interval, &tz)
         }
     }
 }
@@ -391,6 +408,7 @@ pub fn rule_weekday_time_of_day_with_timezone() -> Rule {
             re!(r"(?i)\b(mondays?|mon|tuesdays?|tues?|wed?nesdays?|wed|thursdays?|thurs?|thu|fridays?|fri|saturdays?|sat|sundays?|sun)\s+(\d{1,2})\s+([ap])\.?\s?m\.?\s+\(?(BST|PST|EST|CST|MST|CET|UTC|GMT|IST|JST|KST|AEST|AEDT|NZST|NZDT)\)?\b"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        group: RuleGroups::TIMEZONES,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             // Parse weekday
             let dow_match = match &tokens.first()?.kind {
@@ -425,12 +443,7 @@ pub fn rule_weekday_time_of_day_with_timezone() -> Rule {
                 TokenKind::RegexMatch(groups) => groups.get(4)?.as_str(),
                 _ => return None,
             };
-            let tz_offset = tz_offset_hours(tz_abbr)?;
-            let delta = (LOCAL_TZ_OFFSET_HOURS - tz_offset) as i64;
-
-            // Apply timezone shift
-            let final_hour = ((hour_24 + delta) % 24 + 24) % 24;
-            let time = NaiveTime::from_hms_opt(final_hour as u32, 0, 0)?;
+            let time = NaiveTime::from_hms_opt(hour_24 as u32, 0, 0)?;
 
             // Create weekday constraint
             let weekday_expr = TimeExpr::Intersect {
@@ -438,11 +451,12 @@ pub fn rule_weekday_time_of_day_with_timezone() -> Rule {
                 constraint: Constraint::DayOfWeek(weekday),
             };
 
-            // Intersect with time
-            Some(TimeExpr::Intersect {
+            // Intersect with time, read in the given timezone
+            let expr = TimeExpr::Intersect {
                 expr: Box::new(weekday_expr),
                 constraint: Constraint::TimeOfDay(time),
-            })
+            };
+            in_timezone(expr, tz_abbr)
         }
     }
 }
@@ -456,6 +470,7 @@ pub fn rule_weekday_at_time_with_minutes_and_timezone() -> Rule {
             re!(r"(?i)\b(mondays?|mon|tuesdays?|tues?|wed?nesdays?|wed|thursdays?|thurs?|thu|fridays?|fri|saturdays?|sat|sundays?|sun)\s+at\s+(\d{1,2}):(\d{2})\s*([ap])\.?\s?m\.?\s+\(?(BST|PST|EST|CST|MST|CET|UTC|GMT|IST|JST|KST|AEST|AEDT|NZST|NZDT)\)?\b"),
         ],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON | BucketMask::WEEKDAYISH).bits(),
+        group: RuleGroups::TIMEZONES,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             // Parse weekday
             let dow_match = match &tokens.first()?.kind {
@@ -476,7 +491,7 @@ pub fn rule_weekday_at_time_with_minutes_and_timezone() -> Rule {
 
             // Parse hour, minute, and am/pm
             let hour = regex_group_int_value(tokens.first()?, 2)? as i64;
-            let _minute = regex_group_int_value(tokens.first()?, 3)? as u32;
+            let minute = regex_group_int_value(tokens.first()?, 3)? as u32;
             let ap_group = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => groups.get(4)?.as_str(),
                 _ => return None,
@@ -491,12 +506,7 @@ pub fn rule_weekday_at_time_with_minutes_and_timezone() -> Rule {
                 TokenKind::RegexMatch(groups) => groups.get(5)?.as_str(),
                 _ => return None,
             };
-            let tz_offset = tz_offset_hours(tz_abbr)?;
-            let delta = (LOCAL_TZ_OFFSET_HOURS - tz_offset) as i64;
-
-            // Apply timezone shift (ignoring minutes for simplicity)
-            let final_hour = ((hour_24 + delta) % 24 + 24) % 24;
-            let time = NaiveTime::from_hms_opt(final_hour as u32, 0, 0)?;
+            let time = NaiveTime::from_hms_opt(hour_24 as u32, minute, 0)?;
 
             // Create weekday constraint
             let weekday_expr = TimeExpr::Intersect {
@@ -504,11 +514,12 @@ pub fn rule_weekday_at_time_with_minutes_and_timezone() -> Rule {
                 constraint: Constraint::DayOfWeek(weekday),
             };
 
-            // Intersect with time
-            Some(TimeExpr::Intersect {
+            // Intersect with time, read in the given timezone
+            let expr = TimeExpr::Intersect {
                 expr: Box::new(weekday_expr),
                 constraint: Constraint::TimeOfDay(time),
-            })
+            };
+            in_timezone(expr, tz_abbr)
         }
     }
 }
@@ -635,10 +646,10 @@ pub fn rule_cycle_numeral_qtr() -> Rule {
         name: "<integer> qtr",
         pattern: [
             pred!(|t: &Token| number_between::<1, 4>(t)),
-            re!(r"\s+"),
             re!(r"(?i)qtr(s)?\b|qr\b"),
         ],
         buckets: (BucketMask::HAS_COLON).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let ordinal = integer_value(tokens.first()?)? as i32;
             if !(1..=4).contains(&ordinal) {
@@ -701,13 +712,7 @@ pub fn rule_year_numeric() -> Rule {
         buckets: (BucketMask::HAS_COLON).bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let year = integer_value(tokens.first()?)? as i32;
-            Some(TimeExpr::Absolute {
-                year,
-                month: 1,
-                day: 1,
-                hour: None,
-                minute: None,
-            })
+            Some(year_interval(year))
         }
     }
 }
@@ -715,21 +720,66 @@ pub fn rule_year_numeric() -> Rule {
 pub fn rule_year_bc() -> Rule {
     rule! {
         name: "in <year> bc",
-        pattern: [re!(r"(?i)in\s+(\d{1,4})\s*(b\.?c\.?|bc)\b")],
-        buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON).bits(),
+        pattern: [re!(r"(?i)(?:in\s+)?(\d{1,4})\s*(?:bce?|b\.c(?:\.e)?)\b\.?")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let year = regex_group_int_value(tokens.first()?, 1)? as i32;
-            Some(TimeExpr::Absolute {
-                year: -year,
-                month: 1,
-                day: 1,
-                hour: None,
-                minute: None,
-            })
+            if year == 0 {
+                return None;
+            }
+            Some(year_interval(-year))
         }
     }
 }
 
+/// "in <year>" (e.g. "in 1492"); the year itself comes from the year rules.
+pub fn rule_in_year() -> Rule {
+    rule! {
+        name: "in <year>",
+        pattern: [re!(r"(?i)in\s+"), pred!(is_year_expr)],
+        optional_phrases: ["in"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> { get_time_expr(tokens.get(1)?).cloned() }
+    }
+}
+
+/// "the 1970s", "1800s": a decade, or a century when the year ends in 00.
+pub fn rule_decade() -> Rule {
+    rule! {
+        name: "<decade>s",
+        pattern: [re!(r"(?i)(?:the\s+)?(\d{3})0'?s\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let start = regex_group_int_value(tokens.first()?, 1)? as i32 * 10;
+            Some(years_between(start, start + 10))
+        }
+    }
+}
+
+/// "the 20th century" runs 1900-1999, as people say it, not 1901-2000.
+pub fn rule_century() -> Rule {
+    rule! {
+        name: "<nth> century",
+        pattern: [re!(r"(?i)(?:the\s+)?(\d{1,2})(?:st|nd|rd|th)\s+century\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let century = regex_group_int_value(tokens.first()?, 1)? as i32;
+            if century == 0 {
+                return None;
+            }
+            Some(years_between((century - 1) * 100, century * 100))
+        }
+    }
+}
+
+/// January 1st of `start` up to January 1st of `end`.
+fn years_between(start: i32, end: i32) -> TimeExpr {
+    TimeExpr::IntervalBetween {
+        start: Box::new(TimeExpr::Absolute { year: start, month: 1, day: 1, hour: None, minute: None }),
+        end: Box::new(TimeExpr::Absolute { year: end, month: 1, day: 1, hour: None, minute: None }),
+    }
+}
+
 pub fn rule_time_year_suffix() -> Rule {
     rule! {
         name: "<time> <year>",
@@ -751,11 +801,12 @@ pub fn rule_time_numeral_year_suffix() -> Rule {
         name: "<time> <numeral-year>",
         // Include leading whitespace because the engine matches regexes at the
         // current position without skipping spaces.
-        pattern: [pred!(is_time_expr), re!(r"\s+"), pred!(|t: &Token| number_between::<1000, 2500>(t))],
+        pattern: [pred!(is_time_expr), pred!(|t: &Token| number_between::<1000, 2500>(t))],
         buckets: (BucketMask::HAS_COLON).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let base = get_time_expr(tokens.first()?)?;
-            let year = integer_value(tokens.get(2)?)? as i32;
+            let year = integer_value(tokens.get(1)?)? as i32;
             let expr = time_expr_with_year(base, year)?;
             Some(expr)
         }
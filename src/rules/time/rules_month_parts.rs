@@ -1,17 +1,18 @@
-//! Month and month-part related rules
+//! Month and month-part related rules, and parts of other periods ("end of
+//! next week", "middle of Q3")
 
 use crate::engine::BucketMask;
 use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
 use crate::time_expr::{Grain, MonthPart, TimeExpr};
-use crate::{Rule, Token};
+use crate::{Rule, Token, TokenKind};
 
 /// "early March", "mid-March", "late of March"
 pub fn rule_part_of_month() -> Rule {
     rule! {
         name: "part of <named-month>",
         pattern: [
-            re!(r"(?i)(early|mid|late)-?(?:\s+of)?\s+"),
+            re!(r"(?i)(early|mid|late)(?:-\s*|(?:\s+of)?\s+)"),
             pred!(is_month_expr),
         ],
         optional_phrases: ["early", "mid", "late"],
@@ -135,6 +136,7 @@ pub fn rule_by_end_of_time() -> Rule {
         ],
         required_phrases: ["by", "end"],
         buckets: BucketMask::empty().bits(),
+        hint: "deadline",
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time_expr = get_time_expr(tokens.get(1)?)?.clone();
 
@@ -274,16 +276,7 @@ pub fn rule_end_of_specific_year() -> Rule {
         buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let year_expr = get_time_expr(tokens.get(1)?)?;
-            let year = match year_expr {
-                TimeExpr::Absolute {
-                    year,
-                    month: 1,
-                    day: 1,
-                    hour: None,
-                    minute: None,
-                } => *year,
-                _ => return None,
-            };
+            let year = year_of_expr(year_expr)?;
 
             let start = TimeExpr::Absolute {
                 year,
@@ -317,16 +310,7 @@ pub fn rule_beginning_of_specific_year() -> Rule {
         buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let year_expr = get_time_expr(tokens.get(1)?)?;
-            let year = match year_expr {
-                TimeExpr::Absolute {
-                    year,
-                    month: 1,
-                    day: 1,
-                    hour: None,
-                    minute: None,
-                } => *year,
-                _ => return None,
-            };
+            let year = year_of_expr(year_expr)?;
 
             let start = TimeExpr::Absolute {
                 year,
@@ -378,3 +362,42 @@ pub fn rule_beginning_of_year() -> Rule {
         }
     }
 }
+
+/// "end of next week", "middle of Q3", "late next month", "early 2014",
+/// "early next week"
+pub fn rule_part_of_period() -> Rule {
+    rule! {
+        name: "<part> of <period>",
+        pattern: [
+            re!(r"(?i)(?:(?:at|in)\s+)?(?:the\s+)?(beginning|start|middle|end|early|mid|late)(?:\s+of)?(?:\s+the)?[\s-]+"),
+            pred!(is_time_expr),
+        ],
+        optional_phrases: ["beginning", "start", "middle", "end", "early", "mid", "late"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let word = groups.get(1)?.as_str();
+            let part = match word {
+                "beginning" | "start" | "early" => MonthPart::Early,
+                "middle" | "mid" => MonthPart::Mid,
+                _ => MonthPart::Late,
+            };
+            let of = get_time_expr(tokens.get(1)?)?;
+
+            // Named months, seasons and the start and end of weeks and years
+            // have dedicated rules; "early"/"late" split them into thirds.
+            let modifier = matches!(word, "early" | "mid" | "late");
+            let dedicated = is_month_expr(tokens.get(1)?)
+                || matches!(of, TimeExpr::Season(_) | TimeExpr::SeasonPeriod { .. })
+                || (!modifier
+                    && part != MonthPart::Mid
+                    && (year_of_expr(of).is_some() || matches!(of, TimeExpr::IntervalOf { grain: Grain::Week, .. })));
+            let period = matches!(value_grain(of), Grain::Week | Grain::Month | Grain::Quarter | Grain::Year);
+            if dedicated || !period {
+                return None;
+            }
+
+            Some(TimeExpr::PeriodPart { part, of: Box::new(of.clone()) })
+        }
+    }
+}
@@ -26,12 +26,13 @@ pub fn rule_month_year() -> Rule {
 pub fn rule_month_ordinal_day() -> Rule {
     rule! {
         name: "<month> <day-of-month> (ordinal)",
-        pattern: [pred!(is_month_expr), re!(r"\s+"), pred!(is_day_of_month_numeral)],
+        pattern: [pred!(is_month_expr), pred!(is_day_of_month_numeral)],
         buckets: (BucketMask::MONTHISH | BucketMask::ORDINALISH).bits(),
         deps: [Dimension::Numeral],
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let month = month_from_expr(tokens.first()?)?;
-            let day = day_of_month_from_expr(tokens.get(2)?)?;
+            let day = day_of_month_from_expr(tokens.get(1)?)?;
 
             Some(TimeExpr::MonthDay { month, day })
         }
@@ -138,3 +139,27 @@ pub fn rule_month_day_comma_year() -> Rule {
         }
     }
 }
+
+/// "this June", "next June", "last June"
+pub fn rule_this_next_last_month() -> Rule {
+    rule! {
+        name: "this|next|last <month>",
+        pattern: [re!(r"(?i)(this|next|last)\s+"), pred!(is_month_expr)],
+        optional_phrases: ["this", "next", "last"],
+        buckets: BucketMask::MONTHISH.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            // Only a bare month: "next June 5th" is left to other rules.
+            if !matches!(get_time_expr(tokens.get(1)?)?, TimeExpr::Intersect { expr, .. } if matches!(**expr, TimeExpr::Reference)) {
+                return None;
+            }
+            let month = month_from_expr(tokens.get(1)?)?;
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let offset = match groups.get(1)?.as_str() {
+                "this" => 0,
+                "next" => 1,
+                _ => -1,
+            };
+            Some(TimeExpr::NamedMonth { month, offset })
+        }
+    }
+}
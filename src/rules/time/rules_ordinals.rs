@@ -5,14 +5,16 @@ use crate::rules::time::helpers::producers::year_from;
 use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
 use crate::time_expr::{Constraint, TimeExpr};
-use crate::{Rule, Token, TokenKind};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 
-/// Ordinal day of month (e.g., "15th")
+/// Ordinal day of month (e.g., "15th"); latent, as a bare ordinal may count
+/// anything.
 pub fn rule_ordinal_day_of_month() -> Rule {
     rule! {
         name: "ordinal (day of month)",
         pattern: [re!(r"(?i)\b([1-9]|[12]\d|3[01])(st|nd|rd|th)\b")],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::ORDINALISH).bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let day = regex_group_int_value(tokens.first()?, 1)? as u32;
             if !(1..=31).contains(&day) {
@@ -26,12 +28,14 @@ pub fn rule_ordinal_day_of_month() -> Rule {
     }
 }
 
-/// the <day-of-month> (ordinal)
+/// the <day-of-month> (ordinal), e.g. "on the 3rd"; latent like a bare
+/// ordinal. The month comes from `Options::day_of_month`.
 pub fn rule_the_ordinal_day() -> Rule {
     rule! {
         name: "the <day-of-month> (ordinal)",
         pattern: [re!(r"(?i)(?:on\s+the|the)\s+"), pred!(is_day_of_month_numeral)],
         buckets: BucketMask::ORDINALISH.bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let day = day_of_month_from_expr(tokens.get(1)?)?;
             Some(TimeExpr::Intersect {
@@ -8,12 +8,12 @@ use crate::time_expr::Constraint;
 use crate::time_expr::{Grain, PartOfDay, TimeExpr};
 use crate::{Rule, Token};
 
-/// "morning", "afternoon", "evening", "night"
+/// "morning", "afternoon", "evening", "night", "overnight"
 pub fn rule_part_of_days() -> Rule {
     rule! {
         name: "part of days",
-        pattern: [re!(r"(?i)(morning|afternoon|evening|night|tonight)")],
-        optional_phrases: ["morning", "afternoon", "evening", "night", "tonight"],
+        pattern: [re!(r"(?i)(morning|afternoon|evening|overnight|night|tonight)")],
+        optional_phrases: ["morning", "afternoon", "evening", "overnight", "night", "tonight"],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let part_of_day = part_of_day_from_token(tokens.first()?)?;
@@ -130,7 +130,7 @@ pub fn rule_integer_in_part_of_day() -> Rule {
         name: "<integer> in the <part-of-day>",
         pattern: [
             pred!(|t: &Token| number_between::<0, 24>(t)),
-            re!(r"(?i)\s+in\s+the\s+"),
+            words!("in the"),
             re!(r"(?i)(morning|afternoon|evening|night)")
         ],
         optional_phrases: ["morning", "afternoon", "evening", "night"],
@@ -161,7 +161,7 @@ pub fn rule_time_in_part_of_day() -> Rule {
         name: "<time> in the <part-of-day>",
         pattern: [
             pred!(is_time_expr),
-            re!(r"(?i)\s+in\s+the\s+"),
+            words!("in the"),
             re!(r"(?i)(morning|afternoon|evening|night)")
         ],
         optional_phrases: ["morning", "afternoon", "evening", "night"],
@@ -241,7 +241,7 @@ pub fn rule_relative_day_part_of_day() -> Rule {
         pattern: [
             re!(r"(?i)(today|tomorrow|yesterday)"),
             re!(r"(?i)\s+(?:at\s+)?"),
-            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|night)"),
+            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|overnight|night)"),
         ],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
@@ -284,13 +284,13 @@ pub fn rule_weekday_part_of_day() -> Rule {
         name: "<weekday> <part-of-day>",
         pattern: [
             pred!(is_weekday_expr),
-            re!(r"\s+"),
-            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|night)"),
+            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|overnight|night)"),
         ],
         buckets: BucketMask::WEEKDAYISH.bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday_expr = get_time_expr(tokens.first()?)?.clone();
-            let pod = first(&tokens[2..])?;
+            let pod = first(&tokens[1..])?;
             let part = part_of_day_from_text(pod.as_str())?;
 
             Some(TimeExpr::Intersect {
@@ -306,15 +306,14 @@ pub fn rule_weekday_in_the_part_of_day() -> Rule {
         name: "<weekday> in|during the <part-of-day>",
         pattern: [
             pred!(is_weekday_expr),
-            re!(r"\s+"),
             re!(r"(?i)(in|during)( the)?"),
-            re!(r"\s+"),
-            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|night)"),
+            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|overnight|night)"),
         ],
         buckets: BucketMask::WEEKDAYISH.bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday_expr = get_time_expr(tokens.first()?)?.clone();
-            let pod = first(&tokens[4..])?;
+            let pod = first(&tokens[2..])?;
             let part = part_of_day_from_text(pod.as_str())?;
 
             Some(TimeExpr::Intersect {
@@ -330,15 +329,14 @@ pub fn rule_date_in_the_part_of_day() -> Rule {
         name: "<date> in|during the <part-of-day>",
         pattern: [
             pred!(is_time_expr),
-            re!(r"\s+"),
             re!(r"(?i)(in|during)( the)?"),
-            re!(r"\s+"),
-            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|night)"),
+            re!(r"(?i)(?:early\s+morning|early\s+in\s+the\s+morning|early\s+hours\s+of\s+the\s+morning|morning|afternoon|lunch|evening|overnight|night)"),
         ],
         buckets: BucketMask::empty().bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let date_expr = get_time_expr(tokens.first()?)?.clone();
-            let pod = first(&tokens[4..])?;
+            let pod = first(&tokens[2..])?;
             let part = part_of_day_from_text(pod.as_str())?;
 
             Some(TimeExpr::Intersect {
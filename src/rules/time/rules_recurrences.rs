@@ -0,0 +1,75 @@
+//! Recurring times ("every first Monday of the month", "on the last Friday
+//! of every quarter", "the 1st and 15th of every month").
+//!
+//! These produce a `TimeExpr::Recurrence`, resolved to a
+//! [`Recurrence`](crate::Recurrence): the next occurrence and the repeat rule.
+
+use crate::engine::BucketMask;
+use crate::rules::time::helpers::*;
+use crate::rules::time::predicates::*;
+use crate::rules::time::rules_lists::days_of_month;
+use crate::time_expr::{Grain, TimeExpr};
+use crate::{Rule, Token, TokenKind};
+
+/// The nth (`ordinal` group 1) `weekday` of every month, quarter or year
+/// (`period` group 1).
+fn every_nth_weekday(ordinal: &Token, weekday: &Token, period: &Token) -> Option<TimeExpr> {
+    let TokenKind::RegexMatch(groups) = &period.kind else { return None };
+    let every = grain_from_cycle(groups.get(1)?)?;
+    let occurrence = TimeExpr::NthWeekdayOf {
+        n: nth_from_word(ordinal)?,
+        weekday: weekday_from_name(weekday)?,
+        of: Box::new(TimeExpr::StartOf { expr: Box::new(TimeExpr::Reference), grain: every }),
+    };
+    Some(TimeExpr::Recurrence { every, occurrence: Box::new(occurrence) })
+}
+
+/// "every first Monday of the month", "each last Friday of the quarter"
+pub fn rule_every_nth_weekday_of_period() -> Rule {
+    rule! {
+        name: "every <nth> <weekday> of the <cycle>",
+        pattern: [
+            re!(r"(?i)(?:on\s+)?(?:every|each)\s+(?:the\s+)?(first|second|third|fourth|fifth|1st|2nd|3rd|4th|5th|last)\s+"),
+            pred!(is_weekday_name),
+            re!(r"(?i)\s+(?:of|in)\s+(?:the|a|each|every)\s+(month|quarter|year)\b")
+        ],
+        optional_phrases: ["every", "each"],
+        buckets: BucketMask::WEEKDAYISH.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            every_nth_weekday(tokens.first()?, tokens.get(1)?, tokens.get(2)?)
+        }
+    }
+}
+
+/// "on the first Monday of every month", "the last Friday of each quarter"
+pub fn rule_nth_weekday_of_every_period() -> Rule {
+    rule! {
+        name: "<nth> <weekday> of every <cycle>",
+        pattern: [
+            re!(r"(?i)(?:on\s+)?(?:the\s+)?(first|second|third|fourth|fifth|1st|2nd|3rd|4th|5th|last)\s+"),
+            pred!(is_weekday_name),
+            re!(r"(?i)\s+(?:of|in)\s+(?:every|each)\s+(month|quarter|year)\b")
+        ],
+        optional_phrases: ["every", "each"],
+        buckets: BucketMask::WEEKDAYISH.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            every_nth_weekday(tokens.first()?, tokens.get(1)?, tokens.get(2)?)
+        }
+    }
+}
+
+/// "the 1st and 15th of every month", "every 1st and 15th (of the month)"
+pub fn rule_day_list_of_every_month() -> Rule {
+    rule! {
+        name: "<day> and <day> of every month",
+        pattern: [
+            re!(r"(?i)(?:(?:on\s+)?(?:the\s+)?(\d{1,2}(?:st|nd|rd|th)(?:\s*,\s*(?:the\s+)?\d{1,2}(?:st|nd|rd|th))*\s*,?\s+(?:and|&)\s+(?:the\s+)?\d{1,2}(?:st|nd|rd|th))\s+(?:of|in)\s+(?:every|each)\s+month|(?:on\s+)?(?:every|each)\s+(?:the\s+)?(\d{1,2}(?:st|nd|rd|th)(?:\s*,\s*(?:the\s+)?\d{1,2}(?:st|nd|rd|th))*\s*,?\s+(?:and|&)\s+(?:the\s+)?\d{1,2}(?:st|nd|rd|th))(?:\s+(?:of|in)\s+(?:the|a|each|every)\s+month)?)\b"),
+        ],
+        optional_phrases: ["every", "each"],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let occurrence = days_of_month(tokens.first()?)?;
+            Some(TimeExpr::Recurrence { every: Grain::Month, occurrence: Box::new(occurrence) })
+        }
+    }
+}
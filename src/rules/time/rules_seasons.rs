@@ -2,8 +2,8 @@
 
 use crate::engine::BucketMask;
 use crate::rules::time::helpers::*;
-use crate::time_expr::TimeExpr;
-use crate::{Rule, Token, TokenKind};
+use crate::time_expr::{Holiday, MonthPart, Season, TimeExpr};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 
 /// "summer", "fall", "winter", "spring", "autumn"
 pub fn rule_season() -> Rule {
@@ -19,6 +19,30 @@ pub fn rule_season() -> Rule {
     }
 }
 
+/// "early summer", "mid-winter", "late fall"
+pub fn rule_season_part() -> Rule {
+    rule! {
+        name: "early|mid|late <season>",
+        pattern: [re!(r"(?i)\b(early|mid|late)[\s-]*(summer|fall|autumn|winter|spring)\b")],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let part = match groups.get(1)?.as_str() {
+                "early" => MonthPart::Early,
+                "mid" => MonthPart::Mid,
+                _ => MonthPart::Late,
+            };
+            let season = match groups.get(2)?.as_str() {
+                "summer" => Season::Summer,
+                "fall" | "autumn" => Season::Fall,
+                "winter" => Season::Winter,
+                _ => Season::Spring,
+            };
+            Some(TimeExpr::SeasonPart { season, part })
+        }
+    }
+}
+
 /// "this summer", "last winter", "next spring"
 pub fn rule_modifier_season() -> Rule {
     rule! {
@@ -75,8 +99,9 @@ pub fn rule_christmas() -> Rule {
         pattern: [re!(r"(?i)(christmas|xmas)")],
         optional_phrases: ["christmas", "xmas"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
-            Some(TimeExpr::MonthDay { month: 12, day: 25 })
+            Some(TimeExpr::Holiday { holiday: Holiday::Christmas, year: None })
         }
     }
 }
@@ -88,8 +113,9 @@ pub fn rule_christmas_eve() -> Rule {
         pattern: [re!(r"(?i)(christmas|xmas)\s+eve")],
         optional_phrases: ["christmas", "xmas", "eve"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
-            Some(TimeExpr::MonthDay { month: 12, day: 24 })
+            Some(TimeExpr::Holiday { holiday: Holiday::ChristmasEve, year: None })
         }
     }
 }
@@ -101,8 +127,9 @@ pub fn rule_new_years() -> Rule {
         pattern: [re!(r"(?i)new\s+year'?s?(\s+day)?")],
         required_phrases: ["new", "year"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
-            Some(TimeExpr::MonthDay { month: 1, day: 1 })
+            Some(TimeExpr::Holiday { holiday: Holiday::NewYearsDay, year: None })
         }
     }
 }
@@ -114,8 +141,9 @@ pub fn rule_new_years_eve() -> Rule {
         pattern: [re!(r"(?i)new\s+year'?s?\s+eve")],
         required_phrases: ["new", "year", "eve"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::HOLIDAYS,
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
-            Some(TimeExpr::MonthDay { month: 12, day: 31 })
+            Some(TimeExpr::Holiday { holiday: Holiday::NewYearsEve, year: None })
         }
     }
 }
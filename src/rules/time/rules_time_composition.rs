@@ -305,6 +305,34 @@ pub fn rule_cycle_the_of_time_grain() -> Rule {
     }
 }
 
+/// "week of|commencing <date>", "w/c <date>": the week holding the date
+pub fn rule_week_of_date() -> Rule {
+    rule! {
+        name: "week of|commencing <date>",
+        pattern: [
+            re!(r"(?i)(?:(?:the\s+)?week\s+(?:of|commencing|beginning|starting)|w/c)\s+"),
+            pred!(is_day_expr)
+        ],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let date = get_time_expr(tokens.get(1)?)?;
+            // A day ("June 14", "Monday", "the 14th"), not a month or year.
+            let day = match container_grain_for_expr(date) {
+                Grain::Day | Grain::Week => true,
+                Grain::Month => matches!(date, TimeExpr::Intersect { constraint: Constraint::DayOfMonth(_), .. }),
+                _ => false,
+            };
+            if !day {
+                return None;
+            }
+            Some(TimeExpr::IntervalOf {
+                expr: Box::new(date.clone()),
+                grain: Grain::Week,
+            })
+        }
+    }
+}
+
 /// "the <cycle> of <time>" (the week of Christmas, the month of next year)
 pub fn rule_cycle_the_of_time() -> Rule {
     rule! {
@@ -5,7 +5,7 @@ use crate::rules::time::helpers::shift::shift_by_grain;
 use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
 use crate::time_expr::{Constraint, Grain, PartOfDay, TimeExpr};
-use crate::{Rule, Token};
+use crate::{Precision, Rule, Token, WithPrecision};
 
 /// "this|next <day-of-week>" (this Monday, next Friday)
 pub fn rule_next_dow() -> Rule {
@@ -157,7 +157,7 @@ pub fn rule_following_week() -> Rule {
     }
 }
 
-/// "next <time>" (next Christmas, next July)
+/// "next <time>" (next Christmas, next July 4th)
 pub fn rule_next_time_expr() -> Rule {
     rule! {
         name: "next <time>",
@@ -198,14 +198,13 @@ pub fn rule_next_time_expr() -> Rule {
 
             // For supported date-like expressions, the base `time_expr` already
             // represents the next occurrence from the reference time
-            // (e.g., Month / MonthDay pick the upcoming one). Adding an extra
-            // shift would overshoot by a year, which breaks cases like
-            // "next March" that are expected to resolve to the upcoming March.
+            // (e.g., MonthDay picks the upcoming one). Adding an extra shift
+            // would overshoot by a year. Bare months ("next March") are read
+            // by "this|next|last <month>".
             let supported = matches!(
                 time_expr,
-                TimeExpr::Intersect { constraint: Constraint::Month(_), .. }
-                    | TimeExpr::MonthDay { .. }
-            );
+                TimeExpr::Intersect { constraint: Constraint::Month(_), expr } if !matches!(**expr, TimeExpr::Reference)
+            ) || matches!(time_expr, TimeExpr::MonthDay { .. });
 
             if !supported {
                 return None;
@@ -263,10 +262,10 @@ pub fn rule_around_time() -> Rule {
         pattern: [re!(r"(?i)around\s+"), pred!(is_time_expr)],
         required_phrases: ["around"],
         buckets: BucketMask::empty().bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            // "around" is just a modifier that doesn't change the time
+        prod: |tokens: &[Token]| -> Option<WithPrecision<TimeExpr>> {
+            // "around" doesn't change the time, only how precise it is
             let time_expr = get_time_expr(tokens.get(1)?)?;
-            Some(time_expr.clone())
+            Some(WithPrecision(time_expr.clone(), Precision::Approximate))
         }
     }
 }
@@ -281,6 +280,11 @@ pub fn rule_time_before_last_after_next() -> Rule {
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time_expr = get_time_expr(tokens.first()?)?;
             let qualifier = first(&tokens[1..])?.trim().to_lowercase();
+            // A cycle ("the week") is the current one, two steps from the
+            // one after next; the cycle rule reads those.
+            if matches!(time_expr, TimeExpr::IntervalOf { .. } | TimeExpr::StartOf { .. } | TimeExpr::IntervalBetween { .. }) {
+                return None;
+            }
 
             // Determine the grain based on the time expression
             let grain = match time_expr {
@@ -327,25 +331,3 @@ pub fn rule_late_last_night() -> Rule {
         }
     }
 }
-
-/// "yesterday evening|night"
-pub fn rule_yesterday_evening() -> Rule {
-    rule! {
-        name: "yesterday evening",
-        pattern: [re!(r"(?i)yesterday\s+(evening|night)")],
-        required_phrases: ["yesterday", "evening", "night"],
-        buckets: BucketMask::empty().bits(),
-        prod: |_tokens: &[Token]| -> Option<TimeExpr> {
-            // Same as "last night"
-            let yesterday = TimeExpr::Shift {
-                expr: Box::new(TimeExpr::Reference),
-                amount: -1,
-                grain: Grain::Day,
-            };
-            Some(TimeExpr::Intersect {
-                expr: Box::new(yesterday),
-                constraint: Constraint::PartOfDay(PartOfDay::Evening),
-            })
-        }
-    }
-}
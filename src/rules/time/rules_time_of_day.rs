@@ -7,7 +7,7 @@ use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
 use crate::time_expr::Constraint;
 use crate::time_expr::{Grain, TimeExpr};
-use crate::{Rule, Token, TokenKind};
+use crate::{Rule, RuleGroups, Token, TokenKind};
 use chrono::Timelike;
 // Already imported above
 
@@ -179,10 +179,13 @@ pub fn rule_hh_oclock_ampm() -> Rule {
 pub fn rule_hh_oclock() -> Rule {
     rule! {
         name: "hh o'clock",
-        pattern: [re!(r"(?i)\b(\d{1,2})\s*o'?clock\b")],
-        buckets: BucketMask::HAS_DIGITS.bits(),
+        pattern: [re!(r"(?i)\b(\d{1,2}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s*o'?clock\b")],
+        buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let hour = regex_group_int_value(tokens.first()?, 1)? as u32;
+            let hour = match &tokens.first()?.kind {
+                TokenKind::RegexMatch(groups) => parse_integer_text(groups.get(1)?)? as u32,
+                _ => return None,
+            };
 
             if hour > 24 {
                 return None;
@@ -197,6 +200,50 @@ pub fn rule_hh_oclock() -> Rule {
     }
 }
 
+/// Military time in digits, only with its "hours" suffix ("1430 hours",
+/// "0600 hrs"): bare four-digit numbers stay latent.
+pub fn rule_military_hours() -> Rule {
+    rule! {
+        name: "hhmm hours (military)",
+        pattern: [re!(r"(?i)\b(?:([01]\d|2[0-3])([0-5]\d)|(24)(00))\s*(?:hours|hrs)\b")],
+        buckets: BucketMask::HAS_DIGITS.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let hour = regex_group_int_value(tokens.first()?, 1)? % 24;
+            let minute = regex_group_int_value(tokens.first()?, 2)?;
+            time_expr_with_minutes(hour, minute, false)
+        }
+    }
+}
+
+/// Military time in words ("at fourteen hundred", "oh six hundred hours",
+/// "sixteen thirty hours"). Needs "hours" after it, or "at" before an hour
+/// no 12-hour clock shows ("at seven thirty" is left to the 12-hour rules).
+pub fn rule_military_words() -> Rule {
+    rule! {
+        name: "<hour> hundred|<minutes> hours (military)",
+        pattern: [re!(r"(?i)\b(?:at\s+)?((?:oh|zero)\s+(?:one|two|three|four|five|six|seven|eight|nine)|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|twenty(?:[\s-](?:one|two|three))?|one|two|three|four|five|six|seven|eight|nine)\s+(hundred|oh\s+(?:one|two|three|four|five|six|seven|eight|nine)|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|(?:twenty|thirty|forty|fifty)(?:[\s-](?:one|two|three|four|five|six|seven|eight|nine))?)(?:\s+(?:hours|hrs))?\b")],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let words = |text: &str| -> Option<i64> {
+                text.split(|c: char| c.is_whitespace() || c == '-')
+                    .filter(|w| !w.is_empty())
+                    .map(|w| parse_integer_text(w).map(i64::from))
+                    .sum()
+            };
+            let hour = words(groups.get(1)?)?;
+            let minute = if groups.get(2)? == "hundred" { 0 } else { words(groups.get(2)?)? };
+
+            let suffixed = groups.first()?.ends_with("hours") || groups.first()?.ends_with("hrs");
+            let padded = groups.get(1)?.starts_with("oh") || groups.get(1)?.starts_with("zero");
+            if !suffixed && !(groups.first()?.starts_with("at") && (padded || hour >= 13)) {
+                return None;
+            }
+            time_expr_with_minutes(hour, minute, false)
+        }
+    }
+}
+
 pub fn rule_numeral_ampm() -> Rule {
     rule! {
         name: "<integer> am|pm",
@@ -269,6 +316,7 @@ pub fn rule_hh() -> Rule {
             re!(r"(?i)\b(0?[0-9]|1[0-9]|2[0-4])\b")
         ],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let hour = regex_group_int_value(tokens.first()?, 1)? as u32;
 
@@ -1,7 +1,7 @@
 //! Advanced time-of-day patterns including latent times and precision
 
 use crate::time_expr::{Constraint, Grain, TimeExpr};
-use crate::{Rule, Token, TokenKind};
+use crate::{Precision, Rule, RuleGroups, Token, TokenKind, WithPrecision};
 
 use crate::{
     engine::BucketMask,
@@ -33,9 +33,10 @@ pub fn rule_precision_tod() -> Rule {
             pred!(is_time_of_day_expr),
         ],
         buckets: BucketMask::empty().bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+        prod: |tokens: &[Token]| -> Option<WithPrecision<TimeExpr>> {
+            let precision = precision_from_group(tokens.first()?, 1)?;
             let expr = get_time_expr(tokens.get(1)?)?.clone();
-            Some(expr)
+            Some(WithPrecision(expr, precision))
         }
     }
 }
@@ -46,13 +47,16 @@ pub fn rule_tod_latent() -> Rule {
         pattern: [pred!(|t: &Token| number_between::<0, 23>(t))],
         optional_phrases: ["at", "morning", "afternoon", "evening", "night", "tonight"],
         buckets: BucketMask::empty().bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let n = integer_value(tokens.first()?)?;
             let time = chrono::NaiveTime::from_hms_opt(n as u32, 0, 0)?;
-            Some(TimeExpr::Intersect {
+            let tod = TimeExpr::Intersect {
                 expr: Box::new(TimeExpr::Reference),
                 constraint: Constraint::TimeOfDay(time),
-            })
+            };
+            // Hour precision marks it a bare hour, like digits ("at 3").
+            Some(TimeExpr::Shift { expr: Box::new(tod), amount: 0, grain: Grain::Hour })
         }
     }
 }
@@ -78,11 +82,17 @@ pub fn rule_hod_quarter() -> Rule {
 pub fn rule_numeral_to_hod() -> Rule {
     rule! {
         name: "<integer> to|till|before <hour-of-day>",
-        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*(to|till|before|of)\s+"), pred!(is_time_of_day_expr)],
-        buckets: BucketMask::HAS_DIGITS.bits(),
+        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*(to|till|before|of)\s+"), pred!(is_clock_hour)],
+        buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let minutes = integer_value(tokens.first()?)?;
-            time_expr_minutes_offset(tokens.get(2)?, -minutes)
+            let hour = tokens.get(2)?;
+            // "three to five" is a range rather than 4:57; bare hours only
+            // count back round minutes ("ten to seven").
+            if is_hour_numeral(hour) && minutes % 5 != 0 {
+                return None;
+            }
+            time_expr_minutes_offset(hour, -minutes)
         }
     }
 }
@@ -90,8 +100,8 @@ pub fn rule_numeral_to_hod() -> Rule {
 pub fn rule_minutes_to_hod() -> Rule {
     rule! {
         name: "<integer> minutes to|till|before <hour-of-day>",
-        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*minutes?\s*"), re!(r"(?i)(to|till|before|of)\s+"), pred!(is_time_of_day_expr)],
-        buckets: BucketMask::HAS_DIGITS.bits(),
+        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*minutes?\s*"), re!(r"(?i)(to|till|before|of)\s+"), pred!(is_clock_hour)],
+        buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let minutes = integer_value(tokens.first()?)?;
             time_expr_minutes_offset(tokens.get(3)?, -minutes)
@@ -102,8 +112,8 @@ pub fn rule_minutes_to_hod() -> Rule {
 pub fn rule_minutes_after_hod() -> Rule {
     rule! {
         name: "<integer> minutes after|past <hour-of-day>",
-        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*minutes?\s*"), re!(r"(?i)(after|past)\s+"), pred!(is_time_of_day_expr)],
-        buckets: BucketMask::HAS_DIGITS.bits(),
+        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*minutes?\s*"), re!(r"(?i)(after|past)\s+"), pred!(is_clock_hour)],
+        buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let minutes = integer_value(tokens.first()?)?;
             time_expr_minutes_offset(tokens.get(3)?, minutes)
@@ -114,8 +124,8 @@ pub fn rule_minutes_after_hod() -> Rule {
 pub fn rule_numeral_after_hod() -> Rule {
     rule! {
         name: "integer after|past <hour-of-day>",
-        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*(after|past)\s+"), pred!(is_time_of_day_expr)],
-        buckets: BucketMask::HAS_DIGITS.bits(),
+        pattern: [pred!(|t: &Token| number_between::<1, 59>(t)), re!(r"(?i)\s*(after|past)\s+"), pred!(is_clock_hour)],
+        buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let minutes = integer_value(tokens.first()?)?;
             time_expr_minutes_offset(tokens.get(2)?, minutes)
@@ -189,11 +199,12 @@ pub fn rule_hhhmm() -> Rule {
 pub fn rule_hhmm_latent() -> Rule {
     rule! {
         name: "hhmm (latent)",
-        // Avoid matching 4-digit years like "2017" as 20:17.
-        // Accept 3-digit times (e.g. 930) and 4-digit times only when
-        // the first digit is 0 or 1 (i.e. 0000..1959).
-        pattern: [re!(r"(?i)\b(?:([0-9])([0-5]\d)|([01]\d)([0-5]\d))\b")],
+        // Accept 3-digit times (e.g. 930) and 4-digit times 0000..2359. Four
+        // digits that also read as a year ("2017") are disambiguated after
+        // resolution by `disambiguate_year_vs_clock`.
+        pattern: [re!(r"(?i)\b(?:([0-9])([0-5]\d)|([01]\d|2[0-3])([0-5]\d))\b")],
         buckets: (BucketMask::HAS_DIGITS | BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let token = tokens.first()?;
             let h = regex_group_int_value(token, 1)
@@ -215,14 +226,36 @@ pub fn rule_hhmm_ish() -> Rule {
         // the first digit is 0 or 1 (i.e. 0000..1959), to avoid most years.
         pattern: [re!(r"(?i)\b(?:([0-9])([0-5]\d)|([01]\d)([0-5]\d))\s*ish\b")],
         buckets: BucketMask::HAS_DIGITS.bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+        prod: |tokens: &[Token]| -> Option<WithPrecision<TimeExpr>> {
             let token = tokens.first()?;
             let h = regex_group_int_value(token, 1)
                 .or_else(|| regex_group_int_value(token, 3))?;
             let m = regex_group_int_value(token, 2)
                 .or_else(|| regex_group_int_value(token, 4))?;
 
-            time_expr_with_minutes(h, m, false)
+            let expr = time_expr_with_minutes(h, m, false)?;
+            Some(WithPrecision(expr, Precision::Approximate))
+        }
+    }
+}
+
+/// Approximate bare hour (e.g., "five-ish", "tenish", "3ish"), the next one
+/// on the clock whether morning or evening
+pub fn rule_hour_ish() -> Rule {
+    rule! {
+        name: "<hour>-ish",
+        pattern: [re!(r"(?i)\b(\d{1,2}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s*-?\s*ish\b")],
+        required_phrases: ["ish"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<WithPrecision<TimeExpr>> {
+            let hour = match &tokens.first()?.kind {
+                TokenKind::RegexMatch(groups) => parse_integer_text(groups.get(1)?)? as u32,
+                _ => return None,
+            };
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            Some(WithPrecision(TimeExpr::AmbiguousTime { hour, minute: 0 }, Precision::Approximate))
         }
     }
 }
@@ -492,6 +525,7 @@ pub fn rule_pod_intersect_tod_latent() -> Rule {
             pred!(is_time_of_day_expr),
         ],
         buckets: (BucketMask::HAS_COLON).bits(),
+        group: RuleGroups::LATENT,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let pod = first(tokens)?;
             let part = part_of_day_from_text(pod.as_str())?;
@@ -510,19 +544,18 @@ pub fn rule_tod_on_date() -> Rule {
     rule! {
         name: "<time-of-day> on <date>",
         pattern: [
-            pred!(is_time_of_day_expr),
+            pred!(is_bare_time_of_day_expr),
             re!(r"(?i)\s+on\s+"),
             pred!(is_time_expr),
         ],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time = time_from_expr(tokens.first()?)?;
-            let date_expr = get_time_expr(tokens.get(2)?)?.clone();
-
             // Don't combine if the date already has a time-of-day constraint
-            if matches!(date_expr, TimeExpr::Intersect { constraint: Constraint::TimeOfDay(_), .. }) {
+            if has_time_of_day_expr(tokens.get(2)?) {
                 return None;
             }
+            let date_expr = get_time_expr(tokens.get(2)?)?.clone();
 
             Some(TimeExpr::Intersect {
                 expr: Box::new(date_expr),
@@ -536,25 +569,19 @@ pub fn rule_tod_date() -> Rule {
     rule! {
         name: "<time-of-day> <date>",
         pattern: [
-            pred!(is_time_of_day_expr),
-            re!(r"(?i)\s+"),
+            pred!(is_bare_time_of_day_expr),
             pred!(is_time_expr),
         ],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let time = time_from_expr(tokens.first()?)?;
-            let date_expr = get_time_expr(tokens.get(2)?)?.clone();
-
-            // Don't combine if the date already has a time-of-day constraint.
-            if matches!(
-                date_expr,
-                TimeExpr::Intersect {
-                    constraint: Constraint::TimeOfDay(_),
-                    ..
-                }
-            ) {
+            // Don't combine if the date already has a time-of-day constraint,
+            // precision-wrapped ("5pm") or not.
+            if has_time_of_day_expr(tokens.get(1)?) {
                 return None;
             }
+            let date_expr = get_time_expr(tokens.get(1)?)?.clone();
 
             Some(TimeExpr::Intersect {
                 expr: Box::new(date_expr),
@@ -571,17 +598,16 @@ pub fn rule_on_date_for_tod() -> Rule {
             re!(r"(?i)on\s+"),
             pred!(is_time_expr),
             re!(r"(?i)\s+(?:for|at)\s+"),
-            pred!(is_time_of_day_expr),
+            pred!(is_bare_time_of_day_expr),
         ],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let date_expr = get_time_expr(tokens.get(1)?)?.clone();
-            let time = time_from_expr(tokens.get(3)?)?;
-
             // Don't combine if the date already has a time-of-day constraint
-            if matches!(date_expr, TimeExpr::Intersect { constraint: Constraint::TimeOfDay(_), .. }) {
+            if has_time_of_day_expr(tokens.get(1)?) {
                 return None;
             }
+            let date_expr = get_time_expr(tokens.get(1)?)?.clone();
+            let time = time_from_expr(tokens.get(3)?)?;
 
             Some(TimeExpr::Intersect {
                 expr: Box::new(date_expr),
@@ -596,17 +622,17 @@ pub fn rule_absolute_date_tod() -> Rule {
         name: "<absolute-date> <time-of-day>",
         pattern: [
             pred!(is_time_expr),
-            re!(r"(?i)\s+"),
-            pred!(is_time_of_day_expr),
+            pred!(is_bare_time_of_day_expr),
         ],
         buckets: BucketMask::HAS_DIGITS.bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let date_expr = get_time_expr(tokens.first()?)?.clone();
             let TimeExpr::Absolute { hour: None, minute: None, .. } = date_expr else {
                 return None;
             };
 
-            let time = time_from_expr(tokens.get(2)?)?;
+            let time = time_from_expr(tokens.get(1)?)?;
 
             Some(TimeExpr::Intersect {
                 expr: Box::new(date_expr),
@@ -637,7 +663,7 @@ pub fn rule_one_hour_short_as_duration() -> Rule {
     rule! {
         name: "1h as duration (one hour)",
         pattern: [re!(r"(?i)\b1h\b")],
-        buckets: (BucketMask::HAS_COLON).bits(),
+        buckets: BucketMask::HAS_DIGITS.bits(),
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
             let expr = shift_by_grain(TimeExpr::Reference, 1, Grain::Hour);
             Some(expr)
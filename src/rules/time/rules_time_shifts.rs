@@ -8,7 +8,7 @@ use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
 use crate::time_expr::Constraint;
 use crate::time_expr::{Grain, TimeExpr};
-use crate::{Rule, Token, TokenKind};
+use crate::{Precision, Rule, Token, TokenKind, WithPrecision};
 
 /// "in a week" (7 days from now, rounded to day boundary)
 pub fn rule_in_a_week() -> Rule {
@@ -208,16 +208,17 @@ pub fn rule_in_hours_short() -> Rule {
 pub fn rule_in_quarter_half_hour() -> Rule {
     rule! {
         name: "in a quarter/half of an hour",
-        pattern: [re!(r"(?i)in\s+(?:about\s+)?(?:a\s+)?(quarter|half|three-quarters)\s+(?:of\s+)?(?:an\s+)?hour")],
+        pattern: [re!(r"(?i)in\s+(?:(about)\s+)?(?:a\s+)?(quarter|half|three-quarters)\s+(?:of\s+)?(?:an\s+)?hour")],
         required_phrases: ["in", "hour"],
         buckets: BucketMask::empty().bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+        prod: |tokens: &[Token]| -> Option<WithPrecision<TimeExpr>> {
             let groups = match &tokens.first()?.kind {
                 TokenKind::RegexMatch(groups) => groups,
                 _ => return None,
             };
 
-            let fraction = groups.get(1)?.to_lowercase();
+            // Unmatched groups are skipped, so the fraction is always the last group.
+            let fraction = groups.last()?.to_lowercase();
             let minutes = match fraction.as_str() {
                 "quarter" => 15,
                 "half" => 30,
@@ -226,7 +227,8 @@ pub fn rule_in_quarter_half_hour() -> Rule {
             };
 
             let expr = shift_by_grain(TimeExpr::Reference, minutes, Grain::Minute);
-            Some(expr)
+            let precision = if groups.get(1).is_some_and(|g| g == "about") { Precision::Approximate } else { Precision::Exact };
+            Some(WithPrecision(expr, precision))
         }
     }
 }
@@ -5,34 +5,106 @@ use crate::rules::numeral::helpers::first_match_lower;
 use crate::rules::time::helpers::shift::shift_by_grain;
 use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
-use crate::time_expr::{Constraint, Grain, PartOfDay, TimeExpr};
-use crate::{Rule, Token};
+use crate::time_expr::{Constraint, Grain, PartOfDay, SMALL_HOURS_END, SolarEvent, TimeExpr};
+use crate::{Rule, Token, WithPrecision};
+use chrono::Timelike;
 
-/// "noon", "midnight", "EOD", "end of day"
-pub fn rule_noon_midnight_eod() -> Rule {
+/// "noon", "midnight"
+pub fn rule_noon_midnight() -> Rule {
     rule! {
-        name: "noon|midnight|EOD|end of day",
-        pattern: [re!(r"(?i)(noon|midni(ght|te)|(the )?(eod|end of (the )?day))")],
-        optional_phrases: ["noon", "midnight", "midnite", "eod", "end"],
+        name: "noon|midnight",
+        pattern: [re!(r"(?i)(noon|midni(ght|te))")],
+        optional_phrases: ["noon", "midnight", "midnite"],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let matched = first_match_lower(tokens)?;
             let hour = if matched.trim() == "noon" { 12 } else { 0 };
             let time = chrono::NaiveTime::from_hms_opt(hour, 0, 0)?;
+            // Hour precision, like "12pm": "9am to noon" ends at 1pm.
+            Some(TimeExpr::Shift {
+                expr: Box::new(TimeExpr::Intersect {
+                    expr: Box::new(TimeExpr::Reference),
+                    constraint: Constraint::TimeOfDay(time),
+                }),
+                amount: 0,
+                grain: Grain::Hour,
+            })
+        }
+    }
+}
+
+/// "sunrise", "at dusk", "dawn": per the context's daylight settings
+pub fn rule_solar_event() -> Rule {
+    rule! {
+        name: "dawn|sunrise|sunset|dusk",
+        pattern: [re!(r"(?i)\b(dawn|daybreak|sunrise|sunup|sunset|sundown|dusk|nightfall)\b")],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let event = match first_match_lower(tokens)?.trim() {
+                "dawn" | "daybreak" => SolarEvent::Dawn,
+                "sunrise" | "sunup" => SolarEvent::Sunrise,
+                "sunset" | "sundown" => SolarEvent::Sunset,
+                _ => SolarEvent::Dusk,
+            };
             Some(TimeExpr::Intersect {
                 expr: Box::new(TimeExpr::Reference),
-                constraint: Constraint::TimeOfDay(time),
+                constraint: Constraint::Solar(event),
             })
         }
     }
 }
 
+/// "tomorrow at dawn", "Friday at sunset"
+pub fn rule_day_at_solar_event() -> Rule {
+    rule! {
+        name: "<day> at <solar event>",
+        pattern: [pred!(is_day_expr), words!("at"), pred!(is_solar_event_expr)],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let day = tokens.first().filter(|t| !is_solar_event_expr(t))?;
+            let day = get_time_expr(day)?.clone();
+            let event = solar_event_from_expr(tokens.get(2)?)?;
+            Some(TimeExpr::Intersect { expr: Box::new(day), constraint: Constraint::Solar(event) })
+        }
+    }
+}
+
+/// "sunrise on Friday"
+pub fn rule_solar_event_on_day() -> Rule {
+    rule! {
+        name: "<solar event> on <day>",
+        pattern: [pred!(is_solar_event_expr), words!("on"), pred!(is_day_expr)],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let event = solar_event_from_expr(tokens.first()?)?;
+            let day = tokens.get(2).filter(|t| !is_solar_event_expr(t))?;
+            let day = get_time_expr(day)?.clone();
+            Some(TimeExpr::Intersect { expr: Box::new(day), constraint: Constraint::Solar(event) })
+        }
+    }
+}
+
+/// "at dusk tomorrow", "sunset on 3/15" (the date takes the "on")
+pub fn rule_solar_event_day() -> Rule {
+    rule! {
+        name: "<solar event> <day>",
+        pattern: [pred!(is_solar_event_expr), pred!(is_day_expr)],
+        buckets: BucketMask::empty().bits(),
+        skip_separators: true,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let event = solar_event_from_expr(tokens.first()?)?;
+            let day = tokens.get(1).filter(|t| !is_solar_event_expr(t))?;
+            let day = get_time_expr(day)?.clone();
+            Some(TimeExpr::Intersect { expr: Box::new(day), constraint: Constraint::Solar(event) })
+        }
+    }
+}
+
 /// "mid-day", "midday"
 pub fn rule_mid_day() -> Rule {
     rule! {
         name: "Mid-day",
         pattern: [re!(r"(?i)(the )?mid(\s)?day")],
-        required_phrases: ["mid", "day"],
         buckets: BucketMask::empty().bits(),
         prod: |_tokens: &[Token]| -> Option<TimeExpr> {
             let time = chrono::NaiveTime::from_hms_opt(12, 0, 0)?;
@@ -150,12 +222,50 @@ pub fn rule_time_of_day_tonight() -> Rule {
     }
 }
 
+/// A time of day last night: in its evening on the day before the
+/// reference ("last night at 11"), or in the small hours after it ("2am
+/// last night"), which fall on the reference day.
+fn last_night_at(time: chrono::NaiveTime) -> Option<TimeExpr> {
+    let time = if time.hour() < SMALL_HOURS_END { time } else { adjust_time_for_part_of_day(time, PartOfDay::Night) };
+    let yesterday =
+        TimeExpr::StartOf { expr: Box::new(shift_by_grain(TimeExpr::Reference, -1, Grain::Day)), grain: Grain::Day };
+    let yesterday = TimeExpr::Intersect { expr: Box::new(yesterday), constraint: Constraint::TimeOfDay(time) };
+    Some(if time.hour() < SMALL_HOURS_END { shift_by_grain(yesterday, 1, Grain::Day) } else { yesterday })
+}
+
+/// "last night at 11", "last night 2am"
+pub fn rule_last_night_time_of_day() -> Rule {
+    rule! {
+        name: "last night <time-of-day>",
+        pattern: [
+            re!(r"(?i)last\s+night\s+(?:at\s+|around\s+)?"),
+            pred!(is_time_of_day_expr),
+        ],
+        required_phrases: ["last", "night"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> { last_night_at(time_from_expr(tokens.get(1)?)?) }
+    }
+}
+
+/// "2am last night", "at 11 last night"
+pub fn rule_time_of_day_last_night() -> Rule {
+    rule! {
+        name: "<time-of-day> last night",
+        pattern: [
+            pred!(is_time_of_day_expr),
+            re!(r"(?i)\s+last\s+night"),
+        ],
+        required_phrases: ["last", "night"],
+        buckets: BucketMask::empty().bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> { last_night_at(time_from_expr(tokens.first()?)?) }
+    }
+}
+
 /// "after lunch", "after work", "after school"
 pub fn rule_after_partofday() -> Rule {
     rule! {
         name: "after lunch/work/school",
         pattern: [re!(r"(?i)after[\s-]?(lunch|work|school)")],
-        required_phrases: ["after"],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let matched = first(tokens)?;
@@ -251,12 +361,13 @@ pub fn rule_pod_of_time() -> Rule {
 pub fn rule_tod_precision() -> Rule {
     rule! {
         name: "<time-of-day> sharp|exactly",
-        pattern: [pred!(is_time_of_day_expr), re!(r"(?i)(sharp|exactly|-?ish|approximately)")],
-        required_phrases: ["sharp", "exactly", "ish", "approximately"],
+        pattern: [pred!(is_time_of_day_expr), re!(r"(?i)\s*(sharp|exactly|-?ish|approximately)\b")],
+        optional_phrases: ["sharp", "exactly", "ish", "approximately"],
         buckets: BucketMask::HAS_DIGITS.bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+        prod: |tokens: &[Token]| -> Option<WithPrecision<TimeExpr>> {
             let expr = get_time_expr(tokens.first()?)?.clone();
-            Some(expr)
+            let precision = precision_from_group(tokens.get(1)?, 1)?;
+            Some(WithPrecision(expr, precision))
         }
     }
 }
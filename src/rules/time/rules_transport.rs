@@ -0,0 +1,96 @@
+//! Itinerary shorthand from airline and rail bookings: compact dates
+//! ("14MAR", "06JUN25"), dates with a four-digit clock time ("06JUN25 0830")
+//! and departure/arrival times ("dep 0735 arr 0950").
+//!
+//! These forms collide with ordinary text (codes, reference numbers), so the
+//! rules sit in their own bucket and are only compiled in when
+//! `Options::transport_shorthand` is enabled.
+
+use crate::engine::BucketMask;
+use crate::rules::time::helpers::shift::shift_by_grain;
+use crate::rules::time::helpers::*;
+use crate::rules::time::predicates::MONTH_NAME;
+use crate::time_expr::{Grain, TimeExpr};
+use crate::{Rule, Token, TokenKind};
+
+/// Date from the day (group 1), month abbreviation (2) and optional
+/// two-digit year (3) of a matched "06JUN25".
+fn compact_date(token: &Token) -> Option<TimeExpr> {
+    let TokenKind::RegexMatch(groups) = &token.kind else { return None };
+    let day: u32 = groups.get(1)?.parse().ok().filter(|day| (1..=31).contains(day))?;
+    let month = *MONTH_NAME.get(groups.get(2)?.as_str())?;
+    match groups.get(3) {
+        Some(year) => Some(TimeExpr::ShortYearDate { year: year.parse().ok()?, month, day }),
+        None => Some(TimeExpr::MonthDay { month, day }),
+    }
+}
+
+/// Hour and minute of a "0735" clock time in groups `idx` and `idx + 1`.
+fn clock_minutes(groups: &[String], idx: usize) -> Option<i64> {
+    let hours: i64 = groups.get(idx)?.parse().ok()?;
+    let minutes: i64 = groups.get(idx + 1)?.parse().ok()?;
+    Some(hours * 60 + minutes)
+}
+
+fn clock_time(groups: &[String], idx: usize) -> Option<TimeExpr> {
+    let minutes = clock_minutes(groups, idx)?;
+    time_expr_with_hms(minutes / 60, minutes % 60, 0)
+}
+
+/// "06JUN25" ("14MAR" alone is already read as a day and month)
+pub fn rule_compact_date() -> Rule {
+    rule! {
+        name: "<dd><MON><yy>",
+        pattern: [re!(r"(?i)\b(\d{1,2})(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(\d{2})\b")],
+        buckets: BucketMask::TRANSPORT.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            compact_date(tokens.first()?)
+        }
+    }
+}
+
+/// "06JUN25 0830", "14MAR 1915"
+pub fn rule_compact_date_clock_time() -> Rule {
+    rule! {
+        name: "<dd><MON><yy> <hhmm>",
+        pattern: [
+            re!(r"(?i)\b(\d{1,2})(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(\d{2})?\b"),
+            re!(r"\s+([01]\d|2[0-3])([0-5]\d)\b"),
+        ],
+        buckets: BucketMask::TRANSPORT.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(clock) = &tokens.get(1)?.kind else { return None };
+            intersect_time_exprs(&compact_date(tokens.first()?)?, &clock_time(clock, 1)?)
+        }
+    }
+}
+
+/// "dep 0735", "arr 0950"
+pub fn rule_departure_or_arrival() -> Rule {
+    rule! {
+        name: "dep|arr <hhmm>",
+        pattern: [re!(r"(?i)\b(?:dep|arr)\.?\s+([01]\d|2[0-3])([0-5]\d)\b")],
+        buckets: BucketMask::TRANSPORT.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            clock_time(groups, 1)
+        }
+    }
+}
+
+/// "dep 0735 arr 0950"; an arrival before the departure is on the next day.
+pub fn rule_departure_arrival() -> Rule {
+    rule! {
+        name: "dep <hhmm> arr <hhmm>",
+        pattern: [re!(r"(?i)\bdep\.?\s+([01]\d|2[0-3])([0-5]\d)\s+arr\.?\s+([01]\d|2[0-3])([0-5]\d)\b")],
+        buckets: BucketMask::TRANSPORT.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let TokenKind::RegexMatch(groups) = &tokens.first()?.kind else { return None };
+            let departure = clock_time(groups, 1)?;
+            let travel = (clock_minutes(groups, 3)? - clock_minutes(groups, 1)?).rem_euclid(24 * 60);
+            // Interval ends are exclusive at the minute, like "from 7:35 to 9:50".
+            let end = shift_by_grain(departure.clone(), travel as i32 + 1, Grain::Minute);
+            Some(TimeExpr::IntervalBetween { start: Box::new(departure), end: Box::new(end) })
+        }
+    }
+}
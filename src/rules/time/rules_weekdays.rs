@@ -66,6 +66,9 @@ pub fn rule_weekday() -> Rule {
             re!(r"(?i)\b(monday|mon|tuesday|tues?|wednesday|wed|thursday|thu|thurs|friday|fri|saturday|sat|sunday|sun)\b")
         ],
         buckets: BucketMask::WEEKDAYISH.bits(),
+        // "the sun", "she sat", "they wed": the abbreviations as words.
+        not_preceded_by: r"(?i)\b(?:the|hot|morning|evening|setting|rising)\s+sun|\b(?:i|we|you|they|he|she|it)\s+(?:sat|wed)",
+        not_followed_by: r"(?i)sun\s+(?:shines|shone|rises|rose|sets|cream|screen|light|glasses|hat|burn)\b|sat\s+(?:down|up|back|there|around|next\s+to)\b|wed\s+(?:her|him|them)\b|mon\s+(?:ami|amie|cher|dieu)\b",
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday = weekday_from_name(tokens.first()?)?;
             Some(TimeExpr::Intersect {
@@ -143,15 +146,35 @@ pub fn rule_time_poss_weekday() -> Rule {
     }
 }
 
+/// "<week> <weekday>" (next week Tuesday, last week, Friday)
+pub fn rule_week_weekday() -> Rule {
+    rule! {
+        name: "<week> <weekday>",
+        pattern: [pred!(is_week_expr), pred!(is_weekday_name)],
+        buckets: BucketMask::WEEKDAYISH.bits(),
+        skip_separators: true,
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let week_expr = get_time_expr(tokens.first()?)?.clone();
+            let weekday = weekday_from_name(tokens.get(1)?)?;
+
+            Some(TimeExpr::Intersect {
+                expr: Box::new(week_expr),
+                constraint: Constraint::DayOfWeek(weekday),
+            })
+        }
+    }
+}
+
 /// "<weekday> <day-of-month>"
 pub fn rule_weekday_day_of_month() -> Rule {
     rule! {
         name: "<weekday> <day-of-month>",
-        pattern: [pred!(is_weekday_name), re!(r"\s+"), pred!(is_day_of_month_numeral)],
+        pattern: [pred!(is_weekday_name), pred!(is_day_of_month_numeral)],
         buckets: (BucketMask::WEEKDAYISH | BucketMask::HAS_DIGITS).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday = weekday_from_name(tokens.first()?)?;
-            let day = day_of_month_from_expr(tokens.get(2)?)?;
+            let day = day_of_month_from_expr(tokens.get(1)?)?;
 
             let day_expr = TimeExpr::Intersect {
                 expr: Box::new(TimeExpr::Reference),
@@ -339,6 +362,54 @@ pub fn rule_nth_weekday_of_relative_month() -> Rule {
     }
 }
 
+/// "the second Tuesday of next month", "first Friday of the quarter", "last Friday of 2014"
+pub fn rule_nth_weekday_of_period() -> Rule {
+    rule! {
+        name: "nth|last <weekday> of <period>",
+        pattern: [
+            re!(r"(?i)(?:the\s+)?(first|second|third|fourth|fifth|1st|2nd|3rd|4th|5th|last)\s+"),
+            pred!(is_weekday_name),
+            re!(r"(?i)\s+(?:of|in)\s+"),
+            pred!(is_time_expr)
+        ],
+        buckets: BucketMask::WEEKDAYISH.bits(),
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            // Named months have dedicated rules.
+            if is_month_expr(tokens.get(3)?) {
+                return None;
+            }
+            let of = get_time_expr(tokens.get(3)?)?;
+            if !matches!(value_grain(of), Grain::Month | Grain::Quarter | Grain::Year) {
+                return None;
+            }
+            Some(TimeExpr::NthWeekdayOf {
+                n: nth_from_word(tokens.first()?)?,
+                weekday: weekday_from_name(tokens.get(1)?)?,
+                of: Box::new(of.clone()),
+            })
+        }
+    }
+}
+
+/// "last weekday of the year", "first weekday of next month": the day
+/// counts Monday to Friday only.
+pub fn rule_nth_monday_to_friday_of_period() -> Rule {
+    rule! {
+        name: "nth|last weekday of <period>",
+        pattern: [
+            re!(r"(?i)(?:the\s+)?(first|second|third|fourth|fifth|1st|2nd|3rd|4th|5th|last)\s+weekday\s+(?:of|in)\s+"),
+            pred!(is_time_expr)
+        ],
+        prod: |tokens: &[Token]| -> Option<TimeExpr> {
+            let of = get_time_expr(tokens.get(1)?)?;
+            if !matches!(value_grain(of), Grain::Month | Grain::Quarter | Grain::Year) {
+                return None;
+            }
+            Some(TimeExpr::NthMondayToFridayOf { n: nth_from_word(tokens.first()?)?, of: Box::new(of.clone()) })
+        }
+    }
+}
+
 pub fn rule_nth_weekday_after_time() -> Rule {
     rule! {
         name: "nth <weekday> after <time>",
@@ -436,14 +507,14 @@ pub fn rule_weekday_comma_month_day() -> Rule {
             pred!(is_weekday_name),
             re!(r",\s*"),
             pred!(is_month_expr),
-            re!(r"\s+"),
             pred!(is_day_of_month_numeral)
         ],
         buckets: (BucketMask::WEEKDAYISH | BucketMask::MONTHISH | BucketMask::HAS_DIGITS).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday = weekday_from_name(tokens.first()?)?;
             let month = month_from_expr(tokens.get(2)?)?;
-            let day = day_of_month_from_expr(tokens.get(4)?)?;
+            let day = day_of_month_from_expr(tokens.get(3)?)?;
 
             let month_day_expr = TimeExpr::MonthDay { month, day };
 
@@ -461,16 +532,15 @@ pub fn rule_weekday_month_day() -> Rule {
         name: "<weekday> <month> <day>",
         pattern: [
             pred!(is_weekday_name),
-            re!(r"\s+"),
             pred!(is_month_expr),
-            re!(r"\s+"),
             pred!(is_day_of_month_numeral)
         ],
         buckets: (BucketMask::WEEKDAYISH | BucketMask::MONTHISH | BucketMask::HAS_DIGITS).bits(),
+        skip_separators: true,
         prod: |tokens: &[Token]| -> Option<TimeExpr> {
             let weekday = weekday_from_name(tokens.first()?)?;
-            let month = month_from_expr(tokens.get(2)?)?;
-            let day = day_of_month_from_expr(tokens.get(4)?)?;
+            let month = month_from_expr(tokens.get(1)?)?;
+            let day = day_of_month_from_expr(tokens.get(2)?)?;
 
             let month_day_expr = TimeExpr::MonthDay { month, day };
 
@@ -1,58 +1,18 @@
 //! Year references and time formatting rules (quarter to/past, half past, nth week)
 
 use crate::engine::BucketMask;
-use crate::rules::numeral::predicates::{is_integer, number_between};
 use crate::rules::time::helpers::parse::time_expr_with_minutes;
-use crate::rules::time::helpers::shift::shift_by_grain;
 use crate::rules::time::helpers::*;
 use crate::rules::time::predicates::*;
-use crate::time_expr::{Grain, TimeExpr};
+use crate::time_expr::TimeExpr;
 use crate::{Rule, Token, TokenKind};
 // Already imported above
 
-fn is_hour_numeral(token: &Token) -> bool {
-    is_integer(token) && number_between::<1, 13>(token)
-}
-
-/// "this year", "next year", "last year"
-pub fn rule_year_reference() -> Rule {
-    rule! {
-        name: "year reference",
-        pattern: [re!(r"(?i)(this|current|last|previous|past|next)\s+(year|yr)\b")],
-        required_phrases: ["this", "current", "last", "previous", "past", "next", "year", "yr"],
-        buckets: BucketMask::empty().bits(),
-        prod: |tokens: &[Token]| -> Option<TimeExpr> {
-            let qualifier = match &tokens.first()?.kind {
-                TokenKind::RegexMatch(groups) => groups.get(1)?.trim(),
-                _ => return None,
-            };
-
-            let amount = match qualifier {
-                "this" | "current" => 0,
-                "next" => 1,
-                "last" | "previous" | "past" => -1,
-                _ => return None,
-            };
-
-            let base = if amount == 0 {
-                TimeExpr::Reference
-            } else {
-                shift_by_grain(TimeExpr::Reference, amount, Grain::Year)
-            };
-
-            Some(TimeExpr::StartOf {
-                expr: Box::new(base),
-                grain: Grain::Year,
-            })
-        }
-    }
-}
-
 /// "quarter to|till|before <hour>"
 pub fn rule_quarter_to_hod() -> Rule {
     rule! {
         name: "quarter to|till|before <hour-of-day>",
-        pattern: [re!(r"(?i)(?:a|one)?\s*quarter\s+(?:to|till|before|of)\s+"), pred!(is_time_of_day_expr)],
+        pattern: [re!(r"(?i)(?:a|one)?\s*quarter\s+(?:to|till|before|of)\s+"), pred!(is_clock_hour)],
         optional_phrases: ["quarter", "to", "till", "before", "of"],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> { time_expr_minutes_offset(tokens.get(1)?, -15) }
@@ -63,7 +23,7 @@ pub fn rule_quarter_to_hod() -> Rule {
 pub fn rule_quarter_after_hod() -> Rule {
     rule! {
         name: "quarter after|past <hour-of-day>",
-        pattern: [re!(r"(?i)(?:for\s+)?(?:a|one)?\s*quarter\s+(?:after|past)\s+"), pred!(is_time_of_day_expr)],
+        pattern: [re!(r"(?i)(?:for\s+)?(?:a|one)?\s*quarter\s+(?:after|past)\s+"), pred!(is_clock_hour)],
         optional_phrases: ["quarter", "after", "past"],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> { time_expr_minutes_offset(tokens.get(1)?, 15) }
@@ -74,7 +34,7 @@ pub fn rule_quarter_after_hod() -> Rule {
 pub fn rule_half_after_hod() -> Rule {
     rule! {
         name: "half after|past <hour-of-day>",
-        pattern: [re!(r"(?i)half (after|past)\s+"), pred!(is_time_of_day_expr)],
+        pattern: [re!(r"(?i)half (after|past)\s+"), pred!(is_clock_hour)],
         optional_phrases: ["half", "after", "past"],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> { time_expr_minutes_offset(tokens.get(1)?, 30) }
@@ -85,7 +45,7 @@ pub fn rule_half_after_hod() -> Rule {
 pub fn rule_half_to_hod() -> Rule {
     rule! {
         name: "half to|till|before <hour-of-day>",
-        pattern: [re!(r"(?i)half (to|till|before|of)\s+"), pred!(is_time_of_day_expr)],
+        pattern: [re!(r"(?i)half (to|till|before|of)\s+"), pred!(is_clock_hour)],
         optional_phrases: ["half", "to", "till", "before", "of"],
         buckets: BucketMask::empty().bits(),
         prod: |tokens: &[Token]| -> Option<TimeExpr> { time_expr_minutes_offset(tokens.get(1)?, -30) }
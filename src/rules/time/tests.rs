@@ -1,12 +1,13 @@
 use crate::rules::time;
 use crate::{Context, Dimension, Options};
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono_tz::Tz;
 
 fn reference_context() -> Context {
     let date = NaiveDate::from_ymd_opt(2013, 2, 12).unwrap();
     let time = NaiveTime::from_hms_opt(4, 30, 0).unwrap();
 
-    Context { reference_time: NaiveDateTime::new(date, time) }
+    Context { reference_time: Some(NaiveDateTime::new(date, time)), ..Default::default() }
 }
 
 #[test]
@@ -24,6 +25,13 @@ fn time_examples_matching() {
         ("1974-10-31 00:00:00", "31/Oct/1974"),
         ("1974-10-31 00:00:00", "31-Oct-74"),
         ("1974-10-31 00:00:00", "31st Oct 1974"),
+        ("2014-01-05 00:00:00", "Jan. 5"),
+        ("2014-09-12 00:00:00", "Sept. 12, 2014"),
+        ("2013-09-12 00:00:00", "Sept 12"),
+        ("2013-09-12 00:00:00", "Sept.12"),
+        ("2014-01-05 00:00:00", "5 Jan. 2014"),
+        ("2013-12-25 17:00:00", "Dec. 25 at 5pm"),
+        ("2014-01-03 00:00:00/2014-01-10 00:00:00", "between Jan. 3 and Jan. 9"),
         ("2013-04-25 16:00:00", "4/25 at 4:00pm"),
         ("2013-04-25 16:00:00", "4/25 at 16h00"),
         ("2013-04-25 16:00:00", "4/25 at 16h"),
@@ -34,6 +42,10 @@ fn time_examples_matching() {
         ("2013-11-28 00:00:00", "next thanksgiving day"),
         ("2013-11-28 00:00:00", "thanksgiving in 9 months"),
         ("2013-11-28 00:00:00", "thanksgiving 9 months from now"),
+        ("2014-01-20 00:00:00", "MLK day"),
+        ("2014-01-20 00:00:00", "next Martin Luther King day"),
+        ("2013-01-21 00:00:00", "MLK day 2013"),
+        ("2012-01-16 00:00:00", "Civil Rights Day of last year"),
         // ("2014-11-27 00:00:00", "thanksgiving of next year"),
         // ("2014-11-27 00:00:00", "thanksgiving in a year"),
         // ("2014-11-27 00:00:00", "thanksgiving 2014"),
@@ -51,7 +63,7 @@ fn time_examples_matching() {
         ("2013-02-12 19:00:00", "7p"),
         ("2013-02-12 10:30:00", "ten thirty"),
         ("2013-02-12 10:30:00", "ten-thirty"),
-        ("1974-01-01 00:00:00", "1974"),
+        ("1974-01-01 00:00:00/1975-01-01 00:00:00", "1974"),
         ("2013-05-01 00:00:00", "May"),
         ("2013-02-12 00:00:00/2013-02-12 12:00:00", "morning"),
         ("2013-02-12 12:00:00/2013-02-12 19:00:00", "afternoon"),
@@ -76,7 +88,7 @@ fn time_examples_matching() {
         ("2013-02-12 00:00:00", "today"),
         ("2013-02-12 00:00:00", "at this time"),
         ("2013-02-01 00:00:00", "2/2013"),
-        ("2014-01-01 00:00:00", "in 2014"),
+        ("2014-01-01 00:00:00/2015-01-01 00:00:00", "in 2014"),
         ("2013-02-11 00:00:00", "yesterday"),
         ("2013-02-13 00:00:00", "tomorrow"),
         ("2013-02-13 00:00:00", "tomorrows"),
@@ -115,6 +127,27 @@ fn time_examples_matching() {
         ("2015-03-03 00:00:00", "march third 2015"),
         ("2015-03-03 00:00:00", "3/3/2015"),
         ("2015-03-03 00:00:00", "3/3/15"),
+        ("1999-03-05 00:00:00", "3/5/99"),
+        ("2025-12-31 00:00:00", "12-31-25"),
+        ("2025-12-31 00:00:00", "31.12.25"),
+        ("2025-12-31 00:00:00", "31/12/2025"),
+        ("2013-12-25 00:00:00", "25.12.2013"),
+        ("2013-02-12 09:30:00", "2013-02-12T09:30:00"),
+        ("2013-02-12 09:30:15", "2013-02-12 09:30:15"),
+        ("2025-06-14 07:30:00", "2025-06-14T09:30:00Z"),
+        ("2025-06-14 05:30:00", "2025-06-14T09:30+02:00"),
+        ("2025-06-14 13:00:00", "2025-06-14T09:30:00.250-05:30"),
+        ("2025-06-02 00:00:00/2025-06-09 00:00:00", "2025-W23"),
+        ("2025-06-03 00:00:00", "2025-W23-2"),
+        ("2013-02-15 08:30:00", "P3DT4H"),
+        ("2013-02-12 05:00:00", "in PT30M"),
+        ("2014-04-12 04:30:00", "P1Y2M"),
+        ("2013-06-14 09:30:01", "Jun 14 09:30:01"),
+        ("2013-06-04 09:30:01", "Jun  4 09:30:01"),
+        ("2025-06-14 05:30:01", "[14/Jun/2025:09:30:01 +0200]"),
+        ("2025-06-14 05:30:01", "Sat, 14 Jun 2025 09:30:01 +0200"),
+        ("2025-06-14 07:30:01", "Sat, 14 Jun 2025 09:30:01 GMT"),
+        ("2025-06-14 11:30:00", "14 Jun 2025 09:30 EDT"),
         ("2015-03-03 00:00:00", "2015-3-3"),
         ("2015-03-03 00:00:00", "2015-03-03"),
         ("2013-02-15 00:00:00", "on the 15th"),
@@ -139,6 +172,12 @@ fn time_examples_matching() {
         ("2013-02-19 00:00:00", "around next tuesday"),
         ("2013-02-22 00:00:00", "friday after next"),
         ("2013-03-01 00:00:00", "next March"),
+        ("2013-06-01 00:00:00", "next June"),
+        ("2014-02-01 00:00:00", "next February"),
+        ("2013-06-01 00:00:00", "this June"),
+        ("2013-01-01 00:00:00", "this January"),
+        ("2012-06-01 00:00:00", "last June"),
+        ("2013-01-01 00:00:00", "last January"),
         ("2014-03-01 00:00:00", "March after next"),
         ("2013-02-10 00:00:00", "Sunday, Feb 10"),
         ("2013-02-13 00:00:00", "Wed, Feb13"),
@@ -158,6 +197,13 @@ fn time_examples_matching() {
         ("2013-02-18 00:00:00/2013-02-25 00:00:00", "around next week"),
         ("2013-02-18 00:00:00/2013-02-25 00:00:00", "upcoming week"),
         ("2013-02-18 00:00:00/2013-02-25 00:00:00", "coming week"),
+        ("2013-02-25 00:00:00/2013-03-04 00:00:00", "the week after next"),
+        ("2013-01-28 00:00:00/2013-02-04 00:00:00", "the week before last"),
+        ("2013-04-01 00:00:00", "the month after next"),
+        ("2011-01-01 00:00:00", "the year before last"),
+        ("2013-02-22 00:00:00", "next week Friday"),
+        ("2013-02-22 00:00:00", "Friday next week"),
+        ("2013-02-26 00:00:00", "Tuesday the week after next"),
         ("2013-01-01 00:00:00", "last month"),
         ("2013-03-01 00:00:00", "next month"),
         ("2013-03-20 00:00:00", "20 of next month"),
@@ -187,9 +233,43 @@ fn time_examples_matching() {
         ("2013-01-01 00:00:00", "this yr"),
         ("2014-01-01 00:00:00", "next year"),
         ("2014-01-01 00:00:00", "next yr"),
-        ("2014-01-01 00:00:00", "in 2014 AD"),
-        ("-2014-01-01 00:00:00", "in 2014 BC"),
-        ("0014-01-01 00:00:00", "in 14 a.d."),
+        ("2014-01-01 00:00:00/2015-01-01 00:00:00", "in 2014 AD"),
+        ("-2014-01-01 00:00:00/-2013-01-01 00:00:00", "in 2014 BC"),
+        ("-0044-01-01 00:00:00/-0043-01-01 00:00:00", "44 BC"),
+        ("1492-01-01 00:00:00/1493-01-01 00:00:00", "in 1492"),
+        ("0800-01-01 00:00:00/0801-01-01 00:00:00", "800 AD"),
+        ("1970-01-01 00:00:00/1980-01-01 00:00:00", "the 1970s"),
+        ("1800-01-01 00:00:00/1810-01-01 00:00:00", "1800s"),
+        ("2000-01-01 00:00:00/2010-01-01 00:00:00", "the 2000s"),
+        ("1900-01-01 00:00:00/2000-01-01 00:00:00", "the 20th century"),
+        ("2000-01-01 00:00:00/2100-01-01 00:00:00", "21st century"),
+        ("1987-06-14 00:00:00", "born on June 14th 1987"),
+        ("1987-06-14 00:00:00", "DOB 06/14/1987"),
+        ("2012-06-14 00:00:00", "born on June 14th"),
+        ("2012-05-03 00:00:00", "my anniversary is the 3rd of May"),
+        ("2013-01-20 00:00:00", "our anniversary was on January 20"),
+        ("2013-02-15 00:00:00", "in 3 business days"),
+        ("2013-02-19 00:00:00", "in five working days"),
+        ("2013-02-13 00:00:00", "next business day"),
+        ("2013-02-11 00:00:00", "previous workday"),
+        ("2013-02-07 00:00:00", "3 business days ago"),
+        ("2013-02-13 00:00:00", "2 working days before Friday"),
+        ("2013-02-12 17:00:00", "EOD"),
+        ("2013-02-12 17:00:00", "close of business"),
+        ("2013-02-12 17:00:00", "end of the day"),
+        ("2013-02-15 17:00:00", "EOD Friday"),
+        ("2013-02-12 04:30:00/2013-02-15 17:00:00", "by EOD Friday"),
+        ("2013-02-13 17:00:00", "tomorrow COB"),
+        ("2013-02-18 09:00:00", "start of business Monday"),
+        ("2013-02-18 09:00:00", "Monday at start of business"),
+        ("2025-01-01 00:00:00/2026-01-01 00:00:00", "FY2025"),
+        ("2024-01-01 00:00:00/2025-01-01 00:00:00", "fiscal year 2024"),
+        ("2025-04-01 00:00:00/2025-07-01 00:00:00", "Q2 FY25"),
+        ("2025-10-01 00:00:00/2026-01-01 00:00:00", "FY2025Q4"),
+        ("2024-09-01 00:00:00/2025-09-01 00:00:00", "the 2024-25 school year"),
+        ("2024-09-01 00:00:00/2025-09-01 00:00:00", "academic year 2024/2025"),
+        ("1970-01-01 00:00:00/1976-01-01 00:00:00", "1970 - 1975"),
+        ("0014-01-01 00:00:00/0015-01-01 00:00:00", "in 14 a.d."),
         ("2013-02-10 00:00:00", "last sunday"),
         ("2013-02-10 00:00:00", "sunday from last week"),
         ("2013-02-10 00:00:00", "last week's sunday"),
@@ -219,6 +299,10 @@ fn time_examples_matching() {
         ("2013-05-27 00:00:00", "the 5th last day of May"),
         ("2013-10-07 00:00:00", "the week of october 6th"),
         ("2013-10-07 00:00:00", "the week of october 7th"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "the week of June 14"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "week commencing 14 June"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "w/c 14/6"),
+        ("2013-12-25 00:00:00", "25/12"),
         ("2015-10-31 00:00:00", "last day of october 2015"),
         ("2015-10-31 00:00:00", "last day in october 2015"),
         ("2014-09-22 00:00:00", "last week of september 2014"),
@@ -252,6 +336,8 @@ fn time_examples_matching() {
         // ("2013-02-12 15:00:00", "3ish pm"),
         ("2013-02-12 15:00:00", "3pm approximately"),
         ("2013-02-12 15:00:00", "at about 3pm"),
+        ("2013-02-12 15:00:00", "3pm sharp"),
+        ("2013-02-12 15:00:00", "3pm-ish"),
         ("2013-02-12 15:00:00", "at 3p"),
         ("2013-02-12 15:00:00", "at 3p."),
         ("2013-02-12 15:00:00", "15h00"),
@@ -318,6 +404,23 @@ fn time_examples_matching() {
         ("2013-02-12 14:15:00", "for a quarter past 2pm"),
         ("2013-02-12 20:15:00", "a quarter past 8pm"),
         ("2013-02-12 20:15:00", "for a quarter past 8pm"),
+        ("2013-02-12 05:00:00", "five o'clock"),
+        ("2013-02-12 19:00:00", "seven o'clock in the evening"),
+        ("2013-02-12 17:30:00", "half past five"),
+        ("2013-02-12 12:30:00", "half past twelve"),
+        ("2013-02-12 12:45:00", "quarter to one"),
+        ("2013-02-12 20:45:00", "quarter to nine in the evening"),
+        ("2013-02-12 09:15:00", "quarter past nine in the morning"),
+        ("2013-02-12 18:20:00", "twenty past six"),
+        ("2013-02-12 18:50:00", "ten to seven in the evening"),
+        ("2013-02-12 05:00:00", "five-ish"),
+        ("2013-02-12 10:00:00", "tenish"),
+        ("2013-02-12 14:30:00", "1430 hours"),
+        ("2013-02-12 06:00:00", "0600 hrs"),
+        ("2013-02-13 06:00:00", "0600 hours tomorrow"),
+        ("2013-02-12 14:00:00", "at fourteen hundred"),
+        ("2013-02-12 06:00:00", "oh six hundred hours"),
+        ("2013-02-12 16:30:00", "sixteen thirty hours"),
         ("2013-02-12 20:00:00", "8 tonight"),
         ("2013-02-12 20:00:00", "tonight at 8 o'clock"),
         ("2013-02-12 20:00:00", "eight tonight"),
@@ -419,6 +522,10 @@ fn time_examples_matching() {
         ("2013-06-21 00:00:00/2013-09-24 00:00:00", "this Summer"),
         ("2013-06-21 00:00:00/2013-09-24 00:00:00", "current summer"),
         ("2012-12-21 00:00:00/2013-03-21 00:00:00", "this winter"),
+        ("2013-06-21 00:00:00/2013-07-22 00:00:00", "early summer"),
+        ("2013-01-20 00:00:00/2013-02-19 00:00:00", "mid-winter"),
+        ("2013-01-20 00:00:00/2013-02-19 00:00:00", "midwinter"),
+        ("2013-11-21 00:00:00/2013-12-21 00:00:00", "late fall"),
         ("2012-12-21 00:00:00/2013-03-19 00:00:00", "this season"),
         ("2012-12-21 00:00:00/2013-03-19 00:00:00", "current seasons"),
         ("2012-09-23 00:00:00/2012-12-20 00:00:00", "last season"),
@@ -428,6 +535,15 @@ fn time_examples_matching() {
         ("2013-02-11 18:00:00/2013-02-12 00:00:00", "last night"),
         ("2013-02-11 18:00:00/2013-02-12 00:00:00", "yesterday evening"),
         ("2013-02-11 21:00:00/2013-02-12 00:00:00", "late last night"),
+        ("2013-02-11 23:00:00", "last night at 11pm"),
+        ("2013-02-11 23:00:00", "last night at 11"),
+        ("2013-02-12 02:00:00", "last night at 2am"),
+        ("2013-02-12 02:00:00", "2am last night"),
+        ("2013-02-11 20:00:00", "yesterday evening at 8"),
+        ("2013-02-13 18:00:00/2013-02-14 00:00:00", "tomorrow night"),
+        ("2013-02-12 18:00:00/2013-02-13 04:00:00", "overnight"),
+        ("2013-02-15 18:00:00/2013-02-16 04:00:00", "friday overnight"),
+        ("2013-02-13 18:00:00/2013-02-14 04:00:00", "tomorrow overnight"),
         ("2013-12-25 00:00:00", "xmas"),
         ("2013-12-25 00:00:00", "christmas"),
         ("2013-12-25 00:00:00", "christmas day"),
@@ -550,6 +666,10 @@ fn time_examples_matching() {
         ("2011-01-01 00:00:00/2013-01-01 00:00:00", "last two years"),
         ("2014-01-01 00:00:00/2017-01-01 00:00:00", "next 3 years"),
         ("2014-01-01 00:00:00/2017-01-01 00:00:00", "next three years"),
+        ("2013-02-12 04:30:00/2013-03-05 04:30:00", "for the next 3 weeks"),
+        ("2013-02-12 04:30:00/2013-02-14 04:30:00", "over the coming 48 hours"),
+        ("2013-01-13 04:30:00/2013-02-12 04:30:00", "in the last 30 days"),
+        ("2012-11-12 04:30:00/2013-02-12 04:30:00", "during the past three months"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "July 13-15"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "July 13 to 15"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "July 13 thru 15"),
@@ -559,6 +679,12 @@ fn time_examples_matching() {
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "from 13 to 15 July"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "from 13th to 15th July"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "from the 13 to 15 July"),
+        ("2013-07-13 00:00:00/2013-07-16 00:00:00", "July 13–15"),
+        ("2013-06-14 00:00:00/2013-06-17 00:00:00", "6/14-6/16"),
+        ("2013-06-14 00:00:00/2013-06-17 00:00:00", "6/14–6/16"),
+        ("2025-06-14 00:00:00/2025-06-17 00:00:00", "2025-06-14 – 2025-06-16"),
+        ("2013-03-14 00:00:00/2013-03-17 00:00:00", "14.-16.3."),
+        ("2025-03-14 00:00:00/2025-03-17 00:00:00", "14.3.–16.3.2025"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "from the 13th to 15th July"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "from the 13th to the 15th July"),
         ("2013-07-13 00:00:00/2013-07-16 00:00:00", "from the 13 to the 15 July"),
@@ -612,10 +738,23 @@ fn time_examples_matching() {
         ("2013-02-14 09:00:00/2013-02-14 12:00:00", "Thursday from 9a to 11a"),
         ("2013-02-14 09:00:00/2013-02-14 12:00:00", "this Thu 9-11am"),
         ("2013-02-12 11:30:00/2013-02-12 13:31:00", "11:30-1:30"),
+        ("2013-02-15 23:00:00/2013-02-16 02:00:00", "Friday 11pm to 1am"),
+        ("2013-02-15 23:00:00/2013-02-16 02:00:00", "Friday 11pm - 1am"),
+        ("2013-02-12 22:00:00/2013-02-13 03:00:00", "from 10pm to 2am"),
+        ("2013-02-12 23:00:00/2013-02-13 02:00:00", "11pm-1am"),
+        ("2013-02-13 09:00:00/2013-02-13 18:00:00", "tomorrow 9am to 5pm"),
+        ("2013-02-12 09:00:00/2013-02-12 13:00:00", "9am to noon"),
+        ("2013-02-13 15:00:00-", "before 3pm tomorrow"),
+        ("2013-02-13 15:00:00-", "tomorrow before 3pm"),
+        ("2013-02-15 09:00:00+", "after 9 on Friday"),
+        ("2013-02-15 09:00:00+", "on Friday after 9"),
+        ("2013-02-18 17:00:00+", "after 5pm on Monday"),
+        ("2013-02-14 12:00:00-", "before noon on Thursday"),
+        ("2013-02-14 18:30:00+", "Thursday after 6:30pm"),
         ("2013-09-21 13:30:00", "1:30 PM on Sat, Sep 21"),
         ("2013-02-12 04:30:00/2013-02-26 00:00:00", "Within 2 weeks"),
         ("2013-02-12 04:30:00/2013-02-12 14:00:00", "by 2:00pm"),
-        ("2013-02-12 04:30:00/2013-02-13 00:00:00", "by EOD"),
+        ("2013-02-12 04:30:00/2013-02-12 17:00:00", "by EOD"),
         ("2013-02-12 04:30:00/2013-03-01 00:00:00", "by EOM"),
         ("2013-02-12 04:30:00/2013-03-01 00:00:00", "by the EOM"),
         ("2013-02-12 04:30:00/2013-03-01 00:00:00", "by end of the month"),
@@ -634,6 +773,7 @@ fn time_examples_matching() {
         ("2013-02-01 00:00:00/2013-02-11 00:00:00", "at the beginning of month"),
         ("2013-02-12 04:30:00/2013-04-01 00:00:00", "by the end of next month"),
         ("2013-02-12 13:00:00", "4pm CET"),
+        ("2013-02-12 07:30:00", "3pm IST"),
         ("2013-02-14 06:00:00", "Thursday 8:00 GMT"),
         ("2013-02-14 06:00:00", "Thursday 8:00 gmt"),
         ("2013-02-14 06:00:00", "Thursday 8h00 GMT"),
@@ -653,6 +793,13 @@ fn time_examples_matching() {
         ("2013-02-14 14:00:00", "Thu at 8 am PST"),
         ("2013-02-14 14:00:00", "Thu at 8 am pst"),
         ("2013-02-14 14:00:00", "Thursday at 9:30pm ist"),
+        ("2013-02-12 12:00:00", "9am EST"),
+        ("2013-02-12 12:00:00", "9am America/New_York"),
+        ("2013-02-12 12:00:00", "9am ET"),
+        ("2013-02-12 12:00:00", "9am ET / 6am PT"),
+        ("2013-02-12 12:00:00", "15:00 CET (9am ET)"),
+        ("2013-02-12 12:00:00", "3pm Europe/Stockholm"),
+        ("2013-02-12 06:30:00", "2pm Asia/Kolkata"),
         ("2013-02-12 14:00:00", "today at 2pm"),
         ("2013-02-12 14:00:00", "at 2pm"),
         ("2013-02-12 14:00:00", "this afternoon at 2"),
@@ -660,8 +807,11 @@ fn time_examples_matching() {
         ("2013-02-12 14:00:00", "tonight at 2"),
         ("2013-02-13 15:00:00", "3pm tomorrow"),
         ("2013-02-12 05:30:00", "today in one hour"),
-        ("2013-02-12 04:30:00+", "ASAP"),
-        ("2013-02-12 04:30:00+", "as soon as possible"),
+        ("2013-02-12 04:30:00", "ASAP"),
+        ("2013-02-12 04:30:00", "as soon as possible"),
+        ("2013-02-12 04:30:00", "asap"),
+        ("2013-02-12 04:30:00", "immediately"),
+        ("2013-02-12 04:30:00+", "from now on"),
         ("2013-02-12 14:00:00-", "until 2:00pm"),
         ("2013-02-12 14:00:00-", "through 2:00pm"),
         ("2013-02-12 14:00:00+", "after 2 pm"),
@@ -691,6 +841,9 @@ fn time_examples_matching() {
         ("2013-02-12 12:00:00", "at 12pm"),
         ("2013-02-12 12:00:00", "at noon"),
         ("2013-02-12 12:00:00", "midday"),
+        ("2013-02-12 18:00:00", "sunset"),
+        ("2013-02-13 05:30:00/2013-02-13 06:00:00", "tomorrow at dawn"),
+        ("2013-02-15 18:00:00/2013-02-15 18:30:00", "dusk on Friday"),
         ("2013-02-12 12:00:00", "the midday"),
         ("2013-02-12 12:00:00", "mid day"),
         ("2013-02-13 00:00:00", "at 12am"),
@@ -739,6 +892,11 @@ fn time_examples_matching() {
         ("2013-08-27 00:00:00/2013-08-30 00:00:00", "August 27th - 29th"),
         ("2013-08-27 00:00:00/2013-08-30 00:00:00", "from August 27th - 29th"),
         ("2013-10-23 00:00:00/2013-10-27 00:00:00", "23rd to 26th Oct"),
+        ("2013-05-03 00:00:00/2013-05-06 00:00:00", "from the third to the fifth of May"),
+        ("2013-05-03 00:00:00/2013-05-06 00:00:00", "between the third and the fifth of May"),
+        ("2013-06-21 00:00:00/2013-06-24 00:00:00", "twenty first to twenty third of June"),
+        ("2013-05-30 00:00:00/2013-06-01 00:00:00", "from the thirtieth to the thirty-first of May"),
+        ("2013-03-03 00:00:00/2013-03-06 00:00:00", "from the 3rd to the 5th of next month"),
         ("2013-09-01 00:00:00/2013-09-09 00:00:00", "1-8 september"),
         ("2013-09-12 00:00:00/2013-09-17 00:00:00", "12 to 16 september"),
         ("2013-08-19 00:00:00/2013-08-22 00:00:00", "19th To 21st aug"),
@@ -783,6 +941,31 @@ fn time_examples_matching() {
         ("2013-02-18 00:00:00/2013-02-21 00:00:00", "at the beginning of the following week"),
         ("2013-02-18 00:00:00/2013-02-21 00:00:00", "at the beginning of around next week"),
         ("2013-02-15 00:00:00/2013-02-18 00:00:00", "end of this week"),
+        ("2013-02-22 00:00:00/2013-02-25 00:00:00", "end of next week"),
+        ("2013-02-20 00:00:00/2013-02-23 00:00:00", "middle of next week"),
+        ("2013-03-12 00:00:00", "the second Tuesday of next month"),
+        ("2013-03-25 00:00:00", "last Monday of next month"),
+        ("2013-01-04 00:00:00", "first Friday of the quarter"),
+        ("2013-12-27 00:00:00", "last Friday of the year"),
+        ("2013-02-26 00:00:00", "last Tuesday of the month"),
+        ("2014-01-21 00:00:00", "third Tuesday of 2014"),
+        ("2013-07-01 00:00:00", "first Monday of Q3"),
+        ("2013-12-31 00:00:00", "last weekday of the year"),
+        ("2013-03-29 00:00:00", "last weekday of next month"),
+        ("2013-03-01 00:00:00", "first weekday of next month"),
+        ("2013-06-04 00:00:00", "second weekday of June"),
+        ("2013-02-11 00:00:00/2013-02-21 00:00:00", "the middle of this month"),
+        ("2013-03-01 00:00:00/2013-03-11 00:00:00", "early next month"),
+        ("2013-03-21 00:00:00/2013-04-01 00:00:00", "late next month"),
+        ("2013-08-01 00:00:00/2013-09-01 00:00:00", "middle of Q3"),
+        ("2013-04-01 00:00:00/2013-05-01 00:00:00", "beginning of next quarter"),
+        ("2014-09-01 00:00:00/2015-01-01 00:00:00", "end of next year"),
+        ("2014-05-01 00:00:00/2014-09-01 00:00:00", "mid-2014"),
+        ("2013-06-11 00:00:00/2013-06-21 00:00:00", "mid-June"),
+        ("2013-02-18 00:00:00/2013-02-20 00:00:00", "early next week"),
+        ("2013-02-23 00:00:00/2013-02-25 00:00:00", "late next week"),
+        ("2025-01-01 00:00:00/2025-05-01 00:00:00", "early 2025"),
+        ("2025-09-01 00:00:00/2026-01-01 00:00:00", "late 2025"),
         ("2013-02-15 00:00:00/2013-02-18 00:00:00", "end of current week"),
         ("2013-02-15 00:00:00/2013-02-18 00:00:00", "at the end of this week"),
         ("2013-02-15 00:00:00/2013-02-18 00:00:00", "at the end of current week"),
@@ -862,9 +1045,9 @@ fn time_examples_matching() {
     let rules = time::rules::get();
     let ctx = reference_context();
 
+    // Duckling's test context runs at UTC-02:00 (`15:00 GMT` -> `13:00`).
+    let opts = Options::default().with_local_timezone(Tz::Etc__GMTPlus2);
     for (expected, input) in cases {
-        let opts = Options::default();
-
         let parser = crate::engine::Parser::new(input, &rules);
         let resolved = parser.run(&ctx, &opts);
 
@@ -885,3 +1068,181 @@ fn time_examples_matching() {
         );
     }
 }
+
+/// Inputs whose first result must span the whole input, where a shorter
+/// reading would otherwise win ("@ 3pm" read as just "3pm").
+#[test]
+fn time_examples_spanning_input() {
+    let cases: Vec<(&str, &str)> = vec![
+        ("2013-02-12 15:00:00", "@ 3pm"),
+        ("2013-02-12 15:20:00", "tonight @ 3:20"),
+        ("2013-04-01 00:00:00", "upcoming qtr"),
+        ("2013-09-01 00:00:00/2014-01-01 00:00:00", "EOY"),
+        // Whitespace and commas between items.
+        ("2013-03-03 00:00:00", "March, 3"),
+        ("2013-07-13 15:00:00", "July 13 , 15:00"),
+        ("2013-09-12 00:00:00/2013-09-17 00:00:00", "12 to 16 september"),
+        // Emoji separate words like whitespace.
+        ("2013-02-13 17:00:00", "tomorrow 🎉 at 5pm"),
+        ("2013-02-13 17:00:00", "tomorrow🎉at 5pm"),
+        // An abbreviated month takes its period only before a date.
+        ("2014-01-05 00:00:00", "Jan. 5"),
+        ("2014-01-05 00:00:00/2014-02-04 00:00:00", "from Jan. 5 to Feb. 3"),
+        // Day ranges with the month on one side.
+        ("2013-05-03 00:00:00/2013-05-06 00:00:00", "the third until the fifth of May"),
+        ("2013-05-03 00:00:00/2013-05-06 00:00:00", "between the third and the fifth of May"),
+        ("2013-05-03 00:00:00/2013-05-06 00:00:00", "between the 3rd and the 5th of May"),
+        ("2013-05-03 00:00:00/2013-05-06 00:00:00", "May third through the fifth"),
+        ("2013-05-03 00:00:00/2013-05-06 00:00:00", "May 3 - the 5th"),
+        ("2013-05-30 00:00:00/2013-06-01 00:00:00", "from the 30th to the 31st of May"),
+        ("2013-02-21 00:00:00/2013-02-24 00:00:00", "twenty-first through twenty-third"),
+        // Compact ranges take any dash.
+        ("2013-06-14 00:00:00/2013-06-17 00:00:00", "6/14-6/16"),
+        ("2013-06-14 00:00:00/2013-06-17 00:00:00", "6/14–6/16"),
+        ("2013-06-14 00:00:00/2013-06-17 00:00:00", "6/14 — 6/16"),
+        ("2025-06-14 00:00:00/2025-06-17 00:00:00", "2025-06-14 – 2025-06-16"),
+        ("2025-06-14 00:00:00/2025-06-17 00:00:00", "2025-06-14—2025-06-16"),
+        ("2013-02-12 15:00:00/2013-02-12 18:00:00", "3–5pm"),
+        ("2013-03-14 00:00:00/2013-03-17 00:00:00", "14.-16.3."),
+        ("2024-03-30 00:00:00/2024-04-03 00:00:00", "30.3.-2.4.2024"),
+        // Weekdays of relative weeks, either way round. The reference is a
+        // Tuesday, which is never moved to the next week.
+        ("2013-02-12 00:00:00", "Tuesday this week"),
+        ("2013-02-12 00:00:00", "this week Tuesday"),
+        ("2013-02-19 00:00:00", "Tuesday next week"),
+        ("2013-02-19 00:00:00", "next week Tuesday"),
+        ("2013-02-05 00:00:00", "Tuesday last week"),
+        ("2013-02-05 00:00:00", "last week, Tuesday"),
+        ("2013-02-26 00:00:00", "Tuesday the week after next"),
+        ("2013-02-26 00:00:00", "the week after next on Tuesday"),
+        ("2013-02-18 00:00:00", "Monday next week"),
+        ("2013-02-18 00:00:00", "next week Monday"),
+        // The week of a date is the whole week holding it.
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "the week of June 14"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "week of June 14"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "week commencing 14 June"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "week beginning June 14th"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "w/c 14/6"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "W/C 14 June"),
+        ("2013-06-10 00:00:00/2013-06-17 00:00:00", "week of June 16"),
+        ("2013-06-17 00:00:00/2013-06-24 00:00:00", "week of June 17"),
+        // Nights end at midnight; overnight runs into the small hours.
+        ("2013-02-11 18:00:00/2013-02-12 00:00:00", "last night"),
+        ("2013-02-12 18:00:00/2013-02-13 00:00:00", "tonight"),
+        ("2013-02-13 18:00:00/2013-02-14 00:00:00", "tomorrow night"),
+        ("2013-02-12 18:00:00/2013-02-13 04:00:00", "overnight"),
+        ("2013-02-11 23:00:00", "last night at 11pm"),
+        ("2013-02-12 00:00:00", "last night at midnight"),
+        ("2013-02-12 03:00:00", "3am last night"),
+    ];
+
+    let ctx = reference_context();
+    let opts = Options::default().with_local_timezone(Tz::Etc__GMTPlus2);
+    for (expected, input) in cases {
+        let res = crate::parse_with(input, &ctx, &opts);
+        let first = res.results.first().map(|ent| (ent.body.as_str(), ent.value.as_str()));
+        assert_eq!(first, Some((input, expected)), "input '{input}' (results: {:#?})", res.results);
+    }
+}
+
+#[test]
+fn skipped_separators_never_split_a_word() {
+    // Touching items are fine ("3-5"), but "march3" is not "march 3".
+    let rules = time::rules::get();
+    let ctx = reference_context();
+    let fired = |input: &str| {
+        let run = crate::engine::Parser::new(input, &rules).run_with_metrics(&ctx, &Options::default());
+        run.tokens.iter().any(|rt| rt.node.rule_name == "<month> <dd> - <dd> (interval, regex)")
+    };
+
+    assert!(!fired("march3-5"));
+    assert!(fired("march 3-5"));
+}
+
+/// Inputs whose first result covers only part of the input:
+/// (expected value, body, input).
+#[test]
+fn time_examples_inside_input() {
+    let cases: Vec<(&str, &str, &str)> = vec![
+        // Emoji separate words like whitespace.
+        ("2013-02-15 00:00:00", "friday", "meet 👩\u{200d}💻 friday👍🏽"),
+        ("2013-03-03 00:00:00", "3rd🎉 of march", "🎉🎉 3rd🎉 of march"),
+        // An abbreviated month takes its period only before a date.
+        ("2014-09-12 00:00:00", "Sept. 12, 2014", "on Sept. 12, 2014"),
+        ("2013-09-01 00:00:00", "Sept", "see you in Sept."),
+        ("2013-09-05 00:00:00", "the 5th of Sept", "the 5th of Sept."),
+        // A month is not a date to take the week of.
+        ("2013-06-01 00:00:00", "June", "week of June"),
+    ];
+
+    let ctx = reference_context();
+    let opts = Options::default().with_local_timezone(Tz::Etc__GMTPlus2);
+    for (expected, body, input) in cases {
+        let res = crate::parse_with(input, &ctx, &opts);
+        let first = res.results.first().map(|ent| (ent.body.as_str(), ent.value.as_str()));
+        assert_eq!(first, Some((body, expected)), "input '{input}' (results: {:#?})", res.results);
+    }
+}
+
+#[test]
+fn repeated_digits_do_not_cross_combine() {
+    // A date and a time of day sharing a digit combine once, each token in
+    // its own place: never the "5" of the date as an hour, nor the "5pm" as
+    // a day of the month.
+    let cases = [
+        ("2013-05-05 17:00:00", "May 5 5pm"),
+        ("2013-05-05 17:00:00", "5 May 5pm"),
+        ("2013-06-06 18:00:00", "June 6 6 pm"),
+        ("2014-07-18 19:00:00", "Fri, Jul 18, 2014 07:00 PM"),
+        ("2014-07-18 19:00:00", "Fri, Jul 18, 2014 19h"),
+    ];
+
+    let ctx = reference_context();
+    for (expected, input) in cases {
+        let res = crate::parse_with(input, &ctx, &Options::default());
+        let values: Vec<&str> =
+            res.times().filter(|t| t.entity.body == input).map(|t| t.entity.value.as_str()).collect();
+        assert!(!values.is_empty(), "{input:?}");
+        assert!(values.iter().all(|value| *value == expected), "{input:?}: {values:?}");
+    }
+}
+
+/// The bodies of the times found in each input: month and weekday
+/// names that double as verbs and common words are left alone.
+#[test]
+fn time_examples_bodies() {
+    let cases: Vec<(&str, &[&str])> = vec![
+        ("march forward", &[]),
+        ("we march on", &[]),
+        ("it may be fine", &[]),
+        ("the sun is out", &[]),
+        ("she sat next to me", &[]),
+        ("you may go tomorrow", &["tomorrow"]),
+        ("sat down at 5", &["at 5", "at 5"]),
+        ("in march", &["march"]),
+        ("will May 5 work", &["May 5"]),
+        ("from April to May", &["from April to May"]),
+        ("the Sunday after", &["Sunday"]),
+        ("sat at 5", &["sat at 5", "sat at 5", "sat at 5"]),
+    ];
+
+    let ctx = reference_context();
+    let opts = Options::default().with_local_timezone(Tz::Etc__GMTPlus2);
+    for (input, expected) in cases {
+        let res = crate::parse_with(input, &ctx, &opts);
+        let bodies: Vec<&str> = res.times().map(|t| t.entity.body.as_str()).collect();
+        assert_eq!(bodies, expected, "input '{input}'");
+    }
+}
+
+#[test]
+fn compact_ranges_read_the_same_with_any_dash() {
+    let ctx = reference_context();
+    let first = |input: &str| {
+        crate::parse_with(input, &ctx, &Options::default()).times().next().map(|t| t.entity.value.clone())
+    };
+
+    assert_eq!(first("Monday–Friday"), first("Monday-Friday"));
+    // The start of a dotted range can't come after its end.
+    assert!(!first("16.-14.3.").unwrap_or_default().contains('/'));
+}
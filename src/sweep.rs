@@ -0,0 +1,167 @@
+use astorion::{Context, Options, TimeValue, Value, parse_with};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+/// Upper bound on rows so a typo in the range can't parse for minutes.
+const MAX_STEPS: usize = 10_000;
+
+/// Inclusive range of reference times to sweep over.
+#[derive(Debug, Clone)]
+pub struct SweepRange {
+    pub from: NaiveDateTime,
+    pub to: NaiveDateTime,
+    pub step: Duration,
+}
+
+/// One row of the sweep table.
+pub struct SweepRow {
+    pub reference: NaiveDateTime,
+    /// Resolved entity values, in result order.
+    pub values: Vec<String>,
+    /// True when this row breaks from the previous one (see [`run`]).
+    pub discontinuity: bool,
+}
+
+/// Parse `input` once per reference time in `range`.
+///
+/// A row is flagged as a discontinuity when both its values and their
+/// offsets from the reference differ from the previous row. That way
+/// "tomorrow" (constant offset) and "march 3" (constant value) stay quiet
+/// until the resolution actually jumps, e.g. when "march 3" rolls over to
+/// the following year.
+pub fn run(input: &str, range: &SweepRange, opts: &Options) -> Vec<SweepRow> {
+    let mut rows: Vec<SweepRow> = Vec::new();
+    let mut prev_offsets: Option<Vec<Option<Duration>>> = None;
+    let mut reference = range.from;
+
+    while reference <= range.to && rows.len() < MAX_STEPS {
+        let ctx = Context { reference_time: Some(reference), ..Default::default() };
+        let results = parse_with(input, &ctx, opts).results;
+        let offsets: Vec<Option<Duration>> = results.iter().map(|ent| offset_from(&ent.resolved, reference)).collect();
+        let values: Vec<String> = results.into_iter().map(|ent| ent.value).collect();
+
+        let discontinuity = match (rows.last(), &prev_offsets) {
+            (Some(prev), Some(prev_offsets)) => prev.values != values && *prev_offsets != offsets,
+            _ => false,
+        };
+
+        rows.push(SweepRow { reference, values, discontinuity });
+        prev_offsets = Some(offsets);
+        reference += range.step;
+    }
+
+    rows
+}
+
+/// Offset between the start of a resolved time and `reference`; `None` for
+/// values that are not times.
+fn offset_from(value: &Value, reference: NaiveDateTime) -> Option<Duration> {
+    let time = match value {
+        Value::Time(time) => time,
+        Value::Times(times) => times.first()?,
+        Value::Recurrence(recurrence) => &recurrence.next,
+        _ => return None,
+    };
+    let start = match time.value {
+        TimeValue::Instant(start)
+        | TimeValue::Interval { start, .. }
+        | TimeValue::OpenAfter(start)
+        | TimeValue::OpenBefore(start) => start,
+    };
+    Some(start - reference)
+}
+
+/// Parse `<from>..<to>`, where each bound is `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM:SS`.
+pub fn parse_range(value: &str, step: Duration) -> Result<SweepRange, String> {
+    let invalid = || format!("error: invalid --sweep-reference '{value}' (expected <from>..<to>)");
+    let (from, to) = value.split_once("..").ok_or_else(invalid)?;
+    let from = parse_bound(from).ok_or_else(invalid)?;
+    let to = parse_bound(to).ok_or_else(invalid)?;
+    if to < from {
+        return Err(format!("error: --sweep-reference range '{value}' ends before it starts"));
+    }
+    Ok(SweepRange { from, to, step })
+}
+
+fn parse_bound(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))
+}
+
+/// Parse a step such as `1d`, `6h`, `30m` or `1w`.
+pub fn parse_step(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("error: invalid --sweep-step '{value}' (expected <n>m, <n>h, <n>d or <n>w)");
+    let unit_at = value.char_indices().last().map(|(idx, _)| idx).ok_or_else(invalid)?;
+    let (amount, unit) = value.split_at(unit_at);
+    let amount: i64 = amount.parse().ok().filter(|n| *n > 0).ok_or_else(invalid)?;
+    let step = match unit {
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    };
+    step.ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sweep(input: &str, range: &str, step: &str) -> Vec<SweepRow> {
+        let range = parse_range(range, parse_step(step).unwrap()).unwrap();
+        run(input, &range, &Options::default())
+    }
+
+    fn flagged(rows: &[SweepRow]) -> Vec<String> {
+        rows.iter().filter(|row| row.discontinuity).map(|row| row.reference.to_string()).collect()
+    }
+
+    #[test]
+    fn tomorrow_jumps_at_midnight_only() {
+        let rows = sweep("tomorrow", "2013-02-12T21:00:00..2013-02-13T03:00:00", "1h");
+
+        assert_eq!(rows.len(), 7);
+        assert_eq!(rows[2].values, ["2013-02-13 00:00:00"]);
+        assert_eq!(rows[3].values, ["2013-02-14 00:00:00"]);
+        assert_eq!(flagged(&rows), ["2013-02-13 00:00:00"]);
+    }
+
+    #[test]
+    fn constant_offsets_and_constant_values_stay_quiet() {
+        let rows = sweep("in 2 hours", "2013-02-12T22:00:00..2013-02-13T02:00:00", "30m");
+        assert_eq!(rows[3].values[0], "2013-02-13 01:30:00");
+        assert!(flagged(&rows).is_empty());
+
+        let rows = sweep("march 3", "2013-03-01..2013-03-06", "1d");
+        assert_eq!(flagged(&rows), ["2013-03-04 00:00:00"]);
+        assert_eq!(rows.last().unwrap().values[0], "2014-03-03 00:00:00");
+    }
+
+    #[test]
+    fn offsets_come_from_the_resolved_value() {
+        let reference = parse_bound("2013-02-12").unwrap();
+        let ctx = Context { reference_time: Some(reference), ..Default::default() };
+        let offset = |input: &str| {
+            let entity = parse_with(input, &ctx, &Options::default()).results.remove(0);
+            offset_from(&entity.resolved, reference)
+        };
+
+        let bce = NaiveDate::from_ymd_opt(-987, 2, 12).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(offset("3000 years ago"), Some(bce - reference));
+        assert_eq!(offset("in the next 2 hours"), Some(Duration::zero()));
+        assert_eq!(offset("after 5pm"), Some(Duration::hours(17)));
+        assert_eq!(offset("42"), None);
+    }
+
+    #[test]
+    fn ranges_and_steps_are_validated() {
+        assert_eq!(parse_step("90m"), Ok(Duration::minutes(90)));
+        assert!(parse_step("1y").is_err());
+        assert!(parse_step("0d").is_err());
+        assert!(
+            parse_range("2013-02-13..2013-02-12", Duration::days(1)).unwrap_err().contains("ends before it starts")
+        );
+        assert!(parse_range("2013-02-12", Duration::days(1)).is_err());
+    }
+}
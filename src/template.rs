@@ -0,0 +1,196 @@
+//! Slot-filling templates over a parse.
+//!
+//! Bots collecting a command ("a table for 4 guests tomorrow at 7pm") want the
+//! entities of a parse lined up with the slots they expect. A [`Template`]
+//! lists typed slots in the order they are expected in the text, and
+//! [`Template::fill`] aligns the entities of a [`ParseResult`] to them,
+//! reporting the slots left unmatched so the bot can ask for them.
+
+use crate::{IdentifierEntity, NumeralEntity, ParseResult, TimeEntity};
+use chrono::Duration;
+
+/// What a [`Slot`] takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotKind {
+    /// A time entity of any shape: instant, interval or open interval.
+    Time,
+    /// A time entity resolving to a closed interval ("next 3 days", "from 3
+    /// to 5pm"), filled with its length.
+    Duration,
+    /// A numeral entity.
+    Numeral,
+    /// An identifier entity ("order #5678").
+    Identifier,
+}
+
+/// A named, typed slot of a [`Template`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slot {
+    pub name: String,
+    pub kind: SlotKind,
+}
+
+impl Slot {
+    pub fn new(name: impl Into<String>, kind: SlotKind) -> Self {
+        Self { name: name.into(), kind }
+    }
+
+    pub fn time(name: impl Into<String>) -> Self {
+        Self::new(name, SlotKind::Time)
+    }
+
+    pub fn duration(name: impl Into<String>) -> Self {
+        Self::new(name, SlotKind::Duration)
+    }
+
+    pub fn numeral(name: impl Into<String>) -> Self {
+        Self::new(name, SlotKind::Numeral)
+    }
+
+    pub fn identifier(name: impl Into<String>) -> Self {
+        Self::new(name, SlotKind::Identifier)
+    }
+}
+
+/// Slots expected in order in the text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Template {
+    pub slots: Vec<Slot>,
+}
+
+/// The entity a slot was filled with, through the typed entity views.
+#[derive(Debug, Clone, Copy)]
+pub enum SlotValue<'a> {
+    Time(TimeEntity<'a>),
+    /// The interval and its length.
+    Duration(TimeEntity<'a>, Duration),
+    Numeral(NumeralEntity<'a>),
+    Identifier(IdentifierEntity<'a>),
+}
+
+/// A slot and the entity filling it.
+#[derive(Debug, Clone, Copy)]
+pub struct FilledSlot<'a> {
+    pub slot: &'a Slot,
+    pub value: SlotValue<'a>,
+}
+
+/// The result of [`Template::fill`].
+#[derive(Debug, Clone, Default)]
+pub struct SlotFill<'a> {
+    /// Filled slots, in template order.
+    pub filled: Vec<FilledSlot<'a>>,
+    /// Slots no entity was aligned to, in template order.
+    pub unmatched: Vec<&'a Slot>,
+}
+
+impl<'a> SlotFill<'a> {
+    /// Whether every slot was filled.
+    pub fn is_complete(&self) -> bool {
+        self.unmatched.is_empty()
+    }
+
+    /// The value of the slot named `name`, if filled.
+    pub fn get(&self, name: &str) -> Option<&SlotValue<'a>> {
+        self.filled.iter().find(|filled| filled.slot.name == name).map(|filled| &filled.value)
+    }
+}
+
+impl Template {
+    pub fn new(slots: impl IntoIterator<Item = Slot>) -> Self {
+        Self { slots: slots.into_iter().collect() }
+    }
+
+    /// Align the entities of `result` to the slots, in order.
+    ///
+    /// Latent entities, and ones nested in a longer entity (the `5` of "at
+    /// 5pm"), are not candidates; of several readings of one span, the first
+    /// is. Each slot takes the first remaining candidate of its kind after
+    /// the one the previous filled slot took. A slot with none left is
+    /// unmatched, and the next slot searches from the same place.
+    pub fn fill<'a>(&'a self, result: &'a ParseResult) -> SlotFill<'a> {
+        let mut candidates: Vec<usize> = (0..result.results.len())
+            .filter(|&idx| {
+                let entity = &result.results[idx];
+                !entity.latent
+                    && !result.results.iter().any(|other| {
+                        other.start <= entity.start
+                            && entity.end <= other.end
+                            && other.end - other.start > entity.end - entity.start
+                    })
+            })
+            .collect();
+        candidates.sort_by_key(|&idx| (result.results[idx].start, idx));
+        candidates.dedup_by_key(|idx| (result.results[*idx].start, result.results[*idx].end));
+
+        let mut fill = SlotFill::default();
+        let mut next = 0;
+        for slot in &self.slots {
+            let found = candidates[next..]
+                .iter()
+                .enumerate()
+                .find_map(|(offset, &idx)| Some((offset, slot_value(result, idx, slot.kind)?)));
+            match found {
+                Some((offset, value)) => {
+                    next += offset + 1;
+                    fill.filled.push(FilledSlot { slot, value });
+                }
+                None => fill.unmatched.push(slot),
+            }
+        }
+        fill
+    }
+}
+
+/// Entity `idx` of `result` as a value for a slot of `kind`, if it fits.
+fn slot_value(result: &ParseResult, idx: usize, kind: SlotKind) -> Option<SlotValue<'_>> {
+    let entity = &result.results[idx];
+    match kind {
+        SlotKind::Time => Some(SlotValue::Time(TimeEntity { entity, time: entity.resolved.as_time()? })),
+        SlotKind::Duration => {
+            let time = entity.resolved.as_time()?;
+            Some(SlotValue::Duration(TimeEntity { entity, time }, time.value.duration()?))
+        }
+        SlotKind::Numeral => Some(SlotValue::Numeral(NumeralEntity { entity, numeral: entity.resolved.as_numeral()? })),
+        SlotKind::Identifier => {
+            Some(SlotValue::Identifier(IdentifierEntity { entity, identifier: entity.resolved.as_identifier()? }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Options, parse_with};
+    use chrono::NaiveDate;
+
+    fn names<'a>(slots: impl IntoIterator<Item = &'a Slot>) -> Vec<&'a str> {
+        slots.into_iter().map(|slot| slot.name.as_str()).collect()
+    }
+
+    #[test]
+    fn entities_fill_slots_in_order() {
+        let reference = NaiveDate::from_ymd_opt(2013, 2, 12).and_then(|d| d.and_hms_opt(4, 30, 0));
+        let context = Context { reference_time: reference, ..Default::default() };
+        let parse = |text: &str| parse_with(text, &context, &Options::default());
+        let booking = Template::new([Slot::numeral("guests"), Slot::time("when")]);
+
+        let result = parse("table for 4 guests tomorrow at 7pm");
+        let fill = booking.fill(&result);
+        assert!(fill.is_complete());
+        assert!(matches!(fill.get("guests"), Some(SlotValue::Numeral(n)) if n.numeral.value == 4.0));
+        assert!(matches!(fill.get("when"), Some(SlotValue::Time(t)) if t.entity.body == "tomorrow at 7pm"));
+
+        // The 7 of "at 7pm" is not a guest count.
+        let result = parse("a table tomorrow at 7pm");
+        let fill = booking.fill(&result);
+        assert_eq!(names(fill.filled.iter().map(|f| f.slot)), ["when"]);
+        assert_eq!(names(fill.unmatched), ["guests"]);
+
+        let meeting = Template::new([Slot::duration("slot"), Slot::identifier("ticket")]);
+        let result = parse("next 3 days about ABC-123");
+        let fill = meeting.fill(&result);
+        assert!(matches!(fill.get("slot"), Some(SlotValue::Duration(_, d)) if *d == Duration::days(3)));
+        assert!(matches!(fill.get("ticket"), Some(SlotValue::Identifier(i)) if i.identifier.code == "ABC-123"));
+    }
+}
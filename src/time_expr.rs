@@ -1,6 +1,8 @@
-use chrono::{NaiveDateTime, NaiveTime, Weekday};
+use chrono::{Datelike, Duration, NaiveDateTime, NaiveTime, Weekday};
 
+/// A unit of time, from seconds up to years.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Grain {
     Second,
     Minute,
@@ -12,14 +14,47 @@ pub enum Grain {
     Year,
 }
 
+/// Early, mid or late part of a month, season or other period (see
+/// [`TimeExpr::PeriodPart`]).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MonthPart {
     Early,
     Mid,
     Late,
 }
 
+/// Which way from the reference a span runs (see
+/// [`TimeExpr::IntervalFromReference`]).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Past,
+    Future,
+}
+
+/// Sunrise, sunset and the twilight around them, per the context's
+/// [`Daylight`](crate::Daylight).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolarEvent {
+    Dawn,
+    Sunrise,
+    Sunset,
+    Dusk,
+}
+
+/// Which end of the business day ("start of business", "EOD").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BusinessBoundary {
+    Start,
+    End,
+}
+
+/// An astronomical season; the hemisphere is chosen by the context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Season {
     Spring,
     Summer,
@@ -27,7 +62,9 @@ pub enum Season {
     Winter,
 }
 
+/// A holiday with fixed or rule-based Gregorian dates.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 pub enum Holiday {
     NewYearsDay,
@@ -51,15 +88,111 @@ pub enum Holiday {
     BlackFriday,
 }
 
+impl Holiday {
+    /// English display name, e.g. "Independence Day".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Holiday::NewYearsDay => "New Year's Day",
+            Holiday::MLKDay => "Martin Luther King Jr. Day",
+            Holiday::PresidentsDay => "Presidents' Day",
+            Holiday::StPatricksDay => "St. Patrick's Day",
+            Holiday::EarthDay => "Earth Day",
+            Holiday::MemorialDay => "Memorial Day",
+            Holiday::FathersDay => "Father's Day",
+            Holiday::MothersDay => "Mother's Day",
+            Holiday::IndependenceDay => "Independence Day",
+            Holiday::LaborDay => "Labor Day",
+            Holiday::ColumbusDay => "Columbus Day",
+            Holiday::Halloween => "Halloween",
+            Holiday::VeteransDay => "Veterans Day",
+            Holiday::Thanksgiving => "Thanksgiving",
+            Holiday::Christmas => "Christmas",
+            Holiday::ChristmasEve => "Christmas Eve",
+            Holiday::NewYearsEve => "New Year's Eve",
+            Holiday::BossDay => "Boss's Day",
+            Holiday::BlackFriday => "Black Friday",
+        }
+    }
+}
+
+/// A normalized time, as exposed through [`Value::Time`](crate::Value::Time).
+///
+/// Intervals are half-open: `end` is the first instant after the interval,
+/// so "from 3 to 5pm" ends at 18:00 and "July 13-15" on July 16th. Use
+/// [`inclusive_end`](TimeValue::inclusive_end) for the last unit covered.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeValue {
     Instant(NaiveDateTime),
-    Interval { start: NaiveDateTime, end: NaiveDateTime },
+    /// From `start` (inclusive) to `end` (exclusive).
+    Interval {
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    },
     OpenAfter(NaiveDateTime),  // From this time onwards (formatted with +)
     OpenBefore(NaiveDateTime), // Up until this time (formatted with -)
 }
 
+impl TimeValue {
+    /// Length of a closed interval; `None` for instants and open-ended intervals.
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            TimeValue::Interval { start, end } => Some(*end - *start),
+            _ => None,
+        }
+    }
+
+    /// Length of a closed interval in whole seconds.
+    pub fn duration_seconds(&self) -> Option<i64> {
+        self.duration().map(|duration| duration.num_seconds())
+    }
+
+    /// Number of whole `grain` units a closed interval spans, e.g. 3 for
+    /// "next 3 days" at [`Grain::Day`]. Months, quarters and years count
+    /// calendar units, so February is one month like any other.
+    pub fn duration_in(&self, grain: Grain) -> Option<i64> {
+        let TimeValue::Interval { start, end } = *self else { return None };
+        let months = || {
+            let months = (end.year() as i64 - start.year() as i64) * 12 + end.month() as i64 - start.month() as i64;
+            // A partial final month does not count.
+            let short = (end.day(), end.time()) < (start.day(), start.time());
+            months - i64::from(short && months > 0)
+        };
+        Some(match grain {
+            Grain::Second => (end - start).num_seconds(),
+            Grain::Minute => (end - start).num_minutes(),
+            Grain::Hour => (end - start).num_hours(),
+            Grain::Day => (end - start).num_days(),
+            Grain::Week => (end - start).num_weeks(),
+            Grain::Month => months(),
+            Grain::Quarter => months() / 3,
+            Grain::Year => months() / 12,
+        })
+    }
+
+    /// Start of the last `grain` unit a closed interval covers: July 15th
+    /// for "July 13-15" at [`Grain::Day`], 17:00 for "from 3 to 5pm" at
+    /// [`Grain::Hour`]. Pass the [`Time::grain`](crate::Time::grain) the
+    /// value was resolved at.
+    pub fn inclusive_end(&self, grain: Grain) -> Option<NaiveDateTime> {
+        let TimeValue::Interval { end, .. } = *self else { return None };
+        crate::rules::time::helpers::shift::shift_datetime_by_grain(end, -1, grain)
+    }
+
+    /// Apply `f` to every datetime in this value.
+    pub(crate) fn map(self, f: impl Fn(NaiveDateTime) -> NaiveDateTime) -> TimeValue {
+        match self {
+            TimeValue::Instant(dt) => TimeValue::Instant(f(dt)),
+            TimeValue::Interval { start, end } => TimeValue::Interval { start: f(start), end: f(end) },
+            TimeValue::OpenAfter(dt) => TimeValue::OpenAfter(f(dt)),
+            TimeValue::OpenBefore(dt) => TimeValue::OpenBefore(f(dt)),
+        }
+    }
+}
+
+/// A calendar constraint narrowing a time expression ("on Friday", "in May", "at 3pm").
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 pub enum Constraint {
     DayOfMonth(u32),
@@ -68,9 +201,17 @@ pub enum Constraint {
     Day(u32),
     TimeOfDay(NaiveTime),
     PartOfDay(PartOfDay),
+    Solar(SolarEvent),
 }
 
+/// A named part of the day ("morning", "tonight").
+///
+/// The night of a day is in two portions: its evening, 18:00 to midnight,
+/// and the small hours after it, midnight to 04:00 the next day. "Night",
+/// "tonight" and "last night" name the evening portion; "overnight" and
+/// "last night at 2am" reach into the small hours.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PartOfDay {
     EarlyMorning,
     Morning,
@@ -82,9 +223,20 @@ pub enum PartOfDay {
     Tonight,
     LateTonight,
     AfterWork,
+    /// Both portions of the night, 18:00 to 04:00 the next day.
+    Overnight,
 }
 
+/// Hour the small hours of a night end at, the day after it.
+pub(crate) const SMALL_HOURS_END: u32 = 4;
+
+/// A symbolic time expression, as produced by the time rules and resolved
+/// against a [`Context`](crate::Context) by [`normalize`](crate::normalize).
+///
+/// Expressions can also be built by hand to reuse the resolution logic, e.g.
+/// "next Friday" is `Intersect { expr: Reference, constraint: DayOfWeek(Fri) }`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 pub enum TimeExpr {
     Reference,
@@ -114,9 +266,23 @@ pub enum TimeExpr {
         month: Option<u32>, // None means current month
         part: MonthPart,
     },
+    /// Early, middle or late third of the week, month, quarter or year `of`
+    /// resolves to ("end of next week", "middle of Q3", "late next month").
+    PeriodPart {
+        part: MonthPart,
+        of: Box<TimeExpr>,
+    },
     IntervalUntil {
         target: Box<TimeExpr>,
     },
+    /// `amount` units of `grain` running from the reference into the future
+    /// or back into the past ("for the next 3 weeks", "in the last 30
+    /// days"), starting or ending exactly at the reference.
+    IntervalFromReference {
+        amount: i32,
+        grain: Grain,
+        direction: Direction,
+    },
     /// Interval between two time expressions
     IntervalBetween {
         start: Box<TimeExpr>,
@@ -151,6 +317,13 @@ pub enum TimeExpr {
         hour: Option<u32>,
         minute: Option<u32>,
     },
+    /// Date with a two-digit year ("3/5/99"), expanded to a full year with
+    /// the options' century pivot and then resolved like `Absolute`.
+    ShortYearDate {
+        year: u32, // 0-99
+        month: u32,
+        day: u32,
+    },
     /// Last occurrence of a weekday in a month
     LastWeekdayOfMonth {
         year: Option<i32>, // None means current year from reference
@@ -170,6 +343,21 @@ pub enum TimeExpr {
         month: u32,
         weekday: chrono::Weekday,
     },
+    /// Nth occurrence of a weekday within the month, quarter or year `of`
+    /// resolves to ("the second Tuesday of next month", "first Friday of the
+    /// quarter"); negative `n` counts from the end (-1 = last).
+    NthWeekdayOf {
+        n: i32,
+        weekday: chrono::Weekday,
+        of: Box<TimeExpr>,
+    },
+    /// Nth day from Monday to Friday within the month, quarter or year `of`
+    /// resolves to ("last weekday of the year", "first weekday of next
+    /// month"); negative `n` counts from the end (-1 = last).
+    NthMondayToFridayOf {
+        n: i32,
+        of: Box<TimeExpr>,
+    },
     /// Nth week of a month/year
     NthWeekOf {
         n: u32, // 1-based: 1 = first, 2 = second, etc.
@@ -193,6 +381,20 @@ pub enum TimeExpr {
     },
     /// Season expression (spring, summer, fall, winter)
     Season(Season),
+    /// A month named relative to the reference year.
+    ///
+    /// `offset = 0` => that month of the reference year ("this June", even if past).
+    /// `offset = 1` => the next one ("next June"), per [`NextMonthPolicy`](crate::NextMonthPolicy).
+    /// `offset = -1` => the previous one ("last June"), mirroring `offset = 1`.
+    NamedMonth {
+        month: u32,
+        offset: i32,
+    },
+    /// Early, mid or late third of a season ("early summer", "mid-winter")
+    SeasonPart {
+        season: Season,
+        part: MonthPart,
+    },
     /// Holiday (Thanksgiving, Christmas, etc.)
     Holiday {
         holiday: Holiday,
@@ -213,4 +415,86 @@ pub enum TimeExpr {
         hour: u32,   // 1-12
         minute: u32, // 0-59
     },
+    /// `expr` read as wall-clock time in `zone` (e.g. "9am PST"), converted to
+    /// local time on normalization. `zone` is the identifier as recognized.
+    InTimezone {
+        expr: Box<TimeExpr>,
+        zone: String,
+    },
+    /// One time stated in two timezones ("9am ET / 6am PT", "15:00 CET (9am
+    /// ET)"). Normalizes to the `primary` reading; a `secondary` reading that
+    /// disagrees is reported as an alternative.
+    DualTimezone {
+        primary: Box<TimeExpr>,
+        secondary: Box<TimeExpr>,
+    },
+    /// `expr` moved by `amount` business days, per the context's calendar
+    /// ("in 3 business days", "2 working days before Friday").
+    ShiftBusinessDays {
+        expr: Box<TimeExpr>,
+        amount: i32,
+    },
+    /// Opening or closing time of business on the day of `expr`, per the
+    /// context's calendar ("EOD Friday", "start of business Monday").
+    BusinessTime {
+        expr: Box<TimeExpr>,
+        boundary: BusinessBoundary,
+    },
+    /// Fiscal year `year` (named after the calendar year it ends in), or one
+    /// of its quarters, per the fiscal-year start in the options ("FY2025",
+    /// "Q2 FY25").
+    FiscalYear {
+        year: i32,
+        quarter: Option<u32>,
+    },
+    /// Holiday from another calendar ("Ramadan", "Diwali 2014"), resolved by
+    /// the context's holiday provider. `name` is the canonical lowercase name.
+    ProvidedHoliday {
+        name: String,
+        year: Option<i32>,
+    },
+    /// Several times enumerated together ("the 3rd, 7th and 12th of May").
+    /// Resolves to one value per item rather than a single `TimeValue`.
+    List(Vec<TimeExpr>),
+    /// A time repeating every `every` ("every first Monday of the month"):
+    /// `occurrence` resolved in each period in turn. Normalizes to the next
+    /// occurrence.
+    Recurrence {
+        every: Grain,
+        occurrence: Box<TimeExpr>,
+    },
+}
+
+impl TimeExpr {
+    /// Apply `f` to this expression and all its sub-expressions (pre-order,
+    /// a `DualTimezone`'s primary before its secondary, list items in order)
+    /// and return the first `Some`.
+    pub fn find_map<'a, T>(&'a self, f: &impl Fn(&'a TimeExpr) -> Option<T>) -> Option<T> {
+        if let Some(found) = f(self) {
+            return Some(found);
+        }
+        match self {
+            TimeExpr::Shift { expr, .. }
+            | TimeExpr::StartOf { expr, .. }
+            | TimeExpr::IntervalOf { expr, .. }
+            | TimeExpr::Intersect { expr, .. }
+            | TimeExpr::OpenAfter { expr }
+            | TimeExpr::OpenBefore { expr }
+            | TimeExpr::InTimezone { expr, .. }
+            | TimeExpr::ShiftBusinessDays { expr, .. }
+            | TimeExpr::BusinessTime { expr, .. }
+            | TimeExpr::After(expr)
+            | TimeExpr::Before(expr)
+            | TimeExpr::Duration(expr)
+            | TimeExpr::PeriodPart { of: expr, .. }
+            | TimeExpr::NthWeekdayOf { of: expr, .. }
+            | TimeExpr::NthMondayToFridayOf { of: expr, .. }
+            | TimeExpr::Recurrence { occurrence: expr, .. } => expr.find_map(f),
+            TimeExpr::IntervalUntil { target } | TimeExpr::ClosestWeekdayTo { target, .. } => target.find_map(f),
+            TimeExpr::IntervalBetween { start, end } => start.find_map(f).or_else(|| end.find_map(f)),
+            TimeExpr::DualTimezone { primary, secondary } => primary.find_map(f).or_else(|| secondary.find_map(f)),
+            TimeExpr::List(items) => items.iter().find_map(|item| item.find_map(f)),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,507 @@
+//! Structured values attached to resolved entities.
+//!
+//! `Entity::value` keeps the canonical string form for display and golden
+//! tests; `Entity::resolved` carries the same value in a form callers can
+//! compute with, without re-parsing strings.
+
+use crate::engine::resolve_time_expr;
+use crate::rules::time::helpers::shift::shift_datetime_by_grain;
+use crate::rules::time::helpers::timezone::{Zone, lookup_zone};
+use crate::rules::time::normalize::format_time_value;
+use crate::{Context, Holiday, Options, PartOfDay, TimeExpr};
+use chrono::{Datelike, NaiveDateTime, Timelike, Weekday};
+use std::fmt;
+
+pub use crate::time_expr::{Grain, TimeValue};
+
+/// Structured resolved value of an [`Entity`](crate::Entity).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+    Time(Time),
+    /// A date stated without a year, kept as-is when
+    /// [`Options::partial_dates`](crate::Options::partial_dates) is enabled.
+    PartialDate(PartialDate),
+    /// Several times enumerated in one phrase ("the 3rd, 7th and 12th of May"),
+    /// earliest first whatever order they were stated in.
+    Times(Vec<Time>),
+    /// A time left unresolved because [`Options::resolve`](crate::Options::resolve)
+    /// is disabled.
+    Symbolic(SymbolicTime),
+    /// A repeating time ("every first Monday of the month").
+    Recurrence(Recurrence),
+    Numeral(Numeral),
+    /// An order number, tracking code or ticket ID.
+    Identifier(Identifier),
+}
+
+/// A resolved time: an instant, a closed interval, or an open interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Time {
+    /// The value in local time.
+    pub value: TimeValue,
+    pub precision: Precision,
+    /// Finest unit the input named: `Day` for "March 3", `Hour` for "3pm".
+    /// Tells a meaningful midnight ("at midnight") from a date's 00:00:00.
+    pub grain: Grain,
+    /// The timezone the input was stated in ("9am PST"), if any.
+    pub timezone: Option<Timezone>,
+    /// The holiday this time is ("Thanksgiving", "Christmas at 6pm"), if
+    /// any; [`Holiday::name`] gives a label for display.
+    pub holiday: Option<Holiday>,
+    /// The part of the day the input named ("tomorrow evening"), if any.
+    pub part_of_day: Option<PartOfDay>,
+}
+
+impl Time {
+    /// This time widened to its [`grain`](Time::grain): an instant becomes
+    /// the whole unit it starts ("tomorrow" is the day, "3pm" the hour from
+    /// 15:00 to 16:00), as [`Options::round_to_grain`](crate::Options::round_to_grain)
+    /// does at resolution. Intervals and second-grain instants are unchanged.
+    pub fn widen(&self) -> Time {
+        let widen = |value: &TimeValue| match *value {
+            TimeValue::Instant(start) if self.grain != Grain::Second => shift_datetime_by_grain(start, 1, self.grain)
+                .map_or_else(|| value.clone(), |end| TimeValue::Interval { start, end }),
+            _ => value.clone(),
+        };
+        let timezone = self.timezone.as_ref().map(|zone| Timezone { local: widen(&zone.local), ..zone.clone() });
+        Time { value: widen(&self.value), timezone, ..self.clone() }
+    }
+}
+
+/// An unresolved time: the symbolic expression the rules produced, to be
+/// resolved later with [`normalize`](crate::normalize).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SymbolicTime {
+    pub expr: TimeExpr,
+    pub precision: Precision,
+}
+
+impl SymbolicTime {
+    /// Resolve the expression against `context`, e.g. a reminder parsed
+    /// yesterday against today's reference time. Returns `None` when the
+    /// expression has no value for this reference.
+    pub fn resolve_with(&self, context: &Context, options: &Options) -> Option<Value> {
+        let options = Options { resolve: true, ..options.clone() };
+        resolve_time_expr(context, &options, &self.expr, self.precision)
+    }
+}
+
+/// A repeating time: its next occurrence and the rule it repeats by, in the
+/// terms of an iCalendar RRULE (RFC 5545).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recurrence {
+    /// The first occurrence on or after the reference day.
+    pub next: Time,
+    /// `FREQ`: the period it repeats over (`Week`, `Month` or `Year`).
+    pub frequency: Grain,
+    /// `INTERVAL`: how many periods apart occurrences are ("every quarter" is
+    /// every 3 months).
+    pub interval: u32,
+    /// `BYDAY`: the weekday it falls on, if any.
+    pub by_day: Option<Weekday>,
+    /// `BYSETPOS`: which of those weekdays in the period (1 = first, -1 = last).
+    pub by_set_pos: Option<i32>,
+    /// `BYMONTHDAY`: the days of the month it falls on ("the 1st and 15th of
+    /// every month"), empty if none.
+    pub by_month_day: Vec<u32>,
+}
+
+impl Recurrence {
+    /// The rule as an RRULE value, e.g. `FREQ=MONTHLY;BYDAY=MO;BYSETPOS=1`.
+    pub fn rrule(&self) -> String {
+        let freq = match self.frequency {
+            Grain::Year | Grain::Quarter => "YEARLY",
+            Grain::Month => "MONTHLY",
+            Grain::Week => "WEEKLY",
+            Grain::Day => "DAILY",
+            Grain::Hour => "HOURLY",
+            Grain::Minute => "MINUTELY",
+            Grain::Second => "SECONDLY",
+        };
+        let mut parts = vec![format!("FREQ={freq}")];
+        if self.interval > 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if let Some(weekday) = self.by_day {
+            let code = match weekday {
+                Weekday::Mon => "MO",
+                Weekday::Tue => "TU",
+                Weekday::Wed => "WE",
+                Weekday::Thu => "TH",
+                Weekday::Fri => "FR",
+                Weekday::Sat => "SA",
+                Weekday::Sun => "SU",
+            };
+            parts.push(format!("BYDAY={code}"));
+        }
+        if let Some(pos) = self.by_set_pos {
+            parts.push(format!("BYSETPOS={pos}"));
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(u32::to_string).collect();
+            parts.push(format!("BYMONTHDAY={}", days.join(",")));
+        }
+        parts.join(";")
+    }
+}
+
+/// A timezone recognized in the input, with the value as read there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timezone {
+    /// Identifier as recognized, uppercased (e.g. `"PST"`).
+    pub name: String,
+    /// Offset from UTC in seconds.
+    pub utc_offset_seconds: i32,
+    /// The value as wall-clock time in this timezone, e.g. `09:00` for "9am PST".
+    pub local: TimeValue,
+}
+
+/// A month and day with the year left unspecified ("June 14").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialDate {
+    pub month: u32,
+    pub day: u32,
+}
+
+/// A resolved number.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Numeral {
+    pub value: f64,
+    pub precision: Precision,
+}
+
+/// A code matched by one of [`Options::identifier_patterns`](crate::Options::identifier_patterns).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Identifier {
+    /// The [`IdentifierPattern::kind`](crate::IdentifierPattern::kind) that
+    /// matched: "ticket", "tracking", ...
+    pub kind: String,
+    /// The code as written.
+    pub code: String,
+}
+
+/// Whether the input stated a value exactly or hedged it ("around 3pm",
+/// "150ish", "about 20").
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Precision {
+    #[default]
+    Exact,
+    Approximate,
+}
+
+impl Value {
+    /// The resolved time, if this is a time.
+    pub fn as_time(&self) -> Option<&Time> {
+        match self {
+            Value::Time(time) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// The partial date, if this is a date without a year.
+    pub fn as_partial_date(&self) -> Option<&PartialDate> {
+        match self {
+            Value::PartialDate(date) => Some(date),
+            _ => None,
+        }
+    }
+
+    /// The enumerated times, if this is a list of times.
+    pub fn as_times(&self) -> Option<&[Time]> {
+        match self {
+            Value::Times(times) => Some(times),
+            _ => None,
+        }
+    }
+
+    /// The symbolic expression, if this time was left unresolved.
+    pub fn as_symbolic(&self) -> Option<&SymbolicTime> {
+        match self {
+            Value::Symbolic(symbolic) => Some(symbolic),
+            _ => None,
+        }
+    }
+
+    /// The recurrence, if this is a repeating time.
+    pub fn as_recurrence(&self) -> Option<&Recurrence> {
+        match self {
+            Value::Recurrence(recurrence) => Some(recurrence),
+            _ => None,
+        }
+    }
+
+    /// The resolved number, if this is a numeral.
+    pub fn as_numeral(&self) -> Option<&Numeral> {
+        match self {
+            Value::Numeral(numeral) => Some(numeral),
+            _ => None,
+        }
+    }
+
+    /// The identifier, if this is a code.
+    pub fn as_identifier(&self) -> Option<&Identifier> {
+        match self {
+            Value::Identifier(identifier) => Some(identifier),
+            _ => None,
+        }
+    }
+
+    /// This value with every time widened to its grain; see [`Time::widen`].
+    pub fn widen(&self) -> Value {
+        match self {
+            Value::Time(time) => Value::Time(time.widen()),
+            Value::Times(times) => Value::Times(times.iter().map(Time::widen).collect()),
+            Value::Recurrence(recurrence) => {
+                Value::Recurrence(Recurrence { next: recurrence.next.widen(), ..recurrence.clone() })
+            }
+            value => value.clone(),
+        }
+    }
+
+    /// Precision of the underlying value.
+    pub fn precision(&self) -> Precision {
+        match self {
+            Value::Time(time) => time.precision,
+            Value::PartialDate(_) => Precision::Exact,
+            Value::Times(times) => times.first().map_or(Precision::Exact, |time| time.precision),
+            Value::Symbolic(symbolic) => symbolic.precision,
+            Value::Recurrence(recurrence) => recurrence.next.precision,
+            Value::Numeral(numeral) => numeral.precision,
+            Value::Identifier(_) => Precision::Exact,
+        }
+    }
+}
+
+/// Canonical string form, as found in `Entity::value`.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Time(time) => f.write_str(&format_time_value(&time.value)),
+            // ISO 8601 form for a date without a year.
+            Value::PartialDate(PartialDate { month, day }) => write!(f, "--{month:02}-{day:02}"),
+            Value::Times(times) => {
+                let values: Vec<String> = times.iter().map(|time| format_time_value(&time.value)).collect();
+                f.write_str(&values.join(", "))
+            }
+            Value::Symbolic(SymbolicTime { expr, .. }) => write!(f, "{expr:?}"),
+            Value::Recurrence(recurrence) => {
+                write!(f, "{} RRULE:{}", format_time_value(&recurrence.next.value), recurrence.rrule())
+            }
+            // Whole numbers print without a trailing `.0`.
+            Value::Numeral(Numeral { value: v, .. }) if v.fract() == 0.0 => write!(f, "{}", *v as i64),
+            Value::Numeral(Numeral { value: v, .. }) => write!(f, "{}", v),
+            Value::Identifier(Identifier { code, .. }) => f.write_str(code),
+        }
+    }
+}
+
+/// Surface form written by [`Value::canonical`] and
+/// [`Entity::canonical`](crate::Entity::canonical), e.g. for showing parsed
+/// times as normalized chips.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CanonicalFormat {
+    /// ISO 8601 at the grain the input named ("2025-06-20",
+    /// "2025-06-20T15:00:00"); times stated in a zone carry its offset and,
+    /// when known, the IANA name ("2025-06-20T15:00:00-07:00[America/Los_Angeles]").
+    /// Intervals are `start/end`, open ones `start/..` and `../end`.
+    #[default]
+    Iso,
+    /// Short readable text: "Fri 20 Jun 2025 15:00 PT", "every Monday 09:00".
+    Readable,
+}
+
+impl Value {
+    /// This value's canonical surface form in `format`.
+    pub fn canonical(&self, format: CanonicalFormat) -> String {
+        match self {
+            Value::Time(time) => canonical_time(time, format),
+            Value::PartialDate(PartialDate { month, day }) => match format {
+                CanonicalFormat::Iso => format!("--{month:02}-{day:02}"),
+                CanonicalFormat::Readable => match chrono::NaiveDate::from_ymd_opt(2000, *month, *day) {
+                    Some(date) => date.format("%-d %b").to_string(),
+                    None => self.to_string(),
+                },
+            },
+            Value::Times(times) => times.iter().map(|time| canonical_time(time, format)).collect::<Vec<_>>().join(", "),
+            Value::Recurrence(recurrence) => match format {
+                CanonicalFormat::Iso => {
+                    format!("{} RRULE:{}", canonical_time(&recurrence.next, format), recurrence.rrule())
+                }
+                CanonicalFormat::Readable => readable_recurrence(recurrence),
+            },
+            Value::Symbolic(_) | Value::Numeral(_) | Value::Identifier(_) => self.to_string(),
+        }
+    }
+}
+
+fn is_date_grain(grain: Grain) -> bool {
+    matches!(grain, Grain::Day | Grain::Week | Grain::Month | Grain::Quarter | Grain::Year)
+}
+
+fn canonical_time(time: &Time, format: CanonicalFormat) -> String {
+    // A time stated in a zone reads best as it was stated.
+    let (value, zone) = match &time.timezone {
+        Some(zone) => (&zone.local, Some(zone)),
+        None => (&time.value, None),
+    };
+    let point = |dt: NaiveDateTime| match format {
+        CanonicalFormat::Iso => iso_point(dt, time.grain, zone),
+        CanonicalFormat::Readable => readable_point(dt, time.grain, zone),
+    };
+    match (value, format) {
+        (TimeValue::Instant(dt), _) => point(*dt),
+        // A whole year, month or day reads as that unit ("2014", not "2014/2015").
+        (TimeValue::Interval { start, end }, _) if whole_unit(*start, *end).is_some() => {
+            let grain = whole_unit(*start, *end).unwrap_or(time.grain);
+            match format {
+                CanonicalFormat::Iso => iso_point(*start, grain, zone),
+                CanonicalFormat::Readable => readable_point(*start, grain, zone),
+            }
+        }
+        (TimeValue::Interval { start, end }, CanonicalFormat::Iso) => format!("{}/{}", point(*start), point(*end)),
+        (TimeValue::Interval { start, end }, CanonicalFormat::Readable) => {
+            if start.date() == end.date() && !is_date_grain(time.grain) {
+                format!("{}–{}", point(*start), end.format("%H:%M"))
+            } else {
+                format!("{} – {}", point(*start), point(*end))
+            }
+        }
+        (TimeValue::OpenAfter(dt), CanonicalFormat::Iso) => format!("{}/..", point(*dt)),
+        (TimeValue::OpenBefore(dt), CanonicalFormat::Iso) => format!("../{}", point(*dt)),
+        (TimeValue::OpenAfter(dt), CanonicalFormat::Readable) => format!("from {}", point(*dt)),
+        (TimeValue::OpenBefore(dt), CanonicalFormat::Readable) => format!("until {}", point(*dt)),
+    }
+}
+
+/// The grain an interval spans exactly one aligned unit of, if any.
+fn whole_unit(start: NaiveDateTime, end: NaiveDateTime) -> Option<Grain> {
+    if start.time() != chrono::NaiveTime::MIN {
+        return None;
+    }
+    let date = start.date();
+    if date.ordinal() == 1 && date.with_year(date.year() + 1).map(|next| next.and_time(start.time())) == Some(end) {
+        Some(Grain::Year)
+    } else if date.day() == 1
+        && date.checked_add_months(chrono::Months::new(1)).map(|d| d.and_time(start.time())) == Some(end)
+    {
+        Some(Grain::Month)
+    } else if date.succ_opt().map(|d| d.and_time(start.time())) == Some(end) {
+        Some(Grain::Day)
+    } else {
+        None
+    }
+}
+
+fn iso_point(dt: NaiveDateTime, grain: Grain, zone: Option<&Timezone>) -> String {
+    let midnight = dt.time() == chrono::NaiveTime::MIN;
+    match grain {
+        Grain::Year if midnight && dt.ordinal() == 1 => return dt.format("%Y").to_string(),
+        Grain::Month | Grain::Quarter if midnight && dt.day() == 1 => return dt.format("%Y-%m").to_string(),
+        Grain::Day | Grain::Week | Grain::Month | Grain::Quarter | Grain::Year if midnight => {
+            return dt.format("%Y-%m-%d").to_string();
+        }
+        _ => {}
+    }
+    let mut text = dt.format("%Y-%m-%dT%H:%M:%S").to_string();
+    if let Some(zone) = zone {
+        let offset = zone.utc_offset_seconds;
+        let sign = if offset < 0 { '-' } else { '+' };
+        let minutes = offset.abs() / 60;
+        text.push_str(&format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60));
+        if let Some(Zone::Iana(tz)) = lookup_zone(&zone.name) {
+            text.push_str(&format!("[{}]", tz.name()));
+        }
+    }
+    text
+}
+
+fn readable_point(dt: NaiveDateTime, grain: Grain, zone: Option<&Timezone>) -> String {
+    let mut text = match grain {
+        Grain::Year => dt.format("%Y").to_string(),
+        Grain::Month | Grain::Quarter => dt.format("%b %Y").to_string(),
+        Grain::Day | Grain::Week => dt.format("%a %-d %b %Y").to_string(),
+        Grain::Second if dt.second() != 0 => dt.format("%a %-d %b %Y %H:%M:%S").to_string(),
+        _ => dt.format("%a %-d %b %Y %H:%M").to_string(),
+    };
+    if let Some(zone) = zone {
+        text.push(' ');
+        text.push_str(&zone.name);
+    }
+    text
+}
+
+/// `n` with its English ordinal suffix ("1st", "12th", "22nd").
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+fn readable_recurrence(recurrence: &Recurrence) -> String {
+    let unit = match recurrence.frequency {
+        Grain::Second => "second",
+        Grain::Minute => "minute",
+        Grain::Hour => "hour",
+        Grain::Day => "day",
+        Grain::Week => "week",
+        Grain::Month => "month",
+        Grain::Quarter => "quarter",
+        Grain::Year => "year",
+    };
+    let every = match recurrence.interval {
+        0 | 1 => format!("every {unit}"),
+        n => format!("every {n} {unit}s"),
+    };
+    let weekday = recurrence.by_day.map(|day| match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    });
+    let mut text = match (weekday, recurrence.by_set_pos, recurrence.frequency, recurrence.interval) {
+        (Some(day), None, Grain::Week, 0 | 1) => format!("every {day}"),
+        (Some(day), None, _, _) => format!("{every} on {day}"),
+        (Some(day), Some(pos), _, _) => {
+            let nth = match pos {
+                -1 => "last".to_string(),
+                1 => "first".to_string(),
+                2 => "second".to_string(),
+                3 => "third".to_string(),
+                4 => "fourth".to_string(),
+                n => format!("{n}th"),
+            };
+            format!("{every} on the {nth} {day}")
+        }
+        (None, _, _, _) if !recurrence.by_month_day.is_empty() => {
+            let days: Vec<String> = recurrence.by_month_day.iter().map(|&day| ordinal(day)).collect();
+            let days = match days.split_last() {
+                Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+                _ => days.concat(),
+            };
+            format!("{every} on the {days}")
+        }
+        (None, _, _, _) => every,
+    };
+    if let (TimeValue::Instant(next), false) = (&recurrence.next.value, is_date_grain(recurrence.next.grain)) {
+        text.push_str(&next.format(" %H:%M").to_string());
+    }
+    text
+}